@@ -0,0 +1,585 @@
+//! Support for the [Confluent Schema Registry][schema_registry] and the wire format of the
+//! messages whose schemas it stores.
+//!
+//! Each message starts with a zero magic byte and the ID of its schema, as a big-endian 32-bit
+//! integer. Protobuf messages are followed by the indexes locating their message type in the
+//! schema, and then by the encoded message.
+//!
+//! [schema_registry]: https://docs.confluent.io/platform/current/schema-registry/index.html
+
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+    sync::{mpsc, Arc, RwLock},
+    time::{Duration, Instant},
+};
+
+use base64::prelude::{Engine as _, BASE64_STANDARD};
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use derivative::Derivative;
+use prost_reflect::{
+    prost::Message as _, prost_types::FileDescriptorProto, DescriptorPool, FileDescriptor,
+    MessageDescriptor,
+};
+use serde::{Deserialize, Serialize};
+use tokio::runtime::RuntimeFlavor;
+use vector_common::sensitive_string::SensitiveString;
+use vector_config::configurable_component;
+
+const MAGIC_BYTE: u8 = 0;
+const CONTENT_TYPE: &str = "application/vnd.schemaregistry.v1+json";
+
+/// The backoff after a first failure to resolve a schema, doubled after each further failure.
+const MIN_BACKOFF: Duration = Duration::from_secs(1);
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+const fn default_timeout_secs() -> u64 {
+    30
+}
+
+/// Options for connecting to a [Confluent Schema Registry][schema_registry].
+///
+/// [schema_registry]: https://docs.confluent.io/platform/current/schema-registry/index.html
+#[configurable_component]
+#[derive(Clone, Debug, Derivative)]
+#[derivative(Default)]
+pub struct SchemaRegistryOptions {
+    /// The URL of the Schema Registry.
+    #[configurable(metadata(docs::examples = "http://localhost:8081"))]
+    pub url: String,
+
+    #[configurable(derived)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth: Option<SchemaRegistryAuth>,
+
+    /// The timeout for requests to the Schema Registry, in seconds.
+    #[serde(default = "default_timeout_secs")]
+    #[derivative(Default(value = "default_timeout_secs()"))]
+    #[configurable(metadata(docs::type_unit = "seconds"))]
+    #[configurable(metadata(docs::human_name = "Timeout"))]
+    pub timeout_secs: u64,
+}
+
+/// Basic authentication for the Schema Registry.
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub struct SchemaRegistryAuth {
+    /// The username to authenticate with.
+    #[configurable(metadata(docs::examples = "${SCHEMA_REGISTRY_USERNAME}"))]
+    pub user: String,
+
+    /// The password to authenticate with.
+    #[configurable(metadata(docs::examples = "${SCHEMA_REGISTRY_PASSWORD}"))]
+    pub password: SensitiveString,
+}
+
+/// The type of a schema stored by the Schema Registry.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub(crate) enum SchemaType {
+    #[default]
+    Avro,
+    Protobuf,
+    Json,
+}
+
+/// A reference from a schema to a schema registered under another subject.
+#[derive(Clone, Debug, Deserialize)]
+struct SchemaReference {
+    name: String,
+    subject: String,
+    version: i32,
+}
+
+/// A schema as returned by the Schema Registry.
+///
+/// Protobuf schemas are requested in their serialized format, a base64-encoded
+/// `FileDescriptorProto`, so that they don't need to be compiled.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RegisteredSchema {
+    #[serde(default)]
+    pub(crate) id: Option<u32>,
+    pub(crate) schema: String,
+    #[serde(default)]
+    pub(crate) schema_type: SchemaType,
+    #[serde(default)]
+    references: Vec<SchemaReference>,
+}
+
+#[derive(Deserialize)]
+struct RegisterResponse {
+    id: u32,
+}
+
+type Request = Box<dyn FnOnce(&reqwest::blocking::Client) + Send>;
+
+/// A client of the Schema Registry.
+///
+/// Requests are sent from a thread of the client, sharing a single HTTP client, as the blocking
+/// HTTP client can't be used from the threads of the runtime. The thread stops once every clone of
+/// the client has been dropped.
+#[derive(Clone, Debug)]
+pub(crate) struct SchemaRegistryClient {
+    options: SchemaRegistryOptions,
+    requests: mpsc::Sender<Request>,
+}
+
+impl SchemaRegistryClient {
+    pub(crate) fn new(options: SchemaRegistryOptions) -> vector_common::Result<Self> {
+        if options.url.is_empty() {
+            return Err("The Schema Registry URL is required.".into());
+        }
+
+        let timeout = Duration::from_secs(options.timeout_secs);
+        let (requests, receiver) = mpsc::channel::<Request>();
+        let (built, built_receiver) = mpsc::sync_channel(1);
+        std::thread::Builder::new()
+            .name("schema-registry".into())
+            .spawn(move || {
+                let client = match reqwest::blocking::Client::builder()
+                    .timeout(timeout)
+                    .build()
+                {
+                    Ok(client) => client,
+                    Err(error) => {
+                        _ = built.send(Err(error));
+                        return;
+                    }
+                };
+                _ = built.send(Ok(()));
+                for request in receiver {
+                    request(&client);
+                }
+            })?;
+        wait(|| built_receiver.recv()).map_err(|_| {
+            vector_common::Error::from("Failed building the Schema Registry client.")
+        })??;
+
+        Ok(Self { options, requests })
+    }
+
+    /// Returns the schema with the given ID.
+    pub(crate) fn schema_by_id(&self, id: u32) -> vector_common::Result<RegisteredSchema> {
+        let mut schema: RegisteredSchema = self.request(
+            reqwest::Method::GET,
+            &format!("schemas/ids/{id}?format=serialized"),
+            None,
+        )?;
+        schema.id = Some(id);
+        Ok(schema)
+    }
+
+    /// Returns the latest schema registered under the given subject.
+    pub(crate) fn latest_schema(&self, subject: &str) -> vector_common::Result<RegisteredSchema> {
+        self.subject_version(subject, "latest")
+    }
+
+    fn subject_version(
+        &self,
+        subject: &str,
+        version: &str,
+    ) -> vector_common::Result<RegisteredSchema> {
+        self.request(
+            reqwest::Method::GET,
+            &format!("subjects/{subject}/versions/{version}?format=serialized"),
+            None,
+        )
+    }
+
+    /// Registers the schema under the given subject, returning its ID. Registering a schema that
+    /// is already registered under the subject returns its existing ID.
+    pub(crate) fn register(
+        &self,
+        subject: &str,
+        schema: &str,
+        schema_type: SchemaType,
+    ) -> vector_common::Result<u32> {
+        let body = serde_json::json!({ "schema": schema, "schemaType": schema_type });
+        let response: RegisterResponse = self.request(
+            reqwest::Method::POST,
+            &format!("subjects/{subject}/versions"),
+            Some(body),
+        )?;
+        Ok(response.id)
+    }
+
+    /// Parses an Avro schema, along with the schemas defining the named types it references.
+    pub(crate) fn avro_schema(
+        &self,
+        schema: &RegisteredSchema,
+    ) -> vector_common::Result<apache_avro::Schema> {
+        let mut definitions = Vec::new();
+        self.collect_references(schema, &mut HashSet::new(), &mut definitions)?;
+        definitions.push(schema.schema.clone());
+
+        let definitions = definitions.iter().map(String::as_str).collect::<Vec<_>>();
+        apache_avro::Schema::parse_list(&definitions)?
+            .pop()
+            .ok_or_else(|| "Empty Avro schema.".into())
+    }
+
+    /// Builds the file descriptor of a Protobuf schema, along with the files it imports.
+    pub(crate) fn protobuf_file(
+        &self,
+        schema: &RegisteredSchema,
+    ) -> vector_common::Result<FileDescriptor> {
+        let mut pool = DescriptorPool::global();
+        let mut files = Vec::new();
+        self.collect_protobuf_files(schema, &pool, &mut HashSet::new(), &mut files)?;
+
+        let mut file = decode_file_descriptor(&schema.schema)?;
+        let name = file
+            .name
+            .get_or_insert_with(|| format!("schema_{}.proto", schema.id.unwrap_or_default()))
+            .clone();
+        files.push(file);
+        pool.add_file_descriptor_protos(files)?;
+
+        pool.get_file_by_name(&name)
+            .ok_or_else(|| format!("Missing Protobuf file {name:?}.").into())
+    }
+
+    /// Collects the files imported by a Protobuf schema, dependencies first, named after the
+    /// imports referencing them.
+    fn collect_protobuf_files(
+        &self,
+        schema: &RegisteredSchema,
+        pool: &DescriptorPool,
+        visited: &mut HashSet<String>,
+        files: &mut Vec<FileDescriptorProto>,
+    ) -> vector_common::Result<()> {
+        for reference in &schema.references {
+            // The well-known types are already part of the global pool.
+            if pool.get_file_by_name(&reference.name).is_some()
+                || !visited.insert(reference.name.clone())
+            {
+                continue;
+            }
+            let referenced =
+                self.subject_version(&reference.subject, &reference.version.to_string())?;
+            self.collect_protobuf_files(&referenced, pool, visited, files)?;
+            let mut file = decode_file_descriptor(&referenced.schema)?;
+            file.name = Some(reference.name.clone());
+            files.push(file);
+        }
+        Ok(())
+    }
+
+    /// Collects the definitions of the schemas referenced by the schema, dependencies first.
+    fn collect_references(
+        &self,
+        schema: &RegisteredSchema,
+        visited: &mut HashSet<(String, i32)>,
+        definitions: &mut Vec<String>,
+    ) -> vector_common::Result<()> {
+        for reference in &schema.references {
+            if !visited.insert((reference.subject.clone(), reference.version)) {
+                continue;
+            }
+            let referenced =
+                self.subject_version(&reference.subject, &reference.version.to_string())?;
+            self.collect_references(&referenced, visited, definitions)?;
+            definitions.push(referenced.schema);
+        }
+        Ok(())
+    }
+
+    /// Sends a request to the Schema Registry, through the thread of the client.
+    fn request<T>(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: Option<serde_json::Value>,
+    ) -> vector_common::Result<T>
+    where
+        T: serde::de::DeserializeOwned + Send + 'static,
+    {
+        let url = format!("{}/{path}", self.options.url.trim_end_matches('/'));
+        let auth = self.options.auth.clone();
+        let (sender, receiver) = mpsc::sync_channel(1);
+        self.requests
+            .send(Box::new(move |client| {
+                _ = sender.send(send_request(client, method, &url, auth.as_ref(), body));
+            }))
+            .map_err(|_| "The Schema Registry client has stopped.")?;
+        wait(|| receiver.recv())
+            .map_err(|_| vector_common::Error::from("Schema Registry request panicked."))?
+    }
+}
+
+fn send_request<T>(
+    client: &reqwest::blocking::Client,
+    method: reqwest::Method,
+    url: &str,
+    auth: Option<&SchemaRegistryAuth>,
+    body: Option<serde_json::Value>,
+) -> vector_common::Result<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let mut request = client
+        .request(method, url)
+        .header(reqwest::header::ACCEPT, CONTENT_TYPE);
+    if let Some(auth) = auth {
+        request = request.basic_auth(&auth.user, Some(auth.password.inner()));
+    }
+    if let Some(body) = body {
+        request = request
+            .header(reqwest::header::CONTENT_TYPE, CONTENT_TYPE)
+            .body(body.to_string());
+    }
+
+    let response = request.send()?;
+    let status = response.status();
+    if !status.is_success() {
+        let message = response.text().unwrap_or_default();
+        return Err(format!(
+            "Schema Registry request to {url} failed with status {status}: {message}"
+        )
+        .into());
+    }
+    Ok(response.json()?)
+}
+
+/// Waits for the result of a request sent from a codec.
+///
+/// Codecs are synchronous, so when waiting on a worker thread of a multi-threaded runtime, its
+/// other tasks are handed over to another thread in the meantime.
+fn wait<T>(f: impl FnOnce() -> T) -> T {
+    match tokio::runtime::Handle::try_current() {
+        Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => {
+            tokio::task::block_in_place(f)
+        }
+        _ => f(),
+    }
+}
+
+/// A cache of the schemas resolved by a codec, keyed by schema ID or subject.
+///
+/// Failures to resolve a schema are cached as well, so that messages using it fail without
+/// sending a request to the Schema Registry until the backoff of the failure has elapsed.
+#[derive(Debug)]
+pub(crate) struct SchemaCache<K, V> {
+    entries: RwLock<HashMap<K, CacheEntry<V>>>,
+}
+
+#[derive(Debug)]
+enum CacheEntry<V> {
+    Resolved(Arc<V>),
+    Failed {
+        error: String,
+        failures: u32,
+        retry_at: Instant,
+    },
+}
+
+impl<K, V> Default for SchemaCache<K, V> {
+    fn default() -> Self {
+        Self {
+            entries: RwLock::default(),
+        }
+    }
+}
+
+impl<K: Eq + Hash, V> SchemaCache<K, V> {
+    /// Returns the cached value of the key, resolving it if it isn't cached yet, or if resolving
+    /// it failed and the backoff of the failure has elapsed.
+    pub(crate) fn get_or_resolve(
+        &self,
+        key: K,
+        resolve: impl FnOnce() -> vector_common::Result<V>,
+    ) -> vector_common::Result<Arc<V>> {
+        let failures = match self.entries.read().expect("poisoned lock").get(&key) {
+            Some(CacheEntry::Resolved(value)) => return Ok(Arc::clone(value)),
+            Some(CacheEntry::Failed {
+                error, retry_at, ..
+            }) if Instant::now() < *retry_at => return Err(error.clone().into()),
+            Some(CacheEntry::Failed { failures, .. }) => *failures,
+            None => 0,
+        };
+
+        let (entry, result) = match resolve() {
+            Ok(value) => {
+                let value = Arc::new(value);
+                (CacheEntry::Resolved(Arc::clone(&value)), Ok(value))
+            }
+            Err(error) => {
+                let backoff = MIN_BACKOFF
+                    .saturating_mul(2u32.saturating_pow(failures))
+                    .min(MAX_BACKOFF);
+                let entry = CacheEntry::Failed {
+                    error: error.to_string(),
+                    failures: failures.saturating_add(1),
+                    retry_at: Instant::now() + backoff,
+                };
+                (entry, Err(error))
+            }
+        };
+        self.entries
+            .write()
+            .expect("poisoned lock")
+            .insert(key, entry);
+        result
+    }
+}
+
+fn decode_file_descriptor(schema: &str) -> vector_common::Result<FileDescriptorProto> {
+    let bytes = BASE64_STANDARD.decode(schema)?;
+    Ok(FileDescriptorProto::decode(bytes.as_slice())?)
+}
+
+/// Splits a message into the ID of its schema and its payload.
+pub(crate) fn split_schema_id(mut bytes: Bytes) -> vector_common::Result<(u32, Bytes)> {
+    if bytes.len() < 5 || bytes[0] != MAGIC_BYTE {
+        return Err("Expected the message to start with a Schema Registry schema ID.".into());
+    }
+    bytes.advance(1);
+    let id = bytes.get_u32();
+    Ok((id, bytes))
+}
+
+/// Writes the header of a message encoded with the schema of the given ID.
+pub(crate) fn put_schema_id(id: u32, buffer: &mut BytesMut) {
+    buffer.put_u8(MAGIC_BYTE);
+    buffer.put_u32(id);
+}
+
+/// Reads the indexes of a Protobuf message type, as zigzag-encoded varints preceded by their
+/// count. A zero count stands for the first message type of the schema.
+pub(crate) fn read_message_indexes(bytes: &mut Bytes) -> vector_common::Result<Vec<i32>> {
+    let count = read_zigzag(bytes)?;
+    if count == 0 {
+        return Ok(vec![0]);
+    }
+    (0..count).map(|_| read_zigzag(bytes)).collect()
+}
+
+/// Writes the indexes of a Protobuf message type.
+pub(crate) fn put_message_indexes(indexes: &[i32], buffer: &mut BytesMut) {
+    if indexes == [0] {
+        put_zigzag(0, buffer);
+        return;
+    }
+    put_zigzag(indexes.len() as i32, buffer);
+    for index in indexes {
+        put_zigzag(*index, buffer);
+    }
+}
+
+fn read_zigzag(bytes: &mut Bytes) -> vector_common::Result<i32> {
+    let value = prost_reflect::prost::encoding::decode_varint(bytes)? as u32;
+    Ok(((value >> 1) as i32) ^ -((value & 1) as i32))
+}
+
+fn put_zigzag(value: i32, buffer: &mut BytesMut) {
+    let value = ((value << 1) ^ (value >> 31)) as u32;
+    prost_reflect::prost::encoding::encode_varint(value.into(), buffer);
+}
+
+/// Finds the message type located by the indexes, the first one locating a top-level message
+/// type of the file and the following ones nested message types.
+pub(crate) fn message_by_indexes(
+    file: &FileDescriptor,
+    indexes: &[i32],
+) -> vector_common::Result<MessageDescriptor> {
+    let not_found = || format!("No Protobuf message type at indexes {indexes:?}.");
+    let (first, nested) = indexes.split_first().ok_or_else(not_found)?;
+    let mut message = file
+        .messages()
+        .nth(usize::try_from(*first)?)
+        .ok_or_else(not_found)?;
+    for index in nested {
+        message = message
+            .child_messages()
+            .nth(usize::try_from(*index)?)
+            .ok_or_else(not_found)?;
+    }
+    Ok(message)
+}
+
+/// Returns the indexes locating the message type in its file.
+pub(crate) fn message_indexes(message: &MessageDescriptor) -> Vec<i32> {
+    let mut indexes = Vec::new();
+    let mut current = message.clone();
+    loop {
+        let parent = current.parent_message();
+        let position = match &parent {
+            Some(parent) => parent
+                .child_messages()
+                .position(|child| child.full_name() == current.full_name()),
+            None => current
+                .parent_file()
+                .messages()
+                .position(|sibling| sibling.full_name() == current.full_name()),
+        };
+        indexes.push(position.unwrap_or_default() as i32);
+        match parent {
+            Some(parent) => current = parent,
+            None => break,
+        }
+    }
+    indexes.reverse();
+    indexes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_schema_id() {
+        let bytes = Bytes::from_static(&[0, 0, 0, 1, 2, 42]);
+        let (id, payload) = split_schema_id(bytes).unwrap();
+        assert_eq!(id, 258);
+        assert_eq!(payload, Bytes::from_static(&[42]));
+
+        assert!(split_schema_id(Bytes::from_static(&[1, 0, 0, 0, 1, 42])).is_err());
+        assert!(split_schema_id(Bytes::from_static(&[0, 0])).is_err());
+    }
+
+    #[test]
+    fn caches_failures_with_backoff() {
+        let cache = SchemaCache::<u32, u32>::default();
+        let error = cache
+            .get_or_resolve(1, || Err("unavailable".into()))
+            .unwrap_err();
+        assert_eq!(error.to_string(), "unavailable");
+
+        // The failure is returned again without resolving the schema during the backoff.
+        let error = cache
+            .get_or_resolve(1, || panic!("resolved during the backoff"))
+            .unwrap_err();
+        assert_eq!(error.to_string(), "unavailable");
+
+        assert_eq!(*cache.get_or_resolve(2, || Ok(42)).unwrap(), 42);
+        assert_eq!(
+            *cache
+                .get_or_resolve(2, || panic!("resolved a cached schema"))
+                .unwrap(),
+            42
+        );
+    }
+
+    #[test]
+    fn round_trips_message_indexes() {
+        for indexes in [vec![0], vec![1], vec![0, 2], vec![3, 0, 1]] {
+            let mut buffer = BytesMut::new();
+            put_message_indexes(&indexes, &mut buffer);
+            let mut bytes = buffer.freeze();
+            assert_eq!(read_message_indexes(&mut bytes).unwrap(), indexes);
+            assert!(bytes.is_empty());
+        }
+    }
+
+    #[test]
+    fn writes_first_message_index_as_single_byte() {
+        let mut buffer = BytesMut::new();
+        put_message_indexes(&[0], &mut buffer);
+        assert_eq!(buffer.as_ref(), &[0]);
+
+        let mut buffer = BytesMut::new();
+        put_message_indexes(&[1, 2], &mut buffer);
+        assert_eq!(buffer.as_ref(), &[4, 2, 4]);
+    }
+}
@@ -0,0 +1,337 @@
+use bytes::Bytes;
+use derivative::Derivative;
+use lookup::owned_value_path;
+use smallvec::{smallvec, SmallVec};
+use vector_config::configurable_component;
+use vector_core::{
+    config::{DataType, LogNamespace},
+    event::{Event, LogEvent},
+    schema,
+};
+use vrl::value::{kind::Collection, Kind, ObjectMap, Value};
+
+use super::{default_lossy, Deserializer};
+
+/// The fields the CEF header is decoded into, in order.
+const HEADER_FIELDS: [&str; 7] = [
+    "cefVersion",
+    "deviceVendor",
+    "deviceProduct",
+    "deviceVersion",
+    "deviceEventClassId",
+    "name",
+    "severity",
+];
+
+/// The suffix of the extension keys that name the value of another extension.
+const LABEL_SUFFIX: &str = "Label";
+
+/// Config used to build a `CefDeserializer`.
+#[configurable_component]
+#[derive(Debug, Clone, Default)]
+pub struct CefDeserializerConfig {
+    /// CEF-specific decoding options.
+    #[serde(default, skip_serializing_if = "vector_core::serde::is_default")]
+    pub cef: CefDeserializerOptions,
+}
+
+impl CefDeserializerConfig {
+    /// Creates a new `CefDeserializerConfig`.
+    pub const fn new(cef: CefDeserializerOptions) -> Self {
+        Self { cef }
+    }
+
+    /// Build the `CefDeserializer` from this configuration.
+    pub fn build(&self) -> CefDeserializer {
+        CefDeserializer {
+            options: self.cef.clone(),
+        }
+    }
+
+    /// Return the type of event build by this deserializer.
+    pub fn output_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema produced by the deserializer.
+    pub fn schema_definition(&self, log_namespace: LogNamespace) -> schema::Definition {
+        HEADER_FIELDS
+            .iter()
+            .fold(
+                schema::Definition::new_with_default_metadata(
+                    Kind::object(Collection::empty()),
+                    [log_namespace],
+                ),
+                |definition, field| {
+                    definition.with_event_field(&owned_value_path!(*field), Kind::bytes(), None)
+                },
+            )
+            // The extensions.
+            .unknown_fields(Kind::bytes())
+    }
+}
+
+/// CEF-specific decoding options.
+#[configurable_component]
+#[derive(Debug, Clone, PartialEq, Eq, Derivative)]
+#[derivative(Default)]
+pub struct CefDeserializerOptions {
+    /// Determines whether or not to replace invalid UTF-8 sequences instead of failing.
+    ///
+    /// When true, invalid UTF-8 sequences are replaced with the [`U+FFFD REPLACEMENT CHARACTER`][U+FFFD].
+    ///
+    /// [U+FFFD]: https://en.wikipedia.org/wiki/Specials_(Unicode_block)#Replacement_character
+    #[serde(
+        default = "default_lossy",
+        skip_serializing_if = "vector_core::serde::is_default"
+    )]
+    #[derivative(Default(value = "default_lossy()"))]
+    pub lossy: bool,
+
+    /// Whether to name the values of custom extensions after their labels.
+    ///
+    /// Custom extensions, such as `cs1`, come with a label extension, such as `cs1Label`. When
+    /// enabled, the pair of extensions is replaced by a single field named after the label.
+    #[serde(default, skip_serializing_if = "vector_core::serde::is_default")]
+    pub translate_custom_fields: bool,
+}
+
+/// Deserializer that builds an `Event` from a byte frame containing a CEF message.
+///
+/// The fields of the header are stored in `cefVersion`, `deviceVendor`, `deviceProduct`,
+/// `deviceVersion`, `deviceEventClassId`, `name`, and `severity`, and each extension is stored in
+/// the field named after its key. Any text preceding `CEF:`, such as a Syslog header, is ignored.
+#[derive(Debug, Clone, Default)]
+pub struct CefDeserializer {
+    options: CefDeserializerOptions,
+}
+
+impl CefDeserializer {
+    /// Creates a new `CefDeserializer`.
+    pub fn new(options: CefDeserializerOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl Deserializer for CefDeserializer {
+    fn parse(
+        &self,
+        bytes: Bytes,
+        _log_namespace: LogNamespace,
+    ) -> vector_common::Result<SmallVec<[Event; 1]>> {
+        let message = match self.options.lossy {
+            true => String::from_utf8_lossy(&bytes),
+            false => std::str::from_utf8(&bytes)?.into(),
+        };
+
+        let mut fields = parse_cef(&message)?;
+        if self.options.translate_custom_fields {
+            translate_custom_fields(&mut fields);
+        }
+
+        Ok(smallvec![LogEvent::from(fields).into()])
+    }
+}
+
+fn parse_cef(message: &str) -> Result<ObjectMap, String> {
+    let start = message.find("CEF:").ok_or("Missing CEF header.")?;
+    let mut rest = &message[start + "CEF:".len()..];
+
+    let mut fields = ObjectMap::new();
+    for (index, name) in HEADER_FIELDS.iter().enumerate() {
+        let (value, remaining) = header_field(rest).ok_or_else(|| {
+            format!(
+                "Expected {} CEF header fields, found {index}.",
+                HEADER_FIELDS.len()
+            )
+        })?;
+        fields.insert((*name).into(), Value::from(value.trim().to_owned()));
+        rest = remaining;
+    }
+
+    for (key, value) in extensions(rest) {
+        fields.insert(key.into(), Value::from(value));
+    }
+
+    Ok(fields)
+}
+
+/// Reads a header field, returning its unescaped value and the input following its delimiter.
+///
+/// The last field of the header may not be followed by a delimiter when there are no extensions.
+fn header_field(input: &str) -> Option<(String, &str)> {
+    let mut value = String::new();
+    let mut chars = input.char_indices();
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '|' => return Some((value, &input[index + 1..])),
+            '\\' => match chars.next() {
+                Some((_, c @ ('|' | '\\'))) => value.push(c),
+                Some((_, c)) => {
+                    value.push('\\');
+                    value.push(c);
+                }
+                None => value.push('\\'),
+            },
+            c => value.push(c),
+        }
+    }
+    (!value.is_empty()).then_some((value, ""))
+}
+
+/// Splits the extensions into their keys and unescaped values.
+///
+/// Values may contain spaces, so each value spans until the key of the next extension. Unescaped
+/// equal signs that don't follow a valid key, such as in URLs, are kept in the values.
+fn extensions(input: &str) -> Vec<(&str, String)> {
+    let mut keys = Vec::new();
+    let mut escaped = false;
+    for (index, c) in input.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '=' => {
+                let key_start = input[..index]
+                    .rfind(|c: char| c.is_ascii_whitespace())
+                    .map_or(0, |space| space + 1);
+                let key = &input[key_start..index];
+                let after_previous = keys.last().map_or(0, |&(_, _, value_start)| value_start);
+                if key_start >= after_previous && is_extension_key(key) {
+                    keys.push((key, key_start, index + 1));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    keys.iter()
+        .enumerate()
+        .map(|(position, &(key, _, value_start))| {
+            let value_end = keys
+                .get(position + 1)
+                .map_or(input.len(), |&(_, next_key_start, _)| next_key_start);
+            (
+                key,
+                unescape_extension(input[value_start..value_end].trim()),
+            )
+        })
+        .collect()
+}
+
+fn is_extension_key(key: &str) -> bool {
+    !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-' | '[' | ']'))
+}
+
+fn unescape_extension(value: &str) -> String {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some(c @ ('=' | '\\' | '|')) => unescaped.push(c),
+            Some(c) => {
+                unescaped.push('\\');
+                unescaped.push(c);
+            }
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
+
+fn translate_custom_fields(fields: &mut ObjectMap) {
+    let labeled = fields
+        .keys()
+        .filter_map(|key| key.as_str().strip_suffix(LABEL_SUFFIX))
+        .filter(|key| !key.is_empty() && fields.contains_key(*key))
+        .map(|key| key.to_owned())
+        .collect::<Vec<_>>();
+
+    for key in labeled {
+        let label = fields.remove(format!("{key}{LABEL_SUFFIX}").as_str());
+        let value = fields.remove(key.as_str());
+        if let (Some(Value::Bytes(label)), Some(value)) = (label, value) {
+            fields.insert(String::from_utf8_lossy(&label).as_ref().into(), value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vrl::btreemap;
+
+    use super::*;
+
+    fn deserialize(options: CefDeserializerOptions, input: &str) -> vector_common::Result<Value> {
+        let events = CefDeserializerConfig::new(options)
+            .build()
+            .parse(Bytes::from(input.to_owned()), LogNamespace::Legacy)?;
+        Ok(events[0].as_log().value().clone())
+    }
+
+    #[test]
+    fn deserialize_cef() {
+        let input = r#"Sep 29 08:26:10 host CEF:1|Security|threat\|manager|1.0|100|worm successfully stopped|10|src=10.0.0.1 dst=2.1.2.2 spt=1232 msg=Detected a threat. No action needed request=http://example.com/?a=b\=c"#;
+
+        assert_eq!(
+            deserialize(Default::default(), input).unwrap(),
+            Value::from(btreemap! {
+                "cefVersion" => "1",
+                "deviceVendor" => "Security",
+                "deviceProduct" => "threat|manager",
+                "deviceVersion" => "1.0",
+                "deviceEventClassId" => "100",
+                "name" => "worm successfully stopped",
+                "severity" => "10",
+                "src" => "10.0.0.1",
+                "dst" => "2.1.2.2",
+                "spt" => "1232",
+                "msg" => "Detected a threat. No action needed",
+                "request" => "http://example.com/?a=b=c",
+            })
+        );
+    }
+
+    #[test]
+    fn deserialize_cef_without_extensions() {
+        let value = deserialize(Default::default(), "CEF:0|Vendor|Product|1|42|Name|Low").unwrap();
+
+        assert_eq!(value.get("severity"), Some(&Value::from("Low")));
+        assert_eq!(value.as_object().unwrap().len(), HEADER_FIELDS.len());
+    }
+
+    #[test]
+    fn deserialize_cef_custom_fields() {
+        let options = CefDeserializerOptions {
+            translate_custom_fields: true,
+            ..Default::default()
+        };
+        let input =
+            r"CEF:0|Vendor|Product|1|42|Name|5|cs1Label=Department cs1=Sales and marketing cn1=7";
+
+        let value = deserialize(options, input).unwrap();
+
+        assert_eq!(
+            value.get("Department"),
+            Some(&Value::from("Sales and marketing"))
+        );
+        assert_eq!(value.get("cn1"), Some(&Value::from("7")));
+        assert_eq!(value.get("cs1"), None);
+        assert_eq!(value.get("cs1Label"), None);
+    }
+
+    #[test]
+    fn deserialize_cef_err() {
+        for input in ["not a CEF message", "CEF:0|Vendor|Product|1"] {
+            assert!(deserialize(Default::default(), input).is_err(), "{input}");
+        }
+    }
+}
@@ -0,0 +1,350 @@
+use bytes::Bytes;
+use csv_core::{ReadRecordResult, Reader, ReaderBuilder};
+use derivative::Derivative;
+use lookup::owned_value_path;
+use smallvec::SmallVec;
+use vector_config::configurable_component;
+use vector_core::{
+    config::{DataType, LogNamespace},
+    event::{Event, LogEvent},
+    schema,
+};
+use vrl::value::{kind::Collection, Kind, ObjectMap, Value};
+
+use super::Deserializer;
+
+/// Config used to build a `CsvDeserializer`.
+#[configurable_component]
+#[derive(Debug, Clone, Default)]
+pub struct CsvDeserializerConfig {
+    /// CSV-specific decoding options.
+    #[serde(default, skip_serializing_if = "vector_core::serde::is_default")]
+    pub csv: CsvDeserializerOptions,
+}
+
+impl CsvDeserializerConfig {
+    /// Creates a new `CsvDeserializerConfig`.
+    pub const fn new(csv: CsvDeserializerOptions) -> Self {
+        Self { csv }
+    }
+
+    /// Build the `CsvDeserializer` from this configuration.
+    pub fn build(&self) -> vector_common::Result<CsvDeserializer> {
+        if self.csv.columns.is_empty() && !self.csv.has_headers {
+            return Err(
+                "At least one CSV column must be specified, or `has_headers` must be enabled."
+                    .into(),
+            );
+        }
+        Ok(CsvDeserializer {
+            options: self.csv.clone(),
+        })
+    }
+
+    /// Return the type of event build by this deserializer.
+    pub fn output_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema produced by the deserializer.
+    pub fn schema_definition(&self, log_namespace: LogNamespace) -> schema::Definition {
+        let definition = self.csv.columns.iter().fold(
+            schema::Definition::new_with_default_metadata(
+                Kind::object(Collection::empty()),
+                [log_namespace],
+            ),
+            |definition, column| {
+                definition.optional_field(&owned_value_path!(column.as_str()), Kind::bytes(), None)
+            },
+        );
+
+        // The names of the columns are only known once the header is read.
+        if self.csv.has_headers && self.csv.columns.is_empty() {
+            definition.unknown_fields(Kind::bytes())
+        } else {
+            definition
+        }
+    }
+}
+
+/// CSV-specific decoding options.
+#[configurable_component]
+#[derive(Debug, Clone, PartialEq, Eq, Derivative)]
+#[derivative(Default)]
+pub struct CsvDeserializerOptions {
+    /// The names of the columns, in order.
+    ///
+    /// Each value of a record is stored in the field named after its column. Records with more
+    /// values than there are columns are rejected, and columns without a value are left out.
+    ///
+    /// When `has_headers` is enabled, these names take precedence over the ones of the header.
+    #[serde(default, skip_serializing_if = "vector_core::serde::is_default")]
+    #[configurable(metadata(docs::examples = "timestamp", docs::examples = "message"))]
+    pub columns: Vec<String>,
+
+    /// Whether the first record of each frame is a header holding the names of the columns.
+    ///
+    /// This is typically used along with `bytes` framing, so that whole CSV files are decoded
+    /// at once.
+    #[serde(default, skip_serializing_if = "vector_core::serde::is_default")]
+    pub has_headers: bool,
+
+    /// The field delimiter to use when reading CSV.
+    #[configurable(metadata(docs::type_override = "ascii_char"))]
+    #[serde(
+        default = "default_delimiter",
+        with = "vector_core::serde::ascii_char",
+        skip_serializing_if = "vector_core::serde::is_default"
+    )]
+    #[derivative(Default(value = "default_delimiter()"))]
+    pub delimiter: u8,
+
+    /// The quote character to use when reading CSV.
+    #[configurable(metadata(docs::type_override = "ascii_char"))]
+    #[serde(
+        default = "default_quote",
+        with = "vector_core::serde::ascii_char",
+        skip_serializing_if = "vector_core::serde::is_default"
+    )]
+    #[derivative(Default(value = "default_quote()"))]
+    pub quote: u8,
+
+    /// Enable double quote escapes.
+    ///
+    /// When enabled, two consecutive quotes within a quoted value are read as a single quote.
+    #[serde(
+        default = "default_double_quote",
+        skip_serializing_if = "vector_core::serde::is_default"
+    )]
+    #[derivative(Default(value = "default_double_quote()"))]
+    pub double_quote: bool,
+
+    /// The escape character to use when reading CSV.
+    ///
+    /// In some variants of CSV, quotes are escaped using a special escape character
+    /// like \ (instead of escaping quotes by doubling them).
+    ///
+    /// To use this, `double_quote` needs to be disabled as well otherwise it is ignored.
+    #[configurable(metadata(docs::type_override = "ascii_char"))]
+    #[serde(
+        default = "default_quote",
+        with = "vector_core::serde::ascii_char",
+        skip_serializing_if = "vector_core::serde::is_default"
+    )]
+    #[derivative(Default(value = "default_quote()"))]
+    pub escape: u8,
+}
+
+const fn default_delimiter() -> u8 {
+    b','
+}
+
+const fn default_quote() -> u8 {
+    b'"'
+}
+
+const fn default_double_quote() -> bool {
+    true
+}
+
+/// Deserializer that builds `Event`s from a byte frame containing CSV records.
+///
+/// Each record of the frame becomes its own event.
+#[derive(Debug, Clone)]
+pub struct CsvDeserializer {
+    options: CsvDeserializerOptions,
+}
+
+impl CsvDeserializer {
+    /// Creates a new `CsvDeserializer`.
+    pub fn new(options: CsvDeserializerOptions) -> Self {
+        Self { options }
+    }
+
+    fn reader(&self) -> Reader {
+        ReaderBuilder::new()
+            .delimiter(self.options.delimiter)
+            .quote(self.options.quote)
+            .double_quote(self.options.double_quote)
+            .escape(Some(self.options.escape))
+            .build()
+    }
+}
+
+impl Deserializer for CsvDeserializer {
+    fn parse(
+        &self,
+        bytes: Bytes,
+        _log_namespace: LogNamespace,
+    ) -> vector_common::Result<SmallVec<[Event; 1]>> {
+        let mut records = read_records(self.reader(), &bytes).into_iter();
+
+        let header = if self.options.has_headers {
+            records.next()
+        } else {
+            None
+        };
+        let columns = if self.options.columns.is_empty() {
+            header
+                .unwrap_or_default()
+                .iter()
+                .map(|name| String::from_utf8_lossy(name).into_owned())
+                .collect()
+        } else {
+            self.options.columns.clone()
+        };
+
+        records
+            .map(|record| {
+                if record.len() > columns.len() {
+                    return Err(format!(
+                        "CSV record has {} values, but only {} columns are known.",
+                        record.len(),
+                        columns.len()
+                    )
+                    .into());
+                }
+                let fields = columns
+                    .iter()
+                    .zip(record)
+                    .map(|(column, value)| (column.as_str().into(), Value::Bytes(value)))
+                    .collect::<ObjectMap>();
+                Ok(Event::from(LogEvent::from(fields)))
+            })
+            .collect()
+    }
+}
+
+/// Splits the given bytes into CSV records, each being the list of its values.
+fn read_records(mut reader: Reader, bytes: &[u8]) -> Vec<Vec<Bytes>> {
+    let mut records = Vec::new();
+    let mut input = bytes;
+    // The values of a record can't be longer than its input.
+    let mut output = vec![0; bytes.len().max(1)];
+    let mut ends = vec![0; 16];
+    let (mut output_len, mut ends_len) = (0, 0);
+
+    loop {
+        let (result, read, written, ended) =
+            reader.read_record(input, &mut output[output_len..], &mut ends[ends_len..]);
+        input = &input[read..];
+        output_len += written;
+        ends_len += ended;
+
+        match result {
+            // Reading from an empty input marks the end of the last record.
+            ReadRecordResult::InputEmpty => {}
+            ReadRecordResult::OutputFull => output.resize(output.len() * 2, 0),
+            ReadRecordResult::OutputEndsFull => ends.resize(ends.len() * 2, 0),
+            ReadRecordResult::Record => {
+                let mut start = 0;
+                let record = ends[..ends_len]
+                    .iter()
+                    .map(|&end| {
+                        let value = Bytes::copy_from_slice(&output[start..end]);
+                        start = end;
+                        value
+                    })
+                    .collect();
+                records.push(record);
+                (output_len, ends_len) = (0, 0);
+            }
+            ReadRecordResult::End => break,
+        }
+    }
+
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use vrl::btreemap;
+
+    use super::*;
+
+    fn deserializer(options: CsvDeserializerOptions) -> CsvDeserializer {
+        CsvDeserializerConfig::new(options).build().unwrap()
+    }
+
+    #[test]
+    fn deserialize_csv_columns() {
+        let deserializer = deserializer(CsvDeserializerOptions {
+            columns: vec!["host".into(), "message".into(), "status".into()],
+            ..Default::default()
+        });
+        let input = Bytes::from("localhost,\"GET /, then \"\"POST\"\"\"\nexample.com,PUT,500\n");
+
+        for namespace in [LogNamespace::Legacy, LogNamespace::Vector] {
+            let events = deserializer.parse(input.clone(), namespace).unwrap();
+            let logs = events
+                .iter()
+                .map(|event| event.as_log().value().clone())
+                .collect::<Vec<_>>();
+
+            assert_eq!(
+                logs,
+                vec![
+                    Value::from(btreemap! {
+                        "host" => "localhost",
+                        "message" => "GET /, then \"POST\"",
+                    }),
+                    Value::from(btreemap! {
+                        "host" => "example.com",
+                        "message" => "PUT",
+                        "status" => "500",
+                    }),
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn deserialize_csv_headers() {
+        let deserializer = deserializer(CsvDeserializerOptions {
+            has_headers: true,
+            delimiter: b';',
+            escape: b'\\',
+            double_quote: false,
+            ..Default::default()
+        });
+        let input = Bytes::from("name;quote\r\nalice;\"a \\\"b\\\"\"\r\nbob;c");
+
+        let events = deserializer.parse(input, LogNamespace::Legacy).unwrap();
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].as_log()["name"], "alice".into());
+        assert_eq!(events[0].as_log()["quote"], "a \"b\"".into());
+        assert_eq!(events[1].as_log()["name"], "bob".into());
+        assert_eq!(events[1].as_log()["quote"], "c".into());
+    }
+
+    #[test]
+    fn deserialize_csv_too_many_values() {
+        let deserializer = deserializer(CsvDeserializerOptions {
+            columns: vec!["message".into()],
+            ..Default::default()
+        });
+
+        assert!(deserializer
+            .parse(Bytes::from("a,b"), LogNamespace::Legacy)
+            .is_err());
+    }
+
+    #[test]
+    fn deserialize_csv_skip_empty() {
+        let deserializer = deserializer(CsvDeserializerOptions {
+            columns: vec!["message".into()],
+            ..Default::default()
+        });
+
+        let events = deserializer
+            .parse(Bytes::from(""), LogNamespace::Legacy)
+            .unwrap();
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn build_csv_without_columns() {
+        assert!(CsvDeserializerConfig::default().build().is_err());
+    }
+}
@@ -0,0 +1,189 @@
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use smallvec::{smallvec, SmallVec};
+use vector_core::{
+    config::{DataType, LogNamespace},
+    event::{Event, LogEvent},
+    schema,
+};
+use vrl::value::{kind::Collection, Kind, ObjectMap, Value};
+
+use super::Deserializer;
+
+/// Config used to build a `LogfmtDeserializer`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct LogfmtDeserializerConfig;
+
+impl LogfmtDeserializerConfig {
+    /// Creates a new `LogfmtDeserializerConfig`.
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Build the `LogfmtDeserializer` from this configuration.
+    pub const fn build(&self) -> LogfmtDeserializer {
+        LogfmtDeserializer
+    }
+
+    /// Return the type of event build by this deserializer.
+    pub fn output_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema produced by the deserializer.
+    pub fn schema_definition(&self, log_namespace: LogNamespace) -> schema::Definition {
+        schema::Definition::new_with_default_metadata(
+            Kind::object(Collection::empty()),
+            [log_namespace],
+        )
+        // Keys without a value are set to `true`.
+        .unknown_fields(Kind::bytes().or_boolean())
+    }
+}
+
+/// Deserializer that builds an `Event` from a byte frame containing a logfmt line.
+///
+/// Each `key=value` pair of the line becomes a field of the event, with the value kept as a
+/// string. Keys without a value are set to `true`, and the last value of a repeated key wins.
+#[derive(Debug, Clone)]
+pub struct LogfmtDeserializer;
+
+impl Deserializer for LogfmtDeserializer {
+    fn parse(
+        &self,
+        bytes: Bytes,
+        _log_namespace: LogNamespace,
+    ) -> vector_common::Result<SmallVec<[Event; 1]>> {
+        let fields = parse_logfmt(&String::from_utf8_lossy(&bytes))?;
+
+        // Like empty lines in NDJSON, empty logfmt lines are skipped silently.
+        if fields.is_empty() {
+            return Ok(smallvec![]);
+        }
+
+        Ok(smallvec![LogEvent::from(fields).into()])
+    }
+}
+
+fn parse_logfmt(input: &str) -> Result<ObjectMap, String> {
+    let mut fields = ObjectMap::new();
+    let mut chars = input.chars().peekable();
+
+    loop {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        if chars.peek().is_none() {
+            break;
+        }
+
+        let mut key = String::new();
+        while let Some(c) = chars.next_if(|c| !c.is_whitespace() && *c != '=') {
+            if c == '"' {
+                return Err(format!("Unexpected quote in logfmt key {key:?}."));
+            }
+            key.push(c);
+        }
+        if key.is_empty() {
+            return Err("Missing logfmt key before `=`.".to_owned());
+        }
+
+        let value = if chars.next_if_eq(&'=').is_none() {
+            Value::Boolean(true)
+        } else if chars.next_if_eq(&'"').is_some() {
+            let mut value = String::new();
+            loop {
+                match chars.next() {
+                    Some('"') => break,
+                    Some('\\') => match chars.next() {
+                        Some('n') => value.push('\n'),
+                        Some('r') => value.push('\r'),
+                        Some('t') => value.push('\t'),
+                        Some(c @ ('"' | '\\')) => value.push(c),
+                        Some(c) => {
+                            value.push('\\');
+                            value.push(c);
+                        }
+                        None => return Err(format!("Unterminated logfmt value of key {key:?}.")),
+                    },
+                    Some(c) => value.push(c),
+                    None => return Err(format!("Unterminated logfmt value of key {key:?}.")),
+                }
+            }
+            Value::from(value)
+        } else {
+            let mut value = String::new();
+            while let Some(c) = chars.next_if(|c| !c.is_whitespace()) {
+                value.push(c);
+            }
+            Value::from(value)
+        };
+
+        fields.insert(key.into(), value);
+    }
+
+    Ok(fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use vrl::btreemap;
+
+    use super::*;
+
+    #[test]
+    fn deserialize_logfmt() {
+        let input = Bytes::from(
+            r#"level=info msg="Stopping all fetchers" tag="stopping \"fetchers\"" id= debug"#,
+        );
+
+        for namespace in [LogNamespace::Legacy, LogNamespace::Vector] {
+            let events = LogfmtDeserializer.parse(input.clone(), namespace).unwrap();
+
+            assert_eq!(events.len(), 1);
+            assert_eq!(
+                *events[0].as_log().value(),
+                Value::from(btreemap! {
+                    "level" => "info",
+                    "msg" => "Stopping all fetchers",
+                    "tag" => "stopping \"fetchers\"",
+                    "id" => "",
+                    "debug" => true,
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn deserialize_logfmt_dotted_keys() {
+        let input = Bytes::from("http.status=200 http.status=404");
+
+        let events = LogfmtDeserializer
+            .parse(input, LogNamespace::Legacy)
+            .unwrap();
+
+        assert_eq!(
+            *events[0].as_log().value(),
+            Value::from(btreemap! { "http.status" => "404" })
+        );
+    }
+
+    #[test]
+    fn deserialize_logfmt_skip_empty() {
+        let events = LogfmtDeserializer
+            .parse(Bytes::from("  "), LogNamespace::Legacy)
+            .unwrap();
+
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn deserialize_logfmt_err() {
+        for input in [r#"msg="unterminated"#, "=value", r#"ms"g=value"#] {
+            assert!(
+                LogfmtDeserializer
+                    .parse(Bytes::from(input), LogNamespace::Legacy)
+                    .is_err(),
+                "{input}"
+            );
+        }
+    }
+}
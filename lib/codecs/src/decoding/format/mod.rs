@@ -5,27 +5,37 @@
 
 mod avro;
 mod bytes;
+mod cef;
+mod csv;
 mod gelf;
 mod influxdb;
 mod json;
+mod logfmt;
 mod native;
 mod native_json;
 mod protobuf;
+#[cfg(feature = "schema-registry")]
+mod schema_registry;
 #[cfg(feature = "syslog")]
 mod syslog;
 mod vrl;
 
 use ::bytes::Bytes;
 pub use avro::{AvroDeserializer, AvroDeserializerConfig, AvroDeserializerOptions};
+pub use cef::{CefDeserializer, CefDeserializerConfig, CefDeserializerOptions};
+pub use csv::{CsvDeserializer, CsvDeserializerConfig, CsvDeserializerOptions};
 use dyn_clone::DynClone;
 pub use gelf::{GelfDeserializer, GelfDeserializerConfig, GelfDeserializerOptions};
 pub use influxdb::{InfluxdbDeserializer, InfluxdbDeserializerConfig};
 pub use json::{JsonDeserializer, JsonDeserializerConfig, JsonDeserializerOptions};
+pub use logfmt::{LogfmtDeserializer, LogfmtDeserializerConfig};
 pub use native::{NativeDeserializer, NativeDeserializerConfig};
 pub use native_json::{
     NativeJsonDeserializer, NativeJsonDeserializerConfig, NativeJsonDeserializerOptions,
 };
 pub use protobuf::{ProtobufDeserializer, ProtobufDeserializerConfig, ProtobufDeserializerOptions};
+#[cfg(feature = "schema-registry")]
+pub use schema_registry::{SchemaRegistryDeserializer, SchemaRegistryDeserializerConfig};
 use smallvec::SmallVec;
 #[cfg(feature = "syslog")]
 pub use syslog::{SyslogDeserializer, SyslogDeserializerConfig, SyslogDeserializerOptions};
@@ -0,0 +1,136 @@
+use std::sync::Arc;
+
+use bytes::Bytes;
+use prost_reflect::FileDescriptor;
+use smallvec::{smallvec, SmallVec};
+use vector_config::configurable_component;
+use vector_core::{
+    config::{DataType, LogNamespace},
+    event::Event,
+    schema,
+};
+
+use super::{AvroDeserializer, Deserializer, ProtobufDeserializer, ProtobufDeserializerConfig};
+use crate::common::schema_registry::{
+    message_by_indexes, read_message_indexes, split_schema_id, SchemaCache, SchemaRegistryClient,
+    SchemaRegistryOptions, SchemaType,
+};
+
+/// Config used to build a `SchemaRegistryDeserializer`.
+#[configurable_component]
+#[derive(Debug, Clone)]
+pub struct SchemaRegistryDeserializerConfig {
+    /// Options for the Schema Registry deserializer.
+    pub schema_registry: SchemaRegistryOptions,
+}
+
+impl SchemaRegistryDeserializerConfig {
+    /// Build the `SchemaRegistryDeserializer` from this configuration.
+    pub fn build(&self) -> vector_common::Result<SchemaRegistryDeserializer> {
+        Ok(SchemaRegistryDeserializer {
+            client: SchemaRegistryClient::new(self.schema_registry.clone())?,
+            schemas: Arc::default(),
+        })
+    }
+
+    /// Return the type of event build by this deserializer.
+    pub fn output_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema produced by the deserializer.
+    pub fn schema_definition(&self, log_namespace: LogNamespace) -> schema::Definition {
+        // Both formats produce objects with arbitrary fields and add a timestamp in the same way.
+        ProtobufDeserializerConfig::default().schema_definition(log_namespace)
+    }
+}
+
+/// The deserializer for the schema of a given ID.
+#[derive(Clone, Debug)]
+enum SchemaDeserializer {
+    Avro(AvroDeserializer),
+    Protobuf(FileDescriptor),
+}
+
+/// Deserializer that builds `Event`s from Avro or Protobuf messages in the Confluent wire format,
+/// resolving their schemas with the Schema Registry.
+#[derive(Debug, Clone)]
+pub struct SchemaRegistryDeserializer {
+    client: SchemaRegistryClient,
+    schemas: Arc<SchemaCache<u32, SchemaDeserializer>>,
+}
+
+impl SchemaRegistryDeserializer {
+    fn schema_deserializer(&self, id: u32) -> vector_common::Result<Arc<SchemaDeserializer>> {
+        self.schemas.get_or_resolve(id, || {
+            let schema = self.client.schema_by_id(id)?;
+            Ok(match schema.schema_type {
+                SchemaType::Avro => SchemaDeserializer::Avro(AvroDeserializer::new(
+                    self.client.avro_schema(&schema)?,
+                    false,
+                )),
+                SchemaType::Protobuf => {
+                    SchemaDeserializer::Protobuf(self.client.protobuf_file(&schema)?)
+                }
+                SchemaType::Json => {
+                    return Err(
+                        format!("Schema {id} is a JSON schema, which is not supported.").into(),
+                    )
+                }
+            })
+        })
+    }
+}
+
+impl Deserializer for SchemaRegistryDeserializer {
+    fn parse(
+        &self,
+        bytes: Bytes,
+        log_namespace: LogNamespace,
+    ) -> vector_common::Result<SmallVec<[Event; 1]>> {
+        if bytes.is_empty() {
+            return Ok(smallvec![]);
+        }
+
+        let (id, mut payload) = split_schema_id(bytes)?;
+        match self.schema_deserializer(id)?.as_ref() {
+            SchemaDeserializer::Avro(deserializer) => deserializer.parse(payload, log_namespace),
+            SchemaDeserializer::Protobuf(file) => {
+                let indexes = read_message_indexes(&mut payload)?;
+                let message = message_by_indexes(file, &indexes)?;
+                ProtobufDeserializer::new(message).parse(payload, log_namespace)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn requires_url() {
+        let config = SchemaRegistryDeserializerConfig {
+            schema_registry: SchemaRegistryOptions::default(),
+        };
+        assert!(config.build().is_err());
+    }
+
+    #[test]
+    fn rejects_messages_without_schema_id() {
+        let config = SchemaRegistryDeserializerConfig {
+            schema_registry: SchemaRegistryOptions {
+                url: "http://localhost:8081".into(),
+                ..Default::default()
+            },
+        };
+        let deserializer = config.build().unwrap();
+        let error = deserializer
+            .parse(Bytes::from_static(b"{}"), LogNamespace::Legacy)
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Expected the message to start with a Schema Registry schema ID."
+        );
+    }
+}
@@ -9,13 +9,17 @@ use crate::decoding::format::{VrlDeserializer, VrlDeserializerConfig};
 use bytes::{Bytes, BytesMut};
 pub use error::StreamDecodingError;
 pub use format::{
-    BoxedDeserializer, BytesDeserializer, BytesDeserializerConfig, GelfDeserializer,
-    GelfDeserializerConfig, GelfDeserializerOptions, InfluxdbDeserializer,
-    InfluxdbDeserializerConfig, JsonDeserializer, JsonDeserializerConfig, JsonDeserializerOptions,
-    NativeDeserializer, NativeDeserializerConfig, NativeJsonDeserializer,
-    NativeJsonDeserializerConfig, NativeJsonDeserializerOptions, ProtobufDeserializer,
-    ProtobufDeserializerConfig, ProtobufDeserializerOptions,
+    BoxedDeserializer, BytesDeserializer, BytesDeserializerConfig, CefDeserializer,
+    CefDeserializerConfig, CefDeserializerOptions, CsvDeserializer, CsvDeserializerConfig,
+    CsvDeserializerOptions, GelfDeserializer, GelfDeserializerConfig, GelfDeserializerOptions,
+    InfluxdbDeserializer, InfluxdbDeserializerConfig, JsonDeserializer, JsonDeserializerConfig,
+    JsonDeserializerOptions, LogfmtDeserializer, LogfmtDeserializerConfig, NativeDeserializer,
+    NativeDeserializerConfig, NativeJsonDeserializer, NativeJsonDeserializerConfig,
+    NativeJsonDeserializerOptions, ProtobufDeserializer, ProtobufDeserializerConfig,
+    ProtobufDeserializerOptions,
 };
+#[cfg(feature = "schema-registry")]
+pub use format::{SchemaRegistryDeserializer, SchemaRegistryDeserializerConfig};
 #[cfg(feature = "syslog")]
 pub use format::{SyslogDeserializer, SyslogDeserializerConfig, SyslogDeserializerOptions};
 pub use framing::{
@@ -287,6 +291,39 @@ pub enum DeserializerConfig {
     ///
     /// [vrl]: https://vector.dev/docs/reference/vrl
     Vrl(VrlDeserializerConfig),
+
+    /// Decodes the raw bytes as [CSV][csv] records.
+    ///
+    /// Each record of a frame becomes its own event.
+    ///
+    /// [csv]: https://datatracker.ietf.org/doc/html/rfc4180
+    Csv(CsvDeserializerConfig),
+
+    /// Decodes the raw bytes as a [logfmt][logfmt] message.
+    ///
+    /// Values are kept as strings, and keys without a value are set to `true`.
+    ///
+    /// [logfmt]: https://brandur.org/logfmt
+    Logfmt,
+
+    /// Decodes the raw bytes as a [CEF][cef] (Common Event Format) message.
+    ///
+    /// The header is decoded into the `cefVersion`, `deviceVendor`, `deviceProduct`,
+    /// `deviceVersion`, `deviceEventClassId`, `name`, and `severity` fields, and each extension
+    /// into the field named after its key.
+    ///
+    /// [cef]: https://www.microfocus.com/documentation/arcsight/arcsight-smartconnectors-8.3/cef-implementation-standard/Content/CEF/Chapter%201%20What%20is%20CEF.htm
+    Cef(CefDeserializerConfig),
+
+    #[cfg(feature = "schema-registry")]
+    /// Decodes the raw bytes as an Avro or Protobuf message in the [Confluent wire format][wire_format].
+    ///
+    /// The schema of each message is fetched from the [Schema Registry][schema_registry] by the ID
+    /// that prefixes it, and cached.
+    ///
+    /// [wire_format]: https://docs.confluent.io/platform/current/schema-registry/fundamentals/serdes-develop/index.html#wire-format
+    /// [schema_registry]: https://docs.confluent.io/platform/current/schema-registry/index.html
+    SchemaRegistry(SchemaRegistryDeserializerConfig),
 }
 
 impl From<BytesDeserializerConfig> for DeserializerConfig {
@@ -332,6 +369,31 @@ impl From<InfluxdbDeserializerConfig> for DeserializerConfig {
     }
 }
 
+impl From<CsvDeserializerConfig> for DeserializerConfig {
+    fn from(config: CsvDeserializerConfig) -> Self {
+        Self::Csv(config)
+    }
+}
+
+impl From<LogfmtDeserializerConfig> for DeserializerConfig {
+    fn from(_: LogfmtDeserializerConfig) -> Self {
+        Self::Logfmt
+    }
+}
+
+impl From<CefDeserializerConfig> for DeserializerConfig {
+    fn from(config: CefDeserializerConfig) -> Self {
+        Self::Cef(config)
+    }
+}
+
+#[cfg(feature = "schema-registry")]
+impl From<SchemaRegistryDeserializerConfig> for DeserializerConfig {
+    fn from(config: SchemaRegistryDeserializerConfig) -> Self {
+        Self::SchemaRegistry(config)
+    }
+}
+
 impl DeserializerConfig {
     /// Build the `Deserializer` from this configuration.
     pub fn build(&self) -> vector_common::Result<Deserializer> {
@@ -354,6 +416,15 @@ impl DeserializerConfig {
             DeserializerConfig::Gelf(config) => Ok(Deserializer::Gelf(config.build())),
             DeserializerConfig::Influxdb(config) => Ok(Deserializer::Influxdb(config.build())),
             DeserializerConfig::Vrl(config) => Ok(Deserializer::Vrl(config.build()?)),
+            DeserializerConfig::Csv(config) => Ok(Deserializer::Csv(config.build()?)),
+            DeserializerConfig::Logfmt => {
+                Ok(Deserializer::Logfmt(LogfmtDeserializerConfig.build()))
+            }
+            DeserializerConfig::Cef(config) => Ok(Deserializer::Cef(config.build())),
+            #[cfg(feature = "schema-registry")]
+            DeserializerConfig::SchemaRegistry(config) => {
+                Ok(Deserializer::SchemaRegistry(config.build()?))
+            }
         }
     }
 
@@ -365,10 +436,15 @@ impl DeserializerConfig {
             DeserializerConfig::Bytes
             | DeserializerConfig::Json(_)
             | DeserializerConfig::Influxdb(_)
-            | DeserializerConfig::NativeJson(_) => {
-                FramingConfig::NewlineDelimited(Default::default())
-            }
+            | DeserializerConfig::NativeJson(_)
+            | DeserializerConfig::Logfmt
+            | DeserializerConfig::Cef(_) => FramingConfig::NewlineDelimited(Default::default()),
+            // A header only applies to the records following it in the same frame.
+            DeserializerConfig::Csv(config) if config.csv.has_headers => FramingConfig::Bytes,
+            DeserializerConfig::Csv(_) => FramingConfig::NewlineDelimited(Default::default()),
             DeserializerConfig::Protobuf(_) => FramingConfig::Bytes,
+            #[cfg(feature = "schema-registry")]
+            DeserializerConfig::SchemaRegistry(_) => FramingConfig::Bytes,
             #[cfg(feature = "syslog")]
             DeserializerConfig::Syslog(_) => FramingConfig::NewlineDelimited(Default::default()),
             DeserializerConfig::Vrl(_) => FramingConfig::Bytes,
@@ -403,6 +479,11 @@ impl DeserializerConfig {
             DeserializerConfig::Gelf(config) => config.output_type(),
             DeserializerConfig::Vrl(config) => config.output_type(),
             DeserializerConfig::Influxdb(config) => config.output_type(),
+            DeserializerConfig::Csv(config) => config.output_type(),
+            DeserializerConfig::Logfmt => LogfmtDeserializerConfig.output_type(),
+            DeserializerConfig::Cef(config) => config.output_type(),
+            #[cfg(feature = "schema-registry")]
+            DeserializerConfig::SchemaRegistry(config) => config.output_type(),
         }
     }
 
@@ -423,6 +504,11 @@ impl DeserializerConfig {
             DeserializerConfig::Gelf(config) => config.schema_definition(log_namespace),
             DeserializerConfig::Influxdb(config) => config.schema_definition(log_namespace),
             DeserializerConfig::Vrl(config) => config.schema_definition(log_namespace),
+            DeserializerConfig::Csv(config) => config.schema_definition(log_namespace),
+            DeserializerConfig::Logfmt => LogfmtDeserializerConfig.schema_definition(log_namespace),
+            DeserializerConfig::Cef(config) => config.schema_definition(log_namespace),
+            #[cfg(feature = "schema-registry")]
+            DeserializerConfig::SchemaRegistry(config) => config.schema_definition(log_namespace),
         }
     }
 
@@ -449,13 +535,18 @@ impl DeserializerConfig {
                 "application/octet-stream"
             }
             (DeserializerConfig::Protobuf(_), _) => "application/octet-stream",
+            #[cfg(feature = "schema-registry")]
+            (DeserializerConfig::SchemaRegistry(_), _) => "application/octet-stream",
+            (DeserializerConfig::Csv(_), _) => "text/csv",
             (
                 DeserializerConfig::Json(_)
                 | DeserializerConfig::NativeJson(_)
                 | DeserializerConfig::Bytes
                 | DeserializerConfig::Gelf(_)
                 | DeserializerConfig::Influxdb(_)
-                | DeserializerConfig::Vrl(_),
+                | DeserializerConfig::Vrl(_)
+                | DeserializerConfig::Logfmt
+                | DeserializerConfig::Cef(_),
                 _,
             ) => "text/plain",
             #[cfg(feature = "syslog")]
@@ -490,6 +581,15 @@ pub enum Deserializer {
     Influxdb(InfluxdbDeserializer),
     /// Uses a `VrlDeserializer` for deserialization.
     Vrl(VrlDeserializer),
+    /// Uses a `CsvDeserializer` for deserialization.
+    Csv(CsvDeserializer),
+    /// Uses a `LogfmtDeserializer` for deserialization.
+    Logfmt(LogfmtDeserializer),
+    /// Uses a `CefDeserializer` for deserialization.
+    Cef(CefDeserializer),
+    #[cfg(feature = "schema-registry")]
+    /// Uses a `SchemaRegistryDeserializer` for deserialization.
+    SchemaRegistry(SchemaRegistryDeserializer),
 }
 
 impl format::Deserializer for Deserializer {
@@ -511,6 +611,11 @@ impl format::Deserializer for Deserializer {
             Deserializer::Gelf(deserializer) => deserializer.parse(bytes, log_namespace),
             Deserializer::Influxdb(deserializer) => deserializer.parse(bytes, log_namespace),
             Deserializer::Vrl(deserializer) => deserializer.parse(bytes, log_namespace),
+            Deserializer::Csv(deserializer) => deserializer.parse(bytes, log_namespace),
+            Deserializer::Logfmt(deserializer) => deserializer.parse(bytes, log_namespace),
+            Deserializer::Cef(deserializer) => deserializer.parse(bytes, log_namespace),
+            #[cfg(feature = "schema-registry")]
+            Deserializer::SchemaRegistry(deserializer) => deserializer.parse(bytes, log_namespace),
         }
     }
 }
@@ -12,15 +12,24 @@ mod json;
 mod logfmt;
 mod native;
 mod native_json;
+#[cfg(feature = "parquet")]
+mod parquet;
 mod protobuf;
 mod raw_message;
+#[cfg(feature = "schema-registry")]
+mod schema_registry;
 mod text;
 
 use std::fmt::Debug;
 
-pub use self::csv::{CsvSerializer, CsvSerializerConfig};
+pub use self::csv::{CsvSerializer, CsvSerializerConfig, CsvSerializerOptions};
+#[cfg(feature = "parquet")]
+pub use self::parquet::{
+    ParquetColumn, ParquetColumnType, ParquetCompression, ParquetError, ParquetSchema,
+    ParquetSerializer, ParquetSerializerConfig, ParquetSerializerOptions,
+};
 pub use avro::{AvroSerializer, AvroSerializerConfig, AvroSerializerOptions};
-pub use cef::{CefSerializer, CefSerializerConfig};
+pub use cef::{CefSerializer, CefSerializerConfig, CefSerializerOptions};
 use dyn_clone::DynClone;
 pub use gelf::{GelfSerializer, GelfSerializerConfig};
 pub use json::{JsonSerializer, JsonSerializerConfig, JsonSerializerOptions};
@@ -29,6 +38,10 @@ pub use native::{NativeSerializer, NativeSerializerConfig};
 pub use native_json::{NativeJsonSerializer, NativeJsonSerializerConfig};
 pub use protobuf::{ProtobufSerializer, ProtobufSerializerConfig, ProtobufSerializerOptions};
 pub use raw_message::{RawMessageSerializer, RawMessageSerializerConfig};
+#[cfg(feature = "schema-registry")]
+pub use schema_registry::{
+    SchemaRegistrySerializer, SchemaRegistrySerializerConfig, SchemaRegistrySerializerOptions,
+};
 pub use text::{TextSerializer, TextSerializerConfig};
 use vector_core::event::Event;
 
@@ -0,0 +1,756 @@
+use std::{fmt, sync::Arc};
+
+use lookup::{OwnedTargetPath, OwnedValuePath};
+use parquet::{
+    basic::{
+        Compression, GzipLevel, LogicalType, Repetition, TimeUnit, Type as PhysicalType, ZstdLevel,
+    },
+    column::writer::ColumnWriter,
+    data_type::ByteArray,
+    errors::ParquetError as WriteError,
+    file::{
+        properties::{WriterProperties, WriterVersion},
+        writer::{SerializedColumnWriter, SerializedFileWriter},
+    },
+    schema::types::Type,
+};
+use serde::{Deserialize, Serialize};
+use snafu::{ResultExt, Snafu};
+use vector_config::configurable_component;
+use vector_core::{config::DataType, event::Event, schema};
+use vrl::value::{Kind, Value};
+
+use crate::encoding::BuildError;
+
+const fn default_row_group_size() -> usize {
+    100_000
+}
+
+/// Config used to build a `ParquetSerializer`.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ParquetSerializerConfig {
+    /// Options for the Parquet serializer.
+    pub parquet: ParquetSerializerOptions,
+}
+
+impl ParquetSerializerConfig {
+    /// Creates a new `ParquetSerializerConfig`.
+    pub const fn new(parquet: ParquetSerializerOptions) -> Self {
+        Self { parquet }
+    }
+
+    /// Build the `ParquetSerializer` from this configuration.
+    pub fn build(&self) -> Result<ParquetSerializer, BuildError> {
+        let options = &self.parquet;
+        if options.row_group_size == 0 {
+            return Err(
+                "Failed building Parquet serializer: `row_group_size` must not be 0".into(),
+            );
+        }
+
+        let columns = options
+            .schema
+            .iter()
+            .map(|column| {
+                let path = lookup::lookup_v2::parse_value_path(&column.name).map_err(|error| {
+                    format!(
+                        "Failed building Parquet serializer: invalid column name `{}`: {}",
+                        column.name, error
+                    )
+                })?;
+                Ok(Column {
+                    name: column.name.clone(),
+                    path: OwnedTargetPath::event(path),
+                    column_type: column.column_type,
+                    required: column.required,
+                })
+            })
+            .collect::<Result<Vec<_>, BuildError>>()?;
+
+        let properties = Arc::new(
+            WriterProperties::builder()
+                .set_writer_version(WriterVersion::PARQUET_2_0)
+                .set_compression(options.compression.into())
+                .set_created_by(format!("Vector {}", env!("CARGO_PKG_VERSION")))
+                .build(),
+        );
+
+        let schema = if columns.is_empty() {
+            None
+        } else {
+            Some(ParquetSchema::new(
+                columns,
+                Arc::clone(&properties),
+                options.row_group_size,
+            )?)
+        };
+
+        Ok(ParquetSerializer {
+            schema,
+            properties,
+            row_group_size: options.row_group_size,
+        })
+    }
+
+    /// The data type of events that are accepted by `ParquetSerializer`.
+    pub fn input_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema required by the serializer.
+    pub fn schema_requirement(&self) -> schema::Requirement {
+        schema::Requirement::empty()
+    }
+}
+
+/// Apache Parquet serializer options.
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub struct ParquetSerializerOptions {
+    /// The columns of the Parquet files, in order.
+    ///
+    /// When not set, the columns are derived from the schema definition of the events: each
+    /// top-level field known to the schema becomes an optional column. Fields of several types,
+    /// objects, and arrays are stored as JSON.
+    #[serde(default)]
+    pub schema: Vec<ParquetColumn>,
+
+    /// The compression codec applied to the pages of the columns.
+    #[configurable(derived)]
+    #[serde(default)]
+    pub compression: ParquetCompression,
+
+    /// The maximum number of rows per row group.
+    ///
+    /// Batches with more events than this are split into several row groups.
+    #[serde(default = "default_row_group_size")]
+    #[configurable(metadata(docs::type_unit = "rows"))]
+    pub row_group_size: usize,
+}
+
+impl Default for ParquetSerializerOptions {
+    fn default() -> Self {
+        Self {
+            schema: Vec::new(),
+            compression: ParquetCompression::default(),
+            row_group_size: default_row_group_size(),
+        }
+    }
+}
+
+/// A column of the Parquet files.
+#[configurable_component]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParquetColumn {
+    /// The name of the column.
+    ///
+    /// This is also the path of the event field the values of the column are read from.
+    #[configurable(metadata(docs::examples = "message"))]
+    #[configurable(metadata(docs::examples = "kubernetes.pod_name"))]
+    pub name: String,
+
+    /// The type of the column.
+    #[configurable(derived)]
+    #[serde(rename = "type")]
+    pub column_type: ParquetColumnType,
+
+    /// Whether the column is required.
+    ///
+    /// Events that are missing the field of a required column, or have it set to `null`, are
+    /// rejected.
+    #[serde(default)]
+    pub required: bool,
+}
+
+/// The type of a Parquet column.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ParquetColumnType {
+    /// A UTF-8 string.
+    String,
+
+    /// A 64-bit signed integer.
+    Int64,
+
+    /// A 64-bit floating point number.
+    ///
+    /// Integer values are converted.
+    Double,
+
+    /// A boolean.
+    Boolean,
+
+    /// A timestamp, stored as the number of microseconds since the Unix epoch, in UTC.
+    Timestamp,
+
+    /// Any value, stored as a JSON-encoded string.
+    Json,
+}
+
+impl ParquetColumnType {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::String => "string",
+            Self::Int64 => "int64",
+            Self::Double => "double",
+            Self::Boolean => "boolean",
+            Self::Timestamp => "timestamp",
+            Self::Json => "json",
+        }
+    }
+
+    /// Picks the column type for the values of a field of the given kind.
+    fn from_kind(kind: &Kind) -> Self {
+        if kind.contains_object() || kind.contains_array() || kind.contains_regex() {
+            return Self::Json;
+        }
+        let scalars = [
+            (kind.contains_bytes(), Self::String),
+            (kind.contains_integer(), Self::Int64),
+            (kind.contains_float(), Self::Double),
+            (kind.contains_boolean(), Self::Boolean),
+            (kind.contains_timestamp(), Self::Timestamp),
+        ];
+        let mut types = scalars
+            .into_iter()
+            .filter_map(|(contained, column_type)| contained.then_some(column_type));
+        match (types.next(), types.next()) {
+            (Some(column_type), None) => column_type,
+            _ => Self::Json,
+        }
+    }
+
+    fn accepts(self, value: &Value) -> bool {
+        matches!(
+            (self, value),
+            (Self::String, Value::Bytes(_))
+                | (Self::Int64, Value::Integer(_))
+                | (Self::Double, Value::Float(_) | Value::Integer(_))
+                | (Self::Boolean, Value::Boolean(_))
+                | (Self::Timestamp, Value::Timestamp(_))
+                | (Self::Json, _)
+        )
+    }
+}
+
+impl fmt::Display for ParquetColumnType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The compression codec of Parquet files.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ParquetCompression {
+    /// No compression.
+    None,
+
+    /// [Snappy][snappy] compression.
+    ///
+    /// [snappy]: https://github.com/google/snappy/blob/main/docs/README.md
+    #[default]
+    Snappy,
+
+    /// [Gzip][gzip] compression.
+    ///
+    /// [gzip]: https://www.gzip.org/
+    Gzip,
+
+    /// [Zstandard][zstd] compression.
+    ///
+    /// [zstd]: https://facebook.github.io/zstd/
+    Zstd,
+
+    /// [LZ4][lz4] compression, without framing.
+    ///
+    /// [lz4]: https://lz4.org/
+    Lz4,
+}
+
+impl From<ParquetCompression> for Compression {
+    fn from(compression: ParquetCompression) -> Self {
+        match compression {
+            ParquetCompression::None => Compression::UNCOMPRESSED,
+            ParquetCompression::Snappy => Compression::SNAPPY,
+            ParquetCompression::Gzip => Compression::GZIP(GzipLevel::default()),
+            ParquetCompression::Zstd => Compression::ZSTD(ZstdLevel::default()),
+            ParquetCompression::Lz4 => Compression::LZ4_RAW,
+        }
+    }
+}
+
+/// An error that occurred while encoding a batch of events as a Parquet file.
+#[derive(Debug, Snafu)]
+pub enum ParquetError {
+    /// No column was configured, and none could be derived from the schema definition of the
+    /// events.
+    #[snafu(display(
+        "no columns could be derived from the schema definition of the events, configure them with the `schema` option"
+    ))]
+    NoColumns,
+
+    /// The event is not a log event.
+    #[snafu(display("only log events can be encoded as Parquet"))]
+    NotALog,
+
+    /// The event is missing the field of a required column.
+    #[snafu(display("missing a value for required column `{}`", column))]
+    MissingField {
+        /// The name of the column.
+        column: String,
+    },
+
+    /// The field of a column does not have the type of the column.
+    #[snafu(display(
+        "the value for column `{}` is of type `{}`, expected `{}`",
+        column,
+        kind,
+        column_type
+    ))]
+    TypeMismatch {
+        /// The name of the column.
+        column: String,
+        /// The type of the column.
+        column_type: ParquetColumnType,
+        /// The kind of the value.
+        kind: String,
+    },
+
+    /// The Parquet file could not be written.
+    #[snafu(display("failed writing Parquet file: {}", source))]
+    Write {
+        /// The underlying error.
+        source: WriteError,
+    },
+}
+
+#[derive(Clone, Debug)]
+struct Column {
+    name: String,
+    path: OwnedTargetPath,
+    column_type: ParquetColumnType,
+    required: bool,
+}
+
+impl Column {
+    fn parquet_type(&self) -> Result<Type, WriteError> {
+        let (physical_type, logical_type) = match self.column_type {
+            ParquetColumnType::String => (PhysicalType::BYTE_ARRAY, Some(LogicalType::String)),
+            ParquetColumnType::Int64 => (PhysicalType::INT64, None),
+            ParquetColumnType::Double => (PhysicalType::DOUBLE, None),
+            ParquetColumnType::Boolean => (PhysicalType::BOOLEAN, None),
+            ParquetColumnType::Timestamp => (
+                PhysicalType::INT64,
+                Some(LogicalType::Timestamp {
+                    is_adjusted_to_u_t_c: true,
+                    unit: TimeUnit::MICROS(Default::default()),
+                }),
+            ),
+            ParquetColumnType::Json => (PhysicalType::BYTE_ARRAY, Some(LogicalType::Json)),
+        };
+        let repetition = if self.required {
+            Repetition::REQUIRED
+        } else {
+            Repetition::OPTIONAL
+        };
+        Type::primitive_type_builder(&self.name, physical_type)
+            .with_logical_type(logical_type)
+            .with_repetition(repetition)
+            .build()
+    }
+
+    fn value<'a>(&self, event: &'a Event) -> Option<&'a Value> {
+        event
+            .maybe_as_log()?
+            .get(&self.path)
+            .filter(|value| !value.is_null())
+    }
+}
+
+/// Serializer that converts batches of events to Apache Parquet files.
+#[derive(Clone, Debug)]
+pub struct ParquetSerializer {
+    schema: Option<ParquetSchema>,
+    properties: Arc<WriterProperties>,
+    row_group_size: usize,
+}
+
+impl ParquetSerializer {
+    /// Returns the schema to write the given batch of events with.
+    ///
+    /// This is either the configured schema, or the one derived from the merged schema definitions
+    /// of the events of the batch.
+    pub fn schema(&self, events: &[Event]) -> Result<ParquetSchema, ParquetError> {
+        if let Some(schema) = &self.schema {
+            return Ok(schema.clone());
+        }
+
+        let mut definitions: Vec<&Arc<schema::Definition>> = Vec::new();
+        for event in events {
+            let definition = event.metadata().schema_definition();
+            if !definitions
+                .iter()
+                .any(|merged| Arc::ptr_eq(merged, definition))
+            {
+                definitions.push(definition);
+            }
+        }
+        let definition = definitions
+            .into_iter()
+            .map(|definition| definition.as_ref().clone())
+            .reduce(schema::Definition::merge);
+
+        let columns = definition
+            .as_ref()
+            .and_then(|definition| {
+                let fields = definition.event_kind().as_object()?.known();
+                Some(
+                    fields
+                        .iter()
+                        .map(|(field, kind)| {
+                            let name = field.to_string();
+                            let path = OwnedValuePath::root().with_field_appended(&name);
+                            Column {
+                                name,
+                                path: OwnedTargetPath::event(path),
+                                column_type: ParquetColumnType::from_kind(kind),
+                                required: false,
+                            }
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .unwrap_or_default();
+        if columns.is_empty() {
+            return Err(ParquetError::NoColumns);
+        }
+
+        ParquetSchema::new(columns, Arc::clone(&self.properties), self.row_group_size)
+            .context(WriteSnafu)
+    }
+}
+
+/// The columns of a Parquet file.
+#[derive(Clone, Debug)]
+pub struct ParquetSchema {
+    columns: Arc<[Column]>,
+    schema: Arc<Type>,
+    properties: Arc<WriterProperties>,
+    row_group_size: usize,
+}
+
+impl ParquetSchema {
+    fn new(
+        columns: Vec<Column>,
+        properties: Arc<WriterProperties>,
+        row_group_size: usize,
+    ) -> Result<Self, WriteError> {
+        let fields = columns
+            .iter()
+            .map(|column| column.parquet_type().map(Arc::new))
+            .collect::<Result<Vec<_>, _>>()?;
+        let schema = Type::group_type_builder("event")
+            .with_fields(fields)
+            .build()?;
+        Ok(Self {
+            columns: columns.into(),
+            schema: Arc::new(schema),
+            properties,
+            row_group_size,
+        })
+    }
+
+    /// Checks that the event can be written with this schema.
+    pub fn validate(&self, event: &Event) -> Result<(), ParquetError> {
+        if event.maybe_as_log().is_none() {
+            return Err(ParquetError::NotALog);
+        }
+        for column in self.columns.iter() {
+            match column.value(event) {
+                None if column.required => {
+                    return Err(ParquetError::MissingField {
+                        column: column.name.clone(),
+                    })
+                }
+                Some(value) if !column.column_type.accepts(value) => {
+                    return Err(ParquetError::TypeMismatch {
+                        column: column.name.clone(),
+                        column_type: column.column_type,
+                        kind: value.kind_str().to_owned(),
+                    })
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Encodes the events, which must have been validated against this schema, as a Parquet
+    /// file.
+    pub fn encode(&self, events: &[Event]) -> Result<Vec<u8>, ParquetError> {
+        let mut buffer = Vec::new();
+        let mut writer = SerializedFileWriter::new(
+            &mut buffer,
+            Arc::clone(&self.schema),
+            Arc::clone(&self.properties),
+        )
+        .context(WriteSnafu)?;
+
+        for rows in events.chunks(self.row_group_size) {
+            let mut row_group = writer.next_row_group().context(WriteSnafu)?;
+            for column in self.columns.iter() {
+                let mut column_writer = row_group
+                    .next_column()
+                    .context(WriteSnafu)?
+                    .expect("there is a writer for each column of the schema");
+                write_column(&mut column_writer, column, rows).context(WriteSnafu)?;
+                column_writer.close().context(WriteSnafu)?;
+            }
+            row_group.close().context(WriteSnafu)?;
+        }
+        writer.close().context(WriteSnafu)?;
+
+        Ok(buffer)
+    }
+}
+
+fn write_column(
+    writer: &mut SerializedColumnWriter<'_>,
+    column: &Column,
+    rows: &[Event],
+) -> Result<(), WriteError> {
+    let values = rows
+        .iter()
+        .map(|event| column.value(event))
+        .collect::<Vec<_>>();
+    let definition_levels = values
+        .iter()
+        .map(|value| i16::from(value.is_some()))
+        .collect::<Vec<_>>();
+    let definition_levels = (!column.required).then_some(definition_levels.as_slice());
+    let values = values.into_iter().flatten();
+
+    match writer.untyped() {
+        ColumnWriter::ByteArrayColumnWriter(writer) => {
+            let values = values
+                .map(|value| match value {
+                    Value::Bytes(bytes) if column.column_type == ParquetColumnType::String => {
+                        Ok(ByteArray::from(bytes.clone()))
+                    }
+                    value => serde_json::to_vec(value)
+                        .map(ByteArray::from)
+                        .map_err(|error| WriteError::External(Box::new(error))),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            writer.write_batch(&values, definition_levels, None)?;
+        }
+        ColumnWriter::Int64ColumnWriter(writer) => {
+            let values = values
+                .filter_map(|value| match value {
+                    Value::Integer(integer) => Some(*integer),
+                    Value::Timestamp(timestamp) => Some(timestamp.timestamp_micros()),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            writer.write_batch(&values, definition_levels, None)?;
+        }
+        ColumnWriter::DoubleColumnWriter(writer) => {
+            let values = values
+                .filter_map(|value| match value {
+                    Value::Float(float) => Some(float.into_inner()),
+                    Value::Integer(integer) => Some(*integer as f64),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            writer.write_batch(&values, definition_levels, None)?;
+        }
+        ColumnWriter::BoolColumnWriter(writer) => {
+            let values = values.filter_map(Value::as_boolean).collect::<Vec<_>>();
+            writer.write_batch(&values, definition_levels, None)?;
+        }
+        _ => unreachable!("columns are only of the types above"),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use chrono::{TimeZone, Utc};
+    use parquet::{
+        file::reader::{FileReader, SerializedFileReader},
+        record::Field,
+    };
+    use vector_core::event::LogEvent;
+    use vrl::btreemap;
+
+    use super::*;
+
+    fn column(name: &str, column_type: ParquetColumnType, required: bool) -> ParquetColumn {
+        ParquetColumn {
+            name: name.to_owned(),
+            column_type,
+            required,
+        }
+    }
+
+    fn serializer(schema: Vec<ParquetColumn>) -> ParquetSerializer {
+        ParquetSerializerConfig::new(ParquetSerializerOptions {
+            schema,
+            row_group_size: 2,
+            ..Default::default()
+        })
+        .build()
+        .unwrap()
+    }
+
+    #[test]
+    fn encode_parquet() {
+        let serializer = serializer(vec![
+            column("message", ParquetColumnType::String, true),
+            column("count", ParquetColumnType::Int64, false),
+            column("ratio", ParquetColumnType::Double, false),
+            column("timestamp", ParquetColumnType::Timestamp, false),
+            column("labels", ParquetColumnType::Json, false),
+        ]);
+        let timestamp = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+        let events = vec![
+            Event::Log(LogEvent::from(btreemap! {
+                "message" => "first",
+                "count" => 1,
+                "ratio" => 0.5,
+                "timestamp" => timestamp,
+                "labels" => btreemap! { "app" => "vector" },
+            })),
+            Event::Log(LogEvent::from(
+                btreemap! { "message" => "second", "ratio" => 2 },
+            )),
+            Event::Log(LogEvent::from(btreemap! { "message" => "third" })),
+        ];
+
+        let schema = serializer.schema(&events).unwrap();
+        for event in &events {
+            schema.validate(event).unwrap();
+        }
+        let file = schema.encode(&events).unwrap();
+
+        let reader = SerializedFileReader::new(Bytes::from(file)).unwrap();
+        assert_eq!(reader.metadata().num_row_groups(), 2);
+        let rows = reader
+            .get_row_iter(None)
+            .unwrap()
+            .map(|row| row.unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(rows.len(), 3);
+
+        let first = rows[0].get_column_iter().collect::<Vec<_>>();
+        assert_eq!(first[0].1, &Field::Str("first".to_owned()));
+        assert_eq!(first[1].1, &Field::Long(1));
+        assert_eq!(first[2].1, &Field::Double(0.5));
+        assert_eq!(
+            first[3].1,
+            &Field::TimestampMicros(timestamp.timestamp_micros())
+        );
+        assert_eq!(first[4].1, &Field::Str(r#"{"app":"vector"}"#.to_owned()));
+
+        let second = rows[1].get_column_iter().collect::<Vec<_>>();
+        assert_eq!(second[1].1, &Field::Null);
+        assert_eq!(second[2].1, &Field::Double(2.0));
+    }
+
+    #[test]
+    fn validate_parquet() {
+        let serializer = serializer(vec![
+            column("message", ParquetColumnType::String, true),
+            column("count", ParquetColumnType::Int64, false),
+        ]);
+        let schema = serializer.schema(&[]).unwrap();
+
+        let missing = Event::Log(LogEvent::from(btreemap! { "count" => 1 }));
+        assert_eq!(
+            schema.validate(&missing).unwrap_err().to_string(),
+            "missing a value for required column `message`"
+        );
+
+        let mismatch = Event::Log(LogEvent::from(btreemap! {
+            "message" => "hello",
+            "count" => "one",
+        }));
+        assert_eq!(
+            schema.validate(&mismatch).unwrap_err().to_string(),
+            "the value for column `count` is of type `string`, expected `int64`"
+        );
+    }
+
+    #[test]
+    fn derive_parquet_columns() {
+        let serializer = serializer(Vec::new());
+        let event = Event::Log(LogEvent::from(btreemap! { "message" => "hello" }));
+        assert!(matches!(
+            serializer.schema(&[event]),
+            Err(ParquetError::NoColumns)
+        ));
+
+        let definition = schema::Definition::empty_legacy_namespace()
+            .with_event_field(&lookup::owned_value_path!("message"), Kind::bytes(), None)
+            .with_event_field(
+                &lookup::owned_value_path!("status"),
+                Kind::integer().or_null(),
+                None,
+            );
+        let mut event = Event::Log(LogEvent::from(btreemap! { "message" => "hello" }));
+        event
+            .metadata_mut()
+            .set_schema_definition(&Arc::new(definition));
+
+        let schema = serializer.schema(&[event]).unwrap();
+        let columns = schema
+            .columns
+            .iter()
+            .map(|column| (column.name.as_str(), column.column_type))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            columns,
+            vec![
+                ("message", ParquetColumnType::String),
+                ("status", ParquetColumnType::Int64),
+            ]
+        );
+    }
+
+    #[test]
+    fn derive_parquet_columns_from_all_events() {
+        let serializer = serializer(Vec::new());
+        let first = schema::Definition::empty_legacy_namespace().with_event_field(
+            &lookup::owned_value_path!("message"),
+            Kind::bytes(),
+            None,
+        );
+        let second = schema::Definition::empty_legacy_namespace().with_event_field(
+            &lookup::owned_value_path!("status"),
+            Kind::integer(),
+            None,
+        );
+        let events = [first, second]
+            .into_iter()
+            .map(|definition| {
+                let mut event = Event::Log(LogEvent::from(btreemap! { "message" => "hello" }));
+                event
+                    .metadata_mut()
+                    .set_schema_definition(&Arc::new(definition));
+                event
+            })
+            .collect::<Vec<_>>();
+
+        let schema = serializer.schema(&events).unwrap();
+        let columns = schema
+            .columns
+            .iter()
+            .map(|column| column.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(columns, vec!["message", "status"]);
+    }
+}
@@ -0,0 +1,254 @@
+use std::sync::Arc;
+
+use bytes::{BufMut, BytesMut};
+use prost_reflect::FileDescriptor;
+use tokio_util::codec::Encoder;
+use vector_config::configurable_component;
+use vector_core::{config::DataType, event::Event, schema};
+
+use super::ProtobufSerializer;
+use crate::{
+    common::schema_registry::{
+        message_indexes, put_message_indexes, put_schema_id, SchemaCache, SchemaRegistryClient,
+        SchemaRegistryOptions, SchemaType,
+    },
+    encoding::BuildError,
+};
+
+/// Config used to build a `SchemaRegistrySerializer`.
+#[configurable_component]
+#[derive(Debug, Clone)]
+pub struct SchemaRegistrySerializerConfig {
+    /// Options for the Schema Registry serializer.
+    pub schema_registry: SchemaRegistrySerializerOptions,
+}
+
+impl SchemaRegistrySerializerConfig {
+    /// Build the `SchemaRegistrySerializer` from this configuration.
+    pub fn build(&self) -> Result<SchemaRegistrySerializer, BuildError> {
+        let options = &self.schema_registry;
+        let avro_schema = options
+            .avro_schema
+            .as_ref()
+            .map(|schema| {
+                apache_avro::Schema::parse_str(schema)
+                    .map(|parsed| (schema.clone(), parsed))
+                    .map_err(|error| format!("Failed building Avro serializer: {}", error))
+            })
+            .transpose()?;
+
+        Ok(SchemaRegistrySerializer {
+            client: SchemaRegistryClient::new(options.registry.clone())?,
+            subject: options.subject.clone(),
+            avro_schema,
+            message_type: options.message_type.clone(),
+            topic: None,
+            subjects: Arc::default(),
+        })
+    }
+
+    /// The data type of events that are accepted by `SchemaRegistrySerializer`.
+    pub fn input_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema required by the serializer.
+    pub fn schema_requirement(&self) -> schema::Requirement {
+        schema::Requirement::empty()
+    }
+}
+
+/// Schema Registry serializer options.
+#[configurable_component]
+#[derive(Debug, Clone)]
+pub struct SchemaRegistrySerializerOptions {
+    #[serde(flatten)]
+    #[configurable(derived)]
+    pub registry: SchemaRegistryOptions,
+
+    /// The subject whose schema events are encoded with.
+    ///
+    /// When not set, the subject is named after the topic that events are sent to, following the
+    /// topic name strategy: `<topic>-value`. Only the `kafka` sink provides a topic.
+    #[configurable(metadata(docs::examples = "logs-value"))]
+    pub subject: Option<String>,
+
+    /// An Avro schema to register under the subject when it's first used.
+    ///
+    /// When not set, events are encoded with the latest schema registered under the subject,
+    /// which can be an Avro or a Protobuf schema.
+    #[configurable(metadata(
+        docs::examples = r#"{ "type": "record", "name": "log", "fields": [{ "name": "message", "type": "string" }] }"#
+    ))]
+    #[configurable(metadata(docs::human_name = "Avro Schema JSON"))]
+    pub avro_schema: Option<String>,
+
+    /// The message type to encode events as, for Protobuf schemas.
+    ///
+    /// Defaults to the first message type of the schema.
+    #[configurable(metadata(docs::examples = "package.Message"))]
+    pub message_type: Option<String>,
+}
+
+/// How events are encoded for a subject.
+#[derive(Debug)]
+enum SubjectSerializer {
+    Avro {
+        id: u32,
+        schema: apache_avro::Schema,
+    },
+    Protobuf {
+        id: u32,
+        indexes: Vec<i32>,
+        serializer: ProtobufSerializer,
+    },
+}
+
+/// Serializer that converts an `Event` to an Avro or Protobuf message in the Confluent wire
+/// format, with a schema registered in the Schema Registry.
+#[derive(Debug, Clone)]
+pub struct SchemaRegistrySerializer {
+    client: SchemaRegistryClient,
+    subject: Option<String>,
+    avro_schema: Option<(String, apache_avro::Schema)>,
+    message_type: Option<String>,
+    topic: Option<String>,
+    subjects: Arc<SchemaCache<String, SubjectSerializer>>,
+}
+
+impl SchemaRegistrySerializer {
+    /// Sets the topic that events are sent to, which the subject is named after when it isn't
+    /// configured.
+    pub fn set_topic(&mut self, topic: &str) {
+        self.topic = Some(topic.to_owned());
+    }
+
+    fn subject_name(&self) -> vector_common::Result<String> {
+        match (&self.subject, &self.topic) {
+            (Some(subject), _) => Ok(subject.clone()),
+            (None, Some(topic)) => Ok(format!("{topic}-value")),
+            (None, None) => Err("A subject is required when events aren't sent to a topic.".into()),
+        }
+    }
+
+    fn subject_serializer(&self, subject: String) -> vector_common::Result<Arc<SubjectSerializer>> {
+        self.subjects.get_or_resolve(subject.clone(), || {
+            if let Some((definition, schema)) = &self.avro_schema {
+                return Ok(SubjectSerializer::Avro {
+                    id: self
+                        .client
+                        .register(&subject, definition, SchemaType::Avro)?,
+                    schema: schema.clone(),
+                });
+            }
+
+            let latest = self.client.latest_schema(&subject)?;
+            let id = latest
+                .id
+                .ok_or_else(|| format!("Missing schema ID for subject {subject:?}."))?;
+            match latest.schema_type {
+                SchemaType::Avro => Ok(SubjectSerializer::Avro {
+                    id,
+                    schema: self.client.avro_schema(&latest)?,
+                }),
+                SchemaType::Protobuf => {
+                    let file = self.client.protobuf_file(&latest)?;
+                    let message = self.message_type(&file)?;
+                    Ok(SubjectSerializer::Protobuf {
+                        id,
+                        indexes: message_indexes(&message),
+                        serializer: ProtobufSerializer::new(message),
+                    })
+                }
+                SchemaType::Json => Err(format!(
+                    "Subject {subject:?} has a JSON schema, which is not supported."
+                )
+                .into()),
+            }
+        })
+    }
+
+    fn message_type(
+        &self,
+        file: &FileDescriptor,
+    ) -> vector_common::Result<prost_reflect::MessageDescriptor> {
+        match &self.message_type {
+            Some(name) => file
+                .parent_pool()
+                .get_message_by_name(name)
+                .filter(|message| message.parent_file().name() == file.name())
+                .ok_or_else(|| {
+                    format!("Message type {name:?} is not defined by the schema.").into()
+                }),
+            None => file
+                .messages()
+                .next()
+                .ok_or_else(|| "The schema defines no message type.".into()),
+        }
+    }
+}
+
+impl Encoder<Event> for SchemaRegistrySerializer {
+    type Error = vector_common::Error;
+
+    fn encode(&mut self, event: Event, buffer: &mut BytesMut) -> Result<(), Self::Error> {
+        let subject = self.subject_name()?;
+        match self.subject_serializer(subject)?.as_ref() {
+            SubjectSerializer::Avro { id, schema } => {
+                let value = apache_avro::to_value(event.into_log())?.resolve(schema)?;
+                let bytes = apache_avro::to_avro_datum(schema, value)?;
+                put_schema_id(*id, buffer);
+                buffer.put_slice(&bytes);
+            }
+            SubjectSerializer::Protobuf {
+                id,
+                indexes,
+                serializer,
+            } => {
+                put_schema_id(*id, buffer);
+                put_message_indexes(indexes, buffer);
+                serializer.clone().encode(event, buffer)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(subject: Option<&str>) -> SchemaRegistrySerializerConfig {
+        SchemaRegistrySerializerConfig {
+            schema_registry: SchemaRegistrySerializerOptions {
+                registry: SchemaRegistryOptions {
+                    url: "http://localhost:8081".into(),
+                    ..Default::default()
+                },
+                subject: subject.map(Into::into),
+                avro_schema: None,
+                message_type: None,
+            },
+        }
+    }
+
+    #[test]
+    fn names_subject_after_topic() {
+        let mut serializer = config(None).build().unwrap();
+        assert!(serializer.subject_name().is_err());
+
+        serializer.set_topic("logs");
+        assert_eq!(serializer.subject_name().unwrap(), "logs-value");
+
+        let mut serializer = config(Some("events")).build().unwrap();
+        serializer.set_topic("logs");
+        assert_eq!(serializer.subject_name().unwrap(), "events");
+    }
+
+    #[test]
+    fn rejects_invalid_avro_schema() {
+        let mut config = config(None);
+        config.schema_registry.avro_schema = Some("{".into());
+        assert!(config.build().is_err());
+    }
+}
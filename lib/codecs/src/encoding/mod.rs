@@ -9,12 +9,21 @@ use std::fmt::Debug;
 use bytes::BytesMut;
 pub use format::{
     AvroSerializer, AvroSerializerConfig, AvroSerializerOptions, CefSerializer,
-    CefSerializerConfig, CsvSerializer, CsvSerializerConfig, GelfSerializer, GelfSerializerConfig,
-    JsonSerializer, JsonSerializerConfig, JsonSerializerOptions, LogfmtSerializer,
-    LogfmtSerializerConfig, NativeJsonSerializer, NativeJsonSerializerConfig, NativeSerializer,
-    NativeSerializerConfig, ProtobufSerializer, ProtobufSerializerConfig,
-    ProtobufSerializerOptions, RawMessageSerializer, RawMessageSerializerConfig, TextSerializer,
-    TextSerializerConfig,
+    CefSerializerConfig, CefSerializerOptions, CsvSerializer, CsvSerializerConfig,
+    CsvSerializerOptions, GelfSerializer, GelfSerializerConfig, JsonSerializer,
+    JsonSerializerConfig, JsonSerializerOptions, LogfmtSerializer, LogfmtSerializerConfig,
+    NativeJsonSerializer, NativeJsonSerializerConfig, NativeSerializer, NativeSerializerConfig,
+    ProtobufSerializer, ProtobufSerializerConfig, ProtobufSerializerOptions, RawMessageSerializer,
+    RawMessageSerializerConfig, TextSerializer, TextSerializerConfig,
+};
+#[cfg(feature = "parquet")]
+pub use format::{
+    ParquetColumn, ParquetColumnType, ParquetCompression, ParquetError, ParquetSchema,
+    ParquetSerializer, ParquetSerializerConfig, ParquetSerializerOptions,
+};
+#[cfg(feature = "schema-registry")]
+pub use format::{
+    SchemaRegistrySerializer, SchemaRegistrySerializerConfig, SchemaRegistrySerializerOptions,
 };
 pub use framing::{
     BoxedFramer, BoxedFramingError, BytesEncoder, BytesEncoderConfig, CharacterDelimitedEncoder,
@@ -256,6 +265,16 @@ pub enum SerializerConfig {
     /// could lead to the encoding emitting empty strings for the given event.
     RawMessage,
 
+    #[cfg(feature = "schema-registry")]
+    /// Encodes an event as an Avro or Protobuf message in the [Confluent wire format][wire_format].
+    ///
+    /// The schema is that of a subject in the [Schema Registry][schema_registry], and its ID is
+    /// prepended to each message.
+    ///
+    /// [wire_format]: https://docs.confluent.io/platform/current/schema-registry/fundamentals/serdes-develop/index.html#wire-format
+    /// [schema_registry]: https://docs.confluent.io/platform/current/schema-registry/index.html
+    SchemaRegistry(SchemaRegistrySerializerConfig),
+
     /// Plain text encoding.
     ///
     /// This encoding uses the `message` field of a log event. For metrics, it uses an
@@ -327,6 +346,13 @@ impl From<RawMessageSerializerConfig> for SerializerConfig {
     }
 }
 
+#[cfg(feature = "schema-registry")]
+impl From<SchemaRegistrySerializerConfig> for SerializerConfig {
+    fn from(config: SchemaRegistrySerializerConfig) -> Self {
+        Self::SchemaRegistry(config)
+    }
+}
+
 impl From<TextSerializerConfig> for SerializerConfig {
     fn from(config: TextSerializerConfig) -> Self {
         Self::Text(config)
@@ -353,6 +379,10 @@ impl SerializerConfig {
             SerializerConfig::RawMessage => {
                 Ok(Serializer::RawMessage(RawMessageSerializerConfig.build()))
             }
+            #[cfg(feature = "schema-registry")]
+            SerializerConfig::SchemaRegistry(config) => {
+                Ok(Serializer::SchemaRegistry(config.build()?))
+            }
             SerializerConfig::Text(config) => Ok(Serializer::Text(config.build())),
         }
     }
@@ -376,6 +406,10 @@ impl SerializerConfig {
             | SerializerConfig::Protobuf(_) => {
                 FramingConfig::LengthDelimited(LengthDelimitedEncoderConfig::default())
             }
+            #[cfg(feature = "schema-registry")]
+            SerializerConfig::SchemaRegistry(_) => {
+                FramingConfig::LengthDelimited(LengthDelimitedEncoderConfig::default())
+            }
             SerializerConfig::Cef(_)
             | SerializerConfig::Csv(_)
             | SerializerConfig::Json(_)
@@ -404,6 +438,8 @@ impl SerializerConfig {
             SerializerConfig::NativeJson => NativeJsonSerializerConfig.input_type(),
             SerializerConfig::Protobuf(config) => config.input_type(),
             SerializerConfig::RawMessage => RawMessageSerializerConfig.input_type(),
+            #[cfg(feature = "schema-registry")]
+            SerializerConfig::SchemaRegistry(config) => config.input_type(),
             SerializerConfig::Text(config) => config.input_type(),
         }
     }
@@ -423,6 +459,8 @@ impl SerializerConfig {
             SerializerConfig::NativeJson => NativeJsonSerializerConfig.schema_requirement(),
             SerializerConfig::Protobuf(config) => config.schema_requirement(),
             SerializerConfig::RawMessage => RawMessageSerializerConfig.schema_requirement(),
+            #[cfg(feature = "schema-registry")]
+            SerializerConfig::SchemaRegistry(config) => config.schema_requirement(),
             SerializerConfig::Text(config) => config.schema_requirement(),
         }
     }
@@ -451,6 +489,9 @@ pub enum Serializer {
     Protobuf(ProtobufSerializer),
     /// Uses a `RawMessageSerializer` for serialization.
     RawMessage(RawMessageSerializer),
+    #[cfg(feature = "schema-registry")]
+    /// Uses a `SchemaRegistrySerializer` for serialization.
+    SchemaRegistry(SchemaRegistrySerializer),
     /// Uses a `TextSerializer` for serialization.
     Text(TextSerializer),
 }
@@ -468,6 +509,8 @@ impl Serializer {
             | Serializer::Native(_)
             | Serializer::Protobuf(_)
             | Serializer::RawMessage(_) => false,
+            #[cfg(feature = "schema-registry")]
+            Serializer::SchemaRegistry(_) => false,
         }
     }
 
@@ -492,6 +535,19 @@ impl Serializer {
             | Serializer::RawMessage(_) => {
                 panic!("Serializer does not support JSON")
             }
+            #[cfg(feature = "schema-registry")]
+            Serializer::SchemaRegistry(_) => {
+                panic!("Serializer does not support JSON")
+            }
+        }
+    }
+
+    /// Sets the Kafka topic that events are sent to, for serializers that depend on it.
+    #[cfg_attr(not(feature = "schema-registry"), allow(unused_variables))]
+    pub fn set_topic(&mut self, topic: &str) {
+        #[cfg(feature = "schema-registry")]
+        if let Serializer::SchemaRegistry(serializer) = self {
+            serializer.set_topic(topic);
         }
     }
 }
@@ -556,6 +612,13 @@ impl From<RawMessageSerializer> for Serializer {
     }
 }
 
+#[cfg(feature = "schema-registry")]
+impl From<SchemaRegistrySerializer> for Serializer {
+    fn from(serializer: SchemaRegistrySerializer) -> Self {
+        Self::SchemaRegistry(serializer)
+    }
+}
+
 impl From<TextSerializer> for Serializer {
     fn from(serializer: TextSerializer) -> Self {
         Self::Text(serializer)
@@ -577,7 +640,92 @@ impl tokio_util::codec::Encoder<Event> for Serializer {
             Serializer::NativeJson(serializer) => serializer.encode(event, buffer),
             Serializer::Protobuf(serializer) => serializer.encode(event, buffer),
             Serializer::RawMessage(serializer) => serializer.encode(event, buffer),
+            #[cfg(feature = "schema-registry")]
+            Serializer::SchemaRegistry(serializer) => serializer.encode(event, buffer),
             Serializer::Text(serializer) => serializer.encode(event, buffer),
         }
     }
 }
+
+/// Batch serializer configuration.
+#[cfg(feature = "parquet")]
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(tag = "codec", rename_all = "snake_case")]
+#[configurable(metadata(
+    docs::enum_tag_description = "The codec to use for encoding batches of events."
+))]
+pub enum BatchSerializerConfig {
+    /// Encodes each batch of events as an [Apache Parquet][apache_parquet] file.
+    ///
+    /// [apache_parquet]: https://parquet.apache.org/
+    Parquet {
+        /// Apache Parquet-specific encoder options.
+        #[serde(default)]
+        parquet: ParquetSerializerOptions,
+    },
+}
+
+#[cfg(feature = "parquet")]
+impl From<ParquetSerializerConfig> for BatchSerializerConfig {
+    fn from(config: ParquetSerializerConfig) -> Self {
+        Self::Parquet {
+            parquet: config.parquet,
+        }
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl BatchSerializerConfig {
+    /// Build the `BatchSerializer` from this configuration.
+    pub fn build(&self) -> Result<BatchSerializer, BuildError> {
+        match self {
+            BatchSerializerConfig::Parquet { parquet } => Ok(BatchSerializer::Parquet(
+                ParquetSerializerConfig::new(parquet.clone()).build()?,
+            )),
+        }
+    }
+
+    /// The data type of events that are accepted by this batch serializer.
+    pub fn input_type(&self) -> DataType {
+        match self {
+            BatchSerializerConfig::Parquet { parquet } => {
+                ParquetSerializerConfig::new(parquet.clone()).input_type()
+            }
+        }
+    }
+
+    /// The schema required by the batch serializer.
+    pub fn schema_requirement(&self) -> schema::Requirement {
+        match self {
+            BatchSerializerConfig::Parquet { parquet } => {
+                ParquetSerializerConfig::new(parquet.clone()).schema_requirement()
+            }
+        }
+    }
+}
+
+/// Serialize batches of structured events as a whole.
+#[cfg(feature = "parquet")]
+#[derive(Debug, Clone)]
+pub enum BatchSerializer {
+    /// Uses a `ParquetSerializer` for serialization.
+    Parquet(ParquetSerializer),
+}
+
+#[cfg(feature = "parquet")]
+impl BatchSerializer {
+    /// Get the HTTP content type.
+    pub const fn content_type(&self) -> &'static str {
+        match self {
+            BatchSerializer::Parquet(_) => "application/vnd.apache.parquet",
+        }
+    }
+
+    /// Get the file extension of the serialized batches.
+    pub const fn extension(&self) -> &'static str {
+        match self {
+            BatchSerializer::Parquet(_) => "parquet",
+        }
+    }
+}
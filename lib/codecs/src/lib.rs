@@ -9,6 +9,8 @@ pub mod decoding;
 pub mod encoding;
 pub mod gelf;
 
+#[cfg(feature = "schema-registry")]
+pub use common::schema_registry::{SchemaRegistryAuth, SchemaRegistryOptions};
 pub use decoding::{
     BytesDecoder, BytesDecoderConfig, BytesDeserializer, BytesDeserializerConfig,
     CharacterDelimitedDecoder, CharacterDelimitedDecoderConfig, GelfDeserializer,
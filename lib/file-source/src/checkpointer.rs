@@ -32,12 +32,12 @@ enum State {
 
 /// A simple JSON-friendly struct of the fingerprint/position pair, since
 /// fingerprints as objects cannot be keys in a plain JSON map.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, Eq, PartialEq, Ord, PartialOrd)]
 #[serde(rename_all = "snake_case")]
-struct Checkpoint {
-    fingerprint: FileFingerprint,
-    position: FilePosition,
-    modified: DateTime<Utc>,
+pub struct Checkpoint {
+    pub fingerprint: FileFingerprint,
+    pub position: FilePosition,
+    pub modified: DateTime<Utc>,
 }
 
 pub struct Checkpointer {
@@ -87,6 +87,32 @@ impl CheckpointsView {
         }
     }
 
+    /// Returns all the checkpoints, ordered by fingerprint.
+    pub fn checkpoints(&self) -> Vec<Checkpoint> {
+        match self.get_state() {
+            State::V1 { checkpoints } => checkpoints.into_iter().collect(),
+        }
+    }
+
+    /// Adds a checkpoint, replacing any existing checkpoint for the same
+    /// fingerprint while keeping the given modification time.
+    pub fn insert(&self, checkpoint: Checkpoint) {
+        self.load(checkpoint);
+        self.removed_times.remove(&checkpoint.fingerprint);
+    }
+
+    /// Removes the checkpoint of a fingerprint, returning it if there was one.
+    pub fn remove(&self, fng: FileFingerprint) -> Option<Checkpoint> {
+        let (_, position) = self.checkpoints.remove(&fng)?;
+        let modified = self.modified_times.remove(&fng);
+        self.removed_times.remove(&fng);
+        Some(Checkpoint {
+            fingerprint: fng,
+            position,
+            modified: modified.map_or_else(Utc::now, |(_, modified)| modified),
+        })
+    }
+
     pub fn contains_bytes_checksums(&self) -> bool {
         self.checkpoints
             .iter()
@@ -232,6 +258,7 @@ impl Checkpointer {
             BytesChecksum(c) => format!("g{:x}.{}", c, pos),
             FirstLinesChecksum(c) => format!("h{:x}.{}", c, pos),
             DevInode(dev, ino) => format!("i{:x}.{:x}.{}", dev, ino, pos),
+            DevInodeChecksum(dev, ino, c) => format!("j{:x}.{:x}.{:x}.{}", dev, ino, c, pos),
             Unknown(x) => format!("{:x}.{}", x, pos),
         };
         self.directory.join(path)
@@ -263,6 +290,18 @@ impl Checkpointer {
                         .unwrap();
                 (DevInode(dev, ino), pos)
             }
+            'j' => {
+                let (dev, ino, c, pos) = scan_fmt!(
+                    file_name,
+                    "j{x}.{x}.{x}.{}",
+                    [hex u64],
+                    [hex u64],
+                    [hex u64],
+                    FilePosition
+                )
+                .unwrap();
+                (DevInodeChecksum(dev, ino, c), pos)
+            }
             _ => {
                 let (c, pos) = scan_fmt!(file_name, "{x}.{}", [hex u64], FilePosition).unwrap();
                 (Unknown(c), pos)
@@ -397,6 +436,28 @@ impl Checkpointer {
         }
     }
 
+    /// Write the current checkpoints to `writer` in the same format they are
+    /// persisted to disk.
+    pub fn export_checkpoints<W: io::Write>(&self, writer: W) -> Result<usize, io::Error> {
+        let state = self.checkpoints.get_state();
+        serde_json::to_writer_pretty(writer, &state)?;
+        Ok(self.checkpoints.checkpoints.len())
+    }
+
+    /// Read checkpoints in the format written by [`Self::export_checkpoints`]
+    /// from `reader`, replacing the current checkpoints of the same
+    /// fingerprints. Returns the number of checkpoints read.
+    pub fn import_checkpoints<R: io::Read>(&self, reader: R) -> Result<usize, io::Error> {
+        let state: State = serde_json::from_reader(reader)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let State::V1 { checkpoints } = state;
+        let count = checkpoints.len();
+        for checkpoint in checkpoints {
+            self.checkpoints.insert(checkpoint);
+        }
+        Ok(count)
+    }
+
     fn read_checkpoints_file(&self, path: &Path) -> Result<State, io::Error> {
         let reader = io::BufReader::new(fs::File::open(path)?);
         serde_json::from_reader(reader).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
@@ -426,7 +487,7 @@ impl Checkpointer {
 
 #[cfg(test)]
 mod test {
-    use chrono::{Duration, Utc};
+    use chrono::{DateTime, Duration, Utc};
     use similar_asserts::assert_eq;
     use tempfile::tempdir;
 
@@ -440,6 +501,7 @@ mod test {
     fn test_checkpointer_basics() {
         let fingerprints = vec![
             FileFingerprint::DevInode(1, 2),
+            FileFingerprint::DevInodeChecksum(1, 2, 3),
             FileFingerprint::BytesChecksum(3456),
             FileFingerprint::FirstLinesChecksum(78910),
             FileFingerprint::Unknown(1337),
@@ -790,6 +852,10 @@ mod test {
                 FileFingerprint::DevInode(1, 2),
                 r#"{"version":"1","checkpoints":[{"fingerprint":{"dev_inode":[1,2]},"position":1234}]}"#,
             ),
+            (
+                FileFingerprint::DevInodeChecksum(1, 2, 3),
+                r#"{"version":"1","checkpoints":[{"fingerprint":{"dev_inode_checksum":[1,2,3]},"position":1234}]}"#,
+            ),
             (
                 FileFingerprint::BytesChecksum(3456),
                 r#"{"version":"1","checkpoints":[{"fingerprint":{"checksum":3456},"position":1234}]}"#,
@@ -887,4 +953,52 @@ mod test {
             assert_eq!(chkptr.get_checkpoint(fingerprint), Some(1234))
         }
     }
+
+    #[test]
+    fn test_checkpointer_export_import() {
+        let modified = DateTime::parse_from_rfc3339("2021-07-12T18:19:11.769003Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let checkpoints = vec![
+            Checkpoint {
+                fingerprint: FileFingerprint::FirstLinesChecksum(78910),
+                position: 5678,
+                modified,
+            },
+            Checkpoint {
+                fingerprint: FileFingerprint::DevInode(1, 2),
+                position: 1234,
+                modified,
+            },
+        ];
+
+        let data_dir = tempdir().unwrap();
+        let chkptr = Checkpointer::new(data_dir.path());
+        for checkpoint in &checkpoints {
+            chkptr.view().insert(*checkpoint);
+        }
+        assert_eq!(chkptr.view().checkpoints(), checkpoints);
+
+        let mut exported = Vec::new();
+        assert_eq!(chkptr.export_checkpoints(&mut exported).unwrap(), 2);
+
+        let data_dir = tempdir().unwrap();
+        let mut chkptr = Checkpointer::new(data_dir.path());
+        chkptr.update_checkpoint(FileFingerprint::DevInode(1, 2), 1);
+        chkptr.update_checkpoint(FileFingerprint::Unknown(1337), 1);
+        assert_eq!(chkptr.import_checkpoints(exported.as_slice()).unwrap(), 2);
+        assert_eq!(
+            chkptr
+                .view()
+                .remove(FileFingerprint::Unknown(1337))
+                .map(|c| c.position),
+            Some(1)
+        );
+        assert_eq!(chkptr.view().checkpoints(), checkpoints);
+
+        chkptr.write_checkpoints().unwrap();
+        let mut chkptr = Checkpointer::new(data_dir.path());
+        chkptr.read_checkpoints(None);
+        assert_eq!(chkptr.view().checkpoints(), checkpoints);
+    }
 }
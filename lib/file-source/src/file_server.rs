@@ -2,7 +2,7 @@ use std::{
     cmp,
     collections::{BTreeMap, HashSet},
     fs::{self, remove_file},
-    path::PathBuf,
+    path::{Path, PathBuf},
     sync::Arc,
     time::{self, Duration},
 };
@@ -21,6 +21,7 @@ use crate::{
     checkpointer::{Checkpointer, CheckpointsView},
     file_watcher::FileWatcher,
     fingerprinter::{FileFingerprint, Fingerprinter},
+    metadata_ext::PortableFileExt,
     paths_provider::PathsProvider,
     FileSourceInternalEvents, ReadFrom,
 };
@@ -220,6 +221,25 @@ where
                                     }
                                 }
                             }
+                        } else if let Some(old_file_id) = find_rewritten_file(&path, &fp_map) {
+                            // the watched file was rewritten in place, such as by `copytruncate`
+                            // rotation, so its fingerprint changed
+                            info!(
+                                message = "Watched file has been rewritten.",
+                                path = ?path,
+                            );
+                            let (index, _, mut watcher) = fp_map
+                                .shift_remove_full(&old_file_id)
+                                .expect("watcher of rewritten file");
+                            watcher.set_file_findable(true);
+                            match watcher.reset() {
+                                Ok(file_position) => {
+                                    self.emitter.emit_file_truncated(&path, file_position);
+                                }
+                                Err(error) => self.emitter.emit_file_watch_error(&path, error),
+                            }
+                            checkpoints.set_dead(old_file_id);
+                            fp_map.shift_insert(index, file_id, watcher);
                         } else {
                             // untracked file fingerprint
                             self.watch_new_file(path, file_id, &mut fp_map, &checkpoints, false);
@@ -268,6 +288,9 @@ where
 
                 if bytes_read > 0 {
                     global_bytes_read = global_bytes_read.saturating_add(bytes_read);
+                } else if let Ok(Some(file_position)) = watcher.reset_if_truncated() {
+                    self.emitter
+                        .emit_file_truncated(&watcher.path, file_position);
                 } else {
                     // Should the file be removed
                     if let Some(grace_period) = self.remove_after {
@@ -415,6 +438,10 @@ where
                 } else {
                     self.emitter.emit_file_added(&path);
                 }
+                // the file may have been truncated while it wasn't watched
+                if let Ok(Some(file_position)) = watcher.reset_if_truncated() {
+                    self.emitter.emit_file_truncated(&path, file_position);
+                }
                 watcher.set_file_findable(true);
                 fp_map.insert(file_id, watcher);
             }
@@ -423,6 +450,20 @@ where
     }
 }
 
+/// Finds the watcher of the file at `path` when it's the same file, by device and inode, but with a
+/// different fingerprint, which happens when the beginning of the file is rewritten.
+fn find_rewritten_file(
+    path: &Path,
+    fp_map: &IndexMap<FileFingerprint, FileWatcher>,
+) -> Option<FileFingerprint> {
+    let file = fs::File::open(path).ok()?;
+    let dev_inode = (file.portable_dev().ok()?, file.portable_ino().ok()?);
+    fp_map
+        .iter()
+        .find(|(_, watcher)| watcher.path == path && watcher.dev_inode() == dev_inode)
+        .map(|(file_id, _)| *file_id)
+}
+
 async fn checkpoint_writer(
     checkpointer: Checkpointer,
     sleep_duration: Duration,
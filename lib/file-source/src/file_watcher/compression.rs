@@ -0,0 +1,66 @@
+use std::io::{self, BufRead};
+
+use flate2::bufread::MultiGzDecoder;
+use vector_common::constants::GZIP_MAGIC;
+
+const ZSTD_MAGIC: &[u8] = &[0x28, 0xb5, 0x2f, 0xfd];
+const BZIP2_MAGIC: &[u8] = b"BZh";
+const XZ_MAGIC: &[u8] = &[0xfd, 0x37, 0x7a, 0x58, 0x5a, 0x00];
+
+/// The compression formats of files that are transparently decompressed.
+///
+/// Files are identified by the magic bytes they start with, regardless of their name.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(super) enum Compression {
+    Gzip,
+    Zstd,
+    Bzip2,
+    Xz,
+}
+
+impl Compression {
+    /// Detects the compression of the file read by `reader` from its first bytes.
+    pub(super) fn detect<R: BufRead>(reader: &mut R) -> io::Result<Option<Self>> {
+        let header_bytes = reader.fill_buf()?;
+        // WARN: The paired `BufRead::consume` is not called intentionally. If we
+        // do we'll chop a decent part of the potential compressed stream off.
+        Ok([
+            (Self::Gzip, GZIP_MAGIC),
+            (Self::Zstd, ZSTD_MAGIC),
+            (Self::Bzip2, BZIP2_MAGIC),
+            (Self::Xz, XZ_MAGIC),
+        ]
+        .into_iter()
+        .find_map(|(compression, magic)| header_bytes.starts_with(magic).then_some(compression)))
+    }
+
+    pub(super) const fn as_str(self) -> &'static str {
+        match self {
+            Self::Gzip => "gzip",
+            Self::Zstd => "zstd",
+            Self::Bzip2 => "bzip2",
+            Self::Xz => "xz",
+        }
+    }
+
+    /// Wraps `reader` in a decoder of all the concatenated compressed streams it contains.
+    pub(super) fn decoder<R: BufRead + 'static>(self, reader: R) -> io::Result<Box<dyn BufRead>> {
+        Ok(match self {
+            Self::Gzip => Box::new(io::BufReader::new(MultiGzDecoder::new(reader))),
+            Self::Zstd => Box::new(io::BufReader::new(zstd::Decoder::with_buffer(reader)?)),
+            Self::Bzip2 => Box::new(io::BufReader::new(bzip2::bufread::MultiBzDecoder::new(
+                reader,
+            ))),
+            Self::Xz => Box::new(io::BufReader::new(
+                xz2::bufread::XzDecoder::new_multi_decoder(reader),
+            )),
+        })
+    }
+}
+
+/// Skips the first `offset` decompressed bytes of `reader`, returning how many were skipped.
+///
+/// Compressed files can't be seeked into, so this decompresses everything up to `offset`.
+pub(super) fn skip_decompressed(reader: &mut dyn BufRead, offset: u64) -> io::Result<u64> {
+    io::copy(&mut reader.take(offset), &mut io::sink())
+}
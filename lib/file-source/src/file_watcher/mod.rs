@@ -7,16 +7,18 @@ use std::{
 
 use bytes::{Bytes, BytesMut};
 use chrono::{DateTime, Utc};
-use flate2::bufread::MultiGzDecoder;
 use tracing::debug;
-use vector_common::constants::GZIP_MAGIC;
 
 use crate::{
     buffer::read_until_with_max_size, metadata_ext::PortableFileExt, FilePosition, ReadFrom,
 };
+
+mod compression;
 #[cfg(test)]
 mod tests;
 
+use compression::{skip_decompressed, Compression};
+
 /// The `RawLine` struct is a thin wrapper around the bytes that have been read
 /// in order to retain the context of where in the file they have been read from.
 ///
@@ -38,6 +40,8 @@ pub(super) struct RawLine {
 pub struct FileWatcher {
     pub path: PathBuf,
     findable: bool,
+    file: File,
+    compression: Option<Compression>,
     reader: Box<dyn BufRead>,
     file_position: FilePosition,
     devno: u64,
@@ -68,6 +72,7 @@ impl FileWatcher {
         let f = fs::File::open(&path)?;
         let (devno, ino) = (f.portable_dev()?, f.portable_ino()?);
         let metadata = f.metadata()?;
+        let file = f.try_clone()?;
         let mut reader = io::BufReader::new(f);
 
         let too_old = if let (Some(ignore_before), Ok(modified_time)) = (
@@ -79,53 +84,61 @@ impl FileWatcher {
             false
         };
 
-        let gzipped = is_gzipped(&mut reader)?;
+        let compression = Compression::detect(&mut reader)?;
 
         // Determine the actual position at which we should start reading
         let (reader, file_position): (Box<dyn BufRead>, FilePosition) =
-            match (gzipped, too_old, read_from) {
-                (true, true, _) => {
+            match (compression, too_old, read_from) {
+                (Some(compression), true, _) => {
                     debug!(
-                        message = "Not reading gzipped file older than `ignore_older`.",
+                        message = "Not reading compressed file older than `ignore_older`.",
+                        compression = compression.as_str(),
                         ?path,
                     );
                     (Box::new(null_reader()), 0)
                 }
-                (true, _, ReadFrom::Checkpoint(file_position)) => {
-                    debug!(
-                        message = "Not re-reading gzipped file with existing stored offset.",
-                        ?path,
-                        %file_position
-                    );
-                    (Box::new(null_reader()), file_position)
+                // The positions of compressed files are offsets into their decompressed data.
+                (Some(compression), _, ReadFrom::Checkpoint(file_position)) => {
+                    let mut reader = compression.decoder(reader)?;
+                    let pos = skip_decompressed(&mut reader, file_position)?;
+                    if pos < file_position {
+                        debug!(
+                            message = "Compressed file is shorter than its stored offset.",
+                            compression = compression.as_str(),
+                            ?path,
+                            %file_position,
+                        );
+                    }
+                    (reader, pos)
                 }
-                // TODO: This may become the default, leading us to stop reading gzipped files that
-                // we were reading before. Should we merge this and the next branch to read
+                // TODO: This may become the default, leading us to stop reading compressed files
+                // that we were reading before. Should we merge this and the next branch to read
                 // compressed file from the beginning even when `read_from = "end"` (implicitly via
                 // default or explicitly via config)?
-                (true, _, ReadFrom::End) => {
+                (Some(compression), _, ReadFrom::End) => {
                     debug!(
                         message = "Can't read from the end of already-compressed file.",
+                        compression = compression.as_str(),
                         ?path,
                     );
                     (Box::new(null_reader()), 0)
                 }
-                (true, false, ReadFrom::Beginning) => {
-                    (Box::new(io::BufReader::new(MultiGzDecoder::new(reader))), 0)
+                (Some(compression), false, ReadFrom::Beginning) => {
+                    (compression.decoder(reader)?, 0)
                 }
-                (false, true, _) => {
+                (None, true, _) => {
                     let pos = reader.seek(io::SeekFrom::End(0)).unwrap();
                     (Box::new(reader), pos)
                 }
-                (false, false, ReadFrom::Checkpoint(file_position)) => {
+                (None, false, ReadFrom::Checkpoint(file_position)) => {
                     let pos = reader.seek(io::SeekFrom::Start(file_position)).unwrap();
                     (Box::new(reader), pos)
                 }
-                (false, false, ReadFrom::Beginning) => {
+                (None, false, ReadFrom::Beginning) => {
                     let pos = reader.seek(io::SeekFrom::Start(0)).unwrap();
                     (Box::new(reader), pos)
                 }
-                (false, false, ReadFrom::End) => {
+                (None, false, ReadFrom::End) => {
                     let pos = reader.seek(io::SeekFrom::End(0)).unwrap();
                     (Box::new(reader), pos)
                 }
@@ -141,6 +154,8 @@ impl FileWatcher {
         Ok(FileWatcher {
             path,
             findable: true,
+            file,
+            compression,
             reader,
             file_position,
             devno,
@@ -159,18 +174,22 @@ impl FileWatcher {
     pub fn update_path(&mut self, path: PathBuf) -> io::Result<()> {
         let file_handle = File::open(&path)?;
         if (file_handle.portable_dev()?, file_handle.portable_ino()?) != (self.devno, self.inode) {
-            let mut reader = io::BufReader::new(fs::File::open(&path)?);
-            let gzipped = is_gzipped(&mut reader)?;
-            let new_reader: Box<dyn BufRead> = if gzipped {
-                if self.file_position != 0 {
-                    Box::new(null_reader())
-                } else {
-                    Box::new(io::BufReader::new(MultiGzDecoder::new(reader)))
+            let file = fs::File::open(&path)?;
+            let mut reader = io::BufReader::new(file.try_clone()?);
+            let compression = Compression::detect(&mut reader)?;
+            let new_reader: Box<dyn BufRead> = match compression {
+                Some(compression) => {
+                    let mut reader = compression.decoder(reader)?;
+                    skip_decompressed(&mut reader, self.file_position)?;
+                    reader
+                }
+                None => {
+                    reader.seek(io::SeekFrom::Start(self.file_position))?;
+                    Box::new(reader)
                 }
-            } else {
-                reader.seek(io::SeekFrom::Start(self.file_position))?;
-                Box::new(reader)
             };
+            self.file = file;
+            self.compression = compression;
             self.reader = new_reader;
             self.devno = file_handle.portable_dev()?;
             self.inode = file_handle.portable_ino()?;
@@ -179,6 +198,39 @@ impl FileWatcher {
         Ok(())
     }
 
+    /// Restarts reading the file from its beginning if it was truncated below the current read
+    /// position, as happens with `copytruncate` rotation.
+    ///
+    /// Returns the position the file was read up to before it was truncated.
+    pub fn reset_if_truncated(&mut self) -> io::Result<Option<FilePosition>> {
+        // The positions of compressed files are offsets into their decompressed data, so they
+        // can't be compared to the size of the file.
+        if self.compression.is_some() || self.file.metadata()?.len() >= self.file_position {
+            return Ok(None);
+        }
+        self.reset().map(Some)
+    }
+
+    /// Restarts reading the file from its beginning, such as when it was rewritten in place.
+    ///
+    /// Returns the position the file was read up to.
+    pub fn reset(&mut self) -> io::Result<FilePosition> {
+        // The handles share the file offset, so seeking one seeks the new reader.
+        let mut reader = io::BufReader::new(self.file.try_clone()?);
+        reader.seek(io::SeekFrom::Start(0))?;
+        self.reader = match self.compression {
+            Some(compression) => compression.decoder(reader)?,
+            None => Box::new(reader),
+        };
+        self.buf.clear();
+        self.reached_eof = false;
+        Ok(std::mem::replace(&mut self.file_position, 0))
+    }
+
+    pub fn dev_inode(&self) -> (u64, u64) {
+        (self.devno, self.inode)
+    }
+
     pub fn set_file_findable(&mut self, f: bool) {
         self.findable = f;
         if f {
@@ -290,13 +342,6 @@ impl FileWatcher {
     }
 }
 
-fn is_gzipped(r: &mut io::BufReader<fs::File>) -> io::Result<bool> {
-    let header_bytes = r.fill_buf()?;
-    // WARN: The paired `BufReader::consume` is not called intentionally. If we
-    // do we'll chop a decent part of the potential gzip stream off.
-    Ok(header_bytes.starts_with(GZIP_MAGIC))
-}
-
 fn null_reader() -> impl BufRead {
     io::Cursor::new(Vec::new())
 }
@@ -0,0 +1,84 @@
+use std::{fs, io::Write, path::Path};
+
+use bytes::Bytes;
+
+use crate::{
+    file_watcher::{compression::Compression, FileWatcher},
+    ReadFrom,
+};
+
+const LINES: [&str; 4] = ["first line", "second line", "third line", "fourth line"];
+
+fn compress(compression: Compression, data: &[u8]) -> Vec<u8> {
+    match compression {
+        Compression::Gzip => {
+            let mut encoder =
+                flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+            encoder.write_all(data).unwrap();
+            encoder.finish().unwrap()
+        }
+        Compression::Zstd => zstd::encode_all(data, 0).unwrap(),
+        Compression::Bzip2 => {
+            let mut encoder =
+                bzip2::write::BzEncoder::new(Vec::new(), bzip2::Compression::default());
+            encoder.write_all(data).unwrap();
+            encoder.finish().unwrap()
+        }
+        Compression::Xz => {
+            let mut encoder = xz2::write::XzEncoder::new(Vec::new(), 6);
+            encoder.write_all(data).unwrap();
+            encoder.finish().unwrap()
+        }
+    }
+}
+
+fn read_lines(path: &Path, read_from: ReadFrom) -> (Vec<(u64, String)>, u64) {
+    let mut watcher =
+        FileWatcher::new(path.to_path_buf(), read_from, None, 1024, Bytes::from("\n")).unwrap();
+    let mut lines = Vec::new();
+    while let Some(line) = watcher.read_line().unwrap() {
+        lines.push((line.offset, String::from_utf8(line.bytes.to_vec()).unwrap()));
+    }
+    (lines, watcher.get_file_position())
+}
+
+#[test]
+fn reads_and_resumes_compressed_files() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let data = LINES.map(|line| format!("{line}\n")).concat();
+
+    for compression in [
+        Compression::Gzip,
+        Compression::Zstd,
+        Compression::Bzip2,
+        Compression::Xz,
+    ] {
+        let path = dir.path().join(compression.as_str());
+        // Rotated files are often made of several concatenated compressed streams.
+        let (head, tail) = data.split_at(data.len() / 2);
+        let mut compressed = compress(compression, head.as_bytes());
+        compressed.extend(compress(compression, tail.as_bytes()));
+        fs::write(&path, compressed).unwrap();
+
+        let (lines, end) = read_lines(&path, ReadFrom::Beginning);
+        assert_eq!(
+            lines
+                .iter()
+                .map(|(_, line)| line.as_str())
+                .collect::<Vec<_>>(),
+            LINES,
+            "{compression:?}"
+        );
+        assert_eq!(end, data.len() as u64, "{compression:?}");
+
+        // Offsets are positions in the decompressed data, which resume where they left off.
+        let (offset, _) = lines[2];
+        let (resumed, resumed_end) = read_lines(&path, ReadFrom::Checkpoint(offset));
+        assert_eq!(resumed, lines[2..], "{compression:?}");
+        assert_eq!(resumed_end, end, "{compression:?}");
+
+        let (resumed, resumed_end) = read_lines(&path, ReadFrom::Checkpoint(end));
+        assert!(resumed.is_empty(), "{compression:?}");
+        assert_eq!(resumed_end, end, "{compression:?}");
+    }
+}
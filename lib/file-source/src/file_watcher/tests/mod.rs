@@ -1,3 +1,4 @@
+mod compression;
 mod experiment;
 mod experiment_no_truncations;
 
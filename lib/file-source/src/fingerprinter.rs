@@ -32,6 +32,10 @@ pub enum FingerprintStrategy {
         lines: usize,
     },
     DevInode,
+    DevInodeChecksum {
+        ignored_header_bytes: usize,
+        lines: usize,
+    },
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize, Ord, PartialOrd)]
@@ -42,6 +46,7 @@ pub enum FileFingerprint {
     #[serde(alias = "first_line_checksum")]
     FirstLinesChecksum(u64),
     DevInode(u64, u64),
+    DevInodeChecksum(u64, u64, u64),
     Unknown(u64),
 }
 
@@ -58,6 +63,13 @@ impl FileFingerprint {
                 buf.write_all(&ino.to_be_bytes()).expect("writing to array");
                 FINGERPRINT_CRC.checksum(&buf[..])
             }
+            DevInodeChecksum(dev, ino, c) => {
+                let mut buf = Vec::with_capacity(std::mem::size_of_val(dev) * 3);
+                buf.write_all(&dev.to_be_bytes()).expect("writing to array");
+                buf.write_all(&ino.to_be_bytes()).expect("writing to array");
+                buf.write_all(&c.to_be_bytes()).expect("writing to array");
+                FINGERPRINT_CRC.checksum(&buf[..])
+            }
             Unknown(c) => *c,
         }
     }
@@ -93,16 +105,38 @@ impl Fingerprinter {
                 ignored_header_bytes,
                 lines,
             } => {
-                buffer.resize(self.max_line_length, 0u8);
-                let mut fp = fs::File::open(path)?;
-                fp.seek(SeekFrom::Start(ignored_header_bytes as u64))?;
-                let bytes_read = fingerprinter_read_until(fp, b'\n', lines, buffer)?;
-                let fingerprint = FINGERPRINT_CRC.checksum(&buffer[..bytes_read]);
+                let fp = fs::File::open(path)?;
+                let fingerprint =
+                    self.first_lines_checksum(fp, ignored_header_bytes, lines, buffer)?;
                 Ok(FirstLinesChecksum(fingerprint))
             }
+            FingerprintStrategy::DevInodeChecksum {
+                ignored_header_bytes,
+                lines,
+            } => {
+                let fp = fs::File::open(path)?;
+                let dev = fp.portable_dev()?;
+                let ino = fp.portable_ino()?;
+                let fingerprint =
+                    self.first_lines_checksum(fp, ignored_header_bytes, lines, buffer)?;
+                Ok(DevInodeChecksum(dev, ino, fingerprint))
+            }
         }
     }
 
+    fn first_lines_checksum(
+        &self,
+        mut fp: File,
+        ignored_header_bytes: usize,
+        lines: usize,
+        buffer: &mut Vec<u8>,
+    ) -> Result<u64, io::Error> {
+        buffer.resize(self.max_line_length, 0u8);
+        fp.seek(SeekFrom::Start(ignored_header_bytes as u64))?;
+        let bytes_read = fingerprinter_read_until(fp, b'\n', lines, buffer)?;
+        Ok(FINGERPRINT_CRC.checksum(&buffer[..bytes_read]))
+    }
+
     pub fn get_fingerprint_or_log_error(
         &self,
         path: &Path,
@@ -281,7 +315,13 @@ fn fingerprinter_read_until(
 
 #[cfg(test)]
 mod test {
-    use std::{collections::HashSet, fs, io::Error, path::Path, time::Duration};
+    use std::{
+        collections::HashSet,
+        fs,
+        io::{Error, Write},
+        path::Path,
+        time::Duration,
+    };
 
     use tempfile::tempdir;
 
@@ -497,6 +537,58 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_inode_checksum_fingerprint() {
+        let fingerprinter = Fingerprinter {
+            strategy: FingerprintStrategy::DevInodeChecksum {
+                ignored_header_bytes: 0,
+                lines: 1,
+            },
+            max_line_length: 1024,
+            ignore_not_found: false,
+        };
+
+        let target_dir = tempdir().unwrap();
+        let empty_path = target_dir.path().join("empty.log");
+        let path = target_dir.path().join("file.log");
+        let duplicate_path = target_dir.path().join("duplicate.log");
+        fs::write(&empty_path, []).unwrap();
+        fs::write(&path, b"hello world\n").unwrap();
+        fs::write(&duplicate_path, b"hello world\n").unwrap();
+
+        let mut buf = Vec::new();
+        assert!(fingerprinter
+            .get_fingerprint_of_file(&empty_path, &mut buf)
+            .is_err());
+        let fingerprint = fingerprinter
+            .get_fingerprint_of_file(&path, &mut buf)
+            .unwrap();
+        assert_ne!(
+            fingerprint,
+            fingerprinter
+                .get_fingerprint_of_file(&duplicate_path, &mut buf)
+                .unwrap()
+        );
+
+        // Appending doesn't change the fingerprint, rewriting the file in place does.
+        let mut file = fs::OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(b"more data\n").unwrap();
+        assert_eq!(
+            fingerprint,
+            fingerprinter
+                .get_fingerprint_of_file(&path, &mut buf)
+                .unwrap()
+        );
+        file.set_len(0).unwrap();
+        file.write_all(b"new data\n").unwrap();
+        assert_ne!(
+            fingerprint,
+            fingerprinter
+                .get_fingerprint_of_file(&path, &mut buf)
+                .unwrap()
+        );
+    }
+
     #[test]
     fn no_error_on_dir() {
         let target_dir = tempdir().unwrap();
@@ -553,6 +645,10 @@ mod test {
 
         fn emit_files_open(&self, _: usize) {}
 
+        fn emit_file_truncated(&self, _: &Path, _: u64) {}
+
         fn emit_path_globbing_failed(&self, _: &Path, _: &Error) {}
+
+        fn emit_paths_provider_error(&self, _: &Path, _: &Error) {}
     }
 }
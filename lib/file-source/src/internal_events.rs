@@ -25,5 +25,9 @@ pub trait FileSourceInternalEvents: Send + Sync + Clone + 'static {
 
     fn emit_files_open(&self, count: usize);
 
+    fn emit_file_truncated(&self, path: &Path, file_position: u64);
+
     fn emit_path_globbing_failed(&self, path: &Path, error: &Error);
+
+    fn emit_paths_provider_error(&self, path: &Path, error: &Error);
 }
@@ -14,7 +14,7 @@ mod metadata_ext;
 pub mod paths_provider;
 
 pub use self::{
-    checkpointer::{Checkpointer, CheckpointsView, CHECKPOINT_FILE_NAME},
+    checkpointer::{Checkpoint, Checkpointer, CheckpointsView, CHECKPOINT_FILE_NAME},
     file_server::{calculate_ignore_before, FileServer, Line, Shutdown as FileServerShutdown},
     fingerprinter::{FileFingerprint, FingerprintStrategy, Fingerprinter},
     internal_events::FileSourceInternalEvents,
@@ -0,0 +1,171 @@
+//! [`Manifest`] paths provider.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::SystemTime,
+};
+
+use glob::{MatchOptions, Pattern};
+
+use super::PathsProvider;
+use crate::FileSourceInternalEvents;
+
+/// A paths provider reading the paths from a manifest file.
+///
+/// The manifest lists one path per line. Empty lines and lines starting with
+/// `#` are ignored, and relative paths are relative to the directory of the
+/// manifest. The manifest is read again whenever it changes, and the paths
+/// matching the exclude patterns are left out.
+pub struct Manifest<E: FileSourceInternalEvents> {
+    path: PathBuf,
+    exclude_patterns: Vec<Pattern>,
+    glob_match_options: MatchOptions,
+    emitter: E,
+    state: Mutex<ManifestState>,
+}
+
+#[derive(Default)]
+struct ManifestState {
+    /// The modification time and length of the manifest when it was last read.
+    version: Option<(SystemTime, u64)>,
+    paths: Vec<PathBuf>,
+}
+
+impl<E: FileSourceInternalEvents> Manifest<E> {
+    /// Create a new [`Manifest`].
+    ///
+    /// Returns `None` if patterns aren't valid.
+    pub fn new(
+        path: PathBuf,
+        exclude_patterns: &[PathBuf],
+        glob_match_options: MatchOptions,
+        emitter: E,
+    ) -> Option<Self> {
+        let exclude_patterns = exclude_patterns
+            .iter()
+            .filter_map(|path| path.to_str().map(|path| Pattern::new(path).ok()))
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Self {
+            path,
+            exclude_patterns,
+            glob_match_options,
+            emitter,
+            state: Mutex::default(),
+        })
+    }
+
+    fn read(&self) -> io::Result<Vec<PathBuf>> {
+        let base = self.path.parent().unwrap_or_else(|| Path::new(""));
+        let contents = fs::read_to_string(&self.path)?;
+        Ok(contents
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| base.join(line))
+            .filter(|path| {
+                !self.exclude_patterns.iter().any(|exclude_pattern| {
+                    exclude_pattern.matches_path_with(path, self.glob_match_options)
+                })
+            })
+            .collect())
+    }
+}
+
+impl<E: FileSourceInternalEvents> PathsProvider for Manifest<E> {
+    type IntoIter = Vec<PathBuf>;
+
+    fn paths(&self) -> Self::IntoIter {
+        let mut state = self.state.lock().expect("manifest state lock poisoned");
+
+        let version = fs::metadata(&self.path)
+            .and_then(|metadata| Ok((metadata.modified()?, metadata.len())))
+            .and_then(|version| {
+                if state.version != Some(version) {
+                    state.paths = self.read()?;
+                    state.version = Some(version);
+                }
+                Ok(version)
+            });
+        // The paths last read are kept until the manifest can be read again.
+        if let Err(error) = version {
+            self.emitter.emit_paths_provider_error(&self.path, &error);
+        }
+
+        state.paths.clone()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs, io::Error, path::Path, path::PathBuf, time::Duration};
+
+    use glob::MatchOptions;
+    use tempfile::tempdir;
+
+    use super::Manifest;
+    use crate::{paths_provider::PathsProvider, FileSourceInternalEvents};
+
+    #[test]
+    fn test_manifest_paths() {
+        let dir = tempdir().unwrap();
+        let manifest_path = dir.path().join("manifest");
+        fs::write(
+            &manifest_path,
+            "# comment\n/var/log/a.log\n\n  b.log  \nexcluded.log\n",
+        )
+        .unwrap();
+
+        let manifest = Manifest::new(
+            manifest_path.clone(),
+            &[dir.path().join("excluded.*")],
+            MatchOptions::default(),
+            NoErrors,
+        )
+        .unwrap();
+        assert_eq!(
+            manifest.paths(),
+            vec![PathBuf::from("/var/log/a.log"), dir.path().join("b.log")]
+        );
+
+        fs::write(&manifest_path, "/var/log/c.log\n").unwrap();
+        assert_eq!(manifest.paths(), vec![PathBuf::from("/var/log/c.log")]);
+    }
+
+    #[derive(Clone)]
+    struct NoErrors;
+
+    impl FileSourceInternalEvents for NoErrors {
+        fn emit_file_added(&self, _: &Path) {}
+
+        fn emit_file_resumed(&self, _: &Path, _: u64) {}
+
+        fn emit_file_watch_error(&self, _: &Path, _: Error) {}
+
+        fn emit_file_unwatched(&self, _: &Path, _: bool) {}
+
+        fn emit_file_deleted(&self, _: &Path) {}
+
+        fn emit_file_delete_error(&self, _: &Path, _: Error) {}
+
+        fn emit_file_fingerprint_read_error(&self, _: &Path, _: Error) {}
+
+        fn emit_file_checkpointed(&self, _: usize, _: Duration) {}
+
+        fn emit_file_checksum_failed(&self, _: &Path) {}
+
+        fn emit_file_checkpoint_write_error(&self, _: Error) {}
+
+        fn emit_files_open(&self, _: usize) {}
+
+        fn emit_file_truncated(&self, _: &Path, _: u64) {}
+
+        fn emit_path_globbing_failed(&self, _: &Path, _: &Error) {}
+
+        fn emit_paths_provider_error(&self, _: &Path, _: &Error) {
+            panic!();
+        }
+    }
+}
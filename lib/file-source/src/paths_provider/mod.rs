@@ -6,6 +6,8 @@
 use std::path::PathBuf;
 
 pub mod glob;
+pub mod manifest;
+pub mod watch;
 
 /// Represents the ability to enumerate paths.
 ///
@@ -0,0 +1,340 @@
+//! [`Watch`] paths provider.
+
+use std::{
+    collections::BTreeSet,
+    io,
+    path::{Component, Path, PathBuf},
+    sync::{mpsc, Mutex},
+};
+
+use glob::{MatchOptions, Pattern};
+use notify::{
+    event::ModifyKind, EventKind, RecommendedWatcher, RecursiveMode, Watcher as NotifyWatcher,
+};
+use tracing::error;
+
+use super::{glob::Glob, PathsProvider};
+use crate::FileSourceInternalEvents;
+
+/// A paths provider watching the file system for changes.
+///
+/// Provides the same paths as [`Glob`], but rather than searching for them
+/// each time the paths are requested, the directories the include patterns
+/// start from are searched once, then watched for files being created,
+/// renamed, or removed. The directories are searched again when the file
+/// system notifications can't be relied on, such as when they overflowed or
+/// when a directory is moved into a watched one. A watched directory that is
+/// removed is watched again, and searched, once it exists again.
+pub struct Watch<E: FileSourceInternalEvents> {
+    glob: Glob<E>,
+    include_patterns: Vec<Pattern>,
+    exclude_patterns: Vec<Pattern>,
+    glob_match_options: MatchOptions,
+    emitter: E,
+    state: Mutex<WatchState>,
+}
+
+struct WatchState {
+    watcher: RecommendedWatcher,
+    events: mpsc::Receiver<notify::Result<notify::Event>>,
+    /// The directories being watched.
+    watched: Vec<(PathBuf, RecursiveMode)>,
+    /// The directories that couldn't be watched yet, such as ones that don't exist.
+    unwatched: Vec<(PathBuf, RecursiveMode)>,
+    rescan: bool,
+    paths: BTreeSet<PathBuf>,
+}
+
+impl<E: FileSourceInternalEvents> Watch<E> {
+    /// Create a new [`Watch`].
+    ///
+    /// Returns `None` if patterns aren't valid or the file system can't be
+    /// watched.
+    pub fn new(
+        include_patterns: &[PathBuf],
+        exclude_patterns: &[PathBuf],
+        glob_match_options: MatchOptions,
+        emitter: E,
+    ) -> Option<Self> {
+        let glob = Glob::new(
+            include_patterns,
+            exclude_patterns,
+            glob_match_options,
+            emitter.clone(),
+        )?;
+
+        let include_patterns = include_patterns
+            .iter()
+            .filter_map(|path| path.to_str().map(|path| Pattern::new(path).ok()))
+            .collect::<Option<Vec<_>>>()?;
+        let exclude_patterns = exclude_patterns
+            .iter()
+            .filter_map(|path| path.to_str().map(|path| Pattern::new(path).ok()))
+            .collect::<Option<Vec<_>>>()?;
+
+        let (sender, events) = mpsc::channel();
+        let watcher = notify::recommended_watcher(sender)
+            .map_err(|error| {
+                error!(message = "Failed to create file system watcher.", %error);
+            })
+            .ok()?;
+
+        let mut unwatched = Vec::new();
+        for pattern in &include_patterns {
+            let target = watch_target(pattern.as_str());
+            if !unwatched.contains(&target) {
+                unwatched.push(target);
+            }
+        }
+
+        Some(Self {
+            glob,
+            include_patterns,
+            exclude_patterns,
+            glob_match_options,
+            emitter,
+            state: Mutex::new(WatchState {
+                watcher,
+                events,
+                watched: Vec::new(),
+                unwatched,
+                rescan: true,
+                paths: BTreeSet::new(),
+            }),
+        })
+    }
+
+    fn matches(&self, path: &Path) -> bool {
+        self.include_patterns
+            .iter()
+            .any(|pattern| pattern.matches_path_with(path, self.glob_match_options))
+            && !self
+                .exclude_patterns
+                .iter()
+                .any(|pattern| pattern.matches_path_with(path, self.glob_match_options))
+    }
+
+    fn handle_event(&self, state: &mut WatchState, event: notify::Event) {
+        if event.need_rescan() {
+            state.rescan = true;
+            return;
+        }
+        if !matches!(
+            event.kind,
+            EventKind::Any
+                | EventKind::Create(_)
+                | EventKind::Remove(_)
+                | EventKind::Modify(ModifyKind::Name(_) | ModifyKind::Any)
+                | EventKind::Other
+        ) {
+            return;
+        }
+
+        for path in event.paths {
+            if matches!(event.kind, EventKind::Remove(_)) {
+                state.unwatch(&path);
+            }
+
+            if path.is_dir() {
+                // The files of a directory moved into a watched one don't
+                // generate any events.
+                state.rescan = true;
+            } else if !self.matches(&path) {
+                continue;
+            } else if path.exists() {
+                state.paths.insert(path);
+            } else {
+                state.paths.remove(&path);
+            }
+        }
+    }
+}
+
+impl<E: FileSourceInternalEvents> PathsProvider for Watch<E> {
+    type IntoIter = Vec<PathBuf>;
+
+    fn paths(&self) -> Self::IntoIter {
+        let mut state = self.state.lock().expect("watch state lock poisoned");
+        let state = &mut *state;
+
+        while let Ok(event) = state.events.try_recv() {
+            match event {
+                Ok(event) => self.handle_event(state, event),
+                Err(error) => {
+                    let path = error.paths.first().cloned().unwrap_or_default();
+                    self.emitter.emit_paths_provider_error(
+                        &path,
+                        &io::Error::new(io::ErrorKind::Other, error),
+                    );
+                    state.rescan = true;
+                }
+            }
+        }
+
+        // The removal of a watched directory isn't always notified, such as
+        // when it's removed along with its parent.
+        let removed = state
+            .watched
+            .iter()
+            .filter(|(directory, _)| !directory.is_dir())
+            .map(|(directory, _)| directory.clone())
+            .collect::<Vec<_>>();
+        for directory in removed {
+            state.unwatch(&directory);
+        }
+
+        let unwatched = std::mem::take(&mut state.unwatched);
+        for (directory, mode) in unwatched {
+            match state.watcher.watch(&directory, mode) {
+                Ok(()) => {
+                    state.watched.push((directory, mode));
+                    state.rescan = true;
+                }
+                Err(_) => state.unwatched.push((directory, mode)),
+            }
+        }
+
+        if std::mem::take(&mut state.rescan) {
+            state.paths = self.glob.paths().into_iter().collect();
+        }
+
+        state.paths.iter().cloned().collect()
+    }
+}
+
+impl WatchState {
+    /// Stops watching `directory` if it's one of the watched directories, so
+    /// that it's watched again once it exists.
+    fn unwatch(&mut self, directory: &Path) {
+        let Some(index) = self
+            .watched
+            .iter()
+            .position(|(watched, _)| watched == directory)
+        else {
+            return;
+        };
+
+        let (directory, mode) = self.watched.remove(index);
+        // The watch of a removed directory is usually gone already.
+        _ = self.watcher.unwatch(&directory);
+        self.unwatched.push((directory, mode));
+        self.rescan = true;
+    }
+}
+
+/// The directory to watch for the files matching `pattern`, which is the
+/// longest leading path of the pattern without any wildcard, and whether its
+/// subdirectories must be watched as well.
+fn watch_target(pattern: &str) -> (PathBuf, RecursiveMode) {
+    let components = Path::new(pattern).components().collect::<Vec<_>>();
+    let literal = components
+        .iter()
+        .take_while(|component| {
+            let component = component.as_os_str().to_string_lossy();
+            Pattern::escape(&component) == component
+        })
+        .count();
+
+    let (directory, mode) = match components.len() - literal {
+        // The pattern is the path of a file.
+        0 => (
+            &components[..literal.saturating_sub(1)],
+            RecursiveMode::NonRecursive,
+        ),
+        1 => (&components[..literal], RecursiveMode::NonRecursive),
+        _ => (&components[..literal], RecursiveMode::Recursive),
+    };
+    let directory = directory.iter().collect::<PathBuf>();
+
+    if directory.as_os_str().is_empty() {
+        (PathBuf::from(Component::CurDir.as_os_str()), mode)
+    } else {
+        (directory, mode)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs, io::Error, path::Path, path::PathBuf, time::Duration};
+
+    use glob::MatchOptions;
+    use notify::RecursiveMode;
+    use tempfile::tempdir;
+
+    use super::{watch_target, Watch};
+    use crate::{paths_provider::PathsProvider, FileSourceInternalEvents};
+
+    #[test]
+    fn test_watch_removed_directory() {
+        let dir = tempdir().unwrap();
+        let logs = dir.path().join("logs");
+        fs::create_dir(&logs).unwrap();
+
+        let watch = Watch::new(
+            &[logs.join("*.log")],
+            &[],
+            MatchOptions::default(),
+            NoErrors,
+        )
+        .unwrap();
+        assert_eq!(watch.paths(), Vec::<PathBuf>::new());
+
+        fs::remove_dir(&logs).unwrap();
+        assert_eq!(watch.paths(), Vec::<PathBuf>::new());
+
+        fs::create_dir(&logs).unwrap();
+        fs::write(logs.join("app.log"), "").unwrap();
+        assert_eq!(watch.paths(), vec![logs.join("app.log")]);
+    }
+
+    #[test]
+    fn test_watch_target() {
+        for (pattern, directory, mode) in [
+            ("/var/log/app.log", "/var/log", RecursiveMode::NonRecursive),
+            ("/var/log/*.log", "/var/log", RecursiveMode::NonRecursive),
+            ("/var/log/**/*.log", "/var/log", RecursiveMode::Recursive),
+            ("/var/log/app-*/*.log", "/var/log", RecursiveMode::Recursive),
+            ("/var/lo?/app.log", "/var", RecursiveMode::Recursive),
+            ("*.log", ".", RecursiveMode::NonRecursive),
+        ] {
+            assert_eq!(
+                watch_target(pattern),
+                (PathBuf::from(directory), mode),
+                "{pattern}"
+            );
+        }
+    }
+
+    #[derive(Clone)]
+    struct NoErrors;
+
+    impl FileSourceInternalEvents for NoErrors {
+        fn emit_file_added(&self, _: &Path) {}
+
+        fn emit_file_resumed(&self, _: &Path, _: u64) {}
+
+        fn emit_file_watch_error(&self, _: &Path, _: Error) {}
+
+        fn emit_file_unwatched(&self, _: &Path, _: bool) {}
+
+        fn emit_file_deleted(&self, _: &Path) {}
+
+        fn emit_file_delete_error(&self, _: &Path, _: Error) {}
+
+        fn emit_file_fingerprint_read_error(&self, _: &Path, _: Error) {}
+
+        fn emit_file_checkpointed(&self, _: usize, _: Duration) {}
+
+        fn emit_file_checksum_failed(&self, _: &Path) {}
+
+        fn emit_file_checkpoint_write_error(&self, _: Error) {}
+
+        fn emit_files_open(&self, _: usize) {}
+
+        fn emit_file_truncated(&self, _: &Path, _: u64) {}
+
+        fn emit_path_globbing_failed(&self, _: &Path, _: &Error) {}
+
+        fn emit_paths_provider_error(&self, _: &Path, _: &Error) {}
+    }
+}
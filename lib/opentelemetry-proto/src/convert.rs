@@ -5,7 +5,11 @@ use ordered_float::NotNan;
 use std::collections::BTreeMap;
 use vector_core::{
     config::{log_schema, LegacyKey, LogNamespace},
-    event::{Event, LogEvent, TraceEvent},
+    event::{
+        metric::{Bucket, MetricSketch, MetricTags, Quantile},
+        Event, LogEvent, Metric, MetricKind, MetricValue, TraceEvent,
+    },
+    metrics::{ExponentialBucket, NativeHistogram},
 };
 use vrl::value::KeyString;
 use vrl::{
@@ -16,6 +20,11 @@ use vrl::{
 use super::proto::{
     common::v1::{any_value::Value as PBValue, InstrumentationScope, KeyValue},
     logs::v1::{LogRecord, ResourceLogs, SeverityNumber},
+    metrics::v1::{
+        exponential_histogram_data_point::Buckets, metric::Data, number_data_point,
+        AggregationTemporality, DataPointFlags, ExponentialHistogramDataPoint,
+        Metric as OtlpMetric, NumberDataPoint, ResourceMetrics,
+    },
     resource::v1::Resource,
     trace::v1::{
         span::{Event as SpanEvent, Link},
@@ -23,7 +32,7 @@ use super::proto::{
     },
 };
 
-const SOURCE_NAME: &str = "opentelemetry";
+pub(crate) const SOURCE_NAME: &str = "opentelemetry";
 
 pub const RESOURCE_KEY: &str = "resources";
 pub const ATTRIBUTES_KEY: &str = "attributes";
@@ -38,6 +47,13 @@ pub const OBSERVED_TIMESTAMP_KEY: &str = "observed_timestamp";
 pub const DROPPED_ATTRIBUTES_COUNT_KEY: &str = "dropped_attributes_count";
 pub const FLAGS_KEY: &str = "flags";
 
+// Metric tags cannot be nested, so the resource and scope of a metric are stored as tags whose
+// names mirror the field paths used for log events.
+pub const RESOURCE_TAG_PREFIX: &str = "resources.";
+pub const SCOPE_NAME_TAG: &str = "scope.name";
+pub const SCOPE_VERSION_TAG: &str = "scope.version";
+pub const SCOPE_ATTRIBUTES_TAG_PREFIX: &str = "scope.attributes.";
+
 impl ResourceLogs {
     pub fn into_event_iter(self, log_namespace: LogNamespace) -> impl Iterator<Item = Event> {
         let now = Utc::now();
@@ -75,6 +91,32 @@ impl ResourceSpans {
     }
 }
 
+impl ResourceMetrics {
+    pub fn into_event_iter(self) -> impl Iterator<Item = Event> {
+        let mut resource_tags = MetricTags::default();
+        if let Some(resource) = self.resource {
+            insert_attribute_tags(&mut resource_tags, RESOURCE_TAG_PREFIX, resource.attributes);
+        }
+
+        self.scope_metrics
+            .into_iter()
+            .flat_map(move |scope_metrics| {
+                let mut tags = resource_tags.clone();
+                if let Some(scope) = scope_metrics.scope {
+                    insert_scope_tags(&mut tags, scope);
+                }
+
+                scope_metrics.metrics.into_iter().flat_map(move |metric| {
+                    ResourceMetric {
+                        tags: tags.clone(),
+                        metric,
+                    }
+                    .into_events()
+                })
+            })
+    }
+}
+
 impl From<PBValue> for Value {
     fn from(av: PBValue) -> Self {
         match av {
@@ -105,6 +147,11 @@ struct ResourceSpan {
     span: Span,
 }
 
+struct ResourceMetric {
+    tags: MetricTags,
+    metric: OtlpMetric,
+}
+
 fn kv_list_into_value(arr: Vec<KeyValue>) -> Value {
     Value::Object(
         arr.into_iter()
@@ -120,6 +167,27 @@ fn kv_list_into_value(arr: Vec<KeyValue>) -> Value {
     )
 }
 
+/// Inserts attributes as metric tags, stringifying their values as tags cannot hold structured
+/// values. Attributes without a value are skipped.
+fn insert_attribute_tags(tags: &mut MetricTags, prefix: &str, attributes: Vec<KeyValue>) {
+    for kv in attributes {
+        if let Some(value) = kv.value.and_then(|av| av.value) {
+            let value = Value::from(value).to_string_lossy().into_owned();
+            tags.replace(format!("{prefix}{}", kv.key), value);
+        }
+    }
+}
+
+fn insert_scope_tags(tags: &mut MetricTags, scope: InstrumentationScope) {
+    if !scope.name.is_empty() {
+        tags.replace(SCOPE_NAME_TAG.to_string(), scope.name);
+    }
+    if !scope.version.is_empty() {
+        tags.replace(SCOPE_VERSION_TAG.to_string(), scope.version);
+    }
+    insert_attribute_tags(tags, SCOPE_ATTRIBUTES_TAG_PREFIX, scope.attributes);
+}
+
 fn to_hex(d: &[u8]) -> String {
     if d.is_empty() {
         return "".to_string();
@@ -197,6 +265,223 @@ impl ResourceSpan {
     }
 }
 
+// https://opentelemetry.io/docs/specs/otel/metrics/data-model/
+impl ResourceMetric {
+    fn into_events(self) -> Vec<Event> {
+        let Self { tags, metric } = self;
+        let name = metric.name;
+
+        let into_event = |attributes: Vec<KeyValue>,
+                          time_unix_nano: u64,
+                          kind: MetricKind,
+                          value: MetricValue| {
+            let mut tags = tags.clone();
+            insert_attribute_tags(&mut tags, "", attributes);
+            let timestamp =
+                (time_unix_nano > 0).then(|| Utc.timestamp_nanos(time_unix_nano as i64));
+
+            Event::from(
+                Metric::new(name.clone(), kind, value)
+                    .with_tags(tags.as_option())
+                    .with_timestamp(timestamp),
+            )
+        };
+
+        match metric.data {
+            Some(Data::Gauge(gauge)) => gauge
+                .data_points
+                .into_iter()
+                .filter_map(|point| {
+                    let value = number_data_point_value(&point)?;
+                    Some(into_event(
+                        point.attributes,
+                        point.time_unix_nano,
+                        MetricKind::Absolute,
+                        MetricValue::Gauge { value },
+                    ))
+                })
+                .collect(),
+            Some(Data::Sum(sum)) => {
+                let kind = temporality_into_kind(sum.aggregation_temporality);
+                sum.data_points
+                    .into_iter()
+                    .filter_map(|point| {
+                        let value = number_data_point_value(&point)?;
+                        // A non-monotonic sum can decrease, which a counter can not represent.
+                        let value = if sum.is_monotonic {
+                            MetricValue::Counter { value }
+                        } else {
+                            MetricValue::Gauge { value }
+                        };
+                        Some(into_event(
+                            point.attributes,
+                            point.time_unix_nano,
+                            kind,
+                            value,
+                        ))
+                    })
+                    .collect()
+            }
+            Some(Data::Histogram(histogram)) => {
+                let kind = temporality_into_kind(histogram.aggregation_temporality);
+                histogram
+                    .data_points
+                    .into_iter()
+                    .filter(|point| has_recorded_value(point.flags))
+                    .map(|point| {
+                        let value = MetricValue::AggregatedHistogram {
+                            buckets: bounds_and_counts_into_buckets(
+                                point.explicit_bounds,
+                                point.bucket_counts,
+                            ),
+                            count: point.count,
+                            sum: point.sum.unwrap_or_default(),
+                        };
+                        into_event(point.attributes, point.time_unix_nano, kind, value)
+                    })
+                    .collect()
+            }
+            Some(Data::ExponentialHistogram(histogram)) => {
+                let kind = temporality_into_kind(histogram.aggregation_temporality);
+                histogram
+                    .data_points
+                    .into_iter()
+                    .filter(|point| has_recorded_value(point.flags))
+                    .map(|point| {
+                        let value = MetricValue::Sketch {
+                            sketch: MetricSketch::NativeHistogram(
+                                exponential_histogram_into_native(&point),
+                            ),
+                        };
+                        into_event(point.attributes, point.time_unix_nano, kind, value)
+                    })
+                    .collect()
+            }
+            // Summaries are always cumulative.
+            Some(Data::Summary(summary)) => summary
+                .data_points
+                .into_iter()
+                .filter(|point| has_recorded_value(point.flags))
+                .map(|point| {
+                    let value = MetricValue::AggregatedSummary {
+                        quantiles: point
+                            .quantile_values
+                            .into_iter()
+                            .map(|q| Quantile {
+                                quantile: q.quantile,
+                                value: q.value,
+                            })
+                            .collect(),
+                        count: point.count,
+                        sum: point.sum,
+                    };
+                    into_event(
+                        point.attributes,
+                        point.time_unix_nano,
+                        MetricKind::Absolute,
+                        value,
+                    )
+                })
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+}
+
+fn temporality_into_kind(aggregation_temporality: i32) -> MetricKind {
+    if aggregation_temporality == AggregationTemporality::Delta as i32 {
+        MetricKind::Incremental
+    } else {
+        MetricKind::Absolute
+    }
+}
+
+const fn has_recorded_value(flags: u32) -> bool {
+    flags & DataPointFlags::NoRecordedValueMask as u32 == 0
+}
+
+fn number_data_point_value(point: &NumberDataPoint) -> Option<f64> {
+    if !has_recorded_value(point.flags) {
+        return None;
+    }
+    match point.value? {
+        number_data_point::Value::AsDouble(value) => Some(value),
+        number_data_point::Value::AsInt(value) => Some(value as f64),
+    }
+}
+
+/// Converts OTLP explicit bounds and bucket counts into Vector's histogram buckets.
+///
+/// The last OTLP bucket counts the observations above the last explicit bound, and is kept as the
+/// bucket with an infinite upper limit.
+fn bounds_and_counts_into_buckets(
+    explicit_bounds: Vec<f64>,
+    bucket_counts: Vec<u64>,
+) -> Vec<Bucket> {
+    explicit_bounds
+        .into_iter()
+        .chain(std::iter::once(f64::INFINITY))
+        .zip(bucket_counts)
+        .map(|(upper_limit, count)| Bucket { upper_limit, count })
+        .collect()
+}
+
+/// The highest schema supported by native histograms.
+const MAX_NATIVE_HISTOGRAM_SCHEMA: i32 = 8;
+
+/// Converts an exponential histogram into a native histogram, which has the same exponential
+/// buckets, with the scale of the histogram as its schema.
+///
+/// Histograms with a scale above the highest schema of native histograms have their buckets
+/// merged down to that schema.
+fn exponential_histogram_into_native(point: &ExponentialHistogramDataPoint) -> NativeHistogram {
+    let histogram = NativeHistogram {
+        schema: point.scale,
+        zero_threshold: point.zero_threshold,
+        zero_count: point.zero_count as f64,
+        count: point.count as f64,
+        sum: point.sum.unwrap_or_default(),
+        positive: exponential_buckets(point.positive.as_ref()),
+        negative: exponential_buckets(point.negative.as_ref()),
+    };
+    if histogram.schema <= MAX_NATIVE_HISTOGRAM_SCHEMA {
+        return histogram;
+    }
+
+    let mut reduced = NativeHistogram {
+        schema: MAX_NATIVE_HISTOGRAM_SCHEMA,
+        zero_threshold: histogram.zero_threshold,
+        zero_count: 0.0,
+        count: 0.0,
+        sum: 0.0,
+        positive: Vec::new(),
+        negative: Vec::new(),
+    };
+    // Merging can only fail on different zero buckets.
+    let merged = reduced.merge(&histogram);
+    debug_assert!(merged);
+    reduced
+}
+
+/// The OTLP bucket at index `i` holds the observations within `(base^i, base^(i + 1)]`, which is
+/// the bucket at index `i + 1` of a native histogram.
+fn exponential_buckets(buckets: Option<&Buckets>) -> Vec<ExponentialBucket> {
+    let Some(buckets) = buckets else {
+        return Vec::new();
+    };
+
+    buckets
+        .bucket_counts
+        .iter()
+        .enumerate()
+        .filter(|(_, count)| **count > 0)
+        .map(|(i, count)| ExponentialBucket {
+            index: buckets.offset + i as i32 + 1,
+            count: *count as f64,
+        })
+        .collect()
+}
+
 // https://github.com/open-telemetry/opentelemetry-specification/blob/v1.15.0/specification/logs/data-model.md
 impl ResourceLog {
     fn into_event(self, log_namespace: LogNamespace, now: DateTime<Utc>) -> Event {
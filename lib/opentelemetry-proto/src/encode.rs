@@ -0,0 +1,920 @@
+//! Conversion of Vector events into OTLP export requests.
+//!
+//! This reverses the conversions done in [`crate::convert`] when ingesting OTLP data. Events are
+//! grouped by their resource and instrumentation scope, so each distinct resource and scope is
+//! only sent once per export request.
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use lookup::path;
+use vector_core::{
+    config::{log_schema, LogNamespace},
+    event::{
+        metric::{Bucket, MetricSketch, Quantile},
+        LogEvent, Metric, MetricKind, MetricValue, TraceEvent,
+    },
+    metrics::{ExponentialBucket, NativeHistogram},
+};
+use vrl::value::{ObjectMap, Value};
+
+use super::{
+    convert::{
+        ATTRIBUTES_KEY, DROPPED_ATTRIBUTES_COUNT_KEY, FLAGS_KEY, NAME_KEY, OBSERVED_TIMESTAMP_KEY,
+        RESOURCE_KEY, RESOURCE_TAG_PREFIX, SCOPE_ATTRIBUTES_TAG_PREFIX, SCOPE_KEY, SCOPE_NAME_TAG,
+        SCOPE_VERSION_TAG, SEVERITY_NUMBER_KEY, SEVERITY_TEXT_KEY, SOURCE_NAME, SPAN_ID_KEY,
+        TRACE_ID_KEY, VERSION_KEY,
+    },
+    proto::{
+        collector::{
+            logs::v1::ExportLogsServiceRequest, metrics::v1::ExportMetricsServiceRequest,
+            trace::v1::ExportTraceServiceRequest,
+        },
+        common::v1::{
+            any_value::Value as PBValue, AnyValue, ArrayValue, InstrumentationScope, KeyValue,
+            KeyValueList,
+        },
+        logs::v1::{LogRecord, ResourceLogs, ScopeLogs},
+        metrics::v1::{
+            exponential_histogram_data_point::Buckets, metric::Data, number_data_point,
+            summary_data_point::ValueAtQuantile, AggregationTemporality, ExponentialHistogram,
+            ExponentialHistogramDataPoint, Gauge, Histogram, HistogramDataPoint,
+            Metric as OtlpMetric, NumberDataPoint, ResourceMetrics, ScopeMetrics, Sum, Summary,
+            SummaryDataPoint,
+        },
+        resource::v1::Resource,
+        trace::v1::{
+            span::{Event as SpanEvent, Link},
+            ResourceSpans, ScopeSpans, Span, Status as SpanStatus,
+        },
+    },
+};
+
+/// Items grouped by resource, and then by instrumentation scope, in the order in which each
+/// resource and scope was first seen.
+struct Grouped<T> {
+    resources: Vec<(
+        Option<Resource>,
+        Vec<(Option<InstrumentationScope>, Vec<T>)>,
+    )>,
+}
+
+impl<T> Default for Grouped<T> {
+    fn default() -> Self {
+        Self {
+            resources: Vec::new(),
+        }
+    }
+}
+
+impl<T> Grouped<T> {
+    fn push(&mut self, resource: Option<Resource>, scope: Option<InstrumentationScope>, item: T) {
+        let resource_index = match self.resources.iter().position(|(r, _)| *r == resource) {
+            Some(index) => index,
+            None => {
+                self.resources.push((resource, Vec::new()));
+                self.resources.len() - 1
+            }
+        };
+
+        let scopes = &mut self.resources[resource_index].1;
+        match scopes.iter().position(|(s, _)| *s == scope) {
+            Some(index) => scopes[index].1.push(item),
+            None => scopes.push((scope, vec![item])),
+        }
+    }
+}
+
+/// Converts log events into an export request.
+///
+/// The OpenTelemetry specific fields that the `opentelemetry` source adds to each log event are
+/// read from the source metadata for the `Vector` log namespace, and from the event itself for the
+/// `Legacy` log namespace. In the latter case, any field that does not map onto a log record field
+/// is added to the record's attributes.
+impl FromIterator<LogEvent> for ExportLogsServiceRequest {
+    fn from_iter<I: IntoIterator<Item = LogEvent>>(logs: I) -> Self {
+        let mut grouped = Grouped::default();
+        for log in logs {
+            let (resource, scope, record) = log_into_record(log);
+            grouped.push(resource, scope, record);
+        }
+
+        Self {
+            resource_logs: grouped
+                .resources
+                .into_iter()
+                .map(|(resource, scopes)| ResourceLogs {
+                    resource,
+                    scope_logs: scopes
+                        .into_iter()
+                        .map(|(scope, log_records)| ScopeLogs {
+                            scope,
+                            log_records,
+                            schema_url: String::new(),
+                        })
+                        .collect(),
+                    schema_url: String::new(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Converts metric events into an export request.
+///
+/// Resource and instrumentation scope are read from the metric tags prefixed with
+/// `resources.` and `scope.`, while the remaining tags become data point attributes. Metrics
+/// whose value can't be represented in OTLP are skipped, see [`is_supported_metric_value`].
+impl FromIterator<Metric> for ExportMetricsServiceRequest {
+    fn from_iter<I: IntoIterator<Item = Metric>>(metrics: I) -> Self {
+        let mut grouped = Grouped::default();
+        for metric in metrics {
+            if let Some((resource, scope, metric)) = metric_into_otlp(metric) {
+                grouped.push(resource, scope, metric);
+            }
+        }
+
+        Self {
+            resource_metrics: grouped
+                .resources
+                .into_iter()
+                .map(|(resource, scopes)| ResourceMetrics {
+                    resource,
+                    scope_metrics: scopes
+                        .into_iter()
+                        .map(|(scope, metrics)| ScopeMetrics {
+                            scope,
+                            metrics,
+                            schema_url: String::new(),
+                        })
+                        .collect(),
+                    schema_url: String::new(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Converts trace events into an export request.
+impl FromIterator<TraceEvent> for ExportTraceServiceRequest {
+    fn from_iter<I: IntoIterator<Item = TraceEvent>>(traces: I) -> Self {
+        let mut grouped = Grouped::default();
+        for trace in traces {
+            let (resource, span) = trace_into_span(trace);
+            // The `opentelemetry` source does not keep the instrumentation scope of spans.
+            grouped.push(resource, None, span);
+        }
+
+        Self {
+            resource_spans: grouped
+                .resources
+                .into_iter()
+                .map(|(resource, scopes)| ResourceSpans {
+                    resource,
+                    scope_spans: scopes
+                        .into_iter()
+                        .map(|(scope, spans)| ScopeSpans {
+                            scope,
+                            spans,
+                            schema_url: String::new(),
+                        })
+                        .collect(),
+                    schema_url: String::new(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Returns `true` if a metric with the given value can be converted to an OTLP metric.
+///
+/// Native histograms are converted to exponential histograms, but distributions and other
+/// sketches have no OTLP equivalent and are not supported.
+pub const fn is_supported_metric_value(value: &MetricValue) -> bool {
+    !matches!(
+        value,
+        MetricValue::Distribution { .. }
+            | MetricValue::Sketch {
+                sketch: MetricSketch::AgentDDSketch(_)
+            }
+    )
+}
+
+impl From<Value> for AnyValue {
+    fn from(value: Value) -> Self {
+        let value = match value {
+            Value::Bytes(bytes) => match String::from_utf8(bytes.to_vec()) {
+                Ok(string) => PBValue::StringValue(string),
+                Err(error) => PBValue::BytesValue(error.into_bytes()),
+            },
+            Value::Regex(regex) => PBValue::StringValue(regex.as_str().to_string()),
+            Value::Integer(int) => PBValue::IntValue(int),
+            Value::Float(float) => PBValue::DoubleValue(float.into_inner()),
+            Value::Boolean(boolean) => PBValue::BoolValue(boolean),
+            Value::Timestamp(timestamp) => {
+                PBValue::StringValue(timestamp.to_rfc3339_opts(SecondsFormat::AutoSi, true))
+            }
+            Value::Object(object) => PBValue::KvlistValue(KeyValueList {
+                values: object_into_kv_list(object),
+            }),
+            Value::Array(array) => PBValue::ArrayValue(ArrayValue {
+                values: array.into_iter().map(Into::into).collect(),
+            }),
+            Value::Null => return AnyValue { value: None },
+        };
+
+        AnyValue { value: Some(value) }
+    }
+}
+
+fn object_into_kv_list(object: ObjectMap) -> Vec<KeyValue> {
+    object
+        .into_iter()
+        .map(|(key, value)| KeyValue {
+            key: key.into(),
+            value: Some(value.into()),
+        })
+        .collect()
+}
+
+fn value_into_kv_list(value: Option<Value>) -> Vec<KeyValue> {
+    match value {
+        Some(Value::Object(object)) => object_into_kv_list(object),
+        _ => Vec::new(),
+    }
+}
+
+fn into_object(value: Option<Value>) -> ObjectMap {
+    match value {
+        Some(Value::Object(object)) => object,
+        _ => ObjectMap::new(),
+    }
+}
+
+fn take_string(fields: &mut ObjectMap, key: &str) -> String {
+    match fields.remove(key) {
+        None | Some(Value::Null) => String::new(),
+        Some(value) => value.to_string_lossy().into_owned(),
+    }
+}
+
+fn take_integer(fields: &mut ObjectMap, key: &str) -> i64 {
+    match fields.remove(key) {
+        Some(Value::Integer(int)) => int,
+        _ => 0,
+    }
+}
+
+fn take_hex(fields: &mut ObjectMap, key: &str) -> Vec<u8> {
+    match fields.remove(key) {
+        Some(Value::Bytes(bytes)) => hex::decode(bytes).unwrap_or_default(),
+        _ => Vec::new(),
+    }
+}
+
+fn take_unix_nanos(fields: &mut ObjectMap, key: &str) -> u64 {
+    value_to_unix_nanos(fields.remove(key).as_ref())
+}
+
+fn value_to_unix_nanos(value: Option<&Value>) -> u64 {
+    match value {
+        Some(Value::Timestamp(timestamp)) => timestamp_to_unix_nanos(*timestamp),
+        Some(Value::Integer(nanos)) => u64::try_from(*nanos).unwrap_or(0),
+        _ => 0,
+    }
+}
+
+fn timestamp_to_unix_nanos(timestamp: DateTime<Utc>) -> u64 {
+    timestamp
+        .timestamp_nanos_opt()
+        .and_then(|nanos| u64::try_from(nanos).ok())
+        .unwrap_or(0)
+}
+
+fn resource_from_attributes(attributes: Vec<KeyValue>) -> Option<Resource> {
+    (!attributes.is_empty()).then_some(Resource {
+        attributes,
+        dropped_attributes_count: 0,
+    })
+}
+
+fn scope_from_value(value: Option<Value>) -> Option<InstrumentationScope> {
+    let mut fields = match value {
+        Some(Value::Object(fields)) => fields,
+        _ => return None,
+    };
+
+    Some(InstrumentationScope {
+        name: take_string(&mut fields, NAME_KEY),
+        version: take_string(&mut fields, VERSION_KEY),
+        attributes: value_into_kv_list(fields.remove(ATTRIBUTES_KEY)),
+        dropped_attributes_count: take_integer(&mut fields, DROPPED_ATTRIBUTES_COUNT_KEY) as u32,
+    })
+}
+
+// https://github.com/open-telemetry/opentelemetry-specification/blob/v1.15.0/specification/logs/data-model.md
+fn log_into_record(
+    mut log: LogEvent,
+) -> (Option<Resource>, Option<InstrumentationScope>, LogRecord) {
+    let namespace = log.namespace();
+    let (body, timestamp, fields) = match namespace {
+        LogNamespace::Vector => {
+            let timestamp = log.get_timestamp().cloned();
+            let (body, mut metadata) = log.into_parts();
+            let fields = metadata.value_mut().remove(path!(SOURCE_NAME), false);
+            (body, timestamp, fields)
+        }
+        LogNamespace::Legacy => {
+            let body = log_schema()
+                .message_key_target_path()
+                .and_then(|path| log.remove(path))
+                .unwrap_or(Value::Null);
+            let timestamp = log.remove_timestamp();
+            if let Some(path) = log_schema().source_type_key_target_path() {
+                log.remove(path);
+            }
+            let (fields, _) = log.into_parts();
+            (body, timestamp, Some(fields))
+        }
+    };
+
+    let mut fields = into_object(fields);
+    let timestamp = timestamp.or_else(|| fields.remove("timestamp"));
+    let resource = resource_from_attributes(value_into_kv_list(fields.remove(RESOURCE_KEY)));
+    let scope = scope_from_value(fields.remove(SCOPE_KEY));
+    let mut attributes = value_into_kv_list(fields.remove(ATTRIBUTES_KEY));
+
+    let record = LogRecord {
+        time_unix_nano: value_to_unix_nanos(timestamp.as_ref()),
+        observed_time_unix_nano: take_unix_nanos(&mut fields, OBSERVED_TIMESTAMP_KEY),
+        severity_number: take_integer(&mut fields, SEVERITY_NUMBER_KEY) as i32,
+        severity_text: take_string(&mut fields, SEVERITY_TEXT_KEY),
+        trace_id: take_hex(&mut fields, TRACE_ID_KEY),
+        span_id: take_hex(&mut fields, SPAN_ID_KEY),
+        flags: take_integer(&mut fields, FLAGS_KEY) as u32,
+        dropped_attributes_count: take_integer(&mut fields, DROPPED_ATTRIBUTES_COUNT_KEY) as u32,
+        body: (!matches!(body, Value::Null)).then(|| body.into()),
+        attributes: if namespace == LogNamespace::Legacy {
+            attributes.extend(object_into_kv_list(fields));
+            attributes
+        } else {
+            attributes
+        },
+    };
+
+    (resource, scope, record)
+}
+
+fn metric_into_otlp(
+    metric: Metric,
+) -> Option<(Option<Resource>, Option<InstrumentationScope>, OtlpMetric)> {
+    if !is_supported_metric_value(metric.value()) {
+        return None;
+    }
+
+    let name = match metric.namespace() {
+        Some(namespace) => format!("{}.{}", namespace, metric.name()),
+        None => metric.name().to_string(),
+    };
+    let kind = metric.kind();
+    let time_unix_nano = metric.timestamp().map_or(0, timestamp_to_unix_nanos);
+    let start_time_unix_nano = match (kind, metric.timestamp(), metric.interval_ms()) {
+        (MetricKind::Incremental, Some(timestamp), Some(interval_ms)) => timestamp_to_unix_nanos(
+            timestamp - chrono::Duration::milliseconds(i64::from(interval_ms.get())),
+        ),
+        _ => 0,
+    };
+    let aggregation_temporality = match kind {
+        MetricKind::Incremental => AggregationTemporality::Delta,
+        MetricKind::Absolute => AggregationTemporality::Cumulative,
+    } as i32;
+
+    let (series, data, _) = metric.into_parts();
+
+    let mut resource_attributes = Vec::new();
+    let mut scope: Option<InstrumentationScope> = None;
+    let mut attributes = Vec::new();
+    for (key, value) in series
+        .tags
+        .into_iter()
+        .flat_map(|tags| tags.into_iter_single())
+    {
+        if let Some(key) = key.strip_prefix(RESOURCE_TAG_PREFIX) {
+            resource_attributes.push(string_kv(key.to_string(), value));
+        } else if key == SCOPE_NAME_TAG {
+            scope.get_or_insert_with(Default::default).name = value;
+        } else if key == SCOPE_VERSION_TAG {
+            scope.get_or_insert_with(Default::default).version = value;
+        } else if let Some(key) = key.strip_prefix(SCOPE_ATTRIBUTES_TAG_PREFIX) {
+            scope
+                .get_or_insert_with(Default::default)
+                .attributes
+                .push(string_kv(key.to_string(), value));
+        } else {
+            attributes.push(string_kv(key, value));
+        }
+    }
+
+    let number_data_point = |attributes: Vec<KeyValue>, value: f64| NumberDataPoint {
+        attributes,
+        start_time_unix_nano,
+        time_unix_nano,
+        value: Some(number_data_point::Value::AsDouble(value)),
+        ..Default::default()
+    };
+
+    let data = match data.value {
+        MetricValue::Counter { value } => Data::Sum(Sum {
+            data_points: vec![number_data_point(attributes, value)],
+            aggregation_temporality,
+            is_monotonic: true,
+        }),
+        // An incremental gauge reports a change rather than the current value, which OTLP
+        // represents as a non-monotonic delta sum.
+        MetricValue::Gauge { value } if kind == MetricKind::Incremental => Data::Sum(Sum {
+            data_points: vec![number_data_point(attributes, value)],
+            aggregation_temporality,
+            is_monotonic: false,
+        }),
+        MetricValue::Gauge { value } => Data::Gauge(Gauge {
+            data_points: vec![number_data_point(attributes, value)],
+        }),
+        MetricValue::Set { values } => Data::Gauge(Gauge {
+            data_points: vec![number_data_point(attributes, values.len() as f64)],
+        }),
+        MetricValue::AggregatedHistogram {
+            buckets,
+            count,
+            sum,
+        } => {
+            let (explicit_bounds, bucket_counts) = buckets_into_bounds_and_counts(buckets, count);
+            Data::Histogram(Histogram {
+                data_points: vec![HistogramDataPoint {
+                    attributes,
+                    start_time_unix_nano,
+                    time_unix_nano,
+                    count,
+                    sum: Some(sum),
+                    bucket_counts,
+                    explicit_bounds,
+                    ..Default::default()
+                }],
+                aggregation_temporality,
+            })
+        }
+        MetricValue::AggregatedSummary {
+            quantiles,
+            count,
+            sum,
+        } => Data::Summary(Summary {
+            data_points: vec![SummaryDataPoint {
+                attributes,
+                start_time_unix_nano,
+                time_unix_nano,
+                count,
+                sum,
+                quantile_values: quantiles
+                    .into_iter()
+                    .map(|Quantile { quantile, value }| ValueAtQuantile { quantile, value })
+                    .collect(),
+                flags: 0,
+            }],
+        }),
+        MetricValue::Sketch {
+            sketch: MetricSketch::NativeHistogram(histogram),
+        } => Data::ExponentialHistogram(ExponentialHistogram {
+            data_points: vec![native_into_exponential_histogram(
+                histogram,
+                attributes,
+                start_time_unix_nano,
+                time_unix_nano,
+            )],
+            aggregation_temporality,
+        }),
+        MetricValue::Distribution { .. } | MetricValue::Sketch { .. } => return None,
+    };
+
+    let metric = OtlpMetric {
+        name,
+        description: String::new(),
+        unit: String::new(),
+        data: Some(data),
+    };
+
+    Some((resource_from_attributes(resource_attributes), scope, metric))
+}
+
+fn string_kv(key: String, value: String) -> KeyValue {
+    KeyValue {
+        key,
+        value: Some(AnyValue {
+            value: Some(PBValue::StringValue(value)),
+        }),
+    }
+}
+
+/// Converts Vector's histogram buckets, which each hold the number of observations falling
+/// between the previous bucket and its upper limit, into OTLP explicit bounds and bucket counts.
+///
+/// OTLP has an implicit overflow bucket above the last explicit bound, so an infinite upper limit
+/// is folded into it, along with any observations that were not counted in a bucket.
+fn buckets_into_bounds_and_counts(buckets: Vec<Bucket>, count: u64) -> (Vec<f64>, Vec<u64>) {
+    let mut explicit_bounds = Vec::with_capacity(buckets.len());
+    let mut bucket_counts = Vec::with_capacity(buckets.len() + 1);
+    for bucket in buckets {
+        if bucket.upper_limit == f64::INFINITY {
+            continue;
+        }
+        explicit_bounds.push(bucket.upper_limit);
+        bucket_counts.push(bucket.count);
+    }
+
+    let counted: u64 = bucket_counts.iter().sum();
+    bucket_counts.push(count.saturating_sub(counted));
+
+    (explicit_bounds, bucket_counts)
+}
+
+/// Converts a native histogram into an exponential histogram data point, with the schema of the
+/// histogram as its scale.
+fn native_into_exponential_histogram(
+    histogram: NativeHistogram,
+    attributes: Vec<KeyValue>,
+    start_time_unix_nano: u64,
+    time_unix_nano: u64,
+) -> ExponentialHistogramDataPoint {
+    ExponentialHistogramDataPoint {
+        attributes,
+        start_time_unix_nano,
+        time_unix_nano,
+        count: histogram.count as u64,
+        sum: Some(histogram.sum),
+        scale: histogram.schema,
+        zero_count: histogram.zero_count as u64,
+        positive: otlp_buckets(&histogram.positive),
+        negative: otlp_buckets(&histogram.negative),
+        zero_threshold: histogram.zero_threshold,
+        ..Default::default()
+    }
+}
+
+/// The bucket at index `i` of a native histogram holds the observations within
+/// `(base^(i - 1), base^i]`, which is the OTLP bucket at index `i - 1`. OTLP buckets are dense, so
+/// the gaps between the sparse buckets of a native histogram are filled with empty buckets.
+fn otlp_buckets(buckets: &[ExponentialBucket]) -> Option<Buckets> {
+    let first = buckets.first()?.index;
+    let last = buckets.last()?.index;
+    let mut bucket_counts = vec![0; (last - first) as usize + 1];
+    for bucket in buckets {
+        bucket_counts[(bucket.index - first) as usize] += bucket.count as u64;
+    }
+
+    Some(Buckets {
+        offset: first - 1,
+        bucket_counts,
+    })
+}
+
+fn trace_into_span(trace: TraceEvent) -> (Option<Resource>, Span) {
+    let (mut fields, _) = trace.into_parts();
+
+    let resource = resource_from_attributes(value_into_kv_list(fields.remove(RESOURCE_KEY)));
+    let status = match fields.remove("status") {
+        Some(Value::Object(mut status)) => Some(SpanStatus {
+            message: take_string(&mut status, "message"),
+            code: take_integer(&mut status, "code") as i32,
+        }),
+        _ => None,
+    };
+
+    let span = Span {
+        trace_id: take_hex(&mut fields, TRACE_ID_KEY),
+        span_id: take_hex(&mut fields, SPAN_ID_KEY),
+        trace_state: take_string(&mut fields, "trace_state"),
+        parent_span_id: take_hex(&mut fields, "parent_span_id"),
+        name: take_string(&mut fields, "name"),
+        kind: take_integer(&mut fields, "kind") as i32,
+        start_time_unix_nano: take_unix_nanos(&mut fields, "start_time_unix_nano"),
+        end_time_unix_nano: take_unix_nanos(&mut fields, "end_time_unix_nano"),
+        attributes: value_into_kv_list(fields.remove(ATTRIBUTES_KEY)),
+        dropped_attributes_count: take_integer(&mut fields, DROPPED_ATTRIBUTES_COUNT_KEY) as u32,
+        events: array_of_objects(fields.remove("events"))
+            .map(|mut event| SpanEvent {
+                time_unix_nano: take_unix_nanos(&mut event, "time_unix_nano"),
+                name: take_string(&mut event, "name"),
+                attributes: value_into_kv_list(event.remove(ATTRIBUTES_KEY)),
+                dropped_attributes_count: take_integer(&mut event, DROPPED_ATTRIBUTES_COUNT_KEY)
+                    as u32,
+            })
+            .collect(),
+        dropped_events_count: take_integer(&mut fields, "dropped_events_count") as u32,
+        links: array_of_objects(fields.remove("links"))
+            .map(|mut link| Link {
+                trace_id: take_hex(&mut link, TRACE_ID_KEY),
+                span_id: take_hex(&mut link, SPAN_ID_KEY),
+                trace_state: take_string(&mut link, "trace_state"),
+                attributes: value_into_kv_list(link.remove(ATTRIBUTES_KEY)),
+                dropped_attributes_count: take_integer(&mut link, DROPPED_ATTRIBUTES_COUNT_KEY)
+                    as u32,
+            })
+            .collect(),
+        dropped_links_count: take_integer(&mut fields, "dropped_links_count") as u32,
+        status,
+    };
+
+    (resource, span)
+}
+
+fn array_of_objects(value: Option<Value>) -> impl Iterator<Item = ObjectMap> {
+    let array = match value {
+        Some(Value::Array(array)) => array,
+        _ => Vec::new(),
+    };
+    array.into_iter().filter_map(|value| match value {
+        Value::Object(object) => Some(object),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use vector_core::event::{metric::MetricTags, Event};
+    use vrl::event_path;
+
+    use super::*;
+    use crate::proto::logs::v1::SeverityNumber;
+
+    fn kv(key: &str, value: &str) -> KeyValue {
+        string_kv(key.to_string(), value.to_string())
+    }
+
+    fn resource_logs() -> ResourceLogs {
+        ResourceLogs {
+            resource: Some(Resource {
+                attributes: vec![kv("service.name", "checkout")],
+                dropped_attributes_count: 0,
+            }),
+            scope_logs: vec![ScopeLogs {
+                scope: Some(InstrumentationScope {
+                    name: "my.library".to_string(),
+                    version: "1.0.0".to_string(),
+                    attributes: vec![],
+                    dropped_attributes_count: 0,
+                }),
+                log_records: vec![LogRecord {
+                    time_unix_nano: 1_579_134_612_000_000_011,
+                    observed_time_unix_nano: 1_579_134_612_000_000_022,
+                    severity_number: SeverityNumber::Info as i32,
+                    severity_text: "INFO".to_string(),
+                    body: Some(AnyValue {
+                        value: Some(PBValue::StringValue("hello world".to_string())),
+                    }),
+                    attributes: vec![kv("http.method", "GET")],
+                    dropped_attributes_count: 0,
+                    flags: 1,
+                    trace_id: vec![1; 16],
+                    span_id: vec![2; 8],
+                }],
+                schema_url: String::new(),
+            }],
+            schema_url: String::new(),
+        }
+    }
+
+    fn round_trip_logs(namespace: LogNamespace) {
+        let expected = resource_logs();
+        let request = expected
+            .clone()
+            .into_event_iter(namespace)
+            .map(Event::into_log)
+            .collect::<ExportLogsServiceRequest>();
+
+        assert_eq!(request.resource_logs, vec![expected]);
+    }
+
+    #[test]
+    fn logs_round_trip_legacy_namespace() {
+        round_trip_logs(LogNamespace::Legacy);
+    }
+
+    #[test]
+    fn logs_round_trip_vector_namespace() {
+        round_trip_logs(LogNamespace::Vector);
+    }
+
+    #[test]
+    fn logs_are_grouped_by_resource() {
+        let mut other = resource_logs();
+        other.resource = None;
+        let logs = resource_logs()
+            .into_event_iter(LogNamespace::Legacy)
+            .chain(other.into_event_iter(LogNamespace::Legacy))
+            .chain(resource_logs().into_event_iter(LogNamespace::Legacy))
+            .map(Event::into_log);
+
+        let request = logs.collect::<ExportLogsServiceRequest>();
+
+        assert_eq!(request.resource_logs.len(), 2);
+        assert_eq!(request.resource_logs[0].scope_logs[0].log_records.len(), 2);
+        assert_eq!(request.resource_logs[1].scope_logs[0].log_records.len(), 1);
+    }
+
+    #[test]
+    fn legacy_log_fields_become_attributes() {
+        let mut log = LogEvent::default();
+        log.insert(event_path!("message"), "hello");
+        log.insert(event_path!("timestamp"), Utc::now());
+        log.insert(event_path!("host"), "localhost");
+
+        let request = std::iter::once(log).collect::<ExportLogsServiceRequest>();
+        let record = &request.resource_logs[0].scope_logs[0].log_records[0];
+
+        assert_eq!(
+            record.body,
+            Some(AnyValue {
+                value: Some(PBValue::StringValue("hello".to_string()))
+            })
+        );
+        assert_eq!(record.attributes, vec![kv("host", "localhost")]);
+        assert!(record.time_unix_nano > 0);
+    }
+
+    #[test]
+    fn counter_into_sum() {
+        let tags = MetricTags::from_iter([
+            ("resources.service.name".to_string(), "checkout".to_string()),
+            ("scope.name".to_string(), "my.library".to_string()),
+            ("status".to_string(), "200".to_string()),
+        ]);
+        let metric = Metric::new(
+            "requests",
+            MetricKind::Incremental,
+            MetricValue::Counter { value: 2.0 },
+        )
+        .with_namespace(Some("http"))
+        .with_tags(Some(tags));
+
+        let request = std::iter::once(metric).collect::<ExportMetricsServiceRequest>();
+        let resource_metrics = &request.resource_metrics[0];
+        let scope_metrics = &resource_metrics.scope_metrics[0];
+        let metric = &scope_metrics.metrics[0];
+
+        assert_eq!(
+            resource_metrics.resource.as_ref().unwrap().attributes,
+            vec![kv("service.name", "checkout")]
+        );
+        assert_eq!(scope_metrics.scope.as_ref().unwrap().name, "my.library");
+        assert_eq!(metric.name, "http.requests");
+        let Some(Data::Sum(sum)) = &metric.data else {
+            panic!("expected a sum, got {:?}", metric.data);
+        };
+        assert!(sum.is_monotonic);
+        assert_eq!(
+            sum.aggregation_temporality,
+            AggregationTemporality::Delta as i32
+        );
+        assert_eq!(sum.data_points[0].attributes, vec![kv("status", "200")]);
+        assert_eq!(
+            sum.data_points[0].value,
+            Some(number_data_point::Value::AsDouble(2.0))
+        );
+    }
+
+    #[test]
+    fn histogram_buckets_into_bounds() {
+        let metric = Metric::new(
+            "latency",
+            MetricKind::Absolute,
+            MetricValue::AggregatedHistogram {
+                buckets: vector_core::buckets![1.0 => 2, 2.0 => 3, f64::INFINITY => 4],
+                count: 10,
+                sum: 20.0,
+            },
+        );
+
+        let request = std::iter::once(metric).collect::<ExportMetricsServiceRequest>();
+        let metric = &request.resource_metrics[0].scope_metrics[0].metrics[0];
+        let Some(Data::Histogram(histogram)) = &metric.data else {
+            panic!("expected a histogram, got {:?}", metric.data);
+        };
+
+        assert_eq!(histogram.data_points[0].explicit_bounds, vec![1.0, 2.0]);
+        assert_eq!(histogram.data_points[0].bucket_counts, vec![2, 3, 5]);
+        assert_eq!(histogram.data_points[0].count, 10);
+    }
+
+    #[test]
+    fn exponential_histogram_round_trip() {
+        let expected = ExponentialHistogramDataPoint {
+            time_unix_nano: 1_700_000_000_000_000_000,
+            count: 9,
+            sum: Some(12.5),
+            scale: 3,
+            zero_count: 2,
+            positive: Some(Buckets {
+                offset: -2,
+                bucket_counts: vec![1, 0, 3],
+            }),
+            negative: Some(Buckets {
+                offset: 1,
+                bucket_counts: vec![3],
+            }),
+            zero_threshold: 0.001,
+            ..Default::default()
+        };
+        let resource_metrics = ResourceMetrics {
+            resource: None,
+            scope_metrics: vec![ScopeMetrics {
+                scope: None,
+                metrics: vec![OtlpMetric {
+                    name: "latency".to_string(),
+                    description: String::new(),
+                    unit: String::new(),
+                    data: Some(Data::ExponentialHistogram(ExponentialHistogram {
+                        data_points: vec![expected.clone()],
+                        aggregation_temporality: AggregationTemporality::Cumulative as i32,
+                    })),
+                }],
+                schema_url: String::new(),
+            }],
+            schema_url: String::new(),
+        };
+
+        let request = resource_metrics
+            .into_event_iter()
+            .map(Event::into_metric)
+            .collect::<ExportMetricsServiceRequest>();
+        let metric = &request.resource_metrics[0].scope_metrics[0].metrics[0];
+        let Some(Data::ExponentialHistogram(histogram)) = &metric.data else {
+            panic!("expected an exponential histogram, got {:?}", metric.data);
+        };
+
+        assert_eq!(
+            histogram.aggregation_temporality,
+            AggregationTemporality::Cumulative as i32
+        );
+        assert_eq!(histogram.data_points, vec![expected]);
+    }
+
+    #[test]
+    fn unsupported_metrics_are_skipped() {
+        let metric = Metric::new(
+            "samples",
+            MetricKind::Incremental,
+            MetricValue::Distribution {
+                samples: vector_core::samples![1.0 => 1],
+                statistic: vector_core::event::StatisticKind::Histogram,
+            },
+        );
+
+        let request = std::iter::once(metric).collect::<ExportMetricsServiceRequest>();
+
+        assert!(request.resource_metrics.is_empty());
+    }
+
+    #[test]
+    fn traces_round_trip() {
+        let expected = ResourceSpans {
+            resource: Some(Resource {
+                attributes: vec![kv("service.name", "checkout")],
+                dropped_attributes_count: 0,
+            }),
+            scope_spans: vec![ScopeSpans {
+                scope: None,
+                spans: vec![Span {
+                    trace_id: vec![1; 16],
+                    span_id: vec![2; 8],
+                    trace_state: "foo=bar".to_string(),
+                    parent_span_id: vec![3; 8],
+                    name: "GET /".to_string(),
+                    kind: 2,
+                    start_time_unix_nano: 1_579_134_612_000_000_011,
+                    end_time_unix_nano: 1_579_134_612_000_000_022,
+                    attributes: vec![kv("http.method", "GET")],
+                    dropped_attributes_count: 0,
+                    events: vec![SpanEvent {
+                        time_unix_nano: 1_579_134_612_000_000_015,
+                        name: "exception".to_string(),
+                        attributes: vec![],
+                        dropped_attributes_count: 0,
+                    }],
+                    dropped_events_count: 0,
+                    links: vec![],
+                    dropped_links_count: 0,
+                    status: Some(SpanStatus {
+                        message: "ok".to_string(),
+                        code: 1,
+                    }),
+                }],
+                schema_url: String::new(),
+            }],
+            schema_url: String::new(),
+        };
+
+        let request = expected
+            .clone()
+            .into_event_iter()
+            .map(Event::into_trace)
+            .collect::<ExportTraceServiceRequest>();
+
+        assert_eq!(request.resource_spans, vec![expected]);
+    }
+}
@@ -16,8 +16,33 @@ pub const METRIC_NAME_LABEL: &str = "__name__";
 pub mod proto {
     include!(concat!(env!("OUT_DIR"), "/prometheus.rs"));
 
+    /// The protobuf exposition format of Prometheus metrics.
+    pub mod client {
+        include!(concat!(env!("OUT_DIR"), "/io.prometheus.client.rs"));
+    }
+
     pub use metric_metadata::MetricType;
 
+    impl Histogram {
+        /// The number of observations in the histogram, either as an integer or a float.
+        pub fn count_value(&self) -> f64 {
+            match self.count {
+                Some(histogram::Count::CountInt(count)) => count as f64,
+                Some(histogram::Count::CountFloat(count)) => count,
+                None => 0.0,
+            }
+        }
+
+        /// The number of observations in the zero bucket, either as an integer or a float.
+        pub fn zero_count_value(&self) -> f64 {
+            match self.zero_count {
+                Some(histogram::ZeroCount::ZeroCountInt(count)) => count as f64,
+                Some(histogram::ZeroCount::ZeroCountFloat(count)) => count,
+                None => 0.0,
+            }
+        }
+    }
+
     impl MetricType {
         pub fn as_str(&self) -> &'static str {
             match self {
@@ -81,6 +106,7 @@ pub struct SummaryMetric {
     pub quantiles: Vec<SummaryQuantile>,
     pub sum: f64,
     pub count: u64,
+    pub exemplars: Vec<proto::Exemplar>,
 }
 
 #[derive(Debug, Default, PartialEq, PartialOrd)]
@@ -94,11 +120,15 @@ pub struct HistogramMetric {
     pub buckets: Vec<HistogramBucket>,
     pub sum: f64,
     pub count: u64,
+    /// The native histogram, if the metric was sent as one rather than as explicit buckets.
+    pub native: Option<proto::Histogram>,
+    pub exemplars: Vec<proto::Exemplar>,
 }
 
 #[derive(Debug, Default, PartialEq)]
 pub struct SimpleMetric {
     pub value: f64,
+    pub exemplars: Vec<proto::Exemplar>,
 }
 
 type MetricMap<T> = IndexMap<GroupKey, T>;
@@ -125,7 +155,13 @@ impl GroupKind {
 
     fn new_untyped(key: GroupKey, value: f64) -> Self {
         let mut metrics = IndexMap::default();
-        metrics.insert(key, SimpleMetric { value });
+        metrics.insert(
+            key,
+            SimpleMetric {
+                value,
+                ..Default::default()
+            },
+        );
         Self::Untyped(metrics)
     }
 
@@ -166,7 +202,13 @@ impl GroupKind {
                         value,
                     }));
                 }
-                metrics.insert(key, SimpleMetric { value });
+                metrics.insert(
+                    key,
+                    SimpleMetric {
+                        value,
+                        ..Default::default()
+                    },
+                );
             }
             Self::Histogram(ref mut metrics) => match suffix {
                 "_bucket" => {
@@ -376,6 +418,70 @@ impl MetricGroupSet {
         Ok(())
     }
 
+    fn insert_histogram(
+        &mut self,
+        name: &str,
+        labels: &BTreeMap<String, String>,
+        histogram: proto::Histogram,
+    ) -> Result<(), ParserError> {
+        let group = self
+            .0
+            .entry(name.into())
+            .or_insert_with(|| GroupKind::new(MetricKind::Histogram));
+        if matches!(group, GroupKind::Untyped(metrics) if metrics.is_empty()) {
+            *group = GroupKind::new(MetricKind::Histogram);
+        }
+        let GroupKind::Histogram(metrics) = group else {
+            return Err(ParserError::MultipleMetricKinds { name: name.into() });
+        };
+
+        let key = GroupKey {
+            timestamp: Some(histogram.timestamp),
+            labels: labels.clone(),
+        };
+        let metric = matching_group(metrics, key);
+        metric.count = try_f64_to_u64(histogram.count_value())?;
+        metric.sum = histogram.sum;
+        metric.native = Some(histogram);
+        Ok(())
+    }
+
+    /// Attaches the exemplars of a time series to the metric its last sample was grouped into.
+    fn insert_exemplars(
+        &mut self,
+        name: &str,
+        labels: &BTreeMap<String, String>,
+        timestamp: i64,
+        exemplars: Vec<proto::Exemplar>,
+    ) {
+        let (_, basename, group) = self.get_group(name);
+        let suffix = &name[basename.len()..];
+        let mut key = GroupKey {
+            timestamp: Some(timestamp),
+            labels: labels.clone(),
+        };
+        let metric_exemplars = match group {
+            GroupKind::Counter(metrics)
+            | GroupKind::Gauge(metrics)
+            | GroupKind::Untyped(metrics) => {
+                metrics.get_mut(&key).map(|metric| &mut metric.exemplars)
+            }
+            GroupKind::Histogram(metrics) => {
+                if suffix == "_bucket" {
+                    key.labels.remove("le");
+                }
+                metrics.get_mut(&key).map(|metric| &mut metric.exemplars)
+            }
+            GroupKind::Summary(metrics) => {
+                key.labels.remove("quantile");
+                metrics.get_mut(&key).map(|metric| &mut metric.exemplars)
+            }
+        };
+        if let Some(metric_exemplars) = metric_exemplars {
+            metric_exemplars.extend(exemplars);
+        }
+    }
+
     fn finish(self) -> Vec<MetricGroup> {
         self.0
             .into_iter()
@@ -408,9 +514,23 @@ pub fn parse_request(request: proto::WriteRequest) -> Result<Vec<MetricGroup>, P
             None => return Err(ParserError::RequestNoNameLabel),
         };
 
+        let timestamp = timeseries
+            .samples
+            .last()
+            .map(|sample| sample.timestamp)
+            .or_else(|| timeseries.histograms.last().map(|h| h.timestamp));
+
         for sample in timeseries.samples {
             groups.insert_sample(&name, &labels, sample)?;
         }
+        for histogram in timeseries.histograms {
+            groups.insert_histogram(&name, &labels, histogram)?;
+        }
+        if let Some(timestamp) = timestamp {
+            if !timeseries.exemplars.is_empty() {
+                groups.insert_exemplars(&name, &labels, timestamp, timeseries.exemplars);
+            }
+        }
     }
 
     Ok(groups.finish())
@@ -461,7 +581,10 @@ mod test {
                     timestamp: $timestamp,
                     labels: $labels,
                 },
-                &SimpleMetric { value: $value },
+                &SimpleMetric {
+                    value: $value,
+                    ..Default::default()
+                },
             )
         };
     }
@@ -563,6 +686,7 @@ mod test {
                     ],
                     count: 144320,
                     sum: 53423.0,
+                    ..Default::default()
                 },
             ));
         });
@@ -579,6 +703,7 @@ mod test {
                     ],
                     count: 10,
                     sum: 5.0,
+                    ..Default::default()
                 },
             ));
         });
@@ -599,6 +724,7 @@ mod test {
                     ],
                     count: 4588206224,
                     sum: 1.7560473e+07,
+                    ..Default::default()
                 },
             ));
         });
@@ -736,6 +862,8 @@ mod test {
                     samples: vec![
                         $( proto::Sample { value: $sample as f64, timestamp: $timestamp as i64 }, )*
                     ],
+                    exemplars: vec![],
+                    histograms: vec![],
                 }, )* ],
             }
         };
@@ -839,6 +967,7 @@ mod test {
                         ],
                         count: 19,
                         sum: 12.0,
+                        ..Default::default()
                     })
             );
         });
@@ -848,6 +977,88 @@ mod test {
         });
     }
 
+    #[test]
+    fn parse_request_native_histogram() {
+        let histogram = proto::Histogram {
+            count: Some(proto::histogram::Count::CountInt(5)),
+            sum: 12.5,
+            schema: 1,
+            zero_threshold: 0.001,
+            zero_count: Some(proto::histogram::ZeroCount::ZeroCountInt(1)),
+            positive_spans: vec![proto::BucketSpan {
+                offset: 2,
+                length: 2,
+            }],
+            positive_deltas: vec![3, -2],
+            timestamp: 1395066367700,
+            ..Default::default()
+        };
+        let exemplar = proto::Exemplar {
+            labels: vec![proto::Label {
+                name: "trace_id".into(),
+                value: "abc".into(),
+            }],
+            value: 2.5,
+            timestamp: 1395066367600,
+        };
+        let request = proto::WriteRequest {
+            metadata: vec![],
+            timeseries: vec![proto::TimeSeries {
+                labels: vec![proto::Label {
+                    name: METRIC_NAME_LABEL.into(),
+                    value: "one".into(),
+                }],
+                samples: vec![],
+                exemplars: vec![exemplar.clone()],
+                histograms: vec![histogram.clone()],
+            }],
+        };
+
+        let parsed = parse_request(request).unwrap();
+        assert_eq!(parsed.len(), 1);
+        match_group!(parsed[0], "one", Histogram => |metrics: &MetricMap<HistogramMetric>| {
+            assert_eq!(metrics.len(), 1);
+            assert_eq!(
+                metrics.get_index(0).unwrap(), (
+                    &GroupKey {
+                        timestamp: Some(1395066367700),
+                        labels: labels!(),
+                    },
+                    &HistogramMetric {
+                        buckets: vec![],
+                        count: 5,
+                        sum: 12.5,
+                        native: Some(histogram),
+                        exemplars: vec![exemplar],
+                    })
+            );
+        });
+    }
+
+    #[test]
+    fn parse_request_bucket_exemplars() {
+        let mut request = write_request!(
+            ["one" = Histogram],
+            [
+                [__name__ => "one_bucket", le => "1"] => [ 15 @ 1395066367700 ],
+                [__name__ => "one_count"] => [ 15 @ 1395066367700 ],
+                [__name__ => "one_sum"] => [ 12 @ 1395066367700 ]
+            ]
+        );
+        let exemplar = proto::Exemplar {
+            labels: vec![],
+            value: 0.5,
+            timestamp: 1395066367600,
+        };
+        request.timeseries[0].exemplars.push(exemplar.clone());
+
+        let parsed = parse_request(request).unwrap();
+        match_group!(parsed[0], "one", Histogram => |metrics: &MetricMap<HistogramMetric>| {
+            assert_eq!(metrics.len(), 1);
+            assert_eq!(metrics.get_index(0).unwrap().1.exemplars, vec![exemplar]);
+        });
+    }
+
     #[test]
     fn parse_request_summary() {
         let parsed = parse_request(write_request!(
@@ -878,6 +1089,7 @@ mod test {
                         ],
                         count: 21,
                         sum: 12.0,
+                        ..Default::default()
                     })
             );
         });
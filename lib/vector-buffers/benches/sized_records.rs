@@ -81,7 +81,8 @@ fn create_disk_v2_variant(_max_events: usize, max_size: u64) -> BufferType {
 
 fn create_in_memory_variant(max_events: usize, _max_size: u64) -> BufferType {
     BufferType::Memory {
-        max_events: NonZeroUsize::new(max_events).unwrap(),
+        max_events: Some(NonZeroUsize::new(max_events).unwrap()),
+        max_size: None,
         when_full: WhenFull::DropNewest,
     }
 }
@@ -261,7 +261,8 @@ where
                 max_size_events
             );
             BufferType::Memory {
-                max_events: max_size_events,
+                max_events: Some(max_size_events),
+                max_size: None,
                 when_full,
             }
         }
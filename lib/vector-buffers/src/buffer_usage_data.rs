@@ -11,7 +11,10 @@ use tracing::{Instrument, Span};
 use vector_common::internal_event::emit;
 
 use crate::{
-    internal_events::{BufferCreated, BufferEventsDropped, BufferEventsReceived, BufferEventsSent},
+    internal_events::{
+        BufferCreated, BufferEventsDropped, BufferEventsOverflowed, BufferEventsReceived,
+        BufferEventsSent,
+    },
     spawn_named,
 };
 
@@ -127,6 +130,13 @@ impl BufferUsageHandle {
         self.state.sent.increment(count, byte_size);
     }
 
+    /// Increments the number of events (and their total size) overflowed by this buffer component.
+    ///
+    /// This represents the events being sent to the next buffer stage because this one is full.
+    pub fn increment_overflowed_event_count_and_byte_size(&self, count: u64, byte_size: u64) {
+        self.state.overflowed.increment(count, byte_size);
+    }
+
     /// Increment the number of dropped events (and their total size) for this buffer component.
     pub fn increment_dropped_event_count_and_byte_size(
         &self,
@@ -147,6 +157,7 @@ struct BufferUsageData {
     idx: usize,
     received: CategoryMetrics,
     sent: CategoryMetrics,
+    overflowed: CategoryMetrics,
     dropped: CategoryMetrics,
     dropped_intentional: CategoryMetrics,
     max_size: CategoryMetrics,
@@ -163,6 +174,7 @@ impl BufferUsageData {
     fn snapshot(&self) -> BufferUsageSnapshot {
         let received = self.received.get();
         let sent = self.sent.get();
+        let overflowed = self.overflowed.get();
         let dropped = self.dropped.get();
         let dropped_intentional = self.dropped_intentional.get();
         let max_size = self.max_size.get();
@@ -172,6 +184,8 @@ impl BufferUsageData {
             received_byte_size: received.event_byte_size,
             sent_event_count: sent.event_count,
             sent_byte_size: sent.event_byte_size,
+            overflowed_event_count: overflowed.event_count,
+            overflowed_byte_size: overflowed.event_byte_size,
             dropped_event_count: dropped.event_count,
             dropped_event_byte_size: dropped.event_byte_size,
             dropped_event_count_intentional: dropped_intentional.event_count,
@@ -192,6 +206,8 @@ pub struct BufferUsageSnapshot {
     pub received_byte_size: u64,
     pub sent_event_count: u64,
     pub sent_byte_size: u64,
+    pub overflowed_event_count: u64,
+    pub overflowed_byte_size: u64,
     pub dropped_event_count: u64,
     pub dropped_event_byte_size: u64,
     pub dropped_event_count_intentional: u64,
@@ -279,6 +295,15 @@ impl BufferUsage {
                         });
                     }
 
+                    let overflowed = stage.overflowed.consume();
+                    if overflowed.has_updates() {
+                        emit(BufferEventsOverflowed {
+                            idx: stage.idx,
+                            count: overflowed.event_count,
+                            byte_size: overflowed.event_byte_size,
+                        });
+                    }
+
                     let dropped = stage.dropped.consume();
                     if dropped.has_updates() {
                         emit(BufferEventsDropped {
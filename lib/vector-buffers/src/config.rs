@@ -28,6 +28,8 @@ pub enum BufferBuildError {
     FailedToBuildTopology { source: TopologyError },
     #[snafu(display("`max_events` must be greater than zero"))]
     InvalidMaxEvents,
+    #[snafu(display("a buffer can only have a single disk stage"))]
+    MultipleDiskStages,
 }
 
 #[derive(Deserialize, Serialize)]
@@ -86,14 +88,14 @@ impl BufferTypeVisitor {
         let when_full = when_full.unwrap_or_default();
         match kind {
             BufferTypeKind::Memory => {
-                if max_size.is_some() {
-                    return Err(de::Error::unknown_field(
-                        "max_size",
-                        &["type", "max_events", "when_full"],
-                    ));
-                }
+                // Memory buffers are limited in events unless told otherwise.
+                let max_events = match (max_events, max_size) {
+                    (None, None) => Some(memory_buffer_default_max_events()),
+                    (max_events, _) => max_events,
+                };
                 Ok(BufferType::Memory {
-                    max_events: max_events.unwrap_or_else(memory_buffer_default_max_events),
+                    max_events,
+                    max_size,
                     when_full,
                 })
             }
@@ -189,8 +191,17 @@ pub enum BufferType {
     #[serde(rename = "memory")]
     Memory {
         /// The maximum number of events allowed in the buffer.
-        #[serde(default = "memory_buffer_default_max_events")]
-        max_events: NonZeroUsize,
+        ///
+        /// Defaults to 500 events, unless `max_size` is set.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        max_events: Option<NonZeroUsize>,
+
+        /// The maximum size of the events allowed in the buffer, as estimated in memory.
+        ///
+        /// When set along with `max_events`, both limits are enforced.
+        #[configurable(metadata(docs::type_unit = "bytes"))]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        max_size: Option<NonZeroU64>,
 
         #[configurable(derived)]
         #[serde(default)]
@@ -280,8 +291,11 @@ impl BufferType {
             BufferType::Memory {
                 when_full,
                 max_events,
+                max_size,
             } => {
-                builder.stage(MemoryBuffer::new(max_events), when_full);
+                let max_size = max_size
+                    .map(|max_size| NonZeroUsize::try_from(max_size).unwrap_or(NonZeroUsize::MAX));
+                builder.stage(MemoryBuffer::with_limits(max_events, max_size), when_full);
             }
             BufferType::DiskV2 {
                 when_full,
@@ -310,10 +324,9 @@ impl BufferType {
 /// functionality to allow chaining buffers together, you'll see "buffer topology" used in internal
 /// documentation to correctly reflect the internal structure.
 ///
-// TODO: We need to limit chained buffers to only allowing a single copy of each buffer type to be
-// defined, otherwise, for example, two instances of the same disk buffer type in a single chained
-// buffer topology would try to both open the same buffer files on disk, which wouldn't work or
-// would go horribly wrong.
+/// Chained buffers can contain at most one disk stage: disk buffers are identified by the sink they
+/// belong to, so two disk stages in the same buffer topology would try to open the same buffer files
+/// on disk.
 #[configurable_component]
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[serde(untagged)]
@@ -335,7 +348,8 @@ pub enum BufferConfig {
 impl Default for BufferConfig {
     fn default() -> Self {
         Self::Single(BufferType::Memory {
-            max_events: memory_buffer_default_max_events(),
+            max_events: Some(memory_buffer_default_max_events()),
+            max_size: None,
             when_full: WhenFull::default(),
         })
     }
@@ -357,8 +371,9 @@ impl BufferConfig {
     ///
     /// # Errors
     ///
-    /// If the buffer is configured with anything other than a single stage, an error variant will
-    /// be thrown.
+    /// If the stages of the buffer don't form a valid buffer topology, such as a stage other than
+    /// the last one not being configured to overflow, or more than one disk stage being configured,
+    /// an error variant will be thrown.
     ///
     /// If a disk buffer stage is configured and the data directory provided is `None`, an error
     /// variant will be thrown.
@@ -372,6 +387,15 @@ impl BufferConfig {
     where
         T: Bufferable + Clone + Finalizable,
     {
+        let disk_stages = self
+            .stages()
+            .iter()
+            .filter(|stage| matches!(stage, BufferType::DiskV2 { .. }))
+            .count();
+        if disk_stages > 1 {
+            return Err(BufferBuildError::MultipleDiskStages);
+        }
+
         let mut builder = TopologyBuilder::default();
 
         for stage in self.stages() {
@@ -389,7 +413,10 @@ impl BufferConfig {
 mod test {
     use std::num::{NonZeroU64, NonZeroUsize};
 
-    use crate::{BufferConfig, BufferType, WhenFull};
+    use tracing::Span;
+
+    use super::BufferBuildError;
+    use crate::{test::SizedRecord, BufferConfig, BufferType, WhenFull};
 
     fn check_single_stage(source: &str, expected: BufferType) {
         let config: BufferConfig = serde_yaml::from_str(source).unwrap();
@@ -427,6 +454,7 @@ mod test {
     fn parse_partial_invalid_keys() {
         let source = r"max_size: 100
 max_events: 42
+foo: 314
 ";
         let error = serde_yaml::from_str::<BufferConfig>(source).unwrap_err();
         assert_eq!(error.to_string(), BUFFER_CONFIG_NO_MATCH_ERR);
@@ -439,7 +467,8 @@ max_events: 42
           max_events: 100
           ",
             BufferType::Memory {
-                max_events: NonZeroUsize::new(100).unwrap(),
+                max_events: NonZeroUsize::new(100),
+                max_size: None,
                 when_full: WhenFull::Block,
             },
         );
@@ -455,11 +484,13 @@ max_events: 42
           ",
             &[
                 BufferType::Memory {
-                    max_events: NonZeroUsize::new(42).unwrap(),
+                    max_events: NonZeroUsize::new(42),
+                    max_size: None,
                     when_full: WhenFull::Block,
                 },
                 BufferType::Memory {
-                    max_events: NonZeroUsize::new(100).unwrap(),
+                    max_events: NonZeroUsize::new(100),
+                    max_size: None,
                     when_full: WhenFull::DropNewest,
                 },
             ],
@@ -473,7 +504,8 @@ max_events: 42
           type: memory
           ",
             BufferType::Memory {
-                max_events: NonZeroUsize::new(500).unwrap(),
+                max_events: NonZeroUsize::new(500),
+                max_size: None,
                 when_full: WhenFull::Block,
             },
         );
@@ -484,7 +516,8 @@ max_events: 42
           max_events: 100
           ",
             BufferType::Memory {
-                max_events: NonZeroUsize::new(100).unwrap(),
+                max_events: NonZeroUsize::new(100),
+                max_size: None,
                 when_full: WhenFull::Block,
             },
         );
@@ -495,7 +528,8 @@ max_events: 42
           when_full: drop_newest
           ",
             BufferType::Memory {
-                max_events: NonZeroUsize::new(500).unwrap(),
+                max_events: NonZeroUsize::new(500),
+                max_size: None,
                 when_full: WhenFull::DropNewest,
             },
         );
@@ -506,11 +540,37 @@ max_events: 42
           when_full: overflow
           ",
             BufferType::Memory {
-                max_events: NonZeroUsize::new(500).unwrap(),
+                max_events: NonZeroUsize::new(500),
+                max_size: None,
                 when_full: WhenFull::Overflow,
             },
         );
 
+        check_single_stage(
+            r"
+          type: memory
+          max_size: 1024
+          ",
+            BufferType::Memory {
+                max_events: None,
+                max_size: NonZeroU64::new(1024),
+                when_full: WhenFull::Block,
+            },
+        );
+
+        check_single_stage(
+            r"
+          type: memory
+          max_events: 100
+          max_size: 1024
+          ",
+            BufferType::Memory {
+                max_events: NonZeroUsize::new(100),
+                max_size: NonZeroU64::new(1024),
+                when_full: WhenFull::Block,
+            },
+        );
+
         check_single_stage(
             r"
           type: disk
@@ -522,4 +582,46 @@ max_events: 42
             },
         );
     }
+
+    #[test]
+    fn parse_memory_overflow_to_disk() {
+        check_multiple_stages(
+            r"
+          - max_events: 1000
+            when_full: overflow
+          - type: disk
+            max_size: 268435488
+          ",
+            &[
+                BufferType::Memory {
+                    max_events: NonZeroUsize::new(1000),
+                    max_size: None,
+                    when_full: WhenFull::Overflow,
+                },
+                BufferType::DiskV2 {
+                    max_size: NonZeroU64::new(268_435_488).unwrap(),
+                    when_full: WhenFull::Block,
+                },
+            ],
+        );
+    }
+
+    #[tokio::test]
+    async fn build_rejects_multiple_disk_stages() {
+        let config: BufferConfig = serde_yaml::from_str(
+            r"
+          - type: disk
+            max_size: 268435488
+            when_full: overflow
+          - type: disk
+            max_size: 268435488
+          ",
+        )
+        .unwrap();
+
+        let result = config
+            .build::<SizedRecord>(None, String::from("test"), Span::none())
+            .await;
+        assert!(matches!(result, Err(BufferBuildError::MultipleDiskStages)));
+    }
 }
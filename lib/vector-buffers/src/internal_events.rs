@@ -63,6 +63,21 @@ impl InternalEvent for BufferEventsSent {
     }
 }
 
+pub struct BufferEventsOverflowed {
+    pub idx: usize,
+    pub count: u64,
+    pub byte_size: u64,
+}
+
+impl InternalEvent for BufferEventsOverflowed {
+    fn emit(self) {
+        counter!("buffer_overflowed_events_total", "stage" => self.idx.to_string())
+            .increment(self.count);
+        counter!("buffer_overflowed_bytes_total", "stage" => self.idx.to_string())
+            .increment(self.byte_size);
+    }
+}
+
 pub struct BufferEventsDropped {
     pub idx: usize,
     pub count: u64,
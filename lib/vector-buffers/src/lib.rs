@@ -67,16 +67,14 @@ pub enum WhenFull {
     /// potentially be able to buffer the event, but it may also block or drop the event.
     ///
     /// This mode can only be used when two or more buffer stages are configured.
-    #[configurable(metadata(docs::hidden))]
     Overflow,
 }
 
 #[cfg(test)]
 impl Arbitrary for WhenFull {
     fn arbitrary(g: &mut Gen) -> Self {
-        // TODO: We explicitly avoid generating "overflow" as a possible value because nothing yet
-        // supports handling it, and will be defaulted to using "block" if they encounter
-        // "overflow".  Thus, there's no reason to emit it here... yet.
+        // We explicitly avoid generating "overflow" as a possible value because the variants we
+        // generate are single stage buffers, where overflowing isn't valid.
         if bool::arbitrary(g) {
             WhenFull::Block
         } else {
@@ -148,10 +148,14 @@ impl<T: Bufferable> TopologyBuilder<T> {
                     BufferSender::new(sender, stage.when_full),
                     BufferReceiver::new(receiver),
                 ),
-                Some((current_sender, current_receiver)) => (
-                    BufferSender::with_overflow(sender, current_sender),
-                    BufferReceiver::with_overflow(receiver, current_receiver),
-                ),
+                Some((current_sender, current_receiver)) => {
+                    let mut sender = BufferSender::with_overflow(sender, current_sender);
+                    sender.with_overflow_instrumentation(usage_handle.clone());
+                    (
+                        sender,
+                        BufferReceiver::with_overflow(receiver, current_receiver),
+                    )
+                }
             };
 
             sender.with_send_duration_instrumentation(stage_idx, &span);
@@ -257,15 +261,17 @@ impl<T: Bufferable> Default for TopologyBuilder<T> {
 
 #[cfg(test)]
 mod tests {
-    use std::num::NonZeroUsize;
+    use std::num::{NonZeroU64, NonZeroUsize};
 
     use tracing::Span;
+    use vector_common::finalization::{AddBatchNotifier, BatchNotifier, BatchStatus};
 
     use super::TopologyBuilder;
     use crate::{
+        test::{acknowledge, with_temp_dir, SizedRecord},
         topology::builder::TopologyError,
         topology::test_util::{assert_current_send_capacity, Sample},
-        variants::MemoryBuffer,
+        variants::{DiskV2Buffer, MemoryBuffer},
         WhenFull,
     };
 
@@ -365,4 +371,70 @@ mod tests {
         let (mut sender, _) = result.unwrap();
         assert_current_send_capacity(&mut sender, Some(1), Some(1));
     }
+
+    #[tokio::test]
+    async fn two_stage_topology_memory_overflow_to_disk() {
+        with_temp_dir(|dir| {
+            let data_dir = dir.to_path_buf();
+
+            async move {
+                let mut builder = TopologyBuilder::<SizedRecord>::default();
+                builder.stage(
+                    MemoryBuffer::new(NonZeroUsize::new(1).unwrap()),
+                    WhenFull::Overflow,
+                );
+                builder.stage(
+                    DiskV2Buffer::new(
+                        String::from("test"),
+                        data_dir,
+                        NonZeroU64::new(1024 * 1024 * 1024).unwrap(),
+                    ),
+                    WhenFull::Block,
+                );
+                let (mut sender, mut receiver) = builder
+                    .build(String::from("test"), Span::none())
+                    .await
+                    .expect("should not fail to build topology");
+
+                // The first record fits in the memory stage, and the rest overflow to the disk stage.
+                let mut statuses = Vec::new();
+                for i in 0..3 {
+                    let (batch, status) = BatchNotifier::new_with_receiver();
+                    let mut record = SizedRecord::new(i);
+                    record.add_batch_notifier(batch);
+                    sender
+                        .send(record, None)
+                        .await
+                        .expect("send should not fail");
+                    statuses.push(status);
+                }
+                sender.flush().await.expect("flush should not fail");
+                assert_current_send_capacity(&mut sender, Some(0), None);
+                drop(sender);
+
+                // Records written to the disk stage are acknowledged as soon as they're written,
+                // while the record held in the memory stage waits for the receiving side.
+                assert!(statuses[0].try_recv().is_err());
+                assert_eq!(statuses[1].try_recv(), Ok(BatchStatus::Delivered));
+                assert_eq!(statuses[2].try_recv(), Ok(BatchStatus::Delivered));
+
+                let mut records = Vec::new();
+                for _ in 0..3 {
+                    records.push(receiver.next().await.expect("should receive record"));
+                }
+                let mut values = records.iter().map(|record| record.0).collect::<Vec<_>>();
+                values.sort_unstable();
+                assert_eq!(values, vec![0, 1, 2]);
+
+                // Acknowledging out of order must still allow the disk stage to drain fully, at
+                // which point the receiver completes.
+                for record in records.into_iter().rev() {
+                    acknowledge(record).await;
+                }
+                assert_eq!(statuses[0].try_recv(), Ok(BatchStatus::Delivered));
+                assert!(receiver.next().await.is_none());
+            }
+        })
+        .await;
+    }
 }
@@ -1,5 +1,6 @@
 use std::{
     cmp, fmt,
+    num::NonZeroUsize,
     pin::Pin,
     sync::{
         atomic::{AtomicUsize, Ordering},
@@ -8,9 +9,10 @@ use std::{
 };
 
 use async_stream::stream;
-use crossbeam_queue::ArrayQueue;
+use crossbeam_queue::{ArrayQueue, SegQueue};
 use futures::Stream;
 use tokio::sync::{Notify, OwnedSemaphorePermit, Semaphore, TryAcquireError};
+use vector_common::byte_size_of::ByteSizeOf;
 
 use crate::InMemoryBufferable;
 
@@ -34,6 +36,13 @@ pub enum TrySendError<T> {
 }
 
 impl<T> TrySendError<T> {
+    fn from_acquire_error(error: TryAcquireError, item: T) -> Self {
+        match error {
+            TryAcquireError::NoPermits => Self::InsufficientCapacity(item),
+            TryAcquireError::Closed => Self::Disconnected(item),
+        }
+    }
+
     pub fn into_inner(self) -> T {
         match self {
             Self::InsufficientCapacity(item) | Self::Disconnected(item) => item,
@@ -54,20 +63,69 @@ impl<T> fmt::Display for TrySendError<T> {
 
 impl<T: fmt::Debug> std::error::Error for TrySendError<T> {}
 
+/// The storage backing the channel.
+///
+/// When the number of events is limited, the number of items is bounded as well, so a fixed-size
+/// queue can be used. Otherwise, only the size of the items bounds how many of them are stored.
+#[derive(Debug)]
+enum Queue<T> {
+    Bounded(ArrayQueue<T>),
+    Unbounded(SegQueue<T>),
+}
+
+impl<T> Queue<T> {
+    fn push(&self, item: T) -> Result<(), T> {
+        match self {
+            Self::Bounded(queue) => queue.push(item),
+            Self::Unbounded(queue) => {
+                queue.push(item);
+                Ok(())
+            }
+        }
+    }
+
+    fn pop(&self) -> Option<T> {
+        match self {
+            Self::Bounded(queue) => queue.pop(),
+            Self::Unbounded(queue) => queue.pop(),
+        }
+    }
+}
+
+/// The permits held by an item for as long as it is in the channel.
+#[derive(Debug)]
+struct Permits {
+    _events: OwnedSemaphorePermit,
+    _bytes: Option<OwnedSemaphorePermit>,
+}
+
 #[derive(Debug)]
 struct Inner<T> {
-    data: Arc<ArrayQueue<(OwnedSemaphorePermit, T)>>,
+    data: Arc<Queue<(Permits, T)>>,
     limit: usize,
     limiter: Arc<Semaphore>,
+    byte_limit: usize,
+    byte_limiter: Option<Arc<Semaphore>>,
     read_waker: Arc<Notify>,
 }
 
+impl<T> Inner<T> {
+    fn close(&self) {
+        self.limiter.close();
+        if let Some(byte_limiter) = &self.byte_limiter {
+            byte_limiter.close();
+        }
+    }
+}
+
 impl<T> Clone for Inner<T> {
     fn clone(&self) -> Self {
         Self {
             data: self.data.clone(),
             limit: self.limit,
             limiter: self.limiter.clone(),
+            byte_limit: self.byte_limit,
+            byte_limiter: self.byte_limiter.clone(),
             read_waker: self.read_waker.clone(),
         }
     }
@@ -81,11 +139,29 @@ pub struct LimitedSender<T> {
 
 impl<T: InMemoryBufferable> LimitedSender<T> {
     #[allow(clippy::cast_possible_truncation)]
-    fn get_required_permits_for_item(&self, item: &T) -> u32 {
+    fn get_required_permits_for_item(&self, item: &T) -> (u32, u32) {
         // We have to limit the number of permits we ask for to the overall limit since we're always
         // willing to store more items than the limit if the queue is entirely empty, because
         // otherwise we might deadlock ourselves by not being able to send a single item.
-        cmp::min(self.inner.limit, item.event_count()) as u32
+        let events = cmp::min(self.inner.limit, item.event_count()) as u32;
+
+        // Items are sized in bytes, so the same goes for the number of permits a single
+        // acquisition can ask for.
+        let bytes = if self.inner.byte_limiter.is_some() {
+            cmp::min(self.inner.byte_limit, item.size_of()).min(u32::MAX as usize) as u32
+        } else {
+            0
+        };
+
+        (events, bytes)
+    }
+
+    fn push(&self, permits: Permits, item: T) {
+        self.inner
+            .data
+            .push((permits, item))
+            .unwrap_or_else(|_| unreachable!("acquired permits but channel reported being full"));
+        self.inner.read_waker.notify_one();
     }
 
     /// Gets the number of items that this channel could accept.
@@ -101,22 +177,37 @@ impl<T: InMemoryBufferable> LimitedSender<T> {
     /// with the given `item`.
     pub async fn send(&mut self, item: T) -> Result<(), SendError<T>> {
         // Calculate how many permits we need, and wait until we can acquire all of them.
-        let permits_required = self.get_required_permits_for_item(&item);
-        let Ok(permits) = self
+        let (events_required, bytes_required) = self.get_required_permits_for_item(&item);
+        let Ok(events) = self
             .inner
             .limiter
             .clone()
-            .acquire_many_owned(permits_required)
+            .acquire_many_owned(events_required)
             .await
         else {
             return Err(SendError(item));
         };
+        let bytes = match &self.inner.byte_limiter {
+            Some(byte_limiter) => {
+                let Ok(bytes) = byte_limiter
+                    .clone()
+                    .acquire_many_owned(bytes_required)
+                    .await
+                else {
+                    return Err(SendError(item));
+                };
+                Some(bytes)
+            }
+            None => None,
+        };
 
-        self.inner
-            .data
-            .push((permits, item))
-            .unwrap_or_else(|_| unreachable!("acquired permits but channel reported being full"));
-        self.inner.read_waker.notify_one();
+        self.push(
+            Permits {
+                _events: events,
+                _bytes: bytes,
+            },
+            item,
+        );
 
         trace!("Sent item.");
 
@@ -137,27 +228,32 @@ impl<T: InMemoryBufferable> LimitedSender<T> {
     /// Will panic if adding ack amount overflows.
     pub fn try_send(&mut self, item: T) -> Result<(), TrySendError<T>> {
         // Calculate how many permits we need, and try to acquire them all without waiting.
-        let permits_required = self.get_required_permits_for_item(&item);
-        let permits = match self
+        let (events_required, bytes_required) = self.get_required_permits_for_item(&item);
+        let events = match self
             .inner
             .limiter
             .clone()
-            .try_acquire_many_owned(permits_required)
+            .try_acquire_many_owned(events_required)
         {
             Ok(permits) => permits,
-            Err(ae) => {
-                return match ae {
-                    TryAcquireError::NoPermits => Err(TrySendError::InsufficientCapacity(item)),
-                    TryAcquireError::Closed => Err(TrySendError::Disconnected(item)),
-                }
-            }
+            Err(ae) => return Err(TrySendError::from_acquire_error(ae, item)),
+        };
+        let bytes = match &self.inner.byte_limiter {
+            Some(byte_limiter) => match byte_limiter.clone().try_acquire_many_owned(bytes_required)
+            {
+                Ok(permits) => Some(permits),
+                Err(ae) => return Err(TrySendError::from_acquire_error(ae, item)),
+            },
+            None => None,
         };
 
-        self.inner
-            .data
-            .push((permits, item))
-            .unwrap_or_else(|_| unreachable!("acquired permits but channel reported being full"));
-        self.inner.read_waker.notify_one();
+        self.push(
+            Permits {
+                _events: events,
+                _bytes: bytes,
+            },
+            item,
+        );
 
         trace!("Attempt to send item succeeded.");
 
@@ -180,7 +276,7 @@ impl<T> Drop for LimitedSender<T> {
     fn drop(&mut self) {
         // If we're the last sender to drop, close the semaphore on our way out the door.
         if self.sender_count.fetch_sub(1, Ordering::SeqCst) == 1 {
-            self.inner.limiter.close();
+            self.inner.close();
             self.inner.read_waker.notify_one();
         }
     }
@@ -231,15 +327,48 @@ impl<T> Drop for LimitedReceiver<T> {
         // Notify senders that the channel is now closed by closing the semaphore.  Any pending
         // acquisitions will be awoken and notified that the semaphore is closed, and further new
         // sends will immediately see the semaphore is closed.
-        self.inner.limiter.close();
+        self.inner.close();
     }
 }
 
+/// Creates a channel holding up to `limit` events.
+///
+/// # Panics
+///
+/// Will panic if `limit` is zero.
 pub fn limited<T>(limit: usize) -> (LimitedSender<T>, LimitedReceiver<T>) {
+    let limit = NonZeroUsize::new(limit).expect("channel limit must be greater than zero");
+    limited_by(Some(limit), None)
+}
+
+/// Creates a channel holding up to `max_events` events, and up to `max_bytes` bytes worth of
+/// items.
+///
+/// Either limit can be omitted, in which case only the other one is enforced.
+///
+/// # Panics
+///
+/// Will panic if neither limit is given.
+pub fn limited_by<T>(
+    max_events: Option<NonZeroUsize>,
+    max_bytes: Option<NonZeroUsize>,
+) -> (LimitedSender<T>, LimitedReceiver<T>) {
+    assert!(
+        max_events.is_some() || max_bytes.is_some(),
+        "channel must be limited in events, in bytes, or both"
+    );
+
+    let (data, limit) = match max_events {
+        Some(limit) => (Queue::Bounded(ArrayQueue::new(limit.get())), limit.get()),
+        None => (Queue::Unbounded(SegQueue::new()), Semaphore::MAX_PERMITS),
+    };
+    let byte_limit = max_bytes.map_or(0, |limit| cmp::min(limit.get(), Semaphore::MAX_PERMITS));
     let inner = Inner {
-        data: Arc::new(ArrayQueue::new(limit)),
+        data: Arc::new(data),
         limit,
         limiter: Arc::new(Semaphore::new(limit)),
+        byte_limit,
+        byte_limiter: max_bytes.map(|_| Arc::new(Semaphore::new(byte_limit))),
         read_waker: Arc::new(Notify::new()),
     };
 
@@ -254,9 +383,11 @@ pub fn limited<T>(limit: usize) -> (LimitedSender<T>, LimitedReceiver<T>) {
 
 #[cfg(test)]
 mod tests {
+    use std::{mem, num::NonZeroUsize};
+
     use tokio_test::{assert_pending, assert_ready, task::spawn};
 
-    use super::limited;
+    use super::{limited, limited_by, TrySendError};
     use crate::{
         test::MultiEventRecord, topology::channel::limited_queue::SendError,
         topology::test_util::Sample,
@@ -595,4 +726,39 @@ mod tests {
 
         assert_eq!(2, tx.available_capacity());
     }
+
+    #[test]
+    fn sender_limited_by_size() {
+        let (mut tx, mut rx) = limited_by(None, NonZeroUsize::new(2 * mem::size_of::<Sample>()));
+
+        assert_eq!(Ok(()), tx.try_send(Sample(1)));
+        assert_eq!(Ok(()), tx.try_send(Sample(2)));
+
+        // The items sent so far use up all of the bytes the channel can hold.
+        assert_eq!(
+            Err(TrySendError::InsufficientCapacity(Sample(3))),
+            tx.try_send(Sample(3))
+        );
+
+        let mut recv = spawn(async { rx.next().await });
+        assert_eq!(Some(Sample(1)), assert_ready!(recv.poll()));
+        drop(recv);
+
+        assert_eq!(Ok(()), tx.try_send(Sample(3)));
+    }
+
+    #[test]
+    fn sender_limited_by_both_events_and_size() {
+        let (mut tx, _rx) = limited_by(
+            NonZeroUsize::new(1),
+            NonZeroUsize::new(2 * mem::size_of::<Sample>()),
+        );
+
+        // There would be enough room for a second item in bytes, but not in events.
+        assert_eq!(Ok(()), tx.try_send(Sample(1)));
+        assert_eq!(
+            Err(TrySendError::InsufficientCapacity(Sample(2))),
+            tx.try_send(Sample(2))
+        );
+    }
 }
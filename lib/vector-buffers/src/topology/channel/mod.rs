@@ -2,7 +2,7 @@ mod limited_queue;
 mod receiver;
 mod sender;
 
-pub use limited_queue::{limited, LimitedReceiver, LimitedSender, SendError};
+pub use limited_queue::{limited, limited_by, LimitedReceiver, LimitedSender, SendError};
 pub use receiver::*;
 pub use sender::*;
 
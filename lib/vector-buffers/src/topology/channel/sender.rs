@@ -135,6 +135,7 @@ pub struct BufferSender<T: Bufferable> {
     overflow: Option<Box<BufferSender<T>>>,
     when_full: WhenFull,
     instrumentation: Option<BufferUsageHandle>,
+    overflow_instrumentation: Option<BufferUsageHandle>,
     #[derivative(Debug = "ignore")]
     send_duration: Option<Registered<BufferSendDuration>>,
 }
@@ -147,6 +148,7 @@ impl<T: Bufferable> BufferSender<T> {
             overflow: None,
             when_full,
             instrumentation: None,
+            overflow_instrumentation: None,
             send_duration: None,
         }
     }
@@ -158,6 +160,7 @@ impl<T: Bufferable> BufferSender<T> {
             overflow: Some(Box::new(overflow)),
             when_full: WhenFull::Overflow,
             instrumentation: None,
+            overflow_instrumentation: None,
             send_duration: None,
         }
     }
@@ -177,6 +180,11 @@ impl<T: Bufferable> BufferSender<T> {
         self.instrumentation = Some(handle);
     }
 
+    /// Configures this sender to instrument the items overflowing to the next stage.
+    pub fn with_overflow_instrumentation(&mut self, handle: BufferUsageHandle) {
+        self.overflow_instrumentation = Some(handle);
+    }
+
     /// Configures this sender to instrument the send duration.
     pub fn with_send_duration_instrumentation(&mut self, stage: usize, span: &Span) {
         let _enter = span.enter();
@@ -214,6 +222,12 @@ impl<T: Bufferable> BufferSender<T> {
             WhenFull::Overflow => {
                 if let Some(item) = self.base.try_send(item).await? {
                     sent_to_base = false;
+                    if let Some(instrumentation) = self.overflow_instrumentation.as_ref() {
+                        instrumentation.increment_overflowed_event_count_and_byte_size(
+                            item.event_count() as u64,
+                            item.size_of() as u64,
+                        );
+                    }
                     self.overflow
                         .as_mut()
                         .unwrap_or_else(|| unreachable!("overflow must exist"))
@@ -114,6 +114,7 @@ pub(crate) async fn build_buffer(
             )
             .await;
             base_sender.switch_to_overflow(overflow_sender);
+            base_sender.with_overflow_instrumentation(handle.clone());
             base_receiver.switch_to_overflow(overflow_receiver);
 
             (base_sender, base_receiver)
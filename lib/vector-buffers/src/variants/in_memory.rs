@@ -6,18 +6,39 @@ use crate::{
     buffer_usage_data::BufferUsageHandle,
     topology::{
         builder::IntoBuffer,
-        channel::{limited, ReceiverAdapter, SenderAdapter},
+        channel::{limited_by, ReceiverAdapter, SenderAdapter},
     },
     Bufferable,
 };
 
 pub struct MemoryBuffer {
-    capacity: NonZeroUsize,
+    max_events: Option<NonZeroUsize>,
+    max_size: Option<NonZeroUsize>,
 }
 
 impl MemoryBuffer {
     pub fn new(capacity: NonZeroUsize) -> Self {
-        MemoryBuffer { capacity }
+        MemoryBuffer {
+            max_events: Some(capacity),
+            max_size: None,
+        }
+    }
+
+    /// Creates a memory buffer limited by the number of events it holds, their total size in
+    /// bytes, or both.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if neither limit is given.
+    pub fn with_limits(max_events: Option<NonZeroUsize>, max_size: Option<NonZeroUsize>) -> Self {
+        assert!(
+            max_events.is_some() || max_size.is_some(),
+            "memory buffer must be limited in events, in bytes, or both"
+        );
+        MemoryBuffer {
+            max_events,
+            max_size,
+        }
     }
 }
 
@@ -30,9 +51,13 @@ where
         self: Box<Self>,
         usage_handle: BufferUsageHandle,
     ) -> Result<(SenderAdapter<T>, ReceiverAdapter<T>), Box<dyn Error + Send + Sync>> {
-        usage_handle.set_buffer_limits(None, Some(self.capacity.get()));
+        usage_handle.set_buffer_limits(
+            self.max_size
+                .map(|max_size| u64::try_from(max_size.get()).unwrap_or(u64::MAX)),
+            self.max_events.map(NonZeroUsize::get),
+        );
 
-        let (tx, rx) = limited(self.capacity.get());
+        let (tx, rx) = limited_by(self.max_events, self.max_size);
         Ok((tx.into(), rx.into()))
     }
 }
@@ -3,7 +3,14 @@
 //! as it flows through transforms, being duplicated and merged, and
 //! then report its status when the last copy is delivered or dropped.
 
-use std::{cmp, future::Future, mem, pin::Pin, sync::Arc, task::Poll};
+use std::{
+    cmp,
+    future::Future,
+    mem,
+    pin::Pin,
+    sync::{Arc, Mutex, PoisonError},
+    task::Poll,
+};
 
 use crossbeam_utils::atomic::AtomicCell;
 use futures::future::FutureExt;
@@ -86,6 +93,15 @@ impl EventFinalizers {
         }
     }
 
+    /// Updates the status of all event finalizers in the collection, and records the error that
+    /// caused it on their underlying batches.
+    pub fn update_status_with_error(&self, status: EventStatus, error: &Arc<DeliveryError>) {
+        for finalizer in &self.0 {
+            finalizer.update_status(status);
+            finalizer.batch.set_error(Arc::clone(error));
+        }
+    }
+
     /// Consumes all event finalizers and updates their underlying batches immediately.
     pub fn update_sources(&mut self) {
         let finalizers = mem::take(&mut self.0);
@@ -160,14 +176,14 @@ impl Drop for EventFinalizer {
 /// A convenience newtype wrapper for the one-shot receiver for an
 /// individual batch status.
 #[pin_project::pin_project]
-pub struct BatchStatusReceiver(oneshot::Receiver<BatchStatus>);
+pub struct BatchStatusReceiver(oneshot::Receiver<(BatchStatus, Option<Arc<DeliveryError>>)>);
 
 impl Future for BatchStatusReceiver {
     type Output = BatchStatus;
     fn poll(mut self: Pin<&mut Self>, ctx: &mut std::task::Context<'_>) -> Poll<Self::Output> {
         match self.0.poll_unpin(ctx) {
             Poll::Pending => Poll::Pending,
-            Poll::Ready(Ok(status)) => Poll::Ready(status),
+            Poll::Ready(Ok((status, _))) => Poll::Ready(status),
             Poll::Ready(Err(error)) => {
                 error!(%error, "Batch status receiver dropped before sending.");
                 Poll::Ready(BatchStatus::Errored)
@@ -184,7 +200,18 @@ impl BatchStatusReceiver {
     /// - `TryRecvError::Empty` if no value has been sent yet.
     /// - `TryRecvError::Closed` if the sender has dropped without sending a value.
     pub fn try_recv(&mut self) -> Result<BatchStatus, oneshot::error::TryRecvError> {
-        self.0.try_recv()
+        self.0.try_recv().map(|(status, _)| status)
+    }
+
+    /// Waits for the status of the batch, along with the last error recorded for its events.
+    pub async fn with_error(self) -> (BatchStatus, Option<Arc<DeliveryError>>) {
+        match self.0.await {
+            Ok(result) => result,
+            Err(error) => {
+                error!(%error, "Batch status receiver dropped before sending.");
+                (BatchStatus::Errored, None)
+            }
+        }
     }
 }
 
@@ -201,6 +228,7 @@ impl BatchNotifier {
         let (sender, receiver) = oneshot::channel();
         let notifier = OwnedBatchNotifier {
             status: AtomicCell::new(BatchStatus::Delivered),
+            error: Mutex::new(None),
             notifier: Some(sender),
         };
         (Self(Arc::new(notifier)), BatchStatusReceiver(receiver))
@@ -250,13 +278,20 @@ impl BatchNotifier {
                 .unwrap_or_else(|_| unreachable!());
         }
     }
+
+    /// Records the error that caused the delivery of an event of the batch to fail, replacing
+    /// any previously recorded one.
+    fn set_error(&self, error: Arc<DeliveryError>) {
+        *self.0.error.lock().unwrap_or_else(PoisonError::into_inner) = Some(error);
+    }
 }
 
 /// The non-shared data underlying the shared `BatchNotifier`
 #[derive(Debug)]
 pub struct OwnedBatchNotifier {
     status: AtomicCell<BatchStatus>,
-    notifier: Option<oneshot::Sender<BatchStatus>>,
+    error: Mutex<Option<Arc<DeliveryError>>>,
+    notifier: Option<oneshot::Sender<(BatchStatus, Option<Arc<DeliveryError>>)>>,
 }
 
 impl OwnedBatchNotifier {
@@ -264,9 +299,14 @@ impl OwnedBatchNotifier {
     fn send_status(&mut self) {
         if let Some(notifier) = self.notifier.take() {
             let status = self.status.load();
+            let error = self
+                .error
+                .get_mut()
+                .unwrap_or_else(PoisonError::into_inner)
+                .take();
             // Ignore the error case, as it will happen during normal
             // source shutdown and we can't detect that here.
-            _ = notifier.send(status);
+            _ = notifier.send((status, error));
         }
     }
 }
@@ -277,6 +317,16 @@ impl Drop for OwnedBatchNotifier {
     }
 }
 
+/// The error reported by a sink for events it failed to deliver.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeliveryError {
+    /// A description of the error.
+    pub message: String,
+
+    /// The status code returned by the destination, if any.
+    pub status_code: Option<u16>,
+}
+
 /// The status of an individual batch.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[repr(u8)]
@@ -462,6 +512,21 @@ mod tests {
         assert_eq!(receiver2.try_recv(), Ok(BatchStatus::Delivered));
     }
 
+    #[tokio::test]
+    async fn sends_error_with_status() {
+        let (fin, receiver) = make_finalizer();
+        let error = Arc::new(DeliveryError {
+            message: "Request failed.".into(),
+            status_code: Some(400),
+        });
+        fin.update_status_with_error(EventStatus::Rejected, &error);
+        drop(fin);
+        assert_eq!(
+            receiver.with_error().await,
+            (BatchStatus::Rejected, Some(error))
+        );
+    }
+
     #[ignore] // The current implementation does not deduplicate finalizers
     #[test]
     fn clone_and_merge_events() {
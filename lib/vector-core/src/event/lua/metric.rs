@@ -8,7 +8,7 @@ use super::super::{
     Metric, MetricKind, MetricValue, StatisticKind,
 };
 use super::util::{table_to_timestamp, timestamp_to_table};
-use crate::metrics::AgentDDSketch;
+use crate::metrics::{AgentDDSketch, ExponentialBucket, NativeHistogram};
 
 pub struct LuaMetric {
     pub metric: Metric,
@@ -218,6 +218,31 @@ impl IntoLua for LuaMetric {
                         sketch_tbl.raw_set("n", bin_map.counts)?;
                         sketch_tbl
                     }
+                    MetricSketch::NativeHistogram(histogram) => {
+                        let sketch_tbl = lua.create_table()?;
+                        sketch_tbl.raw_set("type", "native_histogram")?;
+                        sketch_tbl.raw_set("schema", histogram.schema)?;
+                        sketch_tbl.raw_set("zero_threshold", histogram.zero_threshold)?;
+                        sketch_tbl.raw_set("zero_count", histogram.zero_count)?;
+                        sketch_tbl.raw_set("count", histogram.count)?;
+                        sketch_tbl.raw_set("sum", histogram.sum)?;
+
+                        let (indexes, counts): (Vec<_>, Vec<_>) = histogram
+                            .positive
+                            .iter()
+                            .map(|bucket| (bucket.index, bucket.count))
+                            .unzip();
+                        sketch_tbl.raw_set("positive_indexes", indexes)?;
+                        sketch_tbl.raw_set("positive_counts", counts)?;
+                        let (indexes, counts): (Vec<_>, Vec<_>) = histogram
+                            .negative
+                            .iter()
+                            .map(|bucket| (bucket.index, bucket.count))
+                            .unzip();
+                        sketch_tbl.raw_set("negative_indexes", indexes)?;
+                        sketch_tbl.raw_set("negative_counts", counts)?;
+                        sketch_tbl
+                    }
                 };
 
                 tbl.raw_set("sketch", sketch_tbl)?;
@@ -318,6 +343,32 @@ impl FromLua for Metric {
                             ),
                         })?
                 }
+                "native_histogram" => {
+                    let buckets = |indexes: Vec<i32>, counts: Vec<f64>| -> Vec<_> {
+                        indexes
+                            .into_iter()
+                            .zip(counts)
+                            .map(|(index, count)| ExponentialBucket { index, count })
+                            .collect()
+                    };
+                    MetricValue::Sketch {
+                        sketch: MetricSketch::NativeHistogram(NativeHistogram {
+                            schema: sketch.raw_get("schema")?,
+                            zero_threshold: sketch.raw_get("zero_threshold")?,
+                            zero_count: sketch.raw_get("zero_count")?,
+                            count: sketch.raw_get("count")?,
+                            sum: sketch.raw_get("sum")?,
+                            positive: buckets(
+                                sketch.raw_get("positive_indexes")?,
+                                sketch.raw_get("positive_counts")?,
+                            ),
+                            negative: buckets(
+                                sketch.raw_get("negative_indexes")?,
+                                sketch.raw_get("negative_counts")?,
+                            ),
+                        }),
+                    }
+                }
                 x => {
                     return Err(LuaError::FromLuaConversionError {
                         from: value.type_name(),
@@ -2,6 +2,7 @@
 
 use std::{borrow::Cow, collections::BTreeMap, fmt, sync::Arc};
 
+use chrono::{DateTime, Utc};
 use derivative::Derivative;
 use lookup::OwnedTargetPath;
 use serde::{Deserialize, Serialize};
@@ -78,6 +79,42 @@ pub(super) struct Inner {
     /// An internal vector id that can be used to identify this event across all components.
     #[derivative(PartialEq = "ignore")]
     pub(crate) source_event_id: Option<Uuid>,
+
+    /// The position of the event in the Kafka partition it was consumed from. Only the `kafka`
+    /// source sets this field, so that sinks producing to Kafka in transactions can commit the
+    /// consumer offset along with the event.
+    #[serde(default)]
+    pub(crate) kafka_offset: Option<KafkaOffset>,
+
+    /// Exemplars of the observations counted in a metric, linking it to the traces they were
+    /// recorded in. This is always empty for log and trace events.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) exemplars: Vec<MetricExemplar>,
+}
+
+/// An exemplar of a metric: a single observation, along with labels such as the ID of the trace
+/// it was recorded in.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct MetricExemplar {
+    /// The labels of the exemplar, such as `trace_id`.
+    pub labels: BTreeMap<String, String>,
+    /// The observed value.
+    pub value: f64,
+    /// The time of the observation.
+    pub timestamp: Option<DateTime<Utc>>,
+}
+
+/// The position of an event in the Kafka partition it was consumed from.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct KafkaOffset {
+    /// The ID of the `kafka` source that consumed the event.
+    pub source_id: Arc<str>,
+    /// The topic the event was consumed from.
+    pub topic: Arc<str>,
+    /// The partition the event was consumed from.
+    pub partition: i32,
+    /// The offset of the message the event was decoded from.
+    pub offset: i64,
 }
 
 /// Metric Origin metadata for submission to Datadog.
@@ -239,6 +276,26 @@ impl EventMetadata {
     pub fn source_event_id(&self) -> Option<Uuid> {
         self.0.source_event_id
     }
+
+    /// Returns a reference to the `KafkaOffset`.
+    pub fn kafka_offset(&self) -> Option<&KafkaOffset> {
+        self.0.kafka_offset.as_ref()
+    }
+
+    /// Sets the `kafka_offset` in the metadata to the provided value.
+    pub fn set_kafka_offset(&mut self, kafka_offset: KafkaOffset) {
+        self.get_mut().kafka_offset = Some(kafka_offset);
+    }
+
+    /// Returns the exemplars of the metric.
+    pub fn exemplars(&self) -> &[MetricExemplar] {
+        &self.0.exemplars
+    }
+
+    /// Sets the exemplars of the metric.
+    pub fn set_exemplars(&mut self, exemplars: Vec<MetricExemplar>) {
+        self.get_mut().exemplars = exemplars;
+    }
 }
 
 impl Default for Inner {
@@ -254,6 +311,8 @@ impl Default for Inner {
             dropped_fields: ObjectMap::new(),
             datadog_origin_metadata: None,
             source_event_id: Some(Uuid::now_v7()),
+            kafka_offset: None,
+            exemplars: Vec::new(),
         }
     }
 }
@@ -331,6 +390,13 @@ impl EventMetadata {
         self
     }
 
+    /// Replaces the existing exemplars with the given ones.
+    #[must_use]
+    pub fn with_exemplars(mut self, exemplars: Vec<MetricExemplar>) -> Self {
+        self.get_mut().exemplars = exemplars;
+        self
+    }
+
     /// Replaces the existing `source_event_id` with the given one.
     #[must_use]
     pub fn with_source_event_id(mut self, source_event_id: Option<Uuid>) -> Self {
@@ -341,11 +407,15 @@ impl EventMetadata {
     /// Merge the other `EventMetadata` into this.
     /// If a Datadog API key is not set in `self`, the one from `other` will be used.
     /// If a Splunk HEC token is not set in `self`, the one from `other` will be used.
+    /// The exemplars of `other`, if any, replace the ones of `self` as they are more recent.
     pub fn merge(&mut self, other: Self) {
         let inner = self.get_mut();
         let other = other.into_owned();
         inner.finalizers.merge(other.finalizers);
         inner.secrets.merge(other.secrets);
+        if !other.exemplars.is_empty() {
+            inner.exemplars = other.exemplars;
+        }
 
         // Update `source_event_id` if necessary.
         match (inner.source_event_id, other.source_event_id) {
@@ -556,6 +626,22 @@ mod test {
         assert_eq!(a.get("key-c").unwrap().as_ref(), "value-c2");
     }
 
+    #[test]
+    fn metadata_kafka_offset_round_trips_through_proto() {
+        let kafka_offset = KafkaOffset {
+            source_id: "in".into(),
+            topic: "logs".into(),
+            partition: 3,
+            offset: 42,
+        };
+        let mut metadata = EventMetadata::default();
+        metadata.set_kafka_offset(kafka_offset.clone());
+
+        let proto = crate::event::proto::Metadata::from(metadata);
+        let metadata = EventMetadata::from(proto);
+        assert_eq!(metadata.kafka_offset(), Some(&kafka_offset));
+    }
+
     #[test]
     fn metadata_source_event_id_merging() {
         let m1 = EventMetadata::default();
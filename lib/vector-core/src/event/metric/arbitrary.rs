@@ -1,8 +1,8 @@
-use proptest::collection::{btree_set, hash_map, hash_set};
+use proptest::collection::{btree_map, btree_set, hash_map, hash_set};
 use proptest::option;
 use proptest::prelude::*;
 
-use crate::metrics::AgentDDSketch;
+use crate::metrics::{AgentDDSketch, ExponentialBucket, NativeHistogram};
 
 use super::{
     samples_to_buckets, Bucket, MetricSketch, MetricTags, MetricValue, Quantile, Sample,
@@ -69,7 +69,10 @@ impl Arbitrary for MetricSketch {
     type Strategy = BoxedStrategy<MetricSketch>;
 
     fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
-        let strategy = prop_oneof![any::<AgentDDSketch>().prop_map(MetricSketch::AgentDDSketch),];
+        let strategy = prop_oneof![
+            any::<AgentDDSketch>().prop_map(MetricSketch::AgentDDSketch),
+            any::<NativeHistogram>().prop_map(MetricSketch::NativeHistogram),
+        ];
         strategy.boxed()
     }
 }
@@ -134,6 +137,51 @@ impl Arbitrary for AgentDDSketch {
     }
 }
 
+impl Arbitrary for NativeHistogram {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<NativeHistogram>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        let buckets = || {
+            btree_map(-64..64_i32, 1..1000_u32, 0..16).prop_map(|buckets| {
+                buckets
+                    .into_iter()
+                    .map(|(index, count)| ExponentialBucket {
+                        index,
+                        count: f64::from(count),
+                    })
+                    .collect::<Vec<_>>()
+            })
+        };
+        (
+            -4..=8_i32,
+            0..1000_u32,
+            realistic_float(),
+            buckets(),
+            buckets(),
+        )
+            .prop_map(|(schema, zero_count, sum, positive, negative)| {
+                let zero_count = f64::from(zero_count);
+                let count = zero_count
+                    + positive
+                        .iter()
+                        .chain(&negative)
+                        .map(|bucket| bucket.count)
+                        .sum::<f64>();
+                NativeHistogram {
+                    schema,
+                    zero_threshold: 1e-128,
+                    zero_count,
+                    count,
+                    sum,
+                    positive,
+                    negative,
+                }
+            })
+            .boxed()
+    }
+}
+
 impl Arbitrary for TagValue {
     type Parameters = ();
     type Strategy = BoxedStrategy<TagValue>;
@@ -6,7 +6,10 @@ use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use vector_common::byte_size_of::ByteSizeOf;
 use vector_config::configurable_component;
 
-use crate::{float_eq, metrics::AgentDDSketch};
+use crate::{
+    float_eq,
+    metrics::{AgentDDSketch, NativeHistogram},
+};
 
 use super::{samples_to_buckets, write_list, write_word};
 
@@ -200,6 +203,9 @@ impl MetricValue {
                 MetricSketch::AgentDDSketch(ddsketch) => {
                     ddsketch.clear();
                 }
+                MetricSketch::NativeHistogram(histogram) => {
+                    histogram.clear();
+                }
             },
         }
     }
@@ -264,6 +270,11 @@ impl MetricValue {
                         MetricSketch::AgentDDSketch(ddsketch),
                         MetricSketch::AgentDDSketch(ddsketch2),
                     ) => ddsketch.merge(ddsketch2).is_ok(),
+                    (
+                        MetricSketch::NativeHistogram(histogram),
+                        MetricSketch::NativeHistogram(histogram2),
+                    ) => histogram.merge(histogram2),
+                    _ => false,
                 }
             }
             _ => false,
@@ -356,6 +367,14 @@ impl MetricValue {
                 *sum -= sum2;
                 true
             }
+            (
+                Self::Sketch {
+                    sketch: MetricSketch::NativeHistogram(histogram),
+                },
+                Self::Sketch {
+                    sketch: MetricSketch::NativeHistogram(histogram2),
+                },
+            ) => histogram.subtract(histogram2),
             _ => false,
         }
     }
@@ -497,6 +516,16 @@ impl fmt::Display for MetricValue {
                             )
                         })
                     }
+                    MetricSketch::NativeHistogram(histogram) => {
+                        write!(
+                            fmt,
+                            "count={} sum={} schema={} ",
+                            histogram.count, histogram.sum, histogram.schema
+                        )?;
+                        write_list(fmt, " ", histogram.to_buckets(), |fmt, bucket| {
+                            write!(fmt, "{}@{}", bucket.count, bucket.upper_limit)
+                        })
+                    }
                 }
             }
         }
@@ -511,6 +540,14 @@ impl From<AgentDDSketch> for MetricValue {
     }
 }
 
+impl From<NativeHistogram> for MetricValue {
+    fn from(histogram: NativeHistogram) -> Self {
+        MetricValue::Sketch {
+            sketch: MetricSketch::NativeHistogram(histogram),
+        }
+    }
+}
+
 // Currently, VRL can only read the type of the value and doesn't consider any actual metric values.
 #[cfg(feature = "vrl")]
 impl From<MetricValue> for vrl::value::Value {
@@ -545,6 +582,11 @@ pub enum MetricSketch {
     /// [ddsketch]: https://www.vldb.org/pvldb/vol12/p2195-masson.pdf
     /// [ddagent]: https://github.com/DataDog/datadog-agent
     AgentDDSketch(AgentDDSketch),
+
+    /// [Native histogram][native_histograms] from Prometheus, with exponential buckets.
+    ///
+    /// [native_histograms]: https://prometheus.io/docs/specs/native_histograms/
+    NativeHistogram(NativeHistogram),
 }
 
 impl MetricSketch {
@@ -552,6 +594,7 @@ impl MetricSketch {
     pub fn is_empty(&self) -> bool {
         match self {
             MetricSketch::AgentDDSketch(ddsketch) => ddsketch.is_empty(),
+            MetricSketch::NativeHistogram(histogram) => histogram.is_empty(),
         }
     }
 
@@ -561,6 +604,7 @@ impl MetricSketch {
     pub fn as_name(&self) -> &'static str {
         match self {
             Self::AgentDDSketch(_) => "agent dd sketch",
+            Self::NativeHistogram(_) => "native histogram",
         }
     }
 }
@@ -569,6 +613,7 @@ impl ByteSizeOf for MetricSketch {
     fn allocated_bytes(&self) -> usize {
         match self {
             Self::AgentDDSketch(ddsketch) => ddsketch.allocated_bytes(),
+            Self::NativeHistogram(histogram) => histogram.allocated_bytes(),
         }
     }
 }
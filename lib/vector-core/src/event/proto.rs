@@ -6,7 +6,10 @@ use ordered_float::NotNan;
 use uuid::Uuid;
 
 use super::{MetricTags, WithMetadata};
-use crate::{event, metrics::AgentDDSketch};
+use crate::{
+    event,
+    metrics::{AgentDDSketch, ExponentialBucket, NativeHistogram},
+};
 
 #[allow(warnings, clippy::all, clippy::pedantic)]
 mod proto_event {
@@ -198,6 +201,9 @@ impl From<MetricValue> for super::MetricValue {
                 sketch::Sketch::AgentDdSketch(ddsketch) => Self::Sketch {
                     sketch: ddsketch.into(),
                 },
+                sketch::Sketch::NativeHistogram(histogram) => Self::Sketch {
+                    sketch: histogram.into(),
+                },
             },
         }
     }
@@ -410,6 +416,9 @@ impl From<super::MetricValue> for MetricValue {
                         })),
                     })
                 }
+                MetricSketch::NativeHistogram(histogram) => Self::Sketch(Sketch {
+                    sketch: Some(sketch::Sketch::NativeHistogram(histogram.into())),
+                }),
             },
         }
     }
@@ -562,6 +571,102 @@ impl From<sketch::AgentDdSketch> for MetricSketch {
     }
 }
 
+impl From<NativeHistogram> for sketch::NativeHistogram {
+    fn from(histogram: NativeHistogram) -> Self {
+        let (positive_indexes, positive_counts) = unzip_buckets(histogram.positive);
+        let (negative_indexes, negative_counts) = unzip_buckets(histogram.negative);
+        Self {
+            schema: histogram.schema,
+            zero_threshold: histogram.zero_threshold,
+            zero_count: histogram.zero_count,
+            count: histogram.count,
+            sum: histogram.sum,
+            positive_indexes,
+            positive_counts,
+            negative_indexes,
+            negative_counts,
+        }
+    }
+}
+
+impl From<sketch::NativeHistogram> for MetricSketch {
+    fn from(histogram: sketch::NativeHistogram) -> Self {
+        MetricSketch::NativeHistogram(NativeHistogram {
+            schema: histogram.schema,
+            zero_threshold: histogram.zero_threshold,
+            zero_count: histogram.zero_count,
+            count: histogram.count,
+            sum: histogram.sum,
+            positive: zip_buckets(histogram.positive_indexes, histogram.positive_counts),
+            negative: zip_buckets(histogram.negative_indexes, histogram.negative_counts),
+        })
+    }
+}
+
+fn unzip_buckets(buckets: Vec<ExponentialBucket>) -> (Vec<i32>, Vec<f64>) {
+    buckets
+        .into_iter()
+        .map(|bucket| (bucket.index, bucket.count))
+        .unzip()
+}
+
+fn zip_buckets(indexes: Vec<i32>, counts: Vec<f64>) -> Vec<ExponentialBucket> {
+    indexes
+        .into_iter()
+        .zip(counts)
+        .map(|(index, count)| ExponentialBucket { index, count })
+        .collect()
+}
+
+impl From<super::metadata::MetricExemplar> for MetricExemplar {
+    fn from(exemplar: super::metadata::MetricExemplar) -> Self {
+        Self {
+            labels: exemplar.labels,
+            value: exemplar.value,
+            timestamp: exemplar.timestamp.map(|ts| prost_types::Timestamp {
+                seconds: ts.timestamp(),
+                nanos: ts.timestamp_subsec_nanos() as i32,
+            }),
+        }
+    }
+}
+
+impl From<MetricExemplar> for super::metadata::MetricExemplar {
+    fn from(exemplar: MetricExemplar) -> Self {
+        Self {
+            labels: exemplar.labels,
+            value: exemplar.value,
+            timestamp: exemplar.timestamp.and_then(|ts| {
+                chrono::Utc
+                    .timestamp_opt(ts.seconds, ts.nanos as u32)
+                    .single()
+            }),
+        }
+    }
+}
+
+impl From<super::KafkaOffset> for KafkaOffset {
+    fn from(value: super::KafkaOffset) -> Self {
+        Self {
+            source_id: value.source_id.to_string(),
+            topic: value.topic.to_string(),
+            partition: value.partition,
+            offset: value.offset,
+        }
+    }
+}
+
+impl From<KafkaOffset> for super::KafkaOffset {
+    fn from(value: KafkaOffset) -> Self {
+        Self {
+            source_id: value.source_id.into(),
+            topic: value.topic.into(),
+            partition: value.partition,
+            offset: value.offset,
+        }
+    }
+}
+
 impl From<super::metadata::Secrets> for Secrets {
     fn from(value: super::metadata::Secrets) -> Self {
         Self {
@@ -626,6 +731,8 @@ impl From<EventMetadata> for Metadata {
             upstream_id,
             datadog_origin_metadata,
             source_event_id,
+            kafka_offset,
+            exemplars,
             ..
         } = value.into_owned();
 
@@ -639,6 +746,8 @@ impl From<EventMetadata> for Metadata {
             upstream_id: upstream_id.map(|id| id.as_ref().clone()).map(Into::into),
             secrets,
             source_event_id: source_event_id.map_or(vec![], std::convert::Into::into),
+            exemplars: exemplars.into_iter().map(Into::into).collect(),
+            kafka_offset: kafka_offset.map(Into::into),
         }
     }
 }
@@ -688,6 +797,14 @@ impl From<Metadata> for EventMetadata {
         };
         metadata = metadata.with_source_event_id(maybe_source_event_id);
 
+        if !value.exemplars.is_empty() {
+            metadata.set_exemplars(value.exemplars.into_iter().map(Into::into).collect());
+        }
+
+        if let Some(kafka_offset) = value.kafka_offset {
+            metadata.set_kafka_offset(kafka_offset.into());
+        }
+
         metadata
     }
 }
@@ -11,7 +11,10 @@ use vector_common::byte_size_of::ByteSizeOf;
 use vector_config::configurable_component;
 
 use crate::{
-    event::{metric::Bucket, Metric, MetricValue},
+    event::{
+        metric::{Bucket, MetricSketch},
+        Metric, MetricValue,
+    },
     float_eq,
 };
 
@@ -774,7 +777,7 @@ impl AgentDDSketch {
     /// free function on `AgentDDSketch` itself.
     ///
     /// If the metric value cannot be represented as a sketch -- essentially, everything that isn't
-    /// a distribution or aggregated histogram -- then the metric is passed back unmodified.  All
+    /// a distribution, aggregated histogram or native histogram -- then the metric is passed back unmodified.  All
     /// existing metadata -- series name, tags, timestamp, etc -- is left unmodified, even if the
     /// metric is converted to a sketch internally.
     ///
@@ -796,6 +799,13 @@ impl AgentDDSketch {
                 sketch.insert_interpolate_buckets(delta_buckets)?;
                 Some(sketch)
             }
+            MetricValue::Sketch {
+                sketch: MetricSketch::NativeHistogram(histogram),
+            } => {
+                let mut sketch = AgentDDSketch::with_agent_defaults();
+                sketch.insert_interpolate_buckets(histogram.to_buckets())?;
+                Some(sketch)
+            }
             // We can't convert from any other metric value.
             _ => None,
         };
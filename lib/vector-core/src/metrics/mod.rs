@@ -1,5 +1,6 @@
 mod ddsketch;
 mod label_filter;
+mod native_histogram;
 mod recency;
 mod recorder;
 mod storage;
@@ -13,6 +14,7 @@ use metrics_util::layers::Layer;
 use snafu::Snafu;
 
 pub use self::ddsketch::{AgentDDSketch, BinMap, Config};
+pub use self::native_histogram::{ExponentialBucket, NativeHistogram};
 use self::{label_filter::VectorLabelFilter, recorder::Registry, recorder::VectorRecorder};
 use crate::event::{Metric, MetricValue};
 
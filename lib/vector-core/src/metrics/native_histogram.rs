@@ -0,0 +1,392 @@
+use std::{cmp::Ordering, mem};
+
+use vector_common::byte_size_of::ByteSizeOf;
+use vector_config::configurable_component;
+
+use crate::{event::metric::Bucket, float_eq};
+
+/// A [native histogram][native_histograms] from Prometheus, also known as a sparse histogram.
+///
+/// Observations are counted into exponential buckets: with a given `schema`, the positive bucket at
+/// index `i` counts the observations in `(base^(i-1), base^i]`, where `base` is `2^(2^-schema)`,
+/// and the negative bucket at index `i` the observations in `[-base^i, -base^(i-1))`. Observations
+/// whose absolute value is at most `zero_threshold` are counted in the zero bucket. Only the
+/// buckets that have observations are stored.
+///
+/// [native_histograms]: https://prometheus.io/docs/specs/native_histograms/
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub struct NativeHistogram {
+    /// The resolution of the buckets, from -4 to 8.
+    pub schema: i32,
+
+    /// The width of the zero bucket.
+    pub zero_threshold: f64,
+
+    /// The number of observations in the zero bucket.
+    pub zero_count: f64,
+
+    /// The number of observations in the histogram.
+    pub count: f64,
+
+    /// The sum of all observations in the histogram.
+    pub sum: f64,
+
+    /// The buckets of positive observations, in increasing order of index.
+    pub positive: Vec<ExponentialBucket>,
+
+    /// The buckets of negative observations, in increasing order of index.
+    pub negative: Vec<ExponentialBucket>,
+}
+
+/// A bucket of a native histogram.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ExponentialBucket {
+    /// The index of the bucket.
+    pub index: i32,
+
+    /// The number of observations in the bucket.
+    pub count: f64,
+}
+
+impl NativeHistogram {
+    /// Returns `true` if the histogram has no observations.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0.0
+    }
+
+    /// Removes all observations from the histogram, keeping its schema and zero bucket width.
+    pub fn clear(&mut self) {
+        self.zero_count = 0.0;
+        self.count = 0.0;
+        self.sum = 0.0;
+        self.positive.clear();
+        self.negative.clear();
+    }
+
+    /// Builds the buckets from the spans of consecutive buckets used by the Prometheus protocols,
+    /// where each span is an offset from the end of the previous span (or from zero for the first
+    /// one) and a number of buckets.
+    pub fn buckets_from_spans(
+        spans: impl IntoIterator<Item = (i32, u32)>,
+        counts: impl IntoIterator<Item = f64>,
+    ) -> Vec<ExponentialBucket> {
+        let mut counts = counts.into_iter();
+        let mut buckets = Vec::new();
+        let mut index = 0;
+        for (offset, length) in spans {
+            index += offset;
+            for _ in 0..length {
+                let Some(count) = counts.next() else {
+                    return buckets;
+                };
+                if count != 0.0 {
+                    buckets.push(ExponentialBucket { index, count });
+                }
+                index += 1;
+            }
+        }
+        buckets
+    }
+
+    /// Splits the buckets into the spans of consecutive buckets used by the Prometheus protocols,
+    /// and their counts. This is the reverse of [`NativeHistogram::buckets_from_spans`].
+    pub fn buckets_to_spans(buckets: &[ExponentialBucket]) -> (Vec<(i32, u32)>, Vec<f64>) {
+        let mut spans: Vec<(i32, u32)> = Vec::new();
+        let mut next_index = 0;
+        for bucket in buckets {
+            match spans.last_mut() {
+                Some((_, length)) if bucket.index == next_index => *length += 1,
+                _ => spans.push((bucket.index - next_index, 1)),
+            }
+            next_index = bucket.index + 1;
+        }
+        let counts = buckets.iter().map(|bucket| bucket.count).collect();
+        (spans, counts)
+    }
+
+    /// Adds the observations of another histogram to this one.
+    ///
+    /// The buckets of the histogram with the highest resolution are merged to match the schema of
+    /// the other one. If the zero buckets of the histograms have different widths, then `false` is
+    /// returned and the histogram is left untouched. Otherwise, `true` is returned.
+    #[must_use]
+    pub fn merge(&mut self, other: &NativeHistogram) -> bool {
+        if !float_eq(self.zero_threshold, other.zero_threshold) {
+            return false;
+        }
+
+        let schema = self.schema.min(other.schema);
+        self.reduce_resolution(schema);
+        let mut other_positive = other.positive.clone();
+        let mut other_negative = other.negative.clone();
+        reduce_buckets(&mut other_positive, other.schema - schema);
+        reduce_buckets(&mut other_negative, other.schema - schema);
+
+        self.zero_count += other.zero_count;
+        self.count += other.count;
+        self.sum += other.sum;
+        self.positive = add_buckets(&self.positive, &other_positive);
+        self.negative = add_buckets(&self.negative, &other_negative);
+        true
+    }
+
+    /// Removes the observations of another histogram from this one.
+    ///
+    /// If the histograms have different schemas or zero buckets, or if the other histogram has
+    /// observations that this one doesn't have, then `false` is returned and the histogram is left
+    /// untouched. Otherwise, `true` is returned.
+    #[must_use]
+    pub fn subtract(&mut self, other: &NativeHistogram) -> bool {
+        if self.schema != other.schema
+            || !float_eq(self.zero_threshold, other.zero_threshold)
+            || self.count < other.count
+            || self.zero_count < other.zero_count
+        {
+            return false;
+        }
+
+        let (Some(positive), Some(negative)) = (
+            subtract_buckets(&self.positive, &other.positive),
+            subtract_buckets(&self.negative, &other.negative),
+        ) else {
+            return false;
+        };
+
+        self.zero_count -= other.zero_count;
+        self.count -= other.count;
+        self.sum -= other.sum;
+        self.positive = positive;
+        self.negative = negative;
+        true
+    }
+
+    /// Converts the buckets to explicit buckets, in increasing order of upper limit.
+    ///
+    /// Bucket counts are rounded to the nearest integer.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn to_buckets(&self) -> Vec<Bucket> {
+        let mut buckets = Vec::with_capacity(self.negative.len() + self.positive.len() + 1);
+        buckets.extend(self.negative.iter().rev().map(|bucket| Bucket {
+            upper_limit: -self.bucket_bound(bucket.index - 1),
+            count: bucket.count.round() as u64,
+        }));
+        if self.zero_count > 0.0 {
+            buckets.push(Bucket {
+                upper_limit: self.zero_threshold,
+                count: self.zero_count.round() as u64,
+            });
+        }
+        buckets.extend(self.positive.iter().map(|bucket| Bucket {
+            upper_limit: self.bucket_bound(bucket.index),
+            count: bucket.count.round() as u64,
+        }));
+        buckets
+    }
+
+    /// Returns the absolute upper bound of the bucket at the given index.
+    fn bucket_bound(&self, index: i32) -> f64 {
+        (f64::from(index) * (-f64::from(self.schema)).exp2()).exp2()
+    }
+
+    fn reduce_resolution(&mut self, schema: i32) {
+        if schema < self.schema {
+            reduce_buckets(&mut self.positive, self.schema - schema);
+            reduce_buckets(&mut self.negative, self.schema - schema);
+            self.schema = schema;
+        }
+    }
+}
+
+/// Merges each group of `2^delta` consecutive buckets into one, lowering the schema by `delta`.
+fn reduce_buckets(buckets: &mut Vec<ExponentialBucket>, delta: i32) {
+    if delta <= 0 {
+        return;
+    }
+    let mut reduced: Vec<ExponentialBucket> = Vec::with_capacity(buckets.len());
+    for bucket in buckets.drain(..) {
+        let index = ((bucket.index - 1) >> delta) + 1;
+        match reduced.last_mut() {
+            Some(last) if last.index == index => last.count += bucket.count,
+            _ => reduced.push(ExponentialBucket {
+                index,
+                count: bucket.count,
+            }),
+        }
+    }
+    *buckets = reduced;
+}
+
+fn add_buckets(lhs: &[ExponentialBucket], rhs: &[ExponentialBucket]) -> Vec<ExponentialBucket> {
+    let mut result = Vec::with_capacity(lhs.len().max(rhs.len()));
+    let (mut lhs, mut rhs) = (lhs.iter().peekable(), rhs.iter().peekable());
+    loop {
+        let bucket = match (lhs.peek(), rhs.peek()) {
+            (Some(left), Some(right)) => match left.index.cmp(&right.index) {
+                Ordering::Less => lhs.next().copied(),
+                Ordering::Greater => rhs.next().copied(),
+                Ordering::Equal => {
+                    lhs.next()
+                        .zip(rhs.next())
+                        .map(|(left, right)| ExponentialBucket {
+                            index: left.index,
+                            count: left.count + right.count,
+                        })
+                }
+            },
+            (Some(_), None) => lhs.next().copied(),
+            (None, Some(_)) => rhs.next().copied(),
+            (None, None) => None,
+        };
+        match bucket {
+            Some(bucket) => result.push(bucket),
+            None => return result,
+        }
+    }
+}
+
+fn subtract_buckets(
+    lhs: &[ExponentialBucket],
+    rhs: &[ExponentialBucket],
+) -> Option<Vec<ExponentialBucket>> {
+    let mut result = Vec::with_capacity(lhs.len());
+    let mut rhs = rhs.iter().peekable();
+    for left in lhs {
+        let mut count = left.count;
+        if let Some(right) = rhs.next_if(|right| right.index == left.index) {
+            if right.count > left.count {
+                return None;
+            }
+            count -= right.count;
+        }
+        if count != 0.0 {
+            result.push(ExponentialBucket {
+                index: left.index,
+                count,
+            });
+        }
+    }
+    // Every bucket of `rhs` must have been matched with a bucket of `lhs`.
+    rhs.peek().is_none().then_some(result)
+}
+
+impl PartialEq for NativeHistogram {
+    fn eq(&self, other: &Self) -> bool {
+        self.schema == other.schema
+            && float_eq(self.zero_threshold, other.zero_threshold)
+            && float_eq(self.zero_count, other.zero_count)
+            && float_eq(self.count, other.count)
+            && float_eq(self.sum, other.sum)
+            && self.positive == other.positive
+            && self.negative == other.negative
+    }
+}
+
+impl Eq for NativeHistogram {}
+
+impl ByteSizeOf for NativeHistogram {
+    fn allocated_bytes(&self) -> usize {
+        (self.positive.len() + self.negative.len()) * mem::size_of::<ExponentialBucket>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bucket(index: i32, count: f64) -> ExponentialBucket {
+        ExponentialBucket { index, count }
+    }
+
+    fn histogram(schema: i32, positive: Vec<ExponentialBucket>) -> NativeHistogram {
+        let count = positive.iter().map(|bucket| bucket.count).sum();
+        NativeHistogram {
+            schema,
+            zero_threshold: 0.001,
+            zero_count: 0.0,
+            count,
+            sum: 10.0,
+            positive,
+            negative: vec![],
+        }
+    }
+
+    #[test]
+    fn spans_round_trip() {
+        let buckets = vec![
+            bucket(-2, 1.0),
+            bucket(-1, 2.0),
+            bucket(3, 3.0),
+            bucket(4, 1.0),
+        ];
+        let (spans, counts) = NativeHistogram::buckets_to_spans(&buckets);
+        assert_eq!(spans, vec![(-2, 2), (3, 2)]);
+        assert_eq!(counts, vec![1.0, 2.0, 3.0, 1.0]);
+        assert_eq!(NativeHistogram::buckets_from_spans(spans, counts), buckets);
+    }
+
+    #[test]
+    fn to_buckets() {
+        let mut histogram = histogram(0, vec![bucket(0, 1.0), bucket(2, 3.0)]);
+        histogram.zero_count = 2.0;
+        histogram.negative = vec![bucket(1, 4.0), bucket(2, 5.0)];
+        assert_eq!(
+            histogram.to_buckets(),
+            vec![
+                Bucket {
+                    upper_limit: -2.0,
+                    count: 5
+                },
+                Bucket {
+                    upper_limit: -1.0,
+                    count: 4
+                },
+                Bucket {
+                    upper_limit: 0.001,
+                    count: 2
+                },
+                Bucket {
+                    upper_limit: 1.0,
+                    count: 1
+                },
+                Bucket {
+                    upper_limit: 4.0,
+                    count: 3
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn merge_reduces_resolution() {
+        let mut a = histogram(0, vec![bucket(1, 1.0), bucket(3, 1.0)]);
+        let b = histogram(1, vec![bucket(1, 1.0), bucket(2, 1.0), bucket(5, 2.0)]);
+        assert!(a.merge(&b));
+        assert_eq!(a.schema, 0);
+        assert_eq!(a.positive, vec![bucket(1, 3.0), bucket(3, 3.0)]);
+        assert_eq!(a.count, 6.0);
+        assert_eq!(a.sum, 20.0);
+    }
+
+    #[test]
+    fn merge_rejects_different_zero_buckets() {
+        let mut a = histogram(0, vec![bucket(1, 1.0)]);
+        let mut b = a.clone();
+        b.zero_threshold = 0.1;
+        assert!(!a.merge(&b));
+        assert_eq!(a, histogram(0, vec![bucket(1, 1.0)]));
+    }
+
+    #[test]
+    fn subtract() {
+        let mut a = histogram(2, vec![bucket(1, 3.0), bucket(2, 1.0)]);
+        let b = histogram(2, vec![bucket(2, 1.0)]);
+        assert!(a.subtract(&b));
+        assert_eq!(a.positive, vec![bucket(1, 3.0)]);
+        assert_eq!(a.count, 3.0);
+
+        let c = histogram(2, vec![bucket(4, 1.0)]);
+        assert!(!a.subtract(&c));
+    }
+}
@@ -1,4 +1,4 @@
-use std::{collections::VecDeque, fmt, future::poll_fn, task::Poll};
+use std::{collections::VecDeque, fmt, future::poll_fn, sync::Arc, task::Poll};
 
 use futures::{poll, FutureExt, Stream, StreamExt, TryFutureExt};
 use tokio::{pin, select};
@@ -10,7 +10,7 @@ use vector_common::internal_event::{
     RegisteredEventCache, SharedString, TaggedEventsSent,
 };
 use vector_common::request_metadata::{GroupedCountByteSize, MetaDescriptive};
-use vector_core::event::{EventFinalizers, EventStatus, Finalizable};
+use vector_core::event::{DeliveryError, EventFinalizers, EventStatus, Finalizable};
 
 use super::FuturesUnorderedCount;
 
@@ -24,6 +24,12 @@ pub trait DriverResponse {
     fn bytes_sent(&self) -> Option<usize> {
         None
     }
+
+    /// Return the error reported by the destination when the events of the request were not
+    /// delivered.
+    fn delivery_error(&self) -> Option<DeliveryError> {
+        None
+    }
 }
 
 /// Drives the interaction between a stream of items and a service which processes them
@@ -205,12 +211,22 @@ where
     ) {
         match result {
             Err(error) => {
+                let delivery_error = Arc::new(DeliveryError {
+                    message: format!("{error:?}"),
+                    status_code: None,
+                });
                 Self::emit_call_error(Some(error), request_id, event_count);
-                finalizers.update_status(EventStatus::Rejected);
+                finalizers.update_status_with_error(EventStatus::Rejected, &delivery_error);
             }
             Ok(response) => {
                 trace!(message = "Service call succeeded.", request_id);
-                finalizers.update_status(response.event_status());
+                match response.delivery_error() {
+                    Some(error) if response.event_status() != EventStatus::Delivered => {
+                        finalizers
+                            .update_status_with_error(response.event_status(), &Arc::new(error));
+                    }
+                    _ => finalizers.update_status(response.event_status()),
+                }
                 if response.event_status() == EventStatus::Delivered {
                     if let Some(bytes_sent) = bytes_sent {
                         if let Some(byte_size) = response.bytes_sent() {
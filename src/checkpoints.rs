@@ -0,0 +1,355 @@
+#![allow(missing_docs)]
+//! Inspect and edit the checkpoints of the sources reading files.
+//!
+//! The checkpoints are read from and written to the data directory of the
+//! source, so Vector must be stopped while they are edited.
+use std::{
+    fs,
+    io::{self, Write},
+    path::PathBuf,
+};
+
+use chrono::Utc;
+use clap::Parser;
+use vector_lib::file_source::{Checkpoint, Checkpointer, FileFingerprint, Fingerprinter};
+
+use crate::config::{self, ComponentKey, Config};
+
+#[derive(Parser, Debug)]
+#[command(rename_all = "kebab-case")]
+pub struct Opts {
+    /// Read configuration from one or more files. Wildcard paths are supported.
+    /// File format is detected from the file name.
+    /// If zero files are specified the default config path
+    /// `/etc/vector/vector.yaml` will be targeted.
+    #[arg(
+        id = "config",
+        short,
+        long,
+        env = "VECTOR_CONFIG",
+        value_delimiter(',')
+    )]
+    paths: Vec<PathBuf>,
+
+    /// Vector config files in TOML format.
+    #[arg(id = "config-toml", long, value_delimiter(','))]
+    paths_toml: Vec<PathBuf>,
+
+    /// Vector config files in JSON format.
+    #[arg(id = "config-json", long, value_delimiter(','))]
+    paths_json: Vec<PathBuf>,
+
+    /// Vector config files in YAML format.
+    #[arg(id = "config-yaml", long, value_delimiter(','))]
+    paths_yaml: Vec<PathBuf>,
+
+    /// Read configuration from files in one or more directories.
+    /// File format is detected from the file name.
+    ///
+    /// Files not ending in .toml, .json, .yaml, or .yml will be ignored.
+    #[arg(
+        id = "config-dir",
+        short = 'C',
+        long,
+        env = "VECTOR_CONFIG_DIR",
+        value_delimiter(',')
+    )]
+    pub config_dirs: Vec<PathBuf>,
+
+    /// The ID of the `file` or `kubernetes_logs` source whose checkpoints are managed.
+    #[arg(long)]
+    source: String,
+
+    #[command(subcommand)]
+    sub_command: SubCommand,
+}
+
+impl Opts {
+    fn paths_with_formats(&self) -> Vec<config::ConfigPath> {
+        config::merge_path_lists(vec![
+            (&self.paths, None),
+            (&self.paths_toml, Some(config::Format::Toml)),
+            (&self.paths_json, Some(config::Format::Json)),
+            (&self.paths_yaml, Some(config::Format::Yaml)),
+        ])
+        .map(|(path, hint)| config::ConfigPath::File(path, hint))
+        .chain(
+            self.config_dirs
+                .iter()
+                .map(|dir| config::ConfigPath::Dir(dir.to_path_buf())),
+        )
+        .collect()
+    }
+}
+
+#[derive(Parser, Debug)]
+#[command(rename_all = "kebab-case")]
+enum SubCommand {
+    /// List the checkpoints.
+    List,
+
+    /// Show the fingerprint of a file, and its checkpoint.
+    Inspect {
+        /// The path of the file.
+        path: PathBuf,
+    },
+
+    /// Export the checkpoints as JSON.
+    Export {
+        /// The file to write the checkpoints to, instead of stdout.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Import checkpoints exported as JSON, replacing the checkpoints of the same files.
+    Import {
+        /// The file to read the checkpoints from.
+        input: PathBuf,
+
+        /// Remove all the existing checkpoints first.
+        #[arg(long)]
+        replace: bool,
+    },
+
+    /// Set the position the source resumes reading a file from.
+    Set {
+        #[command(flatten)]
+        target: Target,
+
+        /// The position in bytes, of the decompressed data for compressed files.
+        position: u64,
+    },
+
+    /// Remove checkpoints, so that the files are read again according to the
+    /// `read_from` option of the source.
+    Reset {
+        #[command(flatten)]
+        target: Target,
+
+        /// Remove all the checkpoints.
+        #[arg(long, conflicts_with_all = ["fingerprint", "path"])]
+        all: bool,
+    },
+}
+
+#[derive(Parser, Debug)]
+#[command(rename_all = "kebab-case")]
+struct Target {
+    /// The fingerprint of the file, as listed by the `list` command, such as
+    /// `{"first_lines_checksum":1234}`.
+    #[arg(long, conflicts_with = "path")]
+    fingerprint: Option<String>,
+
+    /// The path of the file, which is fingerprinted the way the source does.
+    #[arg(long)]
+    path: Option<PathBuf>,
+}
+
+impl Target {
+    fn fingerprint(&self, fingerprinter: &Fingerprinter) -> Result<FileFingerprint, String> {
+        match (&self.fingerprint, &self.path) {
+            (Some(fingerprint), _) => serde_json::from_str(fingerprint)
+                .map_err(|error| format!("Invalid fingerprint {fingerprint:?}: {error}")),
+            (None, Some(path)) => fingerprinter
+                .get_fingerprint_of_file(path, &mut Vec::new())
+                .map_err(|error| format!("Unable to fingerprint {path:?}: {error}")),
+            (None, None) => Err("Either `--fingerprint` or `--path` is required.".to_owned()),
+        }
+    }
+}
+
+/// The data directory and fingerprinter of a source reading files.
+struct FileSource {
+    data_dir: PathBuf,
+    fingerprinter: Fingerprinter,
+}
+
+impl FileSource {
+    fn from_config(config: &Config, id: &ComponentKey) -> Result<Self, String> {
+        let source = config
+            .source(id)
+            .ok_or_else(|| format!("Source {:?} not found.", id.id()))?;
+
+        // The source configurations are only available as trait objects, so
+        // they're converted back to their concrete type through their
+        // serialized form.
+        let mut value = serde_json::to_value(&source.inner).map_err(|error| error.to_string())?;
+        let component_type = value
+            .as_object_mut()
+            .and_then(|value| value.remove("type"))
+            .and_then(|component_type| component_type.as_str().map(ToOwned::to_owned));
+        let (data_dir, fingerprinter) = match component_type.as_deref() {
+            #[cfg(feature = "sources-file")]
+            Some("file") => {
+                let source: crate::sources::file::FileConfig =
+                    serde_json::from_value(value).map_err(|error| error.to_string())?;
+                (source.data_dir.clone(), source.fingerprinter())
+            }
+            #[cfg(feature = "sources-kubernetes_logs")]
+            Some("kubernetes_logs") => {
+                let source: crate::sources::kubernetes_logs::Config =
+                    serde_json::from_value(value).map_err(|error| error.to_string())?;
+                (source.data_dir().cloned(), source.fingerprinter())
+            }
+            _ => {
+                return Err(format!(
+                    "Source {:?} is not a `file` or `kubernetes_logs` source.",
+                    id.id()
+                ))
+            }
+        };
+
+        let data_dir = config
+            .global
+            .resolve_and_validate_data_dir(data_dir.as_ref())
+            .map_err(|error| error.to_string())?
+            .join(id.id());
+        Ok(Self {
+            data_dir,
+            fingerprinter,
+        })
+    }
+
+    fn read_checkpoints(&self) -> Checkpointer {
+        let mut checkpointer = Checkpointer::new(&self.data_dir);
+        checkpointer.read_checkpoints(None);
+        checkpointer
+    }
+
+    fn write_checkpoints(&self, checkpointer: &Checkpointer) -> Result<(), String> {
+        fs::create_dir_all(&self.data_dir)
+            .and_then(|()| checkpointer.write_checkpoints())
+            .map(|_| ())
+            .map_err(|error| {
+                format!(
+                    "Unable to write checkpoints to {:?}: {error}",
+                    self.data_dir
+                )
+            })
+    }
+}
+
+#[allow(clippy::print_stderr)]
+pub(crate) fn cmd(opts: &Opts) -> exitcode::ExitCode {
+    let paths = opts.paths_with_formats();
+    let paths = match config::process_paths(&paths) {
+        Some(paths) => paths,
+        None => return exitcode::CONFIG,
+    };
+
+    let config = match config::load_from_paths(&paths) {
+        Ok(config) => config,
+        Err(errs) => {
+            for err in errs {
+                eprintln!("{}", err);
+            }
+            return exitcode::CONFIG;
+        }
+    };
+
+    let id = ComponentKey::from(opts.source.as_str());
+    let source = match FileSource::from_config(&config, &id) {
+        Ok(source) => source,
+        Err(error) => {
+            eprintln!("{}", error);
+            return exitcode::CONFIG;
+        }
+    };
+
+    match run(&opts.sub_command, &source) {
+        Ok(()) => exitcode::OK,
+        Err(error) => {
+            eprintln!("{}", error);
+            exitcode::SOFTWARE
+        }
+    }
+}
+
+#[allow(clippy::print_stdout)]
+fn run(sub_command: &SubCommand, source: &FileSource) -> Result<(), String> {
+    let checkpointer = source.read_checkpoints();
+    let checkpoints = checkpointer.view();
+
+    match sub_command {
+        SubCommand::List => {
+            println!("FINGERPRINT\tPOSITION\tMODIFIED");
+            for checkpoint in checkpoints.checkpoints() {
+                println!(
+                    "{}\t{}\t{}",
+                    format_fingerprint(checkpoint.fingerprint),
+                    checkpoint.position,
+                    checkpoint.modified.to_rfc3339()
+                );
+            }
+        }
+        SubCommand::Inspect { path } => {
+            let fingerprint = source
+                .fingerprinter
+                .get_fingerprint_of_file(path, &mut Vec::new())
+                .map_err(|error| format!("Unable to fingerprint {path:?}: {error}"))?;
+            println!("Fingerprint: {}", format_fingerprint(fingerprint));
+            match checkpoints
+                .checkpoints()
+                .into_iter()
+                .find(|checkpoint| checkpoint.fingerprint == fingerprint)
+            {
+                Some(checkpoint) => {
+                    println!("Position: {}", checkpoint.position);
+                    println!("Modified: {}", checkpoint.modified.to_rfc3339());
+                }
+                None => println!("No checkpoint."),
+            }
+        }
+        SubCommand::Export { output } => {
+            let result = match output {
+                Some(output) => fs::File::create(output)
+                    .and_then(|file| checkpointer.export_checkpoints(io::BufWriter::new(file))),
+                None => checkpointer
+                    .export_checkpoints(io::stdout().lock())
+                    .and_then(|count| writeln!(io::stdout()).map(|()| count)),
+            };
+            result.map_err(|error| format!("Unable to export checkpoints: {error}"))?;
+        }
+        SubCommand::Import { input, replace } => {
+            if *replace {
+                for checkpoint in checkpoints.checkpoints() {
+                    checkpoints.remove(checkpoint.fingerprint);
+                }
+            }
+            let count = fs::File::open(input)
+                .and_then(|file| checkpointer.import_checkpoints(io::BufReader::new(file)))
+                .map_err(|error| format!("Unable to import checkpoints from {input:?}: {error}"))?;
+            source.write_checkpoints(&checkpointer)?;
+            println!("Imported {count} checkpoints.");
+        }
+        SubCommand::Set { target, position } => {
+            let fingerprint = target.fingerprint(&source.fingerprinter)?;
+            checkpoints.insert(Checkpoint {
+                fingerprint,
+                position: *position,
+                modified: Utc::now(),
+            });
+            source.write_checkpoints(&checkpointer)?;
+        }
+        SubCommand::Reset { target, all } => {
+            let removed = if *all {
+                let removed = checkpoints.checkpoints();
+                for checkpoint in &removed {
+                    checkpoints.remove(checkpoint.fingerprint);
+                }
+                removed.len()
+            } else {
+                let fingerprint = target.fingerprint(&source.fingerprinter)?;
+                usize::from(checkpoints.remove(fingerprint).is_some())
+            };
+            source.write_checkpoints(&checkpointer)?;
+            println!("Removed {removed} checkpoints.");
+        }
+    }
+
+    Ok(())
+}
+
+fn format_fingerprint(fingerprint: FileFingerprint) -> String {
+    serde_json::to_string(&fingerprint).expect("fingerprints always serialize")
+}
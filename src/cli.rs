@@ -4,6 +4,8 @@ use std::{num::NonZeroU64, path::PathBuf};
 
 use clap::{ArgAction, CommandFactory, FromArgMatches, Parser};
 
+#[cfg(any(feature = "sources-file", feature = "sources-kubernetes_logs"))]
+use crate::checkpoints;
 #[cfg(windows)]
 use crate::service;
 #[cfg(feature = "api-client")]
@@ -312,6 +314,10 @@ pub enum SubCommand {
     #[cfg(feature = "api-client")]
     Tap(tap::Opts),
 
+    /// Inspect and edit the checkpoints of a `file` or `kubernetes_logs` source, while Vector is stopped.
+    #[cfg(any(feature = "sources-file", feature = "sources-kubernetes_logs"))]
+    Checkpoints(checkpoints::Opts),
+
     /// Manage the vector service.
     #[cfg(windows)]
     Service(service::Opts),
@@ -327,6 +333,8 @@ impl SubCommand {
         color: bool,
     ) -> exitcode::ExitCode {
         match self {
+            #[cfg(any(feature = "sources-file", feature = "sources-kubernetes_logs"))]
+            Self::Checkpoints(c) => checkpoints::cmd(c),
             Self::Config(c) => config::cmd(c),
             Self::ConvertConfig(opts) => convert_config::cmd(opts),
             Self::Generate(g) => generate::cmd(g),
@@ -1,5 +1,7 @@
 use bytes::BytesMut;
 use tokio_util::codec::Encoder as _;
+#[cfg(feature = "codecs-parquet")]
+use vector_lib::codecs::encoding::BatchSerializer;
 use vector_lib::codecs::{
     encoding::{Error, Framer, Serializer},
     CharacterDelimitedEncoder, NewlineDelimitedEncoder, TextSerializerConfig,
@@ -54,6 +56,11 @@ where
         Ok(())
     }
 
+    /// Sets the Kafka topic that events are sent to, for serializers that depend on it.
+    pub fn set_topic(&mut self, topic: &str) {
+        self.serializer.set_topic(topic);
+    }
+
     /// Serialize the event without applying framing, at the start of the provided buffer.
     fn serialize_at_start(&mut self, event: Event, buffer: &mut BytesMut) -> Result<(), Error> {
         self.serializer.encode(event, buffer).map_err(|error| {
@@ -114,6 +121,8 @@ impl Encoder<Framer> {
                 Framer::CharacterDelimited(CharacterDelimitedEncoder { delimiter: b',' }),
             ) => "application/json",
             (Serializer::Native(_), _) | (Serializer::Protobuf(_), _) => "application/octet-stream",
+            #[cfg(feature = "codecs-schema-registry")]
+            (Serializer::SchemaRegistry(_), _) => "application/octet-stream",
             (
                 Serializer::Avro(_)
                 | Serializer::Cef(_)
@@ -182,6 +191,36 @@ impl tokio_util::codec::Encoder<Event> for Encoder<()> {
     }
 }
 
+/// The encoder of sinks that support encoding batches of events as a whole, besides framing
+/// each event.
+#[cfg(feature = "codecs-parquet")]
+#[derive(Debug, Clone)]
+pub enum EncoderKind {
+    /// Serializes and frames each event of a batch.
+    Framed(Box<Encoder<Framer>>),
+    /// Serializes each batch as a whole.
+    Batch(BatchSerializer),
+}
+
+#[cfg(feature = "codecs-parquet")]
+impl EncoderKind {
+    /// Get the HTTP content type.
+    pub const fn content_type(&self) -> &'static str {
+        match self {
+            EncoderKind::Framed(encoder) => encoder.content_type(),
+            EncoderKind::Batch(serializer) => serializer.content_type(),
+        }
+    }
+
+    /// Get the file extension of the format batches are encoded in, if any.
+    pub const fn extension(&self) -> Option<&'static str> {
+        match self {
+            EncoderKind::Framed(_) => None,
+            EncoderKind::Batch(serializer) => Some(serializer.extension()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use bytes::BufMut;
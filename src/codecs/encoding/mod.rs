@@ -4,4 +4,6 @@ mod transformer;
 
 pub use config::{EncodingConfig, EncodingConfigWithFraming, SinkType};
 pub use encoder::Encoder;
+#[cfg(feature = "codecs-parquet")]
+pub use encoder::EncoderKind;
 pub use transformer::{TimestampFormat, Transformer};
@@ -8,6 +8,8 @@ mod encoding;
 mod ready_frames;
 
 pub use decoding::{Decoder, DecodingConfig};
+#[cfg(feature = "codecs-parquet")]
+pub use encoding::EncoderKind;
 pub use encoding::{
     Encoder, EncodingConfig, EncodingConfigWithFraming, SinkType, TimestampFormat, Transformer,
 };
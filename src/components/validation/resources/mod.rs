@@ -14,6 +14,7 @@ use vector_lib::{
         BytesEncoder,
     },
     config::LogNamespace,
+    lookup::lookup_v2::{ConfigTargetPath, OwnedTargetPath, OwnedValuePath},
 };
 use vector_lib::{config::DataType, event::Event};
 
@@ -172,6 +173,50 @@ fn deserializer_config_to_serializer(config: &DeserializerConfig) -> encoding::S
         // TODO: Influxdb has no serializer yet
         DeserializerConfig::Influxdb { .. } => todo!(),
         DeserializerConfig::Vrl { .. } => unimplemented!(),
+        DeserializerConfig::Logfmt => SerializerConfig::Logfmt,
+        DeserializerConfig::Csv(config) => SerializerConfig::Csv(
+            encoding::CsvSerializerConfig::new(encoding::CsvSerializerOptions {
+                delimiter: config.csv.delimiter,
+                double_quote: config.csv.double_quote,
+                escape: config.csv.escape,
+                fields: config
+                    .csv
+                    .columns
+                    .iter()
+                    .map(|column| {
+                        ConfigTargetPath(OwnedTargetPath::event(
+                            OwnedValuePath::root().with_field_appended(column),
+                        ))
+                    })
+                    .collect(),
+                ..Default::default()
+            }),
+        ),
+        // The extensions of a CEF message aren't known up front, so only the header fields that
+        // the deserializer decodes are encoded back.
+        DeserializerConfig::Cef(_) => SerializerConfig::Cef(encoding::CefSerializerConfig::new(
+            encoding::CefSerializerOptions {
+                name: ConfigTargetPath::from("name"),
+                severity: ConfigTargetPath::from("severity"),
+                ..Default::default()
+            },
+        )),
+        // The deserializer resolves whichever schema the messages were encoded with, so events are
+        // encoded with an Avro schema of their message, registered under a subject of their own.
+        #[cfg(feature = "codecs-schema-registry")]
+        DeserializerConfig::SchemaRegistry(config) => {
+            SerializerConfig::SchemaRegistry(encoding::SchemaRegistrySerializerConfig {
+                schema_registry: encoding::SchemaRegistrySerializerOptions {
+                    registry: config.schema_registry.clone(),
+                    subject: Some("vector-validation-value".into()),
+                    avro_schema: Some(
+                        r#"{ "type": "record", "name": "event", "fields": [{ "name": "message", "type": "string" }] }"#
+                            .into(),
+                    ),
+                    message_type: None,
+                },
+            })
+        }
     };
 
     serializer_config
@@ -214,7 +259,7 @@ fn serializer_config_to_deserializer(
         SerializerConfig::Csv { .. } => todo!(),
         SerializerConfig::Gelf => DeserializerConfig::Gelf(Default::default()),
         SerializerConfig::Json(_) => DeserializerConfig::Json(Default::default()),
-        SerializerConfig::Logfmt => todo!(),
+        SerializerConfig::Logfmt => DeserializerConfig::Logfmt,
         SerializerConfig::Native => DeserializerConfig::Native,
         SerializerConfig::NativeJson => DeserializerConfig::NativeJson(Default::default()),
         SerializerConfig::Protobuf(config) => {
@@ -226,6 +271,12 @@ fn serializer_config_to_deserializer(
             })
         }
         SerializerConfig::RawMessage | SerializerConfig::Text(_) => DeserializerConfig::Bytes,
+        #[cfg(feature = "codecs-schema-registry")]
+        SerializerConfig::SchemaRegistry(config) => DeserializerConfig::SchemaRegistry(
+            vector_lib::codecs::decoding::SchemaRegistryDeserializerConfig {
+                schema_registry: config.schema_registry.registry.clone(),
+            },
+        ),
     };
 
     deserializer_config.build()
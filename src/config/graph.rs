@@ -1,6 +1,6 @@
 use super::{
     schema, ComponentKey, DataType, OutputId, SinkOuter, SourceOuter, SourceOutput, TransformOuter,
-    TransformOutput,
+    TransformOutput, DROPPED_OUTPUT,
 };
 use indexmap::{set::IndexSet, IndexMap};
 use std::collections::{HashMap, HashSet, VecDeque};
@@ -17,6 +17,7 @@ pub enum Node {
     },
     Sink {
         ty: DataType,
+        reroute_dropped: bool,
     },
 }
 
@@ -40,8 +41,15 @@ impl fmt::Display for Node {
                 }
                 Ok(())
             }
-            Node::Sink { ty } => {
-                write!(f, "component_kind: sink\n  types: {ty}")
+            Node::Sink {
+                ty,
+                reroute_dropped,
+            } => {
+                write!(f, "component_kind: sink\n  types: {ty}")?;
+                if *reroute_dropped {
+                    write!(f, "\n  outputs:\n    {DROPPED_OUTPUT}: {ty}")?;
+                }
+                Ok(())
             }
         }
     }
@@ -117,6 +125,7 @@ impl Graph {
                 id.clone(),
                 Node::Sink {
                     ty: config.inner.input().data_type(),
+                    reroute_dropped: config.reroute_dropped,
                 },
             );
         }
@@ -190,7 +199,7 @@ impl Graph {
         match self.nodes[key] {
             Node::Source { .. } => panic!("no inputs on sources"),
             Node::Transform { in_ty, .. } => in_ty,
-            Node::Sink { ty } => ty,
+            Node::Sink { ty, .. } => ty,
         }
     }
 
@@ -198,8 +207,8 @@ impl Graph {
     ///
     /// # Panics
     ///
-    /// Will panic if the given id is not present in the graph or identifies a sink, which can't
-    /// have inputs.
+    /// Will panic if the given id is not present in the graph or identifies an output that doesn't
+    /// exist.
     fn get_output_type(&self, id: &OutputId) -> DataType {
         match &self.nodes[&id.component] {
             Node::Source { outputs } => outputs
@@ -212,7 +221,13 @@ impl Graph {
                 .find(|output| output.port == id.port)
                 .map(|output| output.ty)
                 .expect("output didn't exist"),
-            Node::Sink { .. } => panic!("no outputs on sinks"),
+            // The only output a sink can have carries the events it failed to deliver, which are
+            // necessarily of the type it accepts.
+            Node::Sink {
+                ty,
+                reroute_dropped: true,
+            } if id.port.as_deref() == Some(DROPPED_OUTPUT) => *ty,
+            Node::Sink { .. } => panic!("output didn't exist"),
         }
     }
 
@@ -297,7 +312,18 @@ impl Graph {
         self.nodes
             .iter()
             .flat_map(|(key, node)| match node {
-                Node::Sink { .. } => vec![],
+                Node::Sink {
+                    reroute_dropped, ..
+                } => {
+                    if *reroute_dropped {
+                        vec![OutputId {
+                            component: key.clone(),
+                            port: Some(DROPPED_OUTPUT.to_owned()),
+                        }]
+                    } else {
+                        vec![]
+                    }
+                }
                 Node::Source { outputs } => outputs
                     .iter()
                     .map(|output| OutputId {
@@ -389,7 +415,7 @@ impl Graph {
             .into_iter()
             .filter(|path| {
                 if let Some(key) = path.last() {
-                    matches!(self.nodes.get(key), Some(Node::Sink { ty: _ }))
+                    matches!(self.nodes.get(key), Some(Node::Sink { .. }))
                 } else {
                     false
                 }
@@ -463,7 +489,13 @@ mod test {
         fn add_sink(&mut self, id: &str, ty: DataType, inputs: Vec<&str>) {
             let id = ComponentKey::from(id);
             let inputs = clean_inputs(inputs);
-            self.nodes.insert(id.clone(), Node::Sink { ty });
+            self.nodes.insert(
+                id.clone(),
+                Node::Sink {
+                    ty,
+                    reroute_dropped: false,
+                },
+            );
             for from in inputs {
                 self.edges.push(Edge {
                     from,
@@ -472,6 +504,15 @@ mod test {
             }
         }
 
+        fn reroute_dropped(&mut self, id: &str) {
+            match self.nodes.get_mut(&id.into()) {
+                Some(Node::Sink {
+                    reroute_dropped, ..
+                }) => *reroute_dropped = true,
+                _ => panic!("invalid sink"),
+            }
+        }
+
         fn test_add_input(&mut self, node: &str, input: &str) -> Result<(), String> {
             let available_inputs = self.input_map().unwrap();
             self.add_input(input, &node.into(), &available_inputs)
@@ -666,6 +707,52 @@ mod test {
         );
     }
 
+    #[test]
+    fn allows_sink_dropped_output() {
+        let mut graph = Graph::default();
+        graph.add_source("log_source", DataType::Log);
+        graph.add_sink("primary_sink", DataType::Log, vec!["log_source"]);
+        graph.add_sink("dead_letter_sink", DataType::Log, vec![]);
+        graph.add_sink("other_sink", DataType::Log, vec![]);
+
+        let expected = "Input \"primary_sink.dropped\" for sink \"dead_letter_sink\" doesn't match any components.".to_string();
+        assert_eq!(
+            Err(expected),
+            graph.test_add_input("dead_letter_sink", "primary_sink.dropped")
+        );
+
+        graph.reroute_dropped("primary_sink");
+        assert_eq!(
+            Ok(()),
+            graph.test_add_input("dead_letter_sink", "primary_sink.dropped")
+        );
+        assert_eq!(Ok(()), graph.typecheck());
+
+        // the sink itself still can't be used as an input
+        let expected =
+            "Input \"primary_sink\" for sink \"other_sink\" doesn't match any components."
+                .to_string();
+        assert_eq!(
+            Err(expected),
+            graph.test_add_input("other_sink", "primary_sink")
+        );
+    }
+
+    #[test]
+    fn detects_cycles_through_sink_dropped_output() {
+        let mut graph = Graph::default();
+        graph.add_source("in", DataType::Log);
+        graph.add_transform("retry", DataType::Log, DataType::Log, vec!["in"]);
+        graph.add_sink("out", DataType::Log, vec!["retry"]);
+        graph.reroute_dropped("out");
+        assert_eq!(Ok(()), graph.test_add_input("retry", "out.dropped"));
+
+        assert_eq!(
+            Err("Cyclic dependency detected in the chain [ out -> retry ]".into()),
+            graph.check_for_cycles()
+        );
+    }
+
     #[test]
     fn disallows_ambiguous_inputs() {
         let mut graph = Graph::default();
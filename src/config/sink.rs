@@ -21,6 +21,9 @@ use crate::sinks::{util::UriSerde, Healthcheck};
 
 pub type BoxedSink = Box<dyn SinkConfig>;
 
+/// The name of the output that a sink reroutes the events it failed to deliver to.
+pub const DROPPED_OUTPUT: &str = "dropped";
+
 impl Configurable for BoxedSink {
     fn referenceable_name() -> Option<&'static str> {
         Some("vector::sinks::Sinks")
@@ -79,6 +82,17 @@ where
     #[serde(default, skip_serializing_if = "vector_lib::serde::is_default")]
     proxy: ProxyConfig,
 
+    /// Reroutes the events that the sink fails to deliver to its `dropped` output.
+    ///
+    /// Events end up there once the sink has given up on them, for example after a
+    /// non-retriable response, or after exhausting its retries. The original event is forwarded
+    /// along with metadata describing why it was dropped, including the error and status code
+    /// reported by the sink when available, which allows routing them elsewhere (for example, an
+    /// object store) to be replayed later.
+    #[serde(default, skip_serializing_if = "vector_lib::serde::is_default")]
+    #[configurable(metadata(docs::advanced))]
+    pub reroute_dropped: bool,
+
     #[serde(flatten)]
     #[configurable(metadata(docs::hidden))]
     pub inner: BoxedSink,
@@ -101,6 +115,7 @@ where
             inner: inner.into(),
             proxy: Default::default(),
             graph: Default::default(),
+            reroute_dropped: false,
         }
     }
 
@@ -158,6 +173,7 @@ where
             healthcheck_uri: self.healthcheck_uri,
             proxy: self.proxy,
             graph: self.graph,
+            reroute_dropped: self.reroute_dropped,
         }
     }
 }
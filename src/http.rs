@@ -2,8 +2,10 @@
 use futures::future::BoxFuture;
 use headers::{Authorization, HeaderMapExt};
 use http::{
-    header::HeaderValue, request::Builder, uri::InvalidUri, HeaderMap, Request, Response, Uri,
-    Version,
+    header::{HeaderValue, ACCEPT, AUTHORIZATION, CONTENT_TYPE},
+    request::Builder,
+    uri::InvalidUri,
+    HeaderMap, Request, Response, StatusCode, Uri, Version,
 };
 use hyper::{
     body::{Body, HttpBody},
@@ -13,21 +15,24 @@ use hyper::{
 use hyper_openssl::HttpsConnector;
 use hyper_proxy::ProxyConnector;
 use rand::Rng;
+use serde::Deserialize;
 use serde_with::serde_as;
 use snafu::{ResultExt, Snafu};
 use std::{
     fmt,
     net::SocketAddr,
+    sync::Arc,
     task::{Context, Poll},
     time::Duration,
 };
-use tokio::time::Instant;
+use tokio::{sync::Mutex, time::Instant};
 use tower::{Layer, Service};
 use tower_http::{
     classify::{ServerErrorsAsFailures, SharedClassifier},
     trace::TraceLayer,
 };
 use tracing::{Instrument, Span};
+use url::Url;
 use vector_lib::configurable::configurable_component;
 use vector_lib::sensitive_string::SensitiveString;
 
@@ -37,6 +42,9 @@ use crate::{
     tls::{tls_connector_builder, MaybeTlsSettings, TlsError},
 };
 
+/// Margin before the expiry of an OAuth2 access token at which a new one is fetched.
+const OAUTH2_TOKEN_EXPIRY_MARGIN: Duration = Duration::from_secs(60);
+
 pub mod status {
     pub const FORBIDDEN: u16 = 403;
     pub const NOT_FOUND: u16 = 404;
@@ -56,6 +64,10 @@ pub enum HttpError {
     CallRequest { source: hyper::Error },
     #[snafu(display("Failed to build HTTP request: {}", source))]
     BuildRequest { source: http::Error },
+    #[snafu(display("Failed to fetch OAuth2 access token: {}", source))]
+    FetchOAuth2Token { source: OAuth2Error },
+    #[snafu(display("OAuth2 access token was rejected"))]
+    OAuth2TokenRejected,
 }
 
 impl HttpError {
@@ -64,16 +76,34 @@ impl HttpError {
             HttpError::BuildRequest { .. } | HttpError::MakeProxyConnector { .. } => false,
             HttpError::CallRequest { .. }
             | HttpError::BuildTlsConnector { .. }
-            | HttpError::MakeHttpsConnector { .. } => true,
+            | HttpError::MakeHttpsConnector { .. }
+            | HttpError::FetchOAuth2Token { .. }
+            | HttpError::OAuth2TokenRejected => true,
         }
     }
 }
 
+#[derive(Debug, Snafu)]
+#[snafu(visibility(pub(crate)))]
+pub enum OAuth2Error {
+    #[snafu(display("Failed to build token request: {}", source))]
+    BuildTokenRequest { source: http::Error },
+    #[snafu(display("Failed to call token endpoint: {}", source))]
+    CallTokenEndpoint { source: hyper::Error },
+    #[snafu(display("Token endpoint responded with {}: {}", status, body))]
+    TokenEndpointStatus { status: StatusCode, body: String },
+    #[snafu(display("Failed to parse token response: {}", source))]
+    ParseTokenResponse { source: serde_json::Error },
+    #[snafu(display("Token endpoint returned an invalid access token"))]
+    InvalidAccessToken,
+}
+
 pub type HttpClientFuture = <HttpClient as Service<http::Request<Body>>>::Future;
 type HttpProxyConnector = ProxyConnector<HttpsConnector<HttpConnector>>;
 
 pub struct HttpClient<B = Body> {
     client: Client<HttpProxyConnector, B>,
+    token_client: Client<HttpProxyConnector, Body>,
     user_agent: HeaderValue,
     proxy_connector: HttpProxyConnector,
 }
@@ -98,6 +128,7 @@ where
     ) -> Result<HttpClient<B>, HttpError> {
         let proxy_connector = build_proxy_connector(tls_settings.into(), proxy_config)?;
         let client = client_builder.build(proxy_connector.clone());
+        let token_client = client_builder.build(proxy_connector.clone());
 
         let app_name = crate::get_app_name();
         let version = crate::get_version();
@@ -106,6 +137,7 @@ where
 
         Ok(HttpClient {
             client,
+            token_client,
             user_agent,
             proxy_connector,
         })
@@ -119,13 +151,42 @@ where
         let _enter = span.enter();
 
         default_request_headers(&mut request, &self.user_agent);
-        self.maybe_add_proxy_headers(&mut request);
+        add_proxy_headers(&self.proxy_connector, &mut request);
+
+        // Requests using OAuth2 authentication get their access token right before being sent, as
+        // fetching it may require calling the token endpoint.
+        let oauth2 = request
+            .extensions_mut()
+            .remove::<OAuth2Credentials>()
+            .map(|credentials| {
+                (
+                    credentials,
+                    self.token_client.clone(),
+                    self.proxy_connector.clone(),
+                    self.user_agent.clone(),
+                )
+            });
+        let client = self.client.clone();
+
+        let fut = async move {
+            let authorization = match &oauth2 {
+                Some((credentials, token_client, proxy_connector, user_agent)) => {
+                    let (authorization, fetched) = credentials
+                        .authorization(token_client, proxy_connector, user_agent)
+                        .await
+                        .context(FetchOAuth2TokenSnafu)?;
+                    request
+                        .headers_mut()
+                        .insert(AUTHORIZATION, authorization.clone());
+                    Some((authorization, fetched))
+                }
+                None => None,
+            };
 
-        emit!(http_client::AboutToSendHttpRequest { request: &request });
+            emit!(http_client::AboutToSendHttpRequest { request: &request });
 
-        let response = self.client.request(request);
+            let response = client.request(request);
 
-        let fut = async move {
             // Capture the time right before we issue the request.
             // Request doesn't start the processing until we start polling it.
             let before = std::time::Instant::now();
@@ -150,20 +211,36 @@ where
                 response: &response,
                 roundtrip
             });
+
+            // The access token may have been revoked before its expiry, so make sure the next
+            // request fetches a new one. Requests rejected with a cached token fail with a
+            // retriable error so they get retried with the new token, while a newly fetched token
+            // being rejected means the client isn't authorized, which retrying won't fix.
+            if let (Some((credentials, ..)), Some((authorization, fetched))) =
+                (&oauth2, &authorization)
+            {
+                if response.status() == StatusCode::UNAUTHORIZED {
+                    credentials.invalidate(authorization).await;
+                    if !fetched {
+                        return Err(HttpError::OAuth2TokenRejected);
+                    }
+                }
+            }
+
             Ok(response)
         }
         .instrument(span.clone().or_current());
 
         Box::pin(fut)
     }
+}
 
-    fn maybe_add_proxy_headers(&self, request: &mut Request<B>) {
-        if let Some(proxy_headers) = self.proxy_connector.http_headers(request.uri()) {
-            for (k, v) in proxy_headers {
-                let request_headers = request.headers_mut();
-                if !request_headers.contains_key(k) {
-                    request_headers.insert(k, v.into());
-                }
+fn add_proxy_headers<B>(proxy_connector: &HttpProxyConnector, request: &mut Request<B>) {
+    if let Some(proxy_headers) = proxy_connector.http_headers(request.uri()) {
+        for (k, v) in proxy_headers {
+            let request_headers = request.headers_mut();
+            if !request_headers.contains_key(k) {
+                request_headers.insert(k, v.into());
             }
         }
     }
@@ -247,6 +324,7 @@ impl<B> Clone for HttpClient<B> {
     fn clone(&self) -> Self {
         Self {
             client: self.client.clone(),
+            token_client: self.token_client.clone(),
             user_agent: self.user_agent.clone(),
             proxy_connector: self.proxy_connector.clone(),
         }
@@ -295,6 +373,37 @@ pub enum Auth {
         /// The bearer authentication token.
         token: SensitiveString,
     },
+
+    /// OAuth2 authentication, using the [client credentials grant][client_credentials].
+    ///
+    /// An access token is requested from the token endpoint and passed as a bearer token. The
+    /// token is reused until shortly before it expires, or until a request using it is rejected
+    /// with a `401 Unauthorized` response.
+    ///
+    /// [client_credentials]: https://datatracker.ietf.org/doc/html/rfc6749#section-4.4
+    #[serde(rename = "oauth2")]
+    OAuth2 {
+        /// The URL of the token endpoint of the authorization server.
+        #[configurable(metadata(docs::examples = "https://auth.example.com/oauth2/token"))]
+        token_endpoint: Url,
+
+        /// The client identifier.
+        #[configurable(metadata(docs::examples = "${CLIENT_ID}"))]
+        #[configurable(metadata(docs::examples = "vector"))]
+        client_id: String,
+
+        /// The client secret.
+        #[configurable(metadata(docs::examples = "${CLIENT_SECRET}"))]
+        client_secret: SensitiveString,
+
+        /// The scopes to request for the access token.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        #[configurable(metadata(docs::examples = "logs.write"))]
+        scopes: Vec<String>,
+
+        #[serde(skip)]
+        token: OAuth2TokenCache,
+    },
 }
 
 pub trait MaybeAuth: Sized {
@@ -313,16 +422,27 @@ impl MaybeAuth for Option<Auth> {
 
 impl Auth {
     pub fn apply<B>(&self, req: &mut Request<B>) {
-        self.apply_headers_map(req.headers_mut())
+        self.apply_headers_map(req.headers_mut());
+        if let Some(credentials) = self.oauth2_credentials() {
+            req.extensions_mut().insert(credentials);
+        }
     }
 
     pub fn apply_builder(&self, mut builder: Builder) -> Builder {
         if let Some(map) = builder.headers_mut() {
             self.apply_headers_map(map)
         }
+        if let Some(credentials) = self.oauth2_credentials() {
+            builder = builder.extension(credentials);
+        }
         builder
     }
 
+    /// Adds the authentication headers to the given header map.
+    ///
+    /// OAuth2 authentication adds no headers here, as the access token is only added by
+    /// [`HttpClient`] when sending requests that went through [`Auth::apply`] or
+    /// [`Auth::apply_builder`].
     pub fn apply_headers_map(&self, map: &mut HeaderMap) {
         match &self {
             Auth::Basic { user, password } => {
@@ -333,8 +453,168 @@ impl Auth {
                 Ok(auth) => map.typed_insert(auth),
                 Err(error) => error!(message = "Invalid bearer token.", token = %token, %error),
             },
+            Auth::OAuth2 { .. } => {}
         }
     }
+
+    fn oauth2_credentials(&self) -> Option<OAuth2Credentials> {
+        match self {
+            Auth::OAuth2 {
+                token_endpoint,
+                client_id,
+                client_secret,
+                scopes,
+                token,
+            } => Some(OAuth2Credentials {
+                token_endpoint: token_endpoint.clone(),
+                client_id: client_id.clone(),
+                client_secret: client_secret.clone(),
+                scopes: scopes.clone(),
+                token: token.clone(),
+            }),
+            Auth::Basic { .. } | Auth::Bearer { .. } => None,
+        }
+    }
+}
+
+/// Cached OAuth2 access token, shared by all the copies of an [`Auth::OAuth2`] configuration.
+#[derive(Clone, Debug, Default)]
+pub struct OAuth2TokenCache(Arc<Mutex<Option<OAuth2Token>>>);
+
+// The cached token is runtime state rather than configuration, so it never makes two
+// configurations differ.
+impl PartialEq for OAuth2TokenCache {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for OAuth2TokenCache {}
+
+#[derive(Debug)]
+struct OAuth2Token {
+    authorization: HeaderValue,
+    refresh_at: Option<Instant>,
+}
+
+#[derive(Deserialize)]
+struct OAuth2TokenResponse {
+    access_token: String,
+    expires_in: Option<u64>,
+}
+
+/// Credentials attached to a request by [`Auth::apply`], for [`HttpClient`] to authenticate it
+/// with an OAuth2 access token.
+#[derive(Clone, Debug)]
+struct OAuth2Credentials {
+    token_endpoint: Url,
+    client_id: String,
+    client_secret: SensitiveString,
+    scopes: Vec<String>,
+    token: OAuth2TokenCache,
+}
+
+impl OAuth2Credentials {
+    /// Gets the `Authorization` header value for the cached access token, fetching a new token if
+    /// there is none or it is about to expire.
+    ///
+    /// The returned flag is set if the token was fetched by this call rather than cached.
+    async fn authorization(
+        &self,
+        client: &Client<HttpProxyConnector, Body>,
+        proxy_connector: &HttpProxyConnector,
+        user_agent: &HeaderValue,
+    ) -> Result<(HeaderValue, bool), OAuth2Error> {
+        // Holding the lock while fetching ensures concurrent requests wait for a single new token.
+        let mut token = self.token.0.lock().await;
+        if let Some(token) = token.as_ref() {
+            if token
+                .refresh_at
+                .map_or(true, |refresh_at| Instant::now() < refresh_at)
+            {
+                return Ok((token.authorization.clone(), false));
+            }
+        }
+
+        let fetched = self
+            .fetch_token(client, proxy_connector, user_agent)
+            .await?;
+        let authorization = fetched.authorization.clone();
+        *token = Some(fetched);
+        Ok((authorization, true))
+    }
+
+    /// Discards the cached access token, unless it was already replaced by a newer one.
+    async fn invalidate(&self, authorization: &HeaderValue) {
+        let mut token = self.token.0.lock().await;
+        if token
+            .as_ref()
+            .is_some_and(|token| &token.authorization == authorization)
+        {
+            *token = None;
+        }
+    }
+
+    async fn fetch_token(
+        &self,
+        client: &Client<HttpProxyConnector, Body>,
+        proxy_connector: &HttpProxyConnector,
+        user_agent: &HeaderValue,
+    ) -> Result<OAuth2Token, OAuth2Error> {
+        let mut body = url::form_urlencoded::Serializer::new(String::new());
+        body.append_pair("grant_type", "client_credentials");
+        if !self.scopes.is_empty() {
+            body.append_pair("scope", &self.scopes.join(" "));
+        }
+
+        let mut request = Request::post(self.token_endpoint.as_str())
+            .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .header(ACCEPT, "application/json")
+            .body(Body::from(body.finish()))
+            .context(BuildTokenRequestSnafu)?;
+        request.headers_mut().typed_insert(Authorization::basic(
+            &self.client_id,
+            self.client_secret.inner(),
+        ));
+        default_request_headers(&mut request, user_agent);
+        add_proxy_headers(proxy_connector, &mut request);
+
+        debug!(
+            message = "Fetching OAuth2 access token.",
+            token_endpoint = %self.token_endpoint,
+        );
+        let requested_at = Instant::now();
+        let response = client
+            .request(request)
+            .await
+            .context(CallTokenEndpointSnafu)?;
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body())
+            .await
+            .context(CallTokenEndpointSnafu)?;
+        if !status.is_success() {
+            return Err(OAuth2Error::TokenEndpointStatus {
+                status,
+                body: String::from_utf8_lossy(&body).into_owned(),
+            });
+        }
+
+        let response = serde_json::from_slice::<OAuth2TokenResponse>(&body)
+            .context(ParseTokenResponseSnafu)?;
+        let authorization = HeaderValue::from_str(&format!("Bearer {}", response.access_token))
+            .map_err(|_| OAuth2Error::InvalidAccessToken)?;
+
+        // Tokens without an expiry are used until they get rejected.
+        let refresh_at = response.expires_in.map(|expires_in| {
+            let expires_in = Duration::from_secs(expires_in);
+            requested_at + expires_in - OAUTH2_TOKEN_EXPIRY_MARGIN.min(expires_in / 2)
+        });
+
+        Ok(OAuth2Token {
+            authorization,
+            refresh_at,
+        })
+    }
 }
 
 pub fn get_http_scheme_from_uri(uri: &Uri) -> &'static str {
@@ -556,7 +836,13 @@ where
 
 #[cfg(test)]
 mod tests {
-    use std::convert::Infallible;
+    use std::{
+        convert::Infallible,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Mutex as StdMutex,
+        },
+    };
 
     use hyper::{server::conn::AddrStream, service::make_service_fn, Server};
     use proptest::prelude::*;
@@ -811,4 +1097,193 @@ mod tests {
         let response = client.send(req).await.unwrap();
         assert_eq!(response.headers().get("Connection"), None);
     }
+
+    #[derive(Default)]
+    struct OAuth2ServerState {
+        tokens_issued: AtomicUsize,
+        revoked: StdMutex<Vec<String>>,
+    }
+
+    /// Spawns a server issuing access tokens on `/token`, and only accepting requests authorized
+    /// with one of those tokens that hasn't been revoked on `/data`.
+    async fn spawn_oauth2_server(expires_in: Option<u64>) -> (SocketAddr, Arc<OAuth2ServerState>) {
+        let addr = next_addr();
+        let state = Arc::new(OAuth2ServerState::default());
+
+        let server_state = Arc::clone(&state);
+        let make_svc = make_service_fn(move |_: &AddrStream| {
+            let state = Arc::clone(&server_state);
+            futures_util::future::ok::<_, Infallible>(tower::service_fn(
+                move |req: Request<Body>| {
+                    let state = Arc::clone(&state);
+                    async move {
+                        let response = match req.uri().path() {
+                            "/token" => {
+                                assert_eq!(req.method(), http::Method::POST);
+                                assert_eq!(
+                                    req.headers().get(AUTHORIZATION).unwrap(),
+                                    "Basic dmVjdG9yOnNlY3JldA=="
+                                );
+                                let body = hyper::body::to_bytes(req.into_body()).await?;
+                                assert_eq!(
+                                    body,
+                                    "grant_type=client_credentials&scope=logs.write+metrics.write"
+                                );
+
+                                let id = state.tokens_issued.fetch_add(1, Ordering::SeqCst) + 1;
+                                let response = serde_json::json!({
+                                    "access_token": format!("token-{id}"),
+                                    "token_type": "Bearer",
+                                    "expires_in": expires_in,
+                                });
+                                Response::new(Body::from(response.to_string()))
+                            }
+                            _ => {
+                                let authorization = req
+                                    .headers()
+                                    .get(AUTHORIZATION)
+                                    .and_then(|value| value.to_str().ok())
+                                    .and_then(|value| value.strip_prefix("Bearer "))
+                                    .unwrap_or_default();
+                                let issued = state.tokens_issued.load(Ordering::SeqCst);
+                                let valid = (1..=issued)
+                                    .any(|id| authorization == format!("token-{id}"))
+                                    && !state
+                                        .revoked
+                                        .lock()
+                                        .unwrap()
+                                        .iter()
+                                        .any(|revoked| revoked == authorization);
+
+                                let mut response = Response::new(Body::empty());
+                                if !valid {
+                                    *response.status_mut() = StatusCode::UNAUTHORIZED;
+                                }
+                                response
+                            }
+                        };
+                        Ok::<_, hyper::Error>(response)
+                    }
+                },
+            ))
+        });
+
+        tokio::spawn(async move {
+            Server::bind(&addr).serve(make_svc).await.unwrap();
+        });
+
+        // Wait for the server to start.
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        (addr, state)
+    }
+
+    fn oauth2_auth(addr: SocketAddr) -> Auth {
+        toml::from_str(&format!(
+            r#"
+                strategy = "oauth2"
+                token_endpoint = "http://{addr}/token"
+                client_id = "vector"
+                client_secret = "secret"
+                scopes = ["logs.write", "metrics.write"]
+            "#
+        ))
+        .unwrap()
+    }
+
+    async fn send_with_auth(
+        client: &HttpClient,
+        auth: &Auth,
+        addr: SocketAddr,
+    ) -> Result<StatusCode, HttpError> {
+        let mut req = Request::get(format!("http://{addr}/data"))
+            .body(Body::empty())
+            .unwrap();
+        auth.apply(&mut req);
+        client.send(req).await.map(|response| response.status())
+    }
+
+    #[tokio::test]
+    async fn test_oauth2_token_is_reused_until_rejected() {
+        let (addr, state) = spawn_oauth2_server(Some(3600)).await;
+        let client = HttpClient::new(None, &ProxyConfig::default()).unwrap();
+        let auth = oauth2_auth(addr);
+
+        assert_eq!(
+            send_with_auth(&client, &auth, addr).await.unwrap(),
+            StatusCode::OK
+        );
+        assert_eq!(
+            send_with_auth(&client, &auth, addr).await.unwrap(),
+            StatusCode::OK
+        );
+        assert_eq!(state.tokens_issued.load(Ordering::SeqCst), 1);
+
+        // Once the cached token is rejected, the request fails with a retriable error, and the
+        // next request fetches a new token.
+        state.revoked.lock().unwrap().push("token-1".into());
+        let error = send_with_auth(&client, &auth, addr).await.unwrap_err();
+        assert!(matches!(error, HttpError::OAuth2TokenRejected));
+        assert!(error.is_retriable());
+        assert_eq!(
+            send_with_auth(&client, &auth, addr).await.unwrap(),
+            StatusCode::OK
+        );
+        assert_eq!(state.tokens_issued.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_oauth2_new_token_rejected() {
+        let (addr, state) = spawn_oauth2_server(Some(3600)).await;
+        let client = HttpClient::new(None, &ProxyConfig::default()).unwrap();
+        let auth = oauth2_auth(addr);
+
+        // A newly fetched token being rejected isn't worth retrying, so the response is returned.
+        state.revoked.lock().unwrap().push("token-1".into());
+        assert_eq!(
+            send_with_auth(&client, &auth, addr).await.unwrap(),
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(
+            send_with_auth(&client, &auth, addr).await.unwrap(),
+            StatusCode::OK
+        );
+        assert_eq!(state.tokens_issued.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_oauth2_token_is_refreshed_before_expiry() {
+        let (addr, state) = spawn_oauth2_server(Some(1)).await;
+        let client = HttpClient::new(None, &ProxyConfig::default()).unwrap();
+        let auth = oauth2_auth(addr);
+
+        assert_eq!(
+            send_with_auth(&client, &auth, addr).await.unwrap(),
+            StatusCode::OK
+        );
+        assert_eq!(state.tokens_issued.load(Ordering::SeqCst), 1);
+
+        // The token expires in a second, so it's refreshed after half of that.
+        tokio::time::sleep(Duration::from_millis(600)).await;
+        assert_eq!(
+            send_with_auth(&client, &auth, addr).await.unwrap(),
+            StatusCode::OK
+        );
+        assert_eq!(state.tokens_issued.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_oauth2_token_endpoint_error() {
+        let addr = next_addr();
+        let client = HttpClient::new(None, &ProxyConfig::default()).unwrap();
+        let auth = oauth2_auth(addr);
+
+        let mut req = Request::get(format!("http://{addr}/data"))
+            .body(Body::empty())
+            .unwrap();
+        auth.apply(&mut req);
+        let error = client.send(req).await.unwrap_err();
+        assert!(matches!(error, HttpError::FetchOAuth2Token { .. }));
+        assert!(error.is_retriable());
+    }
 }
@@ -106,6 +106,39 @@ impl<'a> InternalEvent for EncoderSerializeError<'a> {
     }
 }
 
+#[cfg(feature = "codecs-parquet")]
+#[derive(Debug)]
+pub struct EncoderSchemaMismatchError<'a, E> {
+    pub error: &'a E,
+    pub count: usize,
+}
+
+#[cfg(feature = "codecs-parquet")]
+impl<E: std::fmt::Display> InternalEvent for EncoderSchemaMismatchError<'_, E> {
+    fn emit(self) {
+        let reason = "Event does not match the encoding schema.";
+        error!(
+            message = reason,
+            error = %self.error,
+            error_code = "encoder_schema_mismatch",
+            error_type = error_type::ENCODER_FAILED,
+            stage = error_stage::SENDING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total",
+            "error_code" => "encoder_schema_mismatch",
+            "error_type" => error_type::ENCODER_FAILED,
+            "stage" => error_stage::SENDING,
+        )
+        .increment(1);
+        emit!(ComponentEventsDropped::<UNINTENTIONAL> {
+            count: self.count,
+            reason
+        });
+    }
+}
+
 #[derive(Debug)]
 pub struct EncoderWriteError<'a, E> {
     pub error: &'a E,
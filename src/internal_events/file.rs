@@ -469,6 +469,32 @@ mod source {
         }
     }
 
+    #[derive(Debug)]
+    pub struct FileTruncated<'a> {
+        pub file: &'a Path,
+        pub file_position: u64,
+        pub include_file_metric_tag: bool,
+    }
+
+    impl<'a> InternalEvent for FileTruncated<'a> {
+        fn emit(self) {
+            info!(
+                message = "File has been truncated, reading it from the beginning.",
+                file = %self.file.display(),
+                file_position = %self.file_position,
+            );
+            if self.include_file_metric_tag {
+                counter!(
+                    "files_truncated_total",
+                    "file" => self.file.to_string_lossy().into_owned(),
+                )
+            } else {
+                counter!("files_truncated_total")
+            }
+            .increment(1);
+        }
+    }
+
     #[derive(Debug)]
     pub struct PathGlobbingError<'a> {
         pub path: &'a Path,
@@ -496,6 +522,33 @@ mod source {
         }
     }
 
+    #[derive(Debug)]
+    pub struct PathsProviderError<'a> {
+        pub path: &'a Path,
+        pub error: &'a Error,
+    }
+
+    impl<'a> InternalEvent for PathsProviderError<'a> {
+        fn emit(self) {
+            error!(
+                message = "Failed to list paths.",
+                error = %self.error,
+                error_code = "listing_paths",
+                error_type = error_type::READER_FAILED,
+                stage = error_stage::RECEIVING,
+                path = %self.path.display(),
+                internal_log_rate_limit = true,
+            );
+            counter!(
+                "component_errors_total",
+                "error_code" => "listing_paths",
+                "error_type" => error_type::READER_FAILED,
+                "stage" => error_stage::RECEIVING,
+            )
+            .increment(1);
+        }
+    }
+
     #[derive(Clone)]
     pub struct FileSourceInternalEventsEmitter {
         pub include_file_metric_tag: bool,
@@ -575,8 +628,20 @@ mod source {
             emit!(FileOpen { count });
         }
 
+        fn emit_file_truncated(&self, file: &Path, file_position: u64) {
+            emit!(FileTruncated {
+                file,
+                file_position,
+                include_file_metric_tag: self.include_file_metric_tag
+            });
+        }
+
         fn emit_path_globbing_failed(&self, path: &Path, error: &Error) {
             emit!(PathGlobbingError { path, error });
         }
+
+        fn emit_paths_provider_error(&self, path: &Path, error: &Error) {
+            emit!(PathsProviderError { path, error });
+        }
     }
 }
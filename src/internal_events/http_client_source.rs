@@ -87,3 +87,28 @@ impl InternalEvent for HttpClientHttpError {
         .increment(1);
     }
 }
+
+#[derive(Debug)]
+pub struct HttpClientCheckpointError<'a> {
+    pub error: std::io::Error,
+    pub path: &'a std::path::Path,
+}
+
+impl InternalEvent for HttpClientCheckpointError<'_> {
+    fn emit(self) {
+        error!(
+            message = "Unable to read or write the checkpoint.",
+            path = ?self.path,
+            error = %self.error,
+            error_type = error_type::IO_FAILED,
+            stage = error_stage::RECEIVING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total",
+            "error_type" => error_type::IO_FAILED,
+            "stage" => error_stage::RECEIVING,
+        )
+        .increment(1);
+    }
+}
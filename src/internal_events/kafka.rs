@@ -174,3 +174,43 @@ impl InternalEvent for KafkaHeaderExtractionError<'_> {
         .increment(1);
     }
 }
+
+#[derive(Debug)]
+pub struct KafkaTransactionError<'a> {
+    pub error: &'a rdkafka::error::KafkaError,
+}
+
+impl InternalEvent for KafkaTransactionError<'_> {
+    fn emit(self) {
+        error!(
+            message = "Kafka transaction failed.",
+            error = %self.error,
+            error_code = "kafka_transaction",
+            error_type = error_type::REQUEST_FAILED,
+            stage = error_stage::SENDING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total",
+            "error_code" => "kafka_transaction",
+            "error_type" => error_type::REQUEST_FAILED,
+            "stage" => error_stage::SENDING,
+        )
+        .increment(1);
+    }
+}
+
+#[derive(Debug)]
+pub struct KafkaConsumerGroupMissing<'a> {
+    pub source_id: &'a str,
+}
+
+impl InternalEvent for KafkaConsumerGroupMissing<'_> {
+    fn emit(self) {
+        warn!(
+            message = "Consumer group metadata unavailable, committing transaction without consumer offsets.",
+            source_id = self.source_id,
+            internal_log_rate_limit = true,
+        );
+    }
+}
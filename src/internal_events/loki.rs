@@ -100,3 +100,26 @@ impl InternalEvent for LokiTimestampNonParsableEventsDropped {
         .increment(1);
     }
 }
+
+#[derive(Debug)]
+pub struct LokiPushRequestParseError<'a> {
+    pub error: &'a dyn std::error::Error,
+}
+
+impl InternalEvent for LokiPushRequestParseError<'_> {
+    fn emit(self) {
+        error!(
+            message = "Could not decode push request.",
+            error = %self.error,
+            error_type = error_type::PARSER_FAILED,
+            stage = error_stage::PROCESSING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total",
+            "error_type" => error_type::PARSER_FAILED,
+            "stage" => error_stage::PROCESSING,
+        )
+        .increment(1);
+    }
+}
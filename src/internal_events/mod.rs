@@ -79,7 +79,7 @@ mod kubernetes_logs;
 #[cfg(feature = "transforms-log_to_metric")]
 mod log_to_metric;
 mod logplex;
-#[cfg(feature = "sinks-loki")]
+#[cfg(any(feature = "sources-loki", feature = "sinks-loki"))]
 mod loki;
 #[cfg(feature = "transforms-lua")]
 mod lua;
@@ -87,12 +87,16 @@ mod lua;
 mod metric_to_log;
 #[cfg(feature = "sources-mongodb_metrics")]
 mod mongodb_metrics;
-#[cfg(feature = "sinks-mqtt")]
+#[cfg(any(feature = "sources-mqtt", feature = "sinks-mqtt"))]
 mod mqtt;
 #[cfg(feature = "sources-nginx_metrics")]
 mod nginx_metrics;
 mod open;
+#[cfg(feature = "sinks-opentelemetry")]
+mod opentelemetry;
 mod parser;
+#[cfg(feature = "sinks-postgres")]
+mod postgres;
 #[cfg(feature = "sources-postgresql_metrics")]
 mod postgresql_metrics;
 mod process;
@@ -127,6 +131,8 @@ mod udp;
 mod unix;
 #[cfg(feature = "sinks-websocket")]
 mod websocket;
+#[cfg(feature = "sources-websocket_server")]
+mod websocket_server;
 
 #[cfg(any(
     feature = "sources-file",
@@ -217,18 +223,22 @@ pub(crate) use self::kubernetes_logs::*;
 pub(crate) use self::log_to_metric::*;
 #[cfg(feature = "sources-heroku_logs")]
 pub(crate) use self::logplex::*;
-#[cfg(feature = "sinks-loki")]
+#[cfg(any(feature = "sources-loki", feature = "sinks-loki"))]
 pub(crate) use self::loki::*;
 #[cfg(feature = "transforms-lua")]
 pub(crate) use self::lua::*;
 #[cfg(feature = "transforms-metric_to_log")]
 pub(crate) use self::metric_to_log::*;
-#[cfg(feature = "sinks-mqtt")]
+#[cfg(any(feature = "sources-mqtt", feature = "sinks-mqtt"))]
 pub(crate) use self::mqtt::*;
 #[cfg(feature = "sources-nginx_metrics")]
 pub(crate) use self::nginx_metrics::*;
+#[cfg(feature = "sinks-opentelemetry")]
+pub(crate) use self::opentelemetry::*;
 #[allow(unused_imports)]
 pub(crate) use self::parser::*;
+#[cfg(feature = "sinks-postgres")]
+pub(crate) use self::postgres::*;
 #[cfg(feature = "sources-postgresql_metrics")]
 pub(crate) use self::postgresql_metrics::*;
 #[cfg(any(
@@ -261,6 +271,8 @@ pub(crate) use self::throttle::*;
 pub(crate) use self::unix::*;
 #[cfg(feature = "sinks-websocket")]
 pub(crate) use self::websocket::*;
+#[cfg(feature = "sources-websocket_server")]
+pub(crate) use self::websocket_server::*;
 #[cfg(windows)]
 pub(crate) use self::windows::*;
 pub use self::{
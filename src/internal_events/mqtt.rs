@@ -1,15 +1,19 @@
 use std::fmt::Debug;
 
 use metrics::counter;
+#[cfg(feature = "sources-mqtt")]
+use rumqttc::ClientError;
 use rumqttc::ConnectionError;
 use vector_lib::internal_event::InternalEvent;
 use vector_lib::internal_event::{error_stage, error_type};
 
+#[cfg(feature = "sinks-mqtt")]
 #[derive(Debug)]
 pub struct MqttConnectionError {
     pub error: ConnectionError,
 }
 
+#[cfg(feature = "sinks-mqtt")]
 impl InternalEvent for MqttConnectionError {
     fn emit(self) {
         error!(
@@ -33,3 +37,96 @@ impl InternalEvent for MqttConnectionError {
         Some("MqttConnectionError")
     }
 }
+
+#[cfg(feature = "sources-mqtt")]
+#[derive(Debug)]
+pub struct MqttReceiveError {
+    pub error: ConnectionError,
+}
+
+#[cfg(feature = "sources-mqtt")]
+impl InternalEvent for MqttReceiveError {
+    fn emit(self) {
+        error!(
+            message = "MQTT connection error.",
+            error = %self.error,
+            error_code = "mqtt_connection_error",
+            error_type = error_type::CONNECTION_FAILED,
+            stage = error_stage::RECEIVING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total",
+            "error_code" => "mqtt_connection_error",
+            "error_type" => error_type::CONNECTION_FAILED,
+            "stage" => error_stage::RECEIVING,
+        )
+        .increment(1);
+    }
+
+    fn name(&self) -> Option<&'static str> {
+        Some("MqttReceiveError")
+    }
+}
+
+#[cfg(feature = "sources-mqtt")]
+#[derive(Debug)]
+pub struct MqttSubscribeError<'a> {
+    pub topic: &'a str,
+}
+
+#[cfg(feature = "sources-mqtt")]
+impl InternalEvent for MqttSubscribeError<'_> {
+    fn emit(self) {
+        error!(
+            message = "MQTT broker rejected the subscription.",
+            topic = %self.topic,
+            error_code = "mqtt_subscribe_rejected",
+            error_type = error_type::REQUEST_FAILED,
+            stage = error_stage::RECEIVING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total",
+            "error_code" => "mqtt_subscribe_rejected",
+            "error_type" => error_type::REQUEST_FAILED,
+            "stage" => error_stage::RECEIVING,
+        )
+        .increment(1);
+    }
+
+    fn name(&self) -> Option<&'static str> {
+        Some("MqttSubscribeError")
+    }
+}
+
+#[cfg(feature = "sources-mqtt")]
+#[derive(Debug)]
+pub struct MqttAckError {
+    pub error: ClientError,
+}
+
+#[cfg(feature = "sources-mqtt")]
+impl InternalEvent for MqttAckError {
+    fn emit(self) {
+        error!(
+            message = "Unable to acknowledge MQTT message.",
+            error = %self.error,
+            error_code = "mqtt_ack_error",
+            error_type = error_type::ACKNOWLEDGMENT_FAILED,
+            stage = error_stage::RECEIVING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total",
+            "error_code" => "mqtt_ack_error",
+            "error_type" => error_type::ACKNOWLEDGMENT_FAILED,
+            "stage" => error_stage::RECEIVING,
+        )
+        .increment(1);
+    }
+
+    fn name(&self) -> Option<&'static str> {
+        Some("MqttAckError")
+    }
+}
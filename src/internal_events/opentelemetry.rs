@@ -0,0 +1,33 @@
+use metrics::counter;
+use vector_lib::internal_event::{error_stage, error_type};
+use vector_lib::internal_event::{ComponentEventsDropped, InternalEvent, UNINTENTIONAL};
+
+#[derive(Debug)]
+pub struct OpenTelemetryUnsupportedMetricError {
+    pub value_type: &'static str,
+}
+
+impl InternalEvent for OpenTelemetryUnsupportedMetricError {
+    fn emit(self) {
+        let reason = "Metric value type is not supported by OTLP.";
+
+        error!(
+            message = reason,
+            value_type = self.value_type,
+            error_code = "unsupported_metric_value",
+            error_type = error_type::ENCODER_FAILED,
+            stage = error_stage::PROCESSING,
+            internal_log_rate_limit = true,
+        );
+
+        counter!(
+            "component_errors_total",
+            "error_code" => "unsupported_metric_value",
+            "error_type" => error_type::ENCODER_FAILED,
+            "stage" => error_stage::PROCESSING,
+        )
+        .increment(1);
+
+        emit!(ComponentEventsDropped::<UNINTENTIONAL> { count: 1, reason });
+    }
+}
@@ -0,0 +1,36 @@
+use metrics::counter;
+use tokio_postgres::Error as PgError;
+use vector_lib::internal_event::InternalEvent;
+use vector_lib::internal_event::{error_stage, error_type, ComponentEventsDropped, UNINTENTIONAL};
+
+#[derive(Debug)]
+pub struct PostgresRowRejected {
+    pub error: PgError,
+}
+
+impl InternalEvent for PostgresRowRejected {
+    fn emit(self) {
+        let reason = "Row rejected by PostgreSQL.";
+        let error_code = self
+            .error
+            .code()
+            .map_or("unknown", |code| code.code())
+            .to_string();
+        error!(
+            message = reason,
+            error = %self.error,
+            error_code = %error_code,
+            error_type = error_type::REQUEST_FAILED,
+            stage = error_stage::SENDING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total",
+            "error_code" => error_code,
+            "error_type" => error_type::REQUEST_FAILED,
+            "stage" => error_stage::SENDING,
+        )
+        .increment(1);
+        emit!(ComponentEventsDropped::<UNINTENTIONAL> { count: 1, reason });
+    }
+}
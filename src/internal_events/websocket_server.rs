@@ -0,0 +1,96 @@
+use std::net::SocketAddr;
+
+use metrics::counter;
+use tokio_tungstenite::tungstenite::Error as WsError;
+use vector_lib::internal_event::InternalEvent;
+
+use vector_lib::internal_event::{error_stage, error_type};
+
+#[derive(Debug)]
+pub struct WsServerHandshakeError {
+    pub error: WsError,
+    pub peer_addr: SocketAddr,
+}
+
+impl InternalEvent for WsServerHandshakeError {
+    fn emit(self) {
+        error!(
+            message = "WebSocket handshake failed.",
+            error = %self.error,
+            peer_addr = %self.peer_addr,
+            error_code = "handshake_failed",
+            error_type = error_type::CONNECTION_FAILED,
+            stage = error_stage::RECEIVING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total",
+            "error_code" => "handshake_failed",
+            "error_type" => error_type::CONNECTION_FAILED,
+            "stage" => error_stage::RECEIVING,
+        )
+        .increment(1);
+    }
+
+    fn name(&self) -> Option<&'static str> {
+        Some("WsServerHandshakeError")
+    }
+}
+
+#[derive(Debug)]
+pub struct WsServerReceiveError {
+    pub error: WsError,
+}
+
+impl InternalEvent for WsServerReceiveError {
+    fn emit(self) {
+        error!(
+            message = "Error receiving WebSocket message, dropping connection.",
+            error = %self.error,
+            error_code = "ws_receive_error",
+            error_type = error_type::READER_FAILED,
+            stage = error_stage::RECEIVING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total",
+            "error_code" => "ws_receive_error",
+            "error_type" => error_type::READER_FAILED,
+            "stage" => error_stage::RECEIVING,
+        )
+        .increment(1);
+    }
+
+    fn name(&self) -> Option<&'static str> {
+        Some("WsServerReceiveError")
+    }
+}
+
+#[derive(Debug)]
+pub struct WsServerSendError {
+    pub error: WsError,
+}
+
+impl InternalEvent for WsServerSendError {
+    fn emit(self) {
+        error!(
+            message = "Error writing acknowledgement, dropping connection.",
+            error = %self.error,
+            error_code = "ack_failed",
+            error_type = error_type::WRITER_FAILED,
+            stage = error_stage::SENDING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total",
+            "error_code" => "ack_failed",
+            "error_type" => error_type::WRITER_FAILED,
+            "stage" => error_stage::SENDING,
+        )
+        .increment(1);
+    }
+
+    fn name(&self) -> Option<&'static str> {
+        Some("WsServerSendError")
+    }
+}
@@ -1,7 +1,17 @@
 #![allow(missing_docs)]
-use std::path::{Path, PathBuf};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, LazyLock, Mutex,
+    },
+};
 
-use rdkafka::{consumer::ConsumerContext, ClientConfig, ClientContext, Statistics};
+use rdkafka::{
+    consumer::{ConsumerContext, ConsumerGroupMetadata},
+    ClientConfig, ClientContext, Statistics,
+};
 use snafu::Snafu;
 use tracing::Span;
 use vector_lib::configurable::configurable_component;
@@ -185,3 +195,79 @@ impl ClientContext for KafkaStatisticsContext {
 }
 
 impl ConsumerContext for KafkaStatisticsContext {}
+
+/// A consumer of a `kafka` source, with the function returning its current group metadata.
+struct RegisteredConsumer {
+    group_metadata: Box<dyn Fn() -> Option<ConsumerGroupMetadata> + Send + Sync>,
+    /// Whether a sink has committed offsets of the consumer in a transaction, after which the
+    /// source no longer stores the offsets of the events it consumed.
+    transactional: AtomicBool,
+}
+
+/// The consumers of the `kafka` sources, by source ID, so that sinks producing in transactions can
+/// commit the offsets of the events they consumed. Several sources can share a consumer group.
+static CONSUMERS: LazyLock<Mutex<HashMap<String, Arc<RegisteredConsumer>>>> =
+    LazyLock::new(Default::default);
+
+/// The consumer of a `kafka` source, registered until dropped.
+pub(crate) struct ConsumerRegistration {
+    source_id: Arc<str>,
+    consumer: Arc<RegisteredConsumer>,
+}
+
+impl ConsumerRegistration {
+    pub(crate) fn source_id(&self) -> &Arc<str> {
+        &self.source_id
+    }
+
+    /// Whether the offsets of the consumer are committed by a sink producing in transactions,
+    /// rather than by the source itself.
+    pub(crate) fn is_transactional(&self) -> bool {
+        self.consumer.transactional.load(Ordering::Acquire)
+    }
+}
+
+impl Drop for ConsumerRegistration {
+    fn drop(&mut self) {
+        let mut consumers = CONSUMERS.lock().expect("poisoned lock");
+        // A reloaded source registers its new consumer before the previous one is dropped.
+        if consumers
+            .get(self.source_id.as_ref())
+            .is_some_and(|registered| Arc::ptr_eq(registered, &self.consumer))
+        {
+            consumers.remove(self.source_id.as_ref());
+        }
+    }
+}
+
+/// Registers the consumer of a source, with the function returning its current group metadata.
+pub(crate) fn register_consumer(
+    source_id: &str,
+    group_metadata: impl Fn() -> Option<ConsumerGroupMetadata> + Send + Sync + 'static,
+) -> ConsumerRegistration {
+    let consumer = Arc::new(RegisteredConsumer {
+        group_metadata: Box::new(group_metadata),
+        transactional: AtomicBool::new(false),
+    });
+    CONSUMERS
+        .lock()
+        .expect("poisoned lock")
+        .insert(source_id.to_owned(), Arc::clone(&consumer));
+    ConsumerRegistration {
+        source_id: source_id.into(),
+        consumer,
+    }
+}
+
+/// Returns the group metadata of the consumer of the source, if it's still registered, for a sink
+/// to commit the offsets of the events it consumed in a transaction. The source stops storing
+/// offsets from then on, so that they aren't committed outside of transactions.
+pub(crate) fn transactional_group_metadata(source_id: &str) -> Option<ConsumerGroupMetadata> {
+    let consumer = CONSUMERS
+        .lock()
+        .expect("poisoned lock")
+        .get(source_id)
+        .cloned()?;
+    consumer.transactional.store(true, Ordering::Release);
+    (consumer.group_metadata)()
+}
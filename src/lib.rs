@@ -69,6 +69,8 @@ pub mod app;
 pub mod async_read;
 #[cfg(feature = "aws-config")]
 pub mod aws;
+#[cfg(any(feature = "sources-file", feature = "sources-kubernetes_logs"))]
+pub mod checkpoints;
 #[allow(unreachable_pub)]
 pub mod codecs;
 pub mod common;
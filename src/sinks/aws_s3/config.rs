@@ -1,7 +1,7 @@
 use aws_sdk_s3::Client as S3Client;
 use tower::ServiceBuilder;
 use vector_lib::codecs::{
-    encoding::{Framer, FramingConfig},
+    encoding::{BatchSerializerConfig, Framer, FramingConfig},
     TextSerializerConfig,
 };
 use vector_lib::configurable::configurable_component;
@@ -11,7 +11,7 @@ use vector_lib::TimeZone;
 use super::sink::S3RequestOptions;
 use crate::{
     aws::{AwsAuthentication, RegionOrEndpoint},
-    codecs::{Encoder, EncodingConfigWithFraming, SinkType},
+    codecs::{Encoder, EncoderKind, EncodingConfigWithFraming, SinkType},
     config::{AcknowledgementsConfig, GenerateConfig, Input, ProxyConfig, SinkConfig, SinkContext},
     sinks::{
         s3_common::{
@@ -90,7 +90,8 @@ pub struct S3SinkConfig {
 
     /// The filename extension to use in the object key.
     ///
-    /// This overrides setting the extension based on the configured `compression`.
+    /// This overrides setting the extension based on the configured `compression` or
+    /// `batch_encoding`.
     #[configurable(metadata(docs::examples = "json"))]
     pub filename_extension: Option<String>,
 
@@ -103,6 +104,17 @@ pub struct S3SinkConfig {
     #[serde(flatten)]
     pub encoding: EncodingConfigWithFraming,
 
+    /// Encoding of each batch of events as a whole.
+    ///
+    /// When set, each batch is written as a single object in this format, and events are not
+    /// serialized with the `encoding` codec. The other `encoding` options, such as `only_fields`,
+    /// still apply.
+    ///
+    /// The `compression` option is ignored, as the format handles compression itself.
+    #[configurable(derived)]
+    #[serde(default)]
+    pub batch_encoding: Option<BatchSerializerConfig>,
+
     /// Compression configuration.
     ///
     /// All compression algorithms use the default compression level unless otherwise specified.
@@ -160,6 +172,7 @@ impl GenerateConfig for S3SinkConfig {
             options: S3Options::default(),
             region: RegionOrEndpoint::default(),
             encoding: (None::<FramingConfig>, TextSerializerConfig::default()).into(),
+            batch_encoding: None,
             compression: Compression::gzip_default(),
             batch: BatchConfig::default(),
             request: TowerRequestConfig::default(),
@@ -183,7 +196,10 @@ impl SinkConfig for S3SinkConfig {
     }
 
     fn input(&self) -> Input {
-        Input::new(self.encoding.config().1.input_type())
+        match &self.batch_encoding {
+            Some(batch_encoding) => Input::new(batch_encoding.input_type()),
+            None => Input::new(self.encoding.config().1.input_type()),
+        }
     }
 
     fn acknowledgements(&self) -> &AcknowledgementsConfig {
@@ -227,17 +243,42 @@ impl S3SinkConfig {
         let partitioner = S3KeyPartitioner::new(key_prefix, ssekms_key_id, None);
 
         let transformer = self.encoding.transformer();
-        let (framer, serializer) = self.encoding.build(SinkType::MessageBased)?;
-        let encoder = Encoder::<Framer>::new(framer, serializer);
+        let mut api_options = self.options.clone();
+        let (encoder, compression, filename_extension) = match &self.batch_encoding {
+            Some(batch_encoding) => {
+                let serializer = batch_encoding.build()?;
+                let extension = self
+                    .filename_extension
+                    .clone()
+                    .unwrap_or_else(|| serializer.extension().to_owned());
+                api_options
+                    .content_type
+                    .get_or_insert_with(|| serializer.content_type().to_owned());
+                (
+                    EncoderKind::Batch(serializer),
+                    Compression::None,
+                    Some(extension),
+                )
+            }
+            None => {
+                let (framer, serializer) = self.encoding.build(SinkType::MessageBased)?;
+                let encoder = Encoder::<Framer>::new(framer, serializer);
+                (
+                    EncoderKind::Framed(Box::new(encoder)),
+                    self.compression,
+                    self.filename_extension.clone(),
+                )
+            }
+        };
 
         let request_options = S3RequestOptions {
             bucket: self.bucket.clone(),
-            api_options: self.options.clone(),
-            filename_extension: self.filename_extension.clone(),
+            api_options,
+            filename_extension,
             filename_time_format: self.filename_time_format.clone(),
             filename_append_uuid: self.filename_append_uuid,
             encoder: (transformer, encoder),
-            compression: self.compression,
+            compression,
             filename_tz_offset: offset,
         };
 
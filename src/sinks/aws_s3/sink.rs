@@ -3,13 +3,11 @@ use std::io;
 use bytes::Bytes;
 use chrono::{FixedOffset, Utc};
 use uuid::Uuid;
-use vector_lib::codecs::encoding::Framer;
 use vector_lib::event::Finalizable;
 use vector_lib::request_metadata::RequestMetadata;
 
 use crate::{
-    codecs::{Encoder, Transformer},
-    event::Event,
+    codecs::{EncoderKind, Transformer},
     sinks::{
         s3_common::{
             config::S3Options,
@@ -17,8 +15,8 @@ use crate::{
             service::{S3Metadata, S3Request},
         },
         util::{
-            metadata::RequestMetadataBuilder, request_builder::EncodeResult, Compression,
-            RequestBuilder,
+            encoding::ValidatedBatch, metadata::RequestMetadataBuilder,
+            request_builder::EncodeResult, Compression, RequestBuilder,
         },
     },
 };
@@ -30,15 +28,15 @@ pub struct S3RequestOptions {
     pub filename_append_uuid: bool,
     pub filename_extension: Option<String>,
     pub api_options: S3Options,
-    pub encoder: (Transformer, Encoder<Framer>),
+    pub encoder: (Transformer, EncoderKind),
     pub compression: Compression,
     pub filename_tz_offset: Option<FixedOffset>,
 }
 
-impl RequestBuilder<(S3PartitionKey, Vec<Event>)> for S3RequestOptions {
+impl RequestBuilder<(S3PartitionKey, ValidatedBatch)> for S3RequestOptions {
     type Metadata = S3Metadata;
-    type Events = Vec<Event>;
-    type Encoder = (Transformer, Encoder<Framer>);
+    type Events = ValidatedBatch;
+    type Encoder = (Transformer, EncoderKind);
     type Payload = Bytes;
     type Request = S3Request;
     type Error = io::Error; // TODO: this is ugly.
@@ -53,12 +51,12 @@ impl RequestBuilder<(S3PartitionKey, Vec<Event>)> for S3RequestOptions {
 
     fn split_input(
         &self,
-        input: (S3PartitionKey, Vec<Event>),
+        input: (S3PartitionKey, ValidatedBatch),
     ) -> (Self::Metadata, RequestMetadataBuilder, Self::Events) {
-        let (partition_key, mut events) = input;
-        let builder = RequestMetadataBuilder::from_events(&events);
+        let (partition_key, mut batch) = input;
+        let builder = RequestMetadataBuilder::from_events(&batch.events);
 
-        let finalizers = events.take_finalizers();
+        let finalizers = batch.events.take_finalizers();
         let s3_key_prefix = partition_key.key_prefix.clone();
 
         let metadata = S3Metadata {
@@ -67,7 +65,7 @@ impl RequestBuilder<(S3PartitionKey, Vec<Event>)> for S3RequestOptions {
             finalizers,
         };
 
-        (metadata, builder, events)
+        (metadata, builder, batch)
     }
 
     fn build_request(
@@ -1,18 +1,17 @@
 use bytes::Bytes;
 use chrono::Utc;
 use uuid::Uuid;
-use vector_lib::codecs::encoding::Framer;
 use vector_lib::request_metadata::RequestMetadata;
 use vector_lib::EstimatedJsonEncodedSizeOf;
 
 use crate::{
-    codecs::{Encoder, Transformer},
-    event::{Event, Finalizable},
+    codecs::{EncoderKind, Transformer},
+    event::Finalizable,
     sinks::{
         azure_common::config::{AzureBlobMetadata, AzureBlobRequest},
         util::{
-            metadata::RequestMetadataBuilder, request_builder::EncodeResult, Compression,
-            RequestBuilder,
+            encoding::ValidatedBatch, metadata::RequestMetadataBuilder,
+            request_builder::EncodeResult, Compression, RequestBuilder,
         },
     },
 };
@@ -22,14 +21,14 @@ pub struct AzureBlobRequestOptions {
     pub container_name: String,
     pub blob_time_format: String,
     pub blob_append_uuid: bool,
-    pub encoder: (Transformer, Encoder<Framer>),
+    pub encoder: (Transformer, EncoderKind),
     pub compression: Compression,
 }
 
-impl RequestBuilder<(String, Vec<Event>)> for AzureBlobRequestOptions {
+impl RequestBuilder<(String, ValidatedBatch)> for AzureBlobRequestOptions {
     type Metadata = AzureBlobMetadata;
-    type Events = Vec<Event>;
-    type Encoder = (Transformer, Encoder<Framer>);
+    type Events = ValidatedBatch;
+    type Encoder = (Transformer, EncoderKind);
     type Payload = Bytes;
     type Request = AzureBlobRequest;
     type Error = std::io::Error;
@@ -44,20 +43,20 @@ impl RequestBuilder<(String, Vec<Event>)> for AzureBlobRequestOptions {
 
     fn split_input(
         &self,
-        input: (String, Vec<Event>),
+        input: (String, ValidatedBatch),
     ) -> (Self::Metadata, RequestMetadataBuilder, Self::Events) {
-        let (partition_key, mut events) = input;
-        let finalizers = events.take_finalizers();
+        let (partition_key, mut batch) = input;
+        let finalizers = batch.events.take_finalizers();
         let azure_metadata = AzureBlobMetadata {
             partition_key,
-            count: events.len(),
-            byte_size: events.estimated_json_encoded_size_of(),
+            count: batch.events.len(),
+            byte_size: batch.events.estimated_json_encoded_size_of(),
             finalizers,
         };
 
-        let builder = RequestMetadataBuilder::from_events(&events);
+        let builder = RequestMetadataBuilder::from_events(&batch.events);
 
-        (azure_metadata, builder, events)
+        (azure_metadata, builder, batch)
     }
 
     fn build_request(
@@ -74,7 +73,11 @@ impl RequestBuilder<(String, Vec<Event>)> for AzureBlobRequestOptions {
                 .unwrap_or_else(|| formatted_ts.to_string())
         };
 
-        let extension = self.compression.extension();
+        let extension = self
+            .encoder
+            .1
+            .extension()
+            .unwrap_or_else(|| self.compression.extension());
         azure_metadata.partition_key = format!(
             "{}{}.{}",
             azure_metadata.partition_key, blob_name, extension
@@ -1,7 +1,7 @@
 use bytes::Bytes;
 use chrono::Utc;
 use vector_lib::codecs::{
-    encoding::{Framer, FramingConfig},
+    encoding::{BatchSerializerConfig, Framer, FramingConfig, ParquetSerializerConfig},
     NewlineDelimitedEncoder, TextSerializerConfig,
 };
 use vector_lib::request_metadata::GroupedCountByteSize;
@@ -11,8 +11,11 @@ use super::config::AzureBlobSinkConfig;
 use super::request_builder::AzureBlobRequestOptions;
 use crate::codecs::EncodingConfigWithFraming;
 use crate::event::{Event, LogEvent};
-use crate::sinks::util::{request_builder::RequestBuilder, Compression};
-use crate::{codecs::Encoder, sinks::util::request_builder::EncodeResult};
+use crate::sinks::util::{encoding::ValidatedBatch, request_builder::RequestBuilder, Compression};
+use crate::{
+    codecs::{Encoder, EncoderKind},
+    sinks::util::request_builder::EncodeResult,
+};
 
 fn default_config(encoding: EncodingConfigWithFraming) -> AzureBlobSinkConfig {
     AzureBlobSinkConfig {
@@ -24,6 +27,7 @@ fn default_config(encoding: EncodingConfigWithFraming) -> AzureBlobSinkConfig {
         blob_time_format: Default::default(),
         blob_append_uuid: Default::default(),
         encoding,
+        batch_encoding: None,
         compression: Compression::gzip_default(),
         batch: Default::default(),
         request: Default::default(),
@@ -61,10 +65,10 @@ fn azure_blob_build_request_without_compression() {
         blob_append_uuid,
         encoder: (
             Default::default(),
-            Encoder::<Framer>::new(
+            EncoderKind::Framed(Box::new(Encoder::<Framer>::new(
                 NewlineDelimitedEncoder::default().into(),
                 TextSerializerConfig::default().build().into(),
-            ),
+            ))),
         ),
         compression,
     };
@@ -72,8 +76,8 @@ fn azure_blob_build_request_without_compression() {
     let mut byte_size = GroupedCountByteSize::new_untagged();
     byte_size.add_event(&log, log.estimated_json_encoded_size_of());
 
-    let (metadata, request_metadata_builder, _events) =
-        request_options.split_input((key, vec![log]));
+    let batch = ValidatedBatch::new(request_options.encoder(), vec![log]);
+    let (metadata, request_metadata_builder, _events) = request_options.split_input((key, batch));
 
     let payload = EncodeResult::uncompressed(Bytes::new(), byte_size);
     let request_metadata = request_metadata_builder.build(&payload);
@@ -109,10 +113,10 @@ fn azure_blob_build_request_with_compression() {
         blob_append_uuid,
         encoder: (
             Default::default(),
-            Encoder::<Framer>::new(
+            EncoderKind::Framed(Box::new(Encoder::<Framer>::new(
                 NewlineDelimitedEncoder::default().into(),
                 TextSerializerConfig::default().build().into(),
-            ),
+            ))),
         ),
         compression,
     };
@@ -120,8 +124,8 @@ fn azure_blob_build_request_with_compression() {
     let mut byte_size = GroupedCountByteSize::new_untagged();
     byte_size.add_event(&log, log.estimated_json_encoded_size_of());
 
-    let (metadata, request_metadata_builder, _events) =
-        request_options.split_input((key, vec![log]));
+    let batch = ValidatedBatch::new(request_options.encoder(), vec![log]);
+    let (metadata, request_metadata_builder, _events) = request_options.split_input((key, batch));
 
     let payload = EncodeResult::uncompressed(Bytes::new(), byte_size);
     let request_metadata = request_metadata_builder.build(&payload);
@@ -157,10 +161,10 @@ fn azure_blob_build_request_with_time_format() {
         blob_append_uuid,
         encoder: (
             Default::default(),
-            Encoder::<Framer>::new(
+            EncoderKind::Framed(Box::new(Encoder::<Framer>::new(
                 NewlineDelimitedEncoder::default().into(),
                 TextSerializerConfig::default().build().into(),
-            ),
+            ))),
         ),
         compression,
     };
@@ -168,8 +172,8 @@ fn azure_blob_build_request_with_time_format() {
     let mut byte_size = GroupedCountByteSize::new_untagged();
     byte_size.add_event(&log, log.estimated_json_encoded_size_of());
 
-    let (metadata, request_metadata_builder, _events) =
-        request_options.split_input((key, vec![log]));
+    let batch = ValidatedBatch::new(request_options.encoder(), vec![log]);
+    let (metadata, request_metadata_builder, _events) = request_options.split_input((key, batch));
 
     let payload = EncodeResult::uncompressed(Bytes::new(), byte_size);
     let request_metadata = request_metadata_builder.build(&payload);
@@ -208,10 +212,10 @@ fn azure_blob_build_request_with_uuid() {
         blob_append_uuid,
         encoder: (
             Default::default(),
-            Encoder::<Framer>::new(
+            EncoderKind::Framed(Box::new(Encoder::<Framer>::new(
                 NewlineDelimitedEncoder::default().into(),
                 TextSerializerConfig::default().build().into(),
-            ),
+            ))),
         ),
         compression,
     };
@@ -219,8 +223,8 @@ fn azure_blob_build_request_with_uuid() {
     let mut byte_size = GroupedCountByteSize::new_untagged();
     byte_size.add_event(&log, log.estimated_json_encoded_size_of());
 
-    let (metadata, request_metadata_builder, _events) =
-        request_options.split_input((key, vec![log]));
+    let batch = ValidatedBatch::new(request_options.encoder(), vec![log]);
+    let (metadata, request_metadata_builder, _events) = request_options.split_input((key, batch));
 
     let payload = EncodeResult::uncompressed(Bytes::new(), byte_size);
     let request_metadata = request_metadata_builder.build(&payload);
@@ -230,3 +234,46 @@ fn azure_blob_build_request_with_uuid() {
     assert_eq!(request.content_encoding, None);
     assert_eq!(request.content_type, "text/plain");
 }
+
+#[test]
+fn azure_blob_build_request_with_parquet() {
+    let log = Event::Log(LogEvent::from("test message"));
+    let container_name = String::from("logs");
+    let sink_config = AzureBlobSinkConfig {
+        blob_prefix: "blob".try_into().unwrap(),
+        container_name: container_name.clone(),
+        ..default_config((None::<FramingConfig>, TextSerializerConfig::default()).into())
+    };
+
+    let key = sink_config
+        .key_partitioner()
+        .unwrap()
+        .partition(&log)
+        .expect("key wasn't provided");
+
+    let batch_encoding = BatchSerializerConfig::from(ParquetSerializerConfig::default());
+    let request_options = AzureBlobRequestOptions {
+        container_name,
+        blob_time_format: String::from(""),
+        blob_append_uuid: false,
+        encoder: (
+            Default::default(),
+            EncoderKind::Batch(batch_encoding.build().unwrap()),
+        ),
+        compression: Compression::None,
+    };
+
+    let mut byte_size = GroupedCountByteSize::new_untagged();
+    byte_size.add_event(&log, log.estimated_json_encoded_size_of());
+
+    let batch = ValidatedBatch::new(request_options.encoder(), vec![log]);
+    let (metadata, request_metadata_builder, _events) = request_options.split_input((key, batch));
+
+    let payload = EncodeResult::uncompressed(Bytes::new(), byte_size);
+    let request_metadata = request_metadata_builder.build(&payload);
+    let request = request_options.build_request(metadata, request_metadata, payload);
+
+    assert_eq!(request.metadata.partition_key, "blob.parquet".to_string());
+    assert_eq!(request.content_encoding, None);
+    assert_eq!(request.content_type, "application/vnd.apache.parquet");
+}
@@ -290,25 +290,25 @@ impl DatadogMetricsEncoder {
             },
             // Sketches are encoded via ProtoBuf, also in an incremental fashion.
             DatadogMetricsEndpoint::Sketches => match metric.value() {
-                MetricValue::Sketch { sketch } => match sketch {
-                    MetricSketch::AgentDDSketch(ddsketch) => {
-                        if let Some(sketch_proto) = sketch_to_proto_message(
-                            &metric,
-                            ddsketch,
-                            &self.default_namespace,
-                            self.log_schema,
-                            self.origin_product_value,
-                        ) {
-                            encode_proto_key_and_message(
-                                sketch_proto,
-                                get_sketch_payload_sketches_field_number(),
-                                &mut self.state.buf,
-                            )?;
-                        } else {
-                            // If the sketch was empty, that's fine too
-                        }
+                MetricValue::Sketch {
+                    sketch: MetricSketch::AgentDDSketch(ddsketch),
+                } => {
+                    if let Some(sketch_proto) = sketch_to_proto_message(
+                        &metric,
+                        ddsketch,
+                        &self.default_namespace,
+                        self.log_schema,
+                        self.origin_product_value,
+                    ) {
+                        encode_proto_key_and_message(
+                            sketch_proto,
+                            get_sketch_payload_sketches_field_number(),
+                            &mut self.state.buf,
+                        )?;
+                    } else {
+                        // If the sketch was empty, that's fine too
                     }
-                },
+                }
                 value => {
                     return Err(EncoderError::InvalidMetric {
                         expected: "sketches",
@@ -1101,17 +1101,16 @@ mod tests {
     {
         let mut sketches = Vec::new();
         for metric in metrics {
-            let MetricValue::Sketch { sketch } = metric.value() else {
+            let MetricValue::Sketch {
+                sketch: MetricSketch::AgentDDSketch(ddsketch),
+            } = metric.value()
+            else {
                 panic!("must be sketch")
             };
-            match sketch {
-                MetricSketch::AgentDDSketch(ddsketch) => {
-                    if let Some(sketch) =
-                        sketch_to_proto_message(metric, ddsketch, default_namespace, log_schema, 14)
-                    {
-                        sketches.push(sketch);
-                    }
-                }
+            if let Some(sketch) =
+                sketch_to_proto_message(metric, ddsketch, default_namespace, log_schema, 14)
+            {
+                sketches.push(sketch);
             }
         }
 
@@ -1488,20 +1487,20 @@ mod tests {
         let mut incremental_buf = Vec::new();
         for metric in &metrics {
             match metric.value() {
-                MetricValue::Sketch { sketch } => match sketch {
-                    MetricSketch::AgentDDSketch(ddsketch) => {
-                        if let Some(sketch_proto) =
-                            sketch_to_proto_message(metric, ddsketch, &None, log_schema(), 14)
-                        {
-                            encode_proto_key_and_message(
-                                sketch_proto,
-                                get_sketch_payload_sketches_field_number(),
-                                &mut incremental_buf,
-                            )
-                            .unwrap();
-                        }
+                MetricValue::Sketch {
+                    sketch: MetricSketch::AgentDDSketch(ddsketch),
+                } => {
+                    if let Some(sketch_proto) =
+                        sketch_to_proto_message(metric, ddsketch, &None, log_schema(), 14)
+                    {
+                        encode_proto_key_and_message(
+                            sketch_proto,
+                            get_sketch_payload_sketches_field_number(),
+                            &mut incremental_buf,
+                        )
+                        .unwrap();
                     }
-                },
+                }
                 _ => panic!("should be a sketch"),
             }
         }
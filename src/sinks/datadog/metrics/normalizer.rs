@@ -1,5 +1,5 @@
 use vector_lib::{
-    event::{Metric, MetricValue},
+    event::{metric::MetricSketch, Metric, MetricValue},
     metrics::AgentDDSketch,
 };
 
@@ -23,7 +23,10 @@ impl MetricNormalize for DatadogMetricsNormalizer {
                 .make_incremental(metric)
                 .filter(|metric| !metric.value().is_empty())
                 .and_then(|metric| AgentDDSketch::transform_to_sketch(metric).ok()),
-            MetricValue::AggregatedHistogram { .. } => state
+            MetricValue::AggregatedHistogram { .. }
+            | MetricValue::Sketch {
+                sketch: MetricSketch::NativeHistogram(_),
+            } => state
                 .make_incremental(metric)
                 .filter(|metric| !metric.value().is_empty())
                 .and_then(|metric| AgentDDSketch::transform_to_sketch(metric).ok()),
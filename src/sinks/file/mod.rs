@@ -38,8 +38,11 @@ use crate::{
 };
 
 mod bytes_path;
+mod rotation;
 
 use bytes_path::BytesPath;
+use rotation::RotationState;
+pub use rotation::{RotationConfig, RotationSuffix};
 
 /// Configuration for the `file` sink.
 #[serde_as]
@@ -89,6 +92,10 @@ pub struct FileSinkConfig {
     #[configurable(derived)]
     #[serde(default)]
     pub internal_metrics: FileInternalMetricsConfig,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub rotation: Option<RotationConfig>,
 }
 
 impl GenerateConfig for FileSinkConfig {
@@ -101,6 +108,7 @@ impl GenerateConfig for FileSinkConfig {
             acknowledgements: Default::default(),
             timezone: Default::default(),
             internal_metrics: Default::default(),
+            rotation: Default::default(),
         })
         .unwrap()
     }
@@ -132,6 +140,17 @@ pub enum Compression {
     None,
 }
 
+impl Compression {
+    /// The extension of the files compressed with this format.
+    const fn extension(self) -> Option<&'static str> {
+        match self {
+            Compression::Gzip => Some("gz"),
+            Compression::Zstd => Some("zst"),
+            Compression::None => None,
+        }
+    }
+}
+
 enum OutFile {
     Regular(File),
     Gzip(GzipEncoder<File>),
@@ -179,6 +198,18 @@ impl OutFile {
     }
 }
 
+/// A file open in the sink, along with what its rotation policies are checked against.
+struct OpenFile {
+    out: OutFile,
+    rotation: RotationState,
+}
+
+impl OpenFile {
+    async fn close(&mut self) -> Result<(), std::io::Error> {
+        self.out.close().await
+    }
+}
+
 #[async_trait::async_trait]
 #[typetag::serde(name = "file")]
 impl SinkConfig for FileSinkConfig {
@@ -207,8 +238,9 @@ pub struct FileSink {
     transformer: Transformer,
     encoder: Encoder<Framer>,
     idle_timeout: Duration,
-    files: ExpiringHashMap<Bytes, OutFile>,
+    files: ExpiringHashMap<Bytes, OpenFile>,
     compression: Compression,
+    rotation: Option<RotationConfig>,
     events_sent: Registered<EventsSent>,
     include_file_metric_tag: bool,
 }
@@ -231,6 +263,7 @@ impl FileSink {
             idle_timeout: config.idle_timeout,
             files: ExpiringHashMap::default(),
             compression: config.compression,
+            rotation: config.rotation.clone(),
             events_sent: register!(EventsSent::from(Output(None))),
             include_file_metric_tag: config.internal_metrics.include_file_tag,
         })
@@ -337,6 +370,8 @@ impl FileSink {
             }
         };
 
+        self.rotate_if_due(&path).await;
+
         let next_deadline = self.deadline_at();
         trace!(message = "Computed next deadline.", next_deadline = ?next_deadline, path = ?path);
 
@@ -345,7 +380,7 @@ impl FileSink {
             file
         } else {
             trace!(message = "Opening new file.", ?path);
-            let file = match open_file(BytesPath::new(path.clone())).await {
+            let file = match self.open(&path).await {
                 Ok(file) => file,
                 Err(error) => {
                     // We couldn't open the file for this event.
@@ -363,9 +398,7 @@ impl FileSink {
                 }
             };
 
-            let outfile = OutFile::new(file, self.compression);
-
-            self.files.insert_at(path.clone(), outfile, next_deadline);
+            self.files.insert_at(path.clone(), file, next_deadline);
             emit!(FileOpen {
                 count: self.files.len()
             });
@@ -375,8 +408,10 @@ impl FileSink {
         trace!(message = "Writing an event to file.", path = ?path);
         let event_size = event.estimated_json_encoded_size_of();
         let finalizers = event.take_finalizers();
-        match write_event_to_file(file, event, &self.transformer, &mut self.encoder).await {
+        match write_event_to_file(&mut file.out, event, &self.transformer, &mut self.encoder).await
+        {
             Ok(byte_size) => {
+                file.rotation.record_write(byte_size);
                 finalizers.update_status(EventStatus::Delivered);
                 self.events_sent.emit(CountByteSize(1, event_size));
                 emit!(FileBytesSent {
@@ -397,6 +432,71 @@ impl FileSink {
             }
         }
     }
+
+    /// Opens the file at `path`, first rotating the file left there if it is due for rotation.
+    async fn open(&self, path: &Bytes) -> std::io::Result<OpenFile> {
+        let mut file = open_file(BytesPath::new(path.clone())).await?;
+        let mut rotation = RotationState::new(&file.metadata().await?);
+
+        if let Some(config) = &self.rotation {
+            if config.is_due(&rotation) {
+                drop(file);
+                self.rotate(config, path).await;
+                file = open_file(BytesPath::new(path.clone())).await?;
+                rotation = RotationState::new(&file.metadata().await?);
+            }
+        }
+
+        Ok(OpenFile {
+            out: OutFile::new(file, self.compression),
+            rotation,
+        })
+    }
+
+    /// Closes and rotates the file open at `path` if it is due for rotation.
+    async fn rotate_if_due(&mut self, path: &Bytes) {
+        let Some(config) = &self.rotation else {
+            return;
+        };
+        if !self
+            .files
+            .get(path)
+            .is_some_and(|file| config.is_due(&file.rotation))
+        {
+            return;
+        }
+
+        let (mut file, _) = self.files.remove(path).expect("file is open");
+        // Closing the file also finishes its compression, if any.
+        if let Err(error) = file.close().await {
+            emit!(FileIoError {
+                error,
+                code: "failed_closing_file",
+                message: "Failed to close file.",
+                path,
+                dropped_events: 0,
+            });
+        }
+        drop(file);
+        emit!(FileOpen {
+            count: self.files.len()
+        });
+
+        self.rotate(config, path).await;
+    }
+
+    async fn rotate(&self, config: &RotationConfig, path: &Bytes) {
+        match config.rotate(path, self.compression).await {
+            Ok(rotated) => debug!(message = "Rotated file.", ?path, ?rotated),
+            Err(error) => emit!(FileIoError {
+                error,
+                code: "failed_rotating_file",
+                message: "Failed to rotate file.",
+                path,
+                dropped_events: 0,
+            }),
+        }
+    }
 }
 
 async fn open_file(path: impl AsRef<std::path::Path>) -> std::io::Result<File> {
@@ -441,7 +541,10 @@ impl StreamSink<Event> for FileSink {
 
 #[cfg(test)]
 mod tests {
-    use std::convert::TryInto;
+    use std::{
+        convert::TryInto,
+        num::{NonZeroU64, NonZeroUsize},
+    };
 
     use futures::{stream, SinkExt};
     use similar_asserts::assert_eq;
@@ -476,6 +579,7 @@ mod tests {
             internal_metrics: FileInternalMetricsConfig {
                 include_file_tag: true,
             },
+            rotation: None,
         };
 
         let (input, _events) = random_lines_with_stream(100, 64, None);
@@ -502,6 +606,7 @@ mod tests {
             internal_metrics: FileInternalMetricsConfig {
                 include_file_tag: true,
             },
+            rotation: None,
         };
 
         let (input, _) = random_lines_with_stream(100, 64, None);
@@ -528,6 +633,7 @@ mod tests {
             internal_metrics: FileInternalMetricsConfig {
                 include_file_tag: true,
             },
+            rotation: None,
         };
 
         let (input, _) = random_lines_with_stream(100, 64, None);
@@ -559,6 +665,7 @@ mod tests {
             internal_metrics: FileInternalMetricsConfig {
                 include_file_tag: true,
             },
+            rotation: None,
         };
 
         let (mut input, _events) = random_events_with_stream(32, 8, None);
@@ -625,6 +732,120 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn rotation_by_size() {
+        let directory = temp_dir();
+        let path = directory.join("rotated.log");
+
+        let config = FileSinkConfig {
+            path: path.to_str().unwrap().try_into().unwrap(),
+            idle_timeout: default_idle_timeout(),
+            encoding: (None::<FramingConfig>, TextSerializerConfig::default()).into(),
+            compression: Compression::None,
+            acknowledgements: Default::default(),
+            timezone: Default::default(),
+            internal_metrics: FileInternalMetricsConfig {
+                include_file_tag: true,
+            },
+            rotation: Some(RotationConfig {
+                max_bytes: NonZeroU64::new(20),
+                max_files: NonZeroUsize::new(2),
+                ..Default::default()
+            }),
+        };
+
+        // Each line is 11 bytes long once framed, so every file holds two lines.
+        let input = (0..8).map(|i| format!("line {i:05}")).collect::<Vec<_>>();
+
+        run_assert_log_sink(config, input.clone()).await;
+
+        // The oldest rotated file was removed.
+        assert!(!directory.join("rotated.log.1").exists());
+        assert_eq!(
+            lines_from_file(directory.join("rotated.log.2")),
+            &input[2..4]
+        );
+        assert_eq!(
+            lines_from_file(directory.join("rotated.log.3")),
+            &input[4..6]
+        );
+        assert_eq!(lines_from_file(&path), &input[6..8]);
+    }
+
+    #[tokio::test]
+    async fn rotation_retention_numeric_suffix() {
+        let directory = temp_dir();
+        let path = directory.join("rotated.log");
+
+        let config = FileSinkConfig {
+            path: path.to_str().unwrap().try_into().unwrap(),
+            idle_timeout: default_idle_timeout(),
+            encoding: (None::<FramingConfig>, TextSerializerConfig::default()).into(),
+            compression: Compression::None,
+            acknowledgements: Default::default(),
+            timezone: Default::default(),
+            internal_metrics: FileInternalMetricsConfig {
+                include_file_tag: true,
+            },
+            rotation: Some(RotationConfig {
+                max_bytes: NonZeroU64::new(1),
+                max_files: NonZeroUsize::new(2),
+                ..Default::default()
+            }),
+        };
+
+        let input = (0..12).map(|i| format!("line {i:05}")).collect::<Vec<_>>();
+
+        run_assert_log_sink(config, input.clone()).await;
+
+        // `.10` and `.11` are the most recent rotated files, even though they sort before `.2`.
+        for sequence in 1..10 {
+            assert!(!directory.join(format!("rotated.log.{sequence}")).exists());
+        }
+        assert_eq!(
+            lines_from_file(directory.join("rotated.log.10")),
+            &input[9..10]
+        );
+        assert_eq!(
+            lines_from_file(directory.join("rotated.log.11")),
+            &input[10..11]
+        );
+        assert_eq!(lines_from_file(&path), &input[11..]);
+    }
+
+    #[tokio::test]
+    async fn rotation_gzip_suffix() {
+        let directory = temp_dir();
+        let path = directory.join("rotated.log.gz");
+
+        let config = FileSinkConfig {
+            path: path.to_str().unwrap().try_into().unwrap(),
+            idle_timeout: default_idle_timeout(),
+            encoding: (None::<FramingConfig>, TextSerializerConfig::default()).into(),
+            compression: Compression::Gzip,
+            acknowledgements: Default::default(),
+            timezone: Default::default(),
+            internal_metrics: FileInternalMetricsConfig {
+                include_file_tag: true,
+            },
+            rotation: Some(RotationConfig {
+                max_bytes: NonZeroU64::new(1),
+                ..Default::default()
+            }),
+        };
+
+        let input = vec!["first".to_owned(), "second".to_owned()];
+
+        run_assert_log_sink(config, input.clone()).await;
+
+        // The rotated file was closed, so it holds a complete gzip stream.
+        assert_eq!(
+            lines_from_gzip_file(directory.join("rotated.log.1.gz")),
+            &input[..1]
+        );
+        assert_eq!(lines_from_gzip_file(&path), &input[1..]);
+    }
+
     #[tokio::test]
     async fn reopening() {
         trace_init();
@@ -641,6 +862,7 @@ mod tests {
             internal_metrics: FileInternalMetricsConfig {
                 include_file_tag: true,
             },
+            rotation: None,
         };
 
         let (mut input, _events) = random_lines_with_stream(10, 64, None);
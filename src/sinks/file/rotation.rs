@@ -0,0 +1,285 @@
+use std::{
+    io,
+    num::{NonZeroU64, NonZeroUsize},
+    path::PathBuf,
+    time::{Duration, SystemTime},
+};
+
+use bytes::Bytes;
+use serde_with::serde_as;
+use tokio::fs;
+use vector_lib::configurable::configurable_component;
+
+use super::Compression;
+
+/// Rotation and retention configuration.
+///
+/// A file is rotated by renaming it with a suffix, so that the next events written to its path go
+/// to a new file. Files are rotated before the next event is written to them, so a file that
+/// doesn't receive any more events is left as is.
+#[serde_as]
+#[configurable_component]
+#[derive(Clone, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct RotationConfig {
+    /// The size of a file, in bytes, at which it is rotated.
+    ///
+    /// The size is counted before compression, starting from the size of the file when the sink
+    /// opens it.
+    #[configurable(metadata(docs::examples = 104857600))]
+    #[configurable(metadata(docs::type_unit = "bytes"))]
+    pub max_bytes: Option<NonZeroU64>,
+
+    /// The amount of time, in seconds, that the sink writes to a file before rotating it.
+    ///
+    /// The age of a file is counted from when it was created, or, where the file system doesn't
+    /// record creation times, from when it was last modified before the sink opened it.
+    #[serde_as(as = "Option<serde_with::DurationSeconds<u64>>")]
+    #[serde(rename = "max_age_secs")]
+    #[configurable(metadata(docs::examples = 3600))]
+    #[configurable(metadata(docs::human_name = "Max Age"))]
+    pub max_age: Option<Duration>,
+
+    /// The interval, in seconds, at which files are rotated.
+    ///
+    /// Intervals are aligned to the Unix epoch, so an interval of `3600` rotates files at the top
+    /// of every hour. A file last modified in an earlier interval, such as one left by a previous
+    /// run of Vector, is rotated before any event is written to it.
+    #[serde_as(as = "Option<serde_with::DurationSeconds<u64>>")]
+    #[serde(rename = "interval_secs")]
+    #[configurable(metadata(docs::examples = 86400))]
+    #[configurable(metadata(docs::human_name = "Interval"))]
+    pub interval: Option<Duration>,
+
+    #[configurable(derived)]
+    #[serde(default, skip_serializing_if = "crate::serde::is_default")]
+    pub suffix: RotationSuffix,
+
+    /// The number of rotated files to keep for each path.
+    ///
+    /// When exceeded, the least recently modified files are removed.
+    #[configurable(metadata(docs::examples = 10))]
+    pub max_files: Option<NonZeroUsize>,
+
+    /// The amount of time, in seconds, after which rotated files are removed.
+    ///
+    /// The age of a rotated file is counted from when it was last modified.
+    #[serde_as(as = "Option<serde_with::DurationSeconds<u64>>")]
+    #[serde(rename = "max_file_age_secs")]
+    #[configurable(metadata(docs::examples = 604800))]
+    #[configurable(metadata(docs::human_name = "Max File Age"))]
+    pub max_file_age: Option<Duration>,
+}
+
+/// The suffix appended to the path of rotated files.
+///
+/// The suffix is inserted before the compression extension of the path, if any, so that
+/// `/tmp/app.log.gz` is rotated to `/tmp/app.log.1.gz`.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RotationSuffix {
+    /// A sequence number, incremented with each rotation of the path.
+    ///
+    /// For example, `/tmp/app.log` is rotated to `/tmp/app.log.1`, then to `/tmp/app.log.2`.
+    #[default]
+    Sequence,
+
+    /// The time of the rotation in UTC, formatted as `%Y%m%d%H%M%S`.
+    ///
+    /// A sequence number is appended when the path was already rotated within the same second,
+    /// such as `/tmp/app.log.20241017120000.1`.
+    Timestamp,
+}
+
+/// What the rotation policies of an open file are checked against.
+pub(super) struct RotationState {
+    created: SystemTime,
+    modified: SystemTime,
+    written: u64,
+}
+
+impl RotationState {
+    pub(super) fn new(metadata: &std::fs::Metadata) -> Self {
+        let modified = metadata.modified().unwrap_or_else(|_| SystemTime::now());
+        Self {
+            created: metadata.created().unwrap_or(modified),
+            modified,
+            written: metadata.len(),
+        }
+    }
+
+    pub(super) fn record_write(&mut self, byte_size: usize) {
+        self.modified = SystemTime::now();
+        self.written += byte_size as u64;
+    }
+}
+
+/// A rotated file of a path.
+struct RotatedFile {
+    path: PathBuf,
+    suffix: String,
+    modified: SystemTime,
+}
+
+impl RotationConfig {
+    /// Whether the file must be rotated before the next event is written to it.
+    pub(super) fn is_due(&self, state: &RotationState) -> bool {
+        if state.written == 0 {
+            return false;
+        }
+
+        self.max_bytes
+            .is_some_and(|max_bytes| state.written >= max_bytes.get())
+            || self.max_age.is_some_and(|max_age| {
+                SystemTime::now()
+                    .duration_since(state.created)
+                    .is_ok_and(|age| age >= max_age)
+            })
+            || self.interval.is_some_and(|interval| {
+                interval_index(state.modified, interval)
+                    < interval_index(SystemTime::now(), interval)
+            })
+    }
+
+    /// Renames the closed file at `path` with the configured suffix, then removes the rotated
+    /// files of `path` that are no longer retained.
+    ///
+    /// Returns the path of the rotated file.
+    pub(super) async fn rotate(
+        &self,
+        path: &Bytes,
+        compression: Compression,
+    ) -> io::Result<PathBuf> {
+        let path = std::str::from_utf8(path)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))?;
+        let (stem, extension) = split_extension(path, compression);
+        let rotated = rotated_files(stem, extension).await?;
+
+        let target = match self.suffix {
+            RotationSuffix::Sequence => {
+                let sequence = rotated
+                    .iter()
+                    .filter_map(|file| file.suffix.parse::<u64>().ok())
+                    .max()
+                    .unwrap_or(0);
+                PathBuf::from(format!("{stem}.{}{extension}", sequence + 1))
+            }
+            RotationSuffix::Timestamp => {
+                let timestamp = chrono::Utc::now().format("%Y%m%d%H%M%S");
+                let mut target = PathBuf::from(format!("{stem}.{timestamp}{extension}"));
+                let mut sequence = 0;
+                while fs::try_exists(&target).await? {
+                    sequence += 1;
+                    target = PathBuf::from(format!("{stem}.{timestamp}.{sequence}{extension}"));
+                }
+                target
+            }
+        };
+
+        fs::rename(path, &target).await?;
+        self.apply_retention(stem, extension).await?;
+        Ok(target)
+    }
+
+    async fn apply_retention(&self, stem: &str, extension: &str) -> io::Result<()> {
+        if self.max_files.is_none() && self.max_file_age.is_none() {
+            return Ok(());
+        }
+
+        let mut rotated = rotated_files(stem, extension).await?;
+        rotated.sort_by(|a, b| {
+            a.modified
+                .cmp(&b.modified)
+                .then_with(|| suffix_order(&a.suffix).cmp(&suffix_order(&b.suffix)))
+        });
+
+        let excess = self
+            .max_files
+            .map_or(0, |max_files| rotated.len().saturating_sub(max_files.get()));
+        let now = SystemTime::now();
+        for (index, file) in rotated.iter().enumerate() {
+            let expired = self.max_file_age.is_some_and(|max_file_age| {
+                now.duration_since(file.modified)
+                    .is_ok_and(|age| age > max_file_age)
+            });
+            if index < excess || expired {
+                debug!(message = "Removing rotated file.", path = ?file.path);
+                fs::remove_file(&file.path).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The index of the interval, aligned to the Unix epoch, that `time` falls into.
+fn interval_index(time: SystemTime, interval: Duration) -> u64 {
+    let elapsed = time
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default();
+    elapsed.as_secs() / interval.as_secs().max(1)
+}
+
+/// The numbers of a suffix, such as `[20241017120000, 1]`, so that `.10` is ordered after `.2`.
+fn suffix_order(suffix: &str) -> Vec<u64> {
+    suffix
+        .split('.')
+        .map(|number| number.parse().unwrap_or(u64::MAX))
+        .collect()
+}
+
+/// Splits `path` into the part the suffix of rotated files is appended to, and the compression
+/// extension following it.
+fn split_extension(path: &str, compression: Compression) -> (&str, &str) {
+    compression
+        .extension()
+        .and_then(|extension| {
+            let stem = path.strip_suffix(extension)?.strip_suffix('.')?;
+            Some((stem, &path[stem.len()..]))
+        })
+        .unwrap_or((path, ""))
+}
+
+/// Lists the rotated files of the path made of `stem` and `extension`.
+async fn rotated_files(stem: &str, extension: &str) -> io::Result<Vec<RotatedFile>> {
+    let stem = std::path::Path::new(stem);
+    let (Some(directory), Some(name)) = (stem.parent(), stem.file_name()) else {
+        return Ok(Vec::new());
+    };
+    let directory = if directory.as_os_str().is_empty() {
+        std::path::Path::new(".")
+    } else {
+        directory
+    };
+    let prefix = format!("{}.", name.to_string_lossy());
+
+    let mut rotated = Vec::new();
+    let mut entries = fs::read_dir(directory).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        let file_name = entry.file_name();
+        let Some(suffix) = file_name
+            .to_str()
+            .and_then(|file_name| file_name.strip_prefix(&prefix))
+            .and_then(|file_name| file_name.strip_suffix(extension))
+        else {
+            continue;
+        };
+        if !suffix.starts_with(|c: char| c.is_ascii_digit())
+            || !suffix.chars().all(|c| c.is_ascii_digit() || c == '.')
+        {
+            continue;
+        }
+
+        let metadata = entry.metadata().await?;
+        if metadata.is_file() {
+            rotated.push(RotatedFile {
+                path: entry.path(),
+                suffix: suffix.to_owned(),
+                modified: metadata.modified()?,
+            });
+        }
+    }
+
+    Ok(rotated)
+}
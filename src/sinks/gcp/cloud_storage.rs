@@ -9,7 +9,7 @@ use snafu::ResultExt;
 use snafu::Snafu;
 use tower::ServiceBuilder;
 use uuid::Uuid;
-use vector_lib::codecs::encoding::Framer;
+use vector_lib::codecs::encoding::{BatchSerializerConfig, Framer};
 use vector_lib::configurable::configurable_component;
 use vector_lib::event::{EventFinalizers, Finalizable};
 use vector_lib::{request_metadata::RequestMetadata, TimeZone};
@@ -17,9 +17,8 @@ use vector_lib::{request_metadata::RequestMetadata, TimeZone};
 use crate::sinks::util::metadata::RequestMetadataBuilder;
 use crate::sinks::util::service::TowerRequestConfigDefaults;
 use crate::{
-    codecs::{Encoder, EncodingConfigWithFraming, SinkType, Transformer},
+    codecs::{Encoder, EncoderKind, EncodingConfigWithFraming, SinkType, Transformer},
     config::{AcknowledgementsConfig, DataType, GenerateConfig, Input, SinkConfig, SinkContext},
-    event::Event,
     gcp::{GcpAuthConfig, GcpAuthenticator, Scope},
     http::{get_http_scheme_from_uri, HttpClient},
     serde::json::to_string,
@@ -33,9 +32,9 @@ use crate::{
             sink::GcsSink,
         },
         util::{
-            batch::BatchConfig, partitioner::KeyPartitioner, request_builder::EncodeResult,
-            timezone_to_offset, BulkSizeBasedDefaultBatchSettings, Compression, RequestBuilder,
-            ServiceBuilderExt, TowerRequestConfig,
+            batch::BatchConfig, encoding::ValidatedBatch, partitioner::KeyPartitioner,
+            request_builder::EncodeResult, timezone_to_offset, BulkSizeBasedDefaultBatchSettings,
+            Compression, RequestBuilder, ServiceBuilderExt, TowerRequestConfig,
         },
         Healthcheck, VectorSink,
     },
@@ -141,13 +140,25 @@ pub struct GcsSinkConfig {
 
     /// The filename extension to use in the object key.
     ///
-    /// If not specified, the extension is determined by the compression scheme used.
+    /// If not specified, the extension is determined by the batch encoding or compression scheme
+    /// used.
     #[configurable(metadata(docs::advanced))]
     filename_extension: Option<String>,
 
     #[serde(flatten)]
     encoding: EncodingConfigWithFraming,
 
+    /// Encoding of each batch of events as a whole.
+    ///
+    /// When set, each batch is written as a single object in this format, and events are not
+    /// serialized with the `encoding` codec. The other `encoding` options, such as `only_fields`,
+    /// still apply.
+    ///
+    /// The `compression` option is ignored, as the format handles compression itself.
+    #[configurable(derived)]
+    #[serde(default)]
+    batch_encoding: Option<BatchSerializerConfig>,
+
     /// Compression configuration.
     ///
     /// All compression algorithms use the default compression level unless otherwise specified.
@@ -207,6 +218,7 @@ fn default_config(encoding: EncodingConfigWithFraming) -> GcsSinkConfig {
         filename_append_uuid: true,
         filename_extension: Default::default(),
         encoding,
+        batch_encoding: None,
         compression: Compression::gzip_default(),
         batch: Default::default(),
         endpoint: Default::default(),
@@ -251,7 +263,11 @@ impl SinkConfig for GcsSinkConfig {
     }
 
     fn input(&self) -> Input {
-        Input::new(self.encoding.config().1.input_type() & DataType::Log)
+        let input_type = match &self.batch_encoding {
+            Some(batch_encoding) => batch_encoding.input_type(),
+            None => self.encoding.config().1.input_type(),
+        };
+        Input::new(input_type & DataType::Log)
     }
 
     fn acknowledgements(&self) -> &AcknowledgementsConfig {
@@ -308,15 +324,15 @@ struct RequestSettings {
     extension: String,
     time_format: String,
     append_uuid: bool,
-    encoder: (Transformer, Encoder<Framer>),
+    encoder: (Transformer, EncoderKind),
     compression: Compression,
     tz_offset: Option<FixedOffset>,
 }
 
-impl RequestBuilder<(String, Vec<Event>)> for RequestSettings {
+impl RequestBuilder<(String, ValidatedBatch)> for RequestSettings {
     type Metadata = (String, EventFinalizers);
-    type Events = Vec<Event>;
-    type Encoder = (Transformer, Encoder<Framer>);
+    type Events = ValidatedBatch;
+    type Encoder = (Transformer, EncoderKind);
     type Payload = Bytes;
     type Request = GcsRequest;
     type Error = io::Error;
@@ -331,13 +347,13 @@ impl RequestBuilder<(String, Vec<Event>)> for RequestSettings {
 
     fn split_input(
         &self,
-        input: (String, Vec<Event>),
+        input: (String, ValidatedBatch),
     ) -> (Self::Metadata, RequestMetadataBuilder, Self::Events) {
-        let (partition_key, mut events) = input;
-        let finalizers = events.take_finalizers();
-        let builder = RequestMetadataBuilder::from_events(&events);
+        let (partition_key, mut batch) = input;
+        let finalizers = batch.events.take_finalizers();
+        let builder = RequestMetadataBuilder::from_events(&batch.events);
 
-        ((partition_key, finalizers), builder, events)
+        ((partition_key, finalizers), builder, batch)
     }
 
     fn build_request(
@@ -386,14 +402,22 @@ impl RequestBuilder<(String, Vec<Event>)> for RequestSettings {
 impl RequestSettings {
     fn new(config: &GcsSinkConfig, cx: SinkContext) -> crate::Result<Self> {
         let transformer = config.encoding.transformer();
-        let (framer, serializer) = config.encoding.build(SinkType::MessageBased)?;
-        let encoder = Encoder::<Framer>::new(framer, serializer);
+        let (encoder, compression) = match &config.batch_encoding {
+            Some(batch_encoding) => (
+                EncoderKind::Batch(batch_encoding.build()?),
+                Compression::None,
+            ),
+            None => {
+                let (framer, serializer) = config.encoding.build(SinkType::MessageBased)?;
+                let encoder = Encoder::<Framer>::new(framer, serializer);
+                (EncoderKind::Framed(Box::new(encoder)), config.compression)
+            }
+        };
         let acl = config
             .acl
             .map(|acl| HeaderValue::from_str(&to_string(acl)).unwrap());
         let content_type = HeaderValue::from_str(encoder.content_type()).unwrap();
-        let content_encoding = config
-            .compression
+        let content_encoding = compression
             .content_encoding()
             .map(|ce| HeaderValue::from_str(&to_string(ce)).unwrap());
         let storage_class = config.storage_class.unwrap_or_default();
@@ -411,7 +435,8 @@ impl RequestSettings {
         let extension = config
             .filename_extension
             .clone()
-            .unwrap_or_else(|| config.compression.extension().into());
+            .or_else(|| encoder.extension().map(Into::into))
+            .unwrap_or_else(|| compression.extension().into());
         let time_format = config.filename_time_format.clone();
         let append_uuid = config.filename_append_uuid;
         let offset = config
@@ -428,7 +453,7 @@ impl RequestSettings {
             extension,
             time_format,
             append_uuid,
-            compression: config.compression,
+            compression,
             encoder: (transformer, encoder),
             tz_offset: offset,
         })
@@ -446,7 +471,7 @@ fn make_header((name, value): (&String, &String)) -> crate::Result<(HeaderName,
 #[cfg(test)]
 mod tests {
     use futures_util::{future::ready, stream};
-    use vector_lib::codecs::encoding::FramingConfig;
+    use vector_lib::codecs::encoding::{FramingConfig, ParquetSerializerConfig};
     use vector_lib::codecs::{
         JsonSerializerConfig, NewlineDelimitedEncoderConfig, TextSerializerConfig,
     };
@@ -454,7 +479,7 @@ mod tests {
     use vector_lib::request_metadata::GroupedCountByteSize;
     use vector_lib::EstimatedJsonEncodedSizeOf;
 
-    use crate::event::LogEvent;
+    use crate::event::{Event, LogEvent};
     use crate::test_util::{
         components::{run_and_assert_sink_compliance, SINK_TAGS},
         http::{always_200_response, spawn_blackhole_http_server},
@@ -544,8 +569,9 @@ mod tests {
         byte_size.add_event(&log, log.estimated_json_encoded_size_of());
 
         let request_settings = request_settings(&sink_config, context);
+        let batch = ValidatedBatch::new(request_settings.encoder(), vec![log]);
         let (metadata, metadata_request_builder, _events) =
-            request_settings.split_input((key, vec![log]));
+            request_settings.split_input((key, batch));
         let payload = EncodeResult::uncompressed(Bytes::new(), byte_size);
         let request_metadata = metadata_request_builder.build(&payload);
 
@@ -566,4 +592,18 @@ mod tests {
         let req = build_request(None, true, Compression::gzip_default());
         assert_ne!(req.key, "key/date.log.gz".to_string());
     }
+
+    #[test]
+    fn gcs_parquet_request_settings() {
+        let sink_config = GcsSinkConfig {
+            batch_encoding: Some(ParquetSerializerConfig::default().into()),
+            ..default_config((None::<FramingConfig>, TextSerializerConfig::default()).into())
+        };
+        let settings = request_settings(&sink_config, SinkContext::default());
+
+        assert_eq!(settings.extension, "parquet");
+        assert_eq!(settings.content_type, "application/vnd.apache.parquet");
+        assert_eq!(settings.content_encoding, None);
+        assert_eq!(settings.compression, Compression::None);
+    }
 }
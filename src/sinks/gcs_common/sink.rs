@@ -1,6 +1,12 @@
 use std::fmt;
 
-use crate::sinks::{prelude::*, util::partitioner::KeyPartitioner};
+use crate::{
+    codecs::EncoderKind,
+    sinks::{
+        prelude::*,
+        util::{encoding::ValidatedBatch, partitioner::KeyPartitioner},
+    },
+};
 
 pub struct GcsSink<Svc, RB> {
     service: Svc,
@@ -34,7 +40,10 @@ where
     Svc::Future: Send + 'static,
     Svc::Response: DriverResponse + Send + 'static,
     Svc::Error: fmt::Debug + Into<crate::Error> + Send,
-    RB: RequestBuilder<(String, Vec<Event>)> + Send + Sync + 'static,
+    RB: RequestBuilder<(String, ValidatedBatch), Encoder = (Transformer, EncoderKind)>
+        + Send
+        + Sync
+        + 'static,
     RB::Error: fmt::Display + Send,
     RB::Request: Finalizable + MetaDescriptive + Send,
 {
@@ -43,6 +52,7 @@ where
         let settings = self.batcher_settings;
 
         let request_builder = self.request_builder;
+        let encoder = request_builder.encoder().clone();
 
         input
             .batched_partitioned(partitioner, || settings.as_byte_size_config())
@@ -51,6 +61,12 @@ where
                 // thus no further `EventsDropped` event needs emitting at this stage.
                 key.map(move |k| (k, batch))
             })
+            .filter_map(|(key, batch)| {
+                // Events which can't be encoded are rejected before the request builder takes the
+                // finalizers of the batch.
+                let batch = ValidatedBatch::new(&encoder, batch);
+                future::ready((!batch.is_empty()).then_some((key, batch)))
+            })
             .request_builder(default_request_builder_concurrency_limit(), request_builder)
             .filter_map(|request| async move {
                 match request {
@@ -75,7 +91,10 @@ where
     Svc::Future: Send + 'static,
     Svc::Response: DriverResponse + Send + 'static,
     Svc::Error: fmt::Debug + Into<crate::Error> + Send,
-    RB: RequestBuilder<(String, Vec<Event>)> + Send + Sync + 'static,
+    RB: RequestBuilder<(String, ValidatedBatch), Encoder = (Transformer, EncoderKind)>
+        + Send
+        + Sync
+        + 'static,
     RB::Error: fmt::Display + Send,
     RB::Request: Finalizable + MetaDescriptive + Send,
 {
@@ -394,6 +394,22 @@ fn get_type_and_fields(
 
                 ("sketch", Some(fields))
             }
+            MetricSketch::NativeHistogram(histogram) => {
+                let mut fields: HashMap<KeyString, Field> = histogram
+                    .to_buckets()
+                    .iter()
+                    .map(|bucket| {
+                        (
+                            format!("bucket_{}", bucket.upper_limit).into(),
+                            Field::UnsignedInt(bucket.count),
+                        )
+                    })
+                    .collect();
+                fields.insert("count".into(), Field::Float(histogram.count));
+                fields.insert("sum".into(), Field::Float(histogram.sum));
+
+                ("histogram", Some(fields))
+            }
         },
     }
 }
@@ -1,4 +1,4 @@
-use std::{collections::HashMap, time::Duration};
+use std::{collections::HashMap, num::NonZeroUsize, time::Duration};
 
 use futures::FutureExt;
 use rdkafka::ClientConfig;
@@ -117,6 +117,10 @@ pub struct KafkaSinkConfig {
     #[configurable(metadata(docs::examples = "headers"))]
     pub headers_key: Option<ConfigTargetPath>,
 
+    #[configurable(derived)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transaction: Option<KafkaTransactionConfig>,
+
     #[configurable(derived)]
     #[serde(
         default,
@@ -126,6 +130,68 @@ pub struct KafkaSinkConfig {
     pub acknowledgements: AcknowledgementsConfig,
 }
 
+/// Configuration for exactly-once delivery, with a transactional producer.
+///
+/// Events are produced in transactions, which are only visible to consumers reading committed
+/// messages once committed. Events consumed by a `kafka` source of the same Vector instance have
+/// their consumer offsets committed in the same transaction, so that each of them is written
+/// exactly once even if Vector restarts. The source then stops committing these offsets itself.
+#[serde_as]
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct KafkaTransactionConfig {
+    /// The transactional ID of the producer.
+    ///
+    /// It must be unique to each instance of the sink and stay the same across restarts, so that
+    /// the transactions left open by a previous instance are aborted.
+    #[configurable(metadata(docs::examples = "vector-billing-0"))]
+    pub transactional_id: String,
+
+    /// The maximum number of events to produce in a single transaction.
+    #[serde(default = "default_transaction_max_events")]
+    #[configurable(metadata(docs::type_unit = "events"))]
+    pub max_events: NonZeroUsize,
+
+    /// The maximum time, in seconds, to wait for events to fill a transaction before committing it.
+    #[serde(default = "default_transaction_timeout_secs")]
+    #[configurable(metadata(docs::type_unit = "seconds"))]
+    #[configurable(metadata(docs::human_name = "Timeout"))]
+    pub timeout_secs: f64,
+
+    /// The maximum time, in milliseconds, that a transaction can remain open before the broker
+    /// aborts it.
+    ///
+    /// The `message_timeout_ms` option is capped to this value.
+    #[serde_as(as = "serde_with::DurationMilliSeconds<u64>")]
+    #[serde(default = "default_transaction_timeout_ms")]
+    #[configurable(metadata(docs::examples = 60000))]
+    #[configurable(metadata(docs::human_name = "Transaction Timeout"))]
+    pub transaction_timeout_ms: Duration,
+}
+
+impl KafkaTransactionConfig {
+    pub(crate) fn batch_settings(&self) -> BatcherSettings {
+        BatcherSettings::new(
+            Duration::from_secs_f64(self.timeout_secs),
+            NonZeroUsize::MAX,
+            self.max_events,
+        )
+    }
+}
+
+const fn default_transaction_max_events() -> NonZeroUsize {
+    NonZeroUsize::new(1000).unwrap()
+}
+
+const fn default_transaction_timeout_secs() -> f64 {
+    1.0
+}
+
+const fn default_transaction_timeout_ms() -> Duration {
+    Duration::from_millis(60000) // default in librdkafka
+}
+
 const fn default_socket_timeout_ms() -> Duration {
     Duration::from_millis(60000) // default in librdkafka
 }
@@ -156,11 +222,22 @@ impl KafkaSinkConfig {
         self.auth.apply(&mut client_config)?;
 
         // All batch options are producer only.
+        let mut message_timeout_ms = self.message_timeout_ms;
+        if let Some(transaction) = &self.transaction {
+            // Messages can't outlive the transaction they are produced in.
+            message_timeout_ms = message_timeout_ms.min(transaction.transaction_timeout_ms);
+            client_config
+                .set("transactional.id", &transaction.transactional_id)
+                .set(
+                    "transaction.timeout.ms",
+                    transaction.transaction_timeout_ms.as_millis().to_string(),
+                );
+        }
         client_config
             .set("compression.codec", to_string(self.compression))
             .set(
                 "message.timeout.ms",
-                self.message_timeout_ms.as_millis().to_string(),
+                message_timeout_ms.as_millis().to_string(),
             );
 
         if let Some(value) = self.batch.timeout_secs {
@@ -247,6 +324,7 @@ impl GenerateConfig for KafkaSinkConfig {
             message_timeout_ms: default_message_timeout_ms(),
             librdkafka_options: Default::default(),
             headers_key: None,
+            transaction: None,
             acknowledgements: Default::default(),
         })
         .unwrap()
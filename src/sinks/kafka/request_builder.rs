@@ -18,7 +18,7 @@ pub struct KafkaRequestBuilder {
 
 impl RequestBuilder<(String, Event)> for KafkaRequestBuilder {
     type Metadata = KafkaRequestMetadata;
-    type Events = Event;
+    type Events = (String, Event);
     type Encoder = (Transformer, Encoder<()>);
     type Payload = Bytes;
     type Request = KafkaRequest;
@@ -44,10 +44,11 @@ impl RequestBuilder<(String, Event)> for KafkaRequestBuilder {
             key: get_key(&event, self.key_field.as_ref()),
             timestamp_millis: get_timestamp_millis(&event),
             headers: get_headers(&event, self.headers_key.as_ref()),
-            topic,
+            topic: topic.clone(),
+            kafka_offset: event.metadata().kafka_offset().cloned(),
         };
 
-        (metadata, builder, event)
+        (metadata, builder, (topic, event))
     }
 
     fn build_request(
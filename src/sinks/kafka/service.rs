@@ -14,7 +14,7 @@ use rdkafka::{
     producer::{FutureProducer, FutureRecord},
     types::RDKafkaErrorCode,
 };
-use vector_lib::config;
+use vector_lib::{config, event::KafkaOffset};
 
 use crate::{kafka::KafkaStatisticsContext, sinks::prelude::*};
 
@@ -30,12 +30,35 @@ pub struct KafkaRequestMetadata {
     pub timestamp_millis: Option<i64>,
     pub headers: Option<OwnedHeaders>,
     pub topic: String,
+    pub kafka_offset: Option<KafkaOffset>,
+}
+
+impl KafkaRequest {
+    /// The size of the record, in bytes.
+    pub(super) fn raw_byte_size(&self) -> usize {
+        self.body.len() + self.metadata.key.as_ref().map_or(0, |x| x.len())
+    }
+
+    /// Builds the record, leaving the request untouched so that it can be produced again.
+    pub(super) fn record(&self) -> FutureRecord<'_, [u8], [u8]> {
+        let mut record = FutureRecord::to(&self.metadata.topic).payload(self.body.as_ref());
+        if let Some(key) = &self.metadata.key {
+            record = record.key(&key[..]);
+        }
+        if let Some(timestamp) = self.metadata.timestamp_millis {
+            record = record.timestamp(timestamp);
+        }
+        if let Some(headers) = &self.metadata.headers {
+            record = record.headers(headers.clone());
+        }
+        record
+    }
 }
 
 pub struct KafkaResponse {
-    event_byte_size: GroupedCountByteSize,
-    raw_byte_size: usize,
-    event_status: EventStatus,
+    pub(super) event_byte_size: GroupedCountByteSize,
+    pub(super) raw_byte_size: usize,
+    pub(super) event_status: EventStatus,
 }
 
 impl DriverResponse for KafkaResponse {
@@ -122,8 +145,7 @@ impl Service<KafkaRequest> for KafkaService {
         let this = self.clone();
 
         Box::pin(async move {
-            let raw_byte_size =
-                request.body.len() + request.metadata.key.as_ref().map_or(0, |x| x.len());
+            let raw_byte_size = request.raw_byte_size();
             let event_byte_size = request
                 .request_metadata
                 .into_events_estimated_json_encoded_byte_size();
@@ -8,10 +8,15 @@ use tokio::time::Duration;
 use tracing::Span;
 use vrl::path::OwnedTargetPath;
 
-use super::config::KafkaSinkConfig;
+use super::config::{KafkaSinkConfig, KafkaTransactionConfig};
 use crate::{
+    internal_events::KafkaTransactionError,
     kafka::KafkaStatisticsContext,
-    sinks::kafka::{request_builder::KafkaRequestBuilder, service::KafkaService},
+    sinks::kafka::{
+        request_builder::KafkaRequestBuilder,
+        service::{KafkaRequest, KafkaService},
+        transaction::{run_blocking, KafkaTransactionRequest, KafkaTransactionService},
+    },
     sinks::prelude::*,
 };
 
@@ -25,7 +30,8 @@ pub(super) enum BuildError {
 pub struct KafkaSink {
     transformer: Transformer,
     encoder: Encoder<()>,
-    service: KafkaService,
+    producer: FutureProducer<KafkaStatisticsContext>,
+    transaction: Option<KafkaTransactionConfig>,
     topic: Template,
     key_field: Option<OwnedTargetPath>,
     headers_key: Option<OwnedTargetPath>,
@@ -55,7 +61,8 @@ impl KafkaSink {
             headers_key: config.headers_key.map(|key| key.0),
             transformer,
             encoder,
-            service: KafkaService::new(producer),
+            producer,
+            transaction: config.transaction,
             topic: config.topic,
             key_field: config.key_field.map(|key| key.0),
         })
@@ -68,7 +75,7 @@ impl KafkaSink {
             encoder: (self.transformer, self.encoder),
         };
 
-        input
+        let requests = input
             .filter_map(|event| {
                 // Compute the topic.
                 future::ready(
@@ -94,8 +101,40 @@ impl KafkaSink {
                     }
                     Ok(req) => Some(req),
                 }
-            })
-            .into_driver(self.service)
+            });
+
+        let Some(transaction) = self.transaction else {
+            return requests
+                .into_driver(KafkaService::new(self.producer))
+                .protocol("kafka")
+                .run()
+                .await;
+        };
+
+        // Fences off the previous instance of the producer, aborting its open transaction.
+        let timeout = transaction.transaction_timeout_ms;
+        if let Err(error) = run_blocking(&self.producer, move |producer| {
+            producer.init_transactions(timeout)
+        })
+        .await
+        {
+            emit!(KafkaTransactionError { error: &error });
+            return Err(());
+        }
+
+        // Transactions are committed one at a time, in order, so that consumer offsets only move
+        // forward.
+        let service = ServiceBuilder::new()
+            .concurrency_limit(1)
+            .service(KafkaTransactionService::new(self.producer, timeout));
+        requests
+            .batched(
+                transaction
+                    .batch_settings()
+                    .as_item_size_config(|request: &KafkaRequest| request.raw_byte_size()),
+            )
+            .map(KafkaTransactionRequest::new)
+            .into_driver(service)
             .protocol("kafka")
             .run()
             .await
@@ -0,0 +1,208 @@
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use rdkafka::{
+    error::{KafkaError, KafkaResult},
+    producer::{FutureProducer, Producer},
+    util::Timeout,
+    Offset, TopicPartitionList,
+};
+
+use super::service::{KafkaRequest, KafkaResponse};
+use crate::{
+    internal_events::{KafkaConsumerGroupMissing, KafkaTransactionError},
+    kafka::{self, KafkaStatisticsContext},
+    sinks::prelude::*,
+};
+
+/// A batch of records to produce in a single transaction, along with the consumer offsets of the
+/// events they were built from, by the `kafka` source that consumed them.
+pub struct KafkaTransactionRequest {
+    requests: Vec<KafkaRequest>,
+    offsets: HashMap<Arc<str>, TopicPartitionList>,
+    finalizers: EventFinalizers,
+    request_metadata: RequestMetadata,
+}
+
+impl KafkaTransactionRequest {
+    pub(super) fn new(mut requests: Vec<KafkaRequest>) -> Self {
+        let mut finalizers = EventFinalizers::default();
+        let mut next_offsets: HashMap<(Arc<str>, Arc<str>, i32), i64> = HashMap::new();
+        for request in &mut requests {
+            finalizers.merge(request.take_finalizers());
+            if let Some(offset) = request.metadata.kafka_offset.take() {
+                // The committed offset is the one of the next message to consume.
+                let next_offset = next_offsets
+                    .entry((offset.source_id, offset.topic, offset.partition))
+                    .or_default();
+                *next_offset = (*next_offset).max(offset.offset + 1);
+            }
+        }
+
+        let mut offsets: HashMap<Arc<str>, TopicPartitionList> = HashMap::new();
+        for ((source_id, topic, partition), offset) in next_offsets {
+            offsets
+                .entry(source_id)
+                .or_default()
+                .add_partition_offset(&topic, partition, Offset::Offset(offset))
+                .expect("offset is valid");
+        }
+
+        let request_metadata = RequestMetadata::from_batch(
+            requests
+                .iter()
+                .map(|request| request.get_metadata().clone()),
+        );
+
+        Self {
+            requests,
+            offsets,
+            finalizers,
+            request_metadata,
+        }
+    }
+}
+
+impl Finalizable for KafkaTransactionRequest {
+    fn take_finalizers(&mut self) -> EventFinalizers {
+        std::mem::take(&mut self.finalizers)
+    }
+}
+
+impl MetaDescriptive for KafkaTransactionRequest {
+    fn get_metadata(&self) -> &RequestMetadata {
+        &self.request_metadata
+    }
+
+    fn metadata_mut(&mut self) -> &mut RequestMetadata {
+        &mut self.request_metadata
+    }
+}
+
+/// Runs a blocking operation of the transactional API off the async runtime.
+pub(super) async fn run_blocking<T, F>(
+    producer: &FutureProducer<KafkaStatisticsContext>,
+    f: F,
+) -> KafkaResult<T>
+where
+    T: Send + 'static,
+    F: FnOnce(&FutureProducer<KafkaStatisticsContext>) -> KafkaResult<T> + Send + 'static,
+{
+    let producer = producer.clone();
+    tokio::task::spawn_blocking(move || f(&producer))
+        .await
+        .expect("kafka transaction task panicked")
+}
+
+/// Whether the transaction can be aborted and attempted again after this error.
+fn is_retriable(error: &KafkaError) -> bool {
+    match error {
+        KafkaError::Transaction(error) => {
+            !error.is_fatal() && (error.is_retriable() || error.txn_requires_abort())
+        }
+        _ => false,
+    }
+}
+
+#[derive(Clone)]
+pub struct KafkaTransactionService {
+    kafka_producer: FutureProducer<KafkaStatisticsContext>,
+    timeout: Duration,
+}
+
+impl KafkaTransactionService {
+    pub(crate) const fn new(
+        kafka_producer: FutureProducer<KafkaStatisticsContext>,
+        timeout: Duration,
+    ) -> Self {
+        Self {
+            kafka_producer,
+            timeout,
+        }
+    }
+
+    async fn run_transaction(&self, request: &KafkaTransactionRequest) -> KafkaResult<()> {
+        run_blocking(&self.kafka_producer, |producer| {
+            producer.begin_transaction()
+        })
+        .await?;
+
+        let results = futures::future::join_all(
+            request
+                .requests
+                .iter()
+                .map(|request| self.kafka_producer.send(request.record(), Timeout::Never)),
+        )
+        .await;
+        for result in results {
+            result.map_err(|(error, _)| error)?;
+        }
+
+        let offsets = request.offsets.clone();
+        let timeout = self.timeout;
+        run_blocking(&self.kafka_producer, move |producer| {
+            for (source_id, offsets) in &offsets {
+                match kafka::transactional_group_metadata(source_id) {
+                    Some(group_metadata) => {
+                        producer.send_offsets_to_transaction(offsets, &group_metadata, timeout)?
+                    }
+                    // The source is gone, its offsets will be committed by its next consumer.
+                    None => emit!(KafkaConsumerGroupMissing { source_id }),
+                }
+            }
+            producer.commit_transaction(timeout)
+        })
+        .await
+    }
+}
+
+impl Service<KafkaTransactionRequest> for KafkaTransactionService {
+    type Response = KafkaResponse;
+    type Error = KafkaError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: KafkaTransactionRequest) -> Self::Future {
+        let this = self.clone();
+
+        Box::pin(async move {
+            loop {
+                let Err(error) = this.run_transaction(&request).await else {
+                    break;
+                };
+                emit!(KafkaTransactionError { error: &error });
+
+                let timeout = this.timeout;
+                let aborted = run_blocking(&this.kafka_producer, move |producer| {
+                    producer.abort_transaction(timeout)
+                })
+                .await;
+                if !is_retriable(&error) {
+                    return Err(error);
+                }
+                aborted?;
+                tokio::time::sleep(Duration::from_millis(100)).await;
+            }
+
+            let raw_byte_size = request
+                .requests
+                .iter()
+                .map(KafkaRequest::raw_byte_size)
+                .sum();
+            Ok(KafkaResponse {
+                event_byte_size: request
+                    .request_metadata
+                    .into_events_estimated_json_encoded_byte_size(),
+                raw_byte_size,
+                event_status: EventStatus::Delivered,
+            })
+        })
+    }
+}
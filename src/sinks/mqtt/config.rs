@@ -1,13 +1,14 @@
 use std::time::Duration;
 
 use rand::Rng;
-use rumqttc::{MqttOptions, QoS, TlsConfiguration, Transport};
+use rumqttc::{MqttOptions, TlsConfiguration, Transport};
 use snafu::{ResultExt, Snafu};
 use vector_lib::codecs::JsonSerializerConfig;
 
 use crate::template::Template;
 use crate::{
     codecs::EncodingConfig,
+    common::mqtt::MqttQoS,
     config::{AcknowledgementsConfig, Input, SinkConfig, SinkContext},
     sinks::{
         mqtt::sink::{ConfigurationSnafu, MqttConnector, MqttError, MqttSink, TlsSnafu},
@@ -72,34 +73,6 @@ pub struct MqttSinkConfig {
     pub quality_of_service: MqttQoS,
 }
 
-/// Supported Quality of Service types for MQTT.
-#[configurable_component]
-#[derive(Clone, Copy, Debug, Derivative)]
-#[derivative(Default)]
-#[serde(rename_all = "lowercase")]
-#[allow(clippy::enum_variant_names)]
-pub enum MqttQoS {
-    /// AtLeastOnce.
-    #[derivative(Default)]
-    AtLeastOnce,
-
-    /// AtMostOnce.
-    AtMostOnce,
-
-    /// ExactlyOnce.
-    ExactlyOnce,
-}
-
-impl From<MqttQoS> for QoS {
-    fn from(value: MqttQoS) -> Self {
-        match value {
-            MqttQoS::AtLeastOnce => QoS::AtLeastOnce,
-            MqttQoS::AtMostOnce => QoS::AtMostOnce,
-            MqttQoS::ExactlyOnce => QoS::ExactlyOnce,
-        }
-    }
-}
-
 const fn default_port() -> u16 {
     1883
 }
@@ -1,5 +1,5 @@
+use crate::common::mqtt::MqttQoS;
 use crate::config::{SinkConfig, SinkContext};
-use crate::sinks::mqtt::config::MqttQoS;
 use crate::sinks::mqtt::MqttSinkConfig;
 use crate::template::Template;
 use crate::test_util::components::{run_and_assert_sink_compliance, SINK_TAGS};
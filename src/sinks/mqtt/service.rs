@@ -6,7 +6,7 @@ use futures::future::BoxFuture;
 use rumqttc::{AsyncClient, ClientError};
 use snafu::Snafu;
 
-use super::config::MqttQoS;
+use crate::common::mqtt::MqttQoS;
 
 pub(super) struct MqttResponse {
     byte_size: usize,
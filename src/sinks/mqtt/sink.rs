@@ -8,7 +8,7 @@ use crate::internal_events::MqttConnectionError;
 use crate::sinks::prelude::*;
 
 use super::{
-    config::{ConfigurationError, MqttQoS},
+    config::ConfigurationError,
     request_builder::{MqttEncoder, MqttRequestBuilder},
     service::MqttService,
     MqttSinkConfig,
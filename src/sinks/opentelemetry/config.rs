@@ -0,0 +1,279 @@
+//! Configuration for the `opentelemetry` sink.
+
+use http::{header::AUTHORIZATION, HeaderMap};
+use hyper::Body;
+use indoc::indoc;
+use tonic::metadata::MetadataMap;
+use vector_lib::codecs::{
+    encoding::{FramingConfig, SerializerConfig},
+    JsonSerializerConfig,
+};
+
+use crate::{
+    codecs::EncodingConfigWithFraming,
+    http::{build_proxy_connector, Auth, HttpClient, MaybeAuth},
+    sinks::{
+        http::config::{HttpMethod, HttpSinkConfig},
+        prelude::*,
+        util::{
+            http::{http_response_retry_logic, validate_headers, HttpService, RequestConfig},
+            RealtimeSizeBasedDefaultBatchSettings, UriSerde,
+        },
+    },
+};
+
+use super::{
+    encoder::OpenTelemetryEncoder,
+    request_builder::OpenTelemetryRequestBuilder,
+    service::{
+        OpenTelemetryGrpcRetryLogic, OpenTelemetryGrpcService, OpenTelemetryHttpRequestBuilder,
+    },
+    sink::{OpenTelemetrySink, Signal},
+};
+
+/// Configuration for the `OpenTelemetry` sink.
+#[configurable_component(sink("opentelemetry", "Deliver OTLP data over HTTP or gRPC."))]
+#[derive(Clone, Debug, Default)]
+pub struct OpenTelemetryConfig {
+    /// Protocol configuration
+    #[configurable(derived)]
+    protocol: Protocol,
+}
+
+/// The protocol used to send data to OpenTelemetry.
+/// The proto definitions are defined [here](https://github.com/vectordotdev/vector/blob/master/lib/opentelemetry-proto/src/proto/opentelemetry-proto/opentelemetry/proto/README.md).
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(rename_all = "snake_case", tag = "type")]
+#[configurable(metadata(docs::enum_tag_description = "The communication protocol."))]
+pub enum Protocol {
+    /// Send data over HTTP, encoding each event with the configured codec.
+    Http(HttpSinkConfig),
+
+    /// Send data over HTTP as OTLP protobuf export requests.
+    HttpProtobuf(OtlpConfig),
+
+    /// Send data over gRPC as OTLP export requests.
+    Grpc(OtlpConfig),
+}
+
+impl Default for Protocol {
+    fn default() -> Self {
+        Protocol::Http(HttpSinkConfig {
+            encoding: EncodingConfigWithFraming::new(
+                Some(FramingConfig::NewlineDelimited),
+                SerializerConfig::Json(JsonSerializerConfig::default()),
+                Transformer::default(),
+            ),
+            uri: Default::default(),
+            method: HttpMethod::Post,
+            auth: Default::default(),
+            headers: Default::default(),
+            compression: Default::default(),
+            payload_prefix: Default::default(),
+            payload_suffix: Default::default(),
+            batch: Default::default(),
+            request: Default::default(),
+            tls: Default::default(),
+            acknowledgements: Default::default(),
+        })
+    }
+}
+
+/// Configuration for sending native OTLP export requests.
+///
+/// Log, metric, and trace events are converted into their OTLP representation, and are batched
+/// separately, with each batch being sent as a single export request that groups events by their
+/// resource and instrumentation scope.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct OtlpConfig {
+    /// The endpoint of the OTLP receiver.
+    ///
+    /// This should include the protocol, host, and port. When using the `http_protobuf` protocol,
+    /// the signal specific path (`/v1/logs`, `/v1/metrics`, or `/v1/traces`) is appended to it.
+    #[configurable(metadata(docs::examples = "http://localhost:4318"))]
+    #[configurable(metadata(docs::examples = "https://otel-collector:4317"))]
+    pub endpoint: UriSerde,
+
+    #[configurable(derived)]
+    pub auth: Option<Auth>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub compression: Compression,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub batch: BatchConfig<RealtimeSizeBasedDefaultBatchSettings>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub request: RequestConfig,
+
+    #[configurable(derived)]
+    pub tls: Option<TlsConfig>,
+
+    #[configurable(derived)]
+    #[serde(
+        default,
+        deserialize_with = "crate::serde::bool_or_struct",
+        skip_serializing_if = "crate::serde::is_default"
+    )]
+    pub acknowledgements: AcknowledgementsConfig,
+}
+
+impl GenerateConfig for OpenTelemetryConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(indoc! {r#"
+            [protocol]
+            type = "http"
+            uri = "http://localhost:5318/v1/logs"
+            encoding.codec = "json"
+        "#})
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "opentelemetry")]
+impl SinkConfig for OpenTelemetryConfig {
+    async fn build(&self, cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
+        match &self.protocol {
+            Protocol::Http(config) => config.build(cx).await,
+            Protocol::HttpProtobuf(config) => config.build_http(cx),
+            Protocol::Grpc(config) => config.build_grpc(cx),
+        }
+    }
+
+    fn input(&self) -> Input {
+        Input::all()
+    }
+
+    fn acknowledgements(&self) -> &AcknowledgementsConfig {
+        match self.protocol {
+            Protocol::Http(ref config) => config.acknowledgements(),
+            Protocol::HttpProtobuf(ref config) | Protocol::Grpc(ref config) => {
+                &config.acknowledgements
+            }
+        }
+    }
+}
+
+impl OtlpConfig {
+    fn build_http(&self, cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
+        let batch_settings = self.batch.validate()?.into_batcher_settings()?;
+        let auth = self.auth.choose_one(&self.endpoint.auth)?;
+        let headers = self.build_headers(auth.is_some())?;
+        let endpoint = self.endpoint.with_default_parts();
+
+        let tls = TlsSettings::from_options(&self.tls)?;
+        let client = HttpClient::new(tls, cx.proxy())?;
+
+        let request_builder = OpenTelemetryHttpRequestBuilder {
+            logs_uri: endpoint.append_path(Signal::Logs.http_path())?.uri,
+            metrics_uri: endpoint.append_path(Signal::Metrics.http_path())?.uri,
+            traces_uri: endpoint.append_path(Signal::Traces.http_path())?.uri,
+            headers,
+            auth,
+            compression: self.compression,
+        };
+        let healthcheck = healthcheck_http(client.clone(), request_builder.clone()).boxed();
+
+        let service = HttpService::new(client, request_builder);
+        let service = ServiceBuilder::new()
+            .settings(
+                self.request.tower.into_settings(),
+                http_response_retry_logic(),
+            )
+            .service(service);
+
+        let sink = OpenTelemetrySink::new(
+            service,
+            batch_settings,
+            OpenTelemetryRequestBuilder {
+                encoder: OpenTelemetryEncoder,
+                compression: self.compression,
+            },
+        );
+
+        Ok((VectorSink::from_event_streamsink(sink), healthcheck))
+    }
+
+    fn build_grpc(&self, cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
+        let gzip = match self.compression {
+            Compression::None => false,
+            Compression::Gzip(_) => true,
+            _ => return Err("Only `gzip` compression is supported by the `grpc` protocol.".into()),
+        };
+        let batch_settings = self.batch.validate()?.into_batcher_settings()?;
+        let auth = self.auth.choose_one(&self.endpoint.auth)?;
+        let mut headers = self.build_headers(auth.is_some())?;
+        match auth {
+            // The gRPC client bypasses `HttpClient`, which is what fetches OAuth2 access tokens.
+            Some(Auth::OAuth2 { .. }) => {
+                return Err("OAuth2 authentication is not supported by the `grpc` protocol.".into())
+            }
+            Some(auth) => auth.apply_headers_map(&mut headers),
+            None => {}
+        }
+        let headers = MetadataMap::from_headers(headers);
+        let endpoint = self.endpoint.with_default_parts().uri;
+
+        let tls = TlsSettings::from_options(&self.tls)?;
+        let proxy = build_proxy_connector(tls.into(), cx.proxy())?;
+        let client = hyper::Client::builder().http2_only(true).build(proxy);
+
+        let service = OpenTelemetryGrpcService::new(client, endpoint, headers, gzip);
+        let healthcheck = service.clone().healthcheck().boxed();
+        let service = ServiceBuilder::new()
+            .settings(
+                self.request.tower.into_settings(),
+                OpenTelemetryGrpcRetryLogic,
+            )
+            .service(service);
+
+        // Compression is handled by the gRPC client rather than the request builder.
+        let sink = OpenTelemetrySink::new(
+            service,
+            batch_settings,
+            OpenTelemetryRequestBuilder {
+                encoder: OpenTelemetryEncoder,
+                compression: Compression::None,
+            },
+        );
+
+        Ok((VectorSink::from_event_streamsink(sink), healthcheck))
+    }
+
+    /// Builds the headers sent with every request, besides those for the configured
+    /// authentication strategy.
+    fn build_headers(&self, has_auth: bool) -> crate::Result<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        for (name, value) in validate_headers(&self.request.headers)? {
+            if has_auth && name == AUTHORIZATION {
+                return Err(
+                    "Authorization header can not be used with defined auth options".into(),
+                );
+            }
+            headers.insert(name, value);
+        }
+
+        Ok(headers)
+    }
+}
+
+/// OTLP receivers do not expose a health endpoint, so an empty export request is sent instead.
+async fn healthcheck_http(
+    client: HttpClient,
+    request_builder: OpenTelemetryHttpRequestBuilder,
+) -> crate::Result<()> {
+    let request = request_builder.build_healthcheck()?.map(Body::from);
+    let response = client.send(request).await?;
+
+    match response.status() {
+        status if status.is_success() => Ok(()),
+        status => Err(HealthcheckError::UnexpectedStatus { status }.into()),
+    }
+}
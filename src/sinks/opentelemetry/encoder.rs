@@ -0,0 +1,50 @@
+//! Encoding for the `opentelemetry` sink.
+
+use std::io;
+
+use prost::Message;
+use vector_lib::opentelemetry::proto::collector::{
+    logs::v1::ExportLogsServiceRequest, metrics::v1::ExportMetricsServiceRequest,
+    trace::v1::ExportTraceServiceRequest,
+};
+
+use crate::sinks::{prelude::*, util::encoding::Encoder as SinkEncoder};
+
+use super::sink::Signal;
+
+/// Encodes a batch of events of a single signal as an OTLP protobuf export request.
+pub(super) struct OpenTelemetryEncoder;
+
+impl SinkEncoder<(Signal, Vec<Event>)> for OpenTelemetryEncoder {
+    fn encode_input(
+        &self,
+        (signal, events): (Signal, Vec<Event>),
+        writer: &mut dyn io::Write,
+    ) -> io::Result<(usize, GroupedCountByteSize)> {
+        let mut byte_size = telemetry().create_request_count_byte_size();
+        let n_events = events.len();
+        for event in &events {
+            byte_size.add_event(event, event.estimated_json_encoded_size_of());
+        }
+
+        let body = match signal {
+            Signal::Logs => events
+                .into_iter()
+                .map(Event::into_log)
+                .collect::<ExportLogsServiceRequest>()
+                .encode_to_vec(),
+            Signal::Metrics => events
+                .into_iter()
+                .map(Event::into_metric)
+                .collect::<ExportMetricsServiceRequest>()
+                .encode_to_vec(),
+            Signal::Traces => events
+                .into_iter()
+                .map(Event::into_trace)
+                .collect::<ExportTraceServiceRequest>()
+                .encode_to_vec(),
+        };
+
+        write_all(writer, n_events, &body).map(|()| (body.len(), byte_size))
+    }
+}
@@ -0,0 +1,48 @@
+//! `RequestBuilder` implementation for the `opentelemetry` sink.
+
+use bytes::Bytes;
+use std::io;
+
+use crate::sinks::{prelude::*, util::http::HttpRequest};
+
+use super::{encoder::OpenTelemetryEncoder, sink::Signal};
+
+pub(super) struct OpenTelemetryRequestBuilder {
+    pub(super) encoder: OpenTelemetryEncoder,
+    pub(super) compression: Compression,
+}
+
+impl RequestBuilder<(Signal, Vec<Event>)> for OpenTelemetryRequestBuilder {
+    type Metadata = (Signal, EventFinalizers);
+    type Events = (Signal, Vec<Event>);
+    type Encoder = OpenTelemetryEncoder;
+    type Payload = Bytes;
+    type Request = HttpRequest<Signal>;
+    type Error = io::Error;
+
+    fn compression(&self) -> Compression {
+        self.compression
+    }
+
+    fn encoder(&self) -> &Self::Encoder {
+        &self.encoder
+    }
+
+    fn split_input(
+        &self,
+        (signal, mut events): (Signal, Vec<Event>),
+    ) -> (Self::Metadata, RequestMetadataBuilder, Self::Events) {
+        let finalizers = events.take_finalizers();
+        let builder = RequestMetadataBuilder::from_events(&events);
+        ((signal, finalizers), builder, (signal, events))
+    }
+
+    fn build_request(
+        &self,
+        (signal, finalizers): Self::Metadata,
+        request_metadata: RequestMetadata,
+        payload: EncodeResult<Self::Payload>,
+    ) -> Self::Request {
+        HttpRequest::new(payload.into_payload(), finalizers, request_metadata, signal)
+    }
+}
@@ -0,0 +1,312 @@
+//! Service implementations for the `opentelemetry` sink.
+
+use std::task::{Context, Poll};
+
+use bytes::{Buf, BufMut, Bytes};
+use http::{
+    header::{CONTENT_ENCODING, CONTENT_TYPE},
+    uri::PathAndQuery,
+    HeaderMap, Request, Uri,
+};
+use hyper::client::HttpConnector;
+use hyper_openssl::HttpsConnector;
+use hyper_proxy::ProxyConnector;
+use snafu::ResultExt;
+use tonic::{
+    body::BoxBody,
+    codec::{Codec, CompressionEncoding, DecodeBuf, Decoder, EncodeBuf, Encoder},
+    metadata::MetadataMap,
+    Status,
+};
+
+use super::{sink::Signal, OpenTelemetrySinkError};
+use crate::{
+    http::Auth,
+    internal_events::EndpointBytesSent,
+    sinks::{
+        prelude::*,
+        util::{
+            http::{HttpRequest, HttpServiceRequestBuilder},
+            uri,
+        },
+        HTTPRequestBuilderSnafu,
+    },
+};
+
+const CONTENT_TYPE_PROTOBUF: &str = "application/x-protobuf";
+
+/// Builds OTLP/HTTP requests for the generic `HttpService`.
+#[derive(Debug, Clone)]
+pub(super) struct OpenTelemetryHttpRequestBuilder {
+    pub(super) logs_uri: Uri,
+    pub(super) metrics_uri: Uri,
+    pub(super) traces_uri: Uri,
+    pub(super) headers: HeaderMap,
+    pub(super) auth: Option<Auth>,
+    pub(super) compression: Compression,
+}
+
+impl HttpServiceRequestBuilder<Signal> for OpenTelemetryHttpRequestBuilder {
+    fn build(&self, mut request: HttpRequest<Signal>) -> Result<Request<Bytes>, crate::Error> {
+        let uri = match request.get_additional_metadata() {
+            Signal::Logs => &self.logs_uri,
+            Signal::Metrics => &self.metrics_uri,
+            Signal::Traces => &self.traces_uri,
+        };
+
+        let mut builder = Request::post(uri).header(CONTENT_TYPE, CONTENT_TYPE_PROTOBUF);
+        if let Some(content_encoding) = self.compression.content_encoding() {
+            builder = builder.header(CONTENT_ENCODING, content_encoding);
+        }
+
+        let mut request = builder
+            .body(request.take_payload())
+            .context(HTTPRequestBuilderSnafu)?;
+        request.headers_mut().extend(self.headers.clone());
+        if let Some(auth) = &self.auth {
+            auth.apply(&mut request);
+        }
+
+        Ok(request)
+    }
+}
+
+impl OpenTelemetryHttpRequestBuilder {
+    /// Builds an empty logs export request, which OTLP receivers accept without storing anything.
+    pub(super) fn build_healthcheck(&self) -> Result<Request<Bytes>, crate::Error> {
+        let mut request = Request::post(&self.logs_uri)
+            .header(CONTENT_TYPE, CONTENT_TYPE_PROTOBUF)
+            .body(Bytes::new())
+            .context(HTTPRequestBuilderSnafu)?;
+        request.headers_mut().extend(self.headers.clone());
+        if let Some(auth) = &self.auth {
+            auth.apply(&mut request);
+        }
+
+        Ok(request)
+    }
+}
+
+type HyperClient = hyper::Client<ProxyConnector<HttpsConnector<HttpConnector>>, BoxBody>;
+
+/// Sends OTLP export requests to the OTLP/gRPC endpoint of the matching signal.
+#[derive(Clone, Debug)]
+pub(super) struct OpenTelemetryGrpcService {
+    client: tonic::client::Grpc<HyperSvc>,
+    metadata: MetadataMap,
+    protocol: String,
+    endpoint: String,
+}
+
+impl OpenTelemetryGrpcService {
+    pub(super) fn new(
+        hyper_client: HyperClient,
+        uri: Uri,
+        metadata: MetadataMap,
+        gzip: bool,
+    ) -> Self {
+        let (protocol, endpoint) = uri::protocol_endpoint(uri.clone());
+        let mut client = tonic::client::Grpc::new(HyperSvc {
+            uri,
+            client: hyper_client,
+        });
+        if gzip {
+            client = client.send_compressed(CompressionEncoding::Gzip);
+        }
+
+        Self {
+            client,
+            metadata,
+            protocol,
+            endpoint,
+        }
+    }
+
+    /// Sends an empty logs export request, which OTLP receivers accept without storing anything.
+    pub(super) async fn healthcheck(mut self) -> crate::Result<()> {
+        let mut request = tonic::Request::new(Bytes::new());
+        *request.metadata_mut() = self.metadata.clone();
+
+        self.client
+            .ready()
+            .await
+            .map_err(|error| Status::unknown(format!("Service was not ready: {}", error)))
+            .context(super::RequestSnafu)?;
+        self.client
+            .unary::<_, Bytes, _>(
+                request,
+                PathAndQuery::from_static(Signal::Logs.grpc_path()),
+                EncodedProtobufCodec,
+            )
+            .await
+            .context(super::RequestSnafu)?;
+
+        Ok(())
+    }
+}
+
+pub(super) struct OpenTelemetryGrpcResponse {
+    events_byte_size: GroupedCountByteSize,
+    raw_byte_size: usize,
+}
+
+impl DriverResponse for OpenTelemetryGrpcResponse {
+    fn event_status(&self) -> EventStatus {
+        EventStatus::Delivered
+    }
+
+    fn events_sent(&self) -> &GroupedCountByteSize {
+        &self.events_byte_size
+    }
+
+    fn bytes_sent(&self) -> Option<usize> {
+        Some(self.raw_byte_size)
+    }
+}
+
+impl Service<HttpRequest<Signal>> for OpenTelemetryGrpcService {
+    type Response = OpenTelemetryGrpcResponse;
+    type Error = crate::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    // Readiness of the client is checked in `call()`, before sending the request.
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    // Emission of internal events for errors and dropped events is handled upstream by the caller.
+    fn call(&mut self, mut request: HttpRequest<Signal>) -> Self::Future {
+        let mut service = self.clone();
+        let path = PathAndQuery::from_static(request.get_additional_metadata().grpc_path());
+        let payload = request.take_payload();
+        let metadata = std::mem::take(request.metadata_mut());
+        let raw_byte_size = metadata.request_encoded_size();
+        let events_byte_size = metadata.into_events_estimated_json_encoded_byte_size();
+
+        Box::pin(async move {
+            let mut request = tonic::Request::new(payload);
+            *request.metadata_mut() = service.metadata.clone();
+
+            service
+                .client
+                .ready()
+                .await
+                .map_err(|error| Status::unknown(format!("Service was not ready: {}", error)))
+                .context(super::RequestSnafu)?;
+            service
+                .client
+                .unary::<_, Bytes, _>(request, path, EncodedProtobufCodec)
+                .await
+                .context(super::RequestSnafu)?;
+
+            emit!(EndpointBytesSent {
+                byte_size: raw_byte_size,
+                protocol: &service.protocol,
+                endpoint: &service.endpoint,
+            });
+
+            Ok(OpenTelemetryGrpcResponse {
+                events_byte_size,
+                raw_byte_size,
+            })
+        })
+    }
+}
+
+/// A gRPC codec for payloads that are already protobuf encoded by the request builder, which
+/// avoids decoding and re-encoding them just to use the generated clients.
+///
+/// Responses are returned undecoded, as only the status of the call is relevant.
+#[derive(Clone, Copy, Debug, Default)]
+struct EncodedProtobufCodec;
+
+impl Codec for EncodedProtobufCodec {
+    type Encode = Bytes;
+    type Decode = Bytes;
+    type Encoder = Self;
+    type Decoder = Self;
+
+    fn encoder(&mut self) -> Self::Encoder {
+        *self
+    }
+
+    fn decoder(&mut self) -> Self::Decoder {
+        *self
+    }
+}
+
+impl Encoder for EncodedProtobufCodec {
+    type Item = Bytes;
+    type Error = Status;
+
+    fn encode(&mut self, item: Self::Item, dst: &mut EncodeBuf<'_>) -> Result<(), Self::Error> {
+        dst.put(item);
+        Ok(())
+    }
+}
+
+impl Decoder for EncodedProtobufCodec {
+    type Item = Bytes;
+    type Error = Status;
+
+    fn decode(&mut self, src: &mut DecodeBuf<'_>) -> Result<Option<Self::Item>, Self::Error> {
+        Ok(Some(src.copy_to_bytes(src.remaining())))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct HyperSvc {
+    uri: Uri,
+    client: HyperClient,
+}
+
+impl Service<hyper::Request<BoxBody>> for HyperSvc {
+    type Response = hyper::Response<hyper::Body>;
+    type Error = hyper::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, mut req: hyper::Request<BoxBody>) -> Self::Future {
+        let uri = Uri::builder()
+            .scheme(self.uri.scheme().unwrap().clone())
+            .authority(self.uri.authority().unwrap().clone())
+            .path_and_query(req.uri().path_and_query().unwrap().clone())
+            .build()
+            .unwrap();
+
+        *req.uri_mut() = uri;
+
+        Box::pin(self.client.request(req))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct OpenTelemetryGrpcRetryLogic;
+
+impl RetryLogic for OpenTelemetryGrpcRetryLogic {
+    type Error = OpenTelemetrySinkError;
+    type Response = OpenTelemetryGrpcResponse;
+
+    fn is_retriable_error(&self, error: &Self::Error) -> bool {
+        use tonic::Code::*;
+
+        match error {
+            // Retryable codes as defined by the OTLP specification:
+            //
+            // <https://opentelemetry.io/docs/specs/otlp/#failures>
+            OpenTelemetrySinkError::Request { source } => matches!(
+                source.code(),
+                Cancelled
+                    | DeadlineExceeded
+                    | Aborted
+                    | OutOfRange
+                    | Unavailable
+                    | DataLoss
+                    | ResourceExhausted
+            ),
+        }
+    }
+}
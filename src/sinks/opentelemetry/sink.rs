@@ -0,0 +1,135 @@
+//! Implementation of the `opentelemetry` sink.
+
+use crate::{
+    internal_events::OpenTelemetryUnsupportedMetricError,
+    sinks::{prelude::*, util::http::HttpRequest},
+};
+use vector_lib::opentelemetry::encode::is_supported_metric_value;
+
+use super::request_builder::OpenTelemetryRequestBuilder;
+
+/// The OTLP signal, which determines the export request and endpoint used for a batch of events.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub(super) enum Signal {
+    Logs,
+    Metrics,
+    Traces,
+}
+
+impl Signal {
+    const fn of(event: &Event) -> Self {
+        match event {
+            Event::Log(_) => Signal::Logs,
+            Event::Metric(_) => Signal::Metrics,
+            Event::Trace(_) => Signal::Traces,
+        }
+    }
+
+    /// The path of the OTLP/HTTP endpoint for this signal.
+    pub(super) const fn http_path(self) -> &'static str {
+        match self {
+            Signal::Logs => "/v1/logs",
+            Signal::Metrics => "/v1/metrics",
+            Signal::Traces => "/v1/traces",
+        }
+    }
+
+    /// The path of the OTLP/gRPC export method for this signal.
+    pub(super) const fn grpc_path(self) -> &'static str {
+        match self {
+            Signal::Logs => "/opentelemetry.proto.collector.logs.v1.LogsService/Export",
+            Signal::Metrics => "/opentelemetry.proto.collector.metrics.v1.MetricsService/Export",
+            Signal::Traces => "/opentelemetry.proto.collector.trace.v1.TraceService/Export",
+        }
+    }
+}
+
+/// Partitions events by signal, as each export request can only hold a single signal.
+struct SignalPartitioner;
+
+impl Partitioner for SignalPartitioner {
+    type Item = Event;
+    type Key = Signal;
+
+    fn partition(&self, item: &Self::Item) -> Self::Key {
+        Signal::of(item)
+    }
+}
+
+pub(super) struct OpenTelemetrySink<S> {
+    service: S,
+    batch_settings: BatcherSettings,
+    request_builder: OpenTelemetryRequestBuilder,
+}
+
+impl<S> OpenTelemetrySink<S>
+where
+    S: Service<HttpRequest<Signal>> + Send + 'static,
+    S::Future: Send + 'static,
+    S::Response: DriverResponse + Send + 'static,
+    S::Error: std::fmt::Debug + Into<crate::Error> + Send,
+{
+    /// Creates a new `OpenTelemetrySink`.
+    pub(super) const fn new(
+        service: S,
+        batch_settings: BatcherSettings,
+        request_builder: OpenTelemetryRequestBuilder,
+    ) -> Self {
+        Self {
+            service,
+            batch_settings,
+            request_builder,
+        }
+    }
+
+    async fn run_inner(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
+        let batch_settings = self.batch_settings;
+
+        input
+            // Reject the metrics that can't be represented in OTLP before they are batched, so they
+            // do not count against the batch size.
+            .filter_map(|mut event| {
+                let supported = match &event {
+                    Event::Metric(metric) => is_supported_metric_value(metric.value()),
+                    Event::Log(_) | Event::Trace(_) => true,
+                };
+                if !supported {
+                    event.take_finalizers().update_status(EventStatus::Rejected);
+                    emit!(OpenTelemetryUnsupportedMetricError {
+                        value_type: event.as_metric().value().as_name(),
+                    });
+                }
+                future::ready(supported.then_some(event))
+            })
+            .batched_partitioned(SignalPartitioner, || batch_settings.as_byte_size_config())
+            .request_builder(
+                default_request_builder_concurrency_limit(),
+                self.request_builder,
+            )
+            .filter_map(|request| async move {
+                match request {
+                    Err(error) => {
+                        emit!(SinkRequestBuildError { error });
+                        None
+                    }
+                    Ok(req) => Some(req),
+                }
+            })
+            .into_driver(self.service)
+            .run()
+            .await
+    }
+}
+
+#[async_trait]
+impl<S> StreamSink<Event> for OpenTelemetrySink<S>
+where
+    S: Service<HttpRequest<Signal>> + Send + 'static,
+    S::Future: Send + 'static,
+    S::Response: DriverResponse + Send + 'static,
+    S::Error: std::fmt::Debug + Into<crate::Error> + Send,
+{
+    async fn run(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
+        self.run_inner(input).await
+    }
+}
@@ -0,0 +1,253 @@
+use bytes::Bytes;
+use futures::{channel::mpsc, stream, StreamExt};
+use http::request::Parts;
+use hyper::{Method, StatusCode};
+use prost::Message;
+use vector_lib::{
+    event::{BatchNotifier, BatchStatus, Metric, MetricKind, MetricValue, StatisticKind},
+    opentelemetry::proto::{
+        collector::{logs::v1::ExportLogsServiceRequest, metrics::v1::ExportMetricsServiceRequest},
+        common::v1::any_value::Value as AnyValueKind,
+        logs::v1::LogRecord,
+        metrics::v1::metric::Data,
+    },
+};
+
+use super::*;
+use crate::{
+    config::{SinkConfig as _, SinkContext},
+    event::Event,
+    sinks::util::test::{build_test_server, build_test_server_generic, build_test_server_status},
+    test_util::{
+        components::{run_and_assert_sink_compliance, HTTP_SINK_TAGS},
+        next_addr, random_lines_with_stream,
+    },
+};
+
+// one byte for the compression flag plus four bytes for the length
+const GRPC_HEADER_SIZE: usize = 5;
+
+#[test]
+fn generate_config() {
+    crate::test_util::test_generate_config::<OpenTelemetryConfig>();
+}
+
+fn build_config(protocol: &str, endpoint: &str) -> OpenTelemetryConfig {
+    toml::from_str(&format!(
+        r#"
+            [protocol]
+            type = "{protocol}"
+            endpoint = "{endpoint}"
+        "#
+    ))
+    .unwrap()
+}
+
+async fn get_received<T: Message + Default>(
+    rx: mpsc::Receiver<(Parts, Bytes)>,
+    assert_parts: impl Fn(Parts),
+    strip_grpc_header: bool,
+) -> Vec<T> {
+    rx.map(|(parts, body)| {
+        assert_parts(parts);
+
+        let body = if strip_grpc_header {
+            body.slice(GRPC_HEADER_SIZE..)
+        } else {
+            body
+        };
+        T::decode(body).unwrap()
+    })
+    .collect()
+    .await
+}
+
+fn log_bodies(requests: Vec<ExportLogsServiceRequest>) -> Vec<String> {
+    requests
+        .into_iter()
+        .flat_map(|request| request.resource_logs)
+        .flat_map(|resource_logs| resource_logs.scope_logs)
+        .flat_map(|scope_logs| scope_logs.log_records)
+        .map(
+            |LogRecord { body, .. }| match body.and_then(|body| body.value) {
+                Some(AnyValueKind::StringValue(body)) => body,
+                other => panic!("unexpected log body: {other:?}"),
+            },
+        )
+        .collect()
+}
+
+#[tokio::test]
+async fn http_protobuf_delivers_logs() {
+    let in_addr = next_addr();
+    let config = build_config("http_protobuf", &format!("http://{in_addr}"));
+    let (sink, _) = config.build(SinkContext::default()).await.unwrap();
+
+    let (rx, trigger, server) = build_test_server(in_addr);
+    tokio::spawn(server);
+
+    let (batch, mut receiver) = BatchNotifier::new_with_receiver();
+    let (input_lines, events) = random_lines_with_stream(8, 10, Some(batch));
+
+    run_and_assert_sink_compliance(sink, events, &HTTP_SINK_TAGS).await;
+    drop(trigger);
+
+    assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
+
+    let requests = get_received::<ExportLogsServiceRequest>(
+        rx,
+        |parts| {
+            assert_eq!(Method::POST, parts.method);
+            assert_eq!("/v1/logs", parts.uri.path());
+            assert_eq!(
+                "application/x-protobuf",
+                parts.headers.get("content-type").unwrap().to_str().unwrap()
+            );
+        },
+        false,
+    )
+    .await;
+
+    assert_eq!(input_lines, log_bodies(requests));
+}
+
+#[tokio::test]
+async fn http_protobuf_delivers_metrics() {
+    let in_addr = next_addr();
+    let config = build_config("http_protobuf", &format!("http://{in_addr}"));
+    let (sink, _) = config.build(SinkContext::default()).await.unwrap();
+
+    let (rx, trigger, server) = build_test_server(in_addr);
+    tokio::spawn(server);
+
+    let metric = Metric::new(
+        "requests",
+        MetricKind::Absolute,
+        MetricValue::Counter { value: 42.0 },
+    );
+
+    run_and_assert_sink_compliance(sink, stream::iter([Event::from(metric)]), &HTTP_SINK_TAGS)
+        .await;
+    drop(trigger);
+
+    let requests = get_received::<ExportMetricsServiceRequest>(
+        rx,
+        |parts| assert_eq!("/v1/metrics", parts.uri.path()),
+        false,
+    )
+    .await;
+
+    let metrics = requests
+        .into_iter()
+        .flat_map(|request| request.resource_metrics)
+        .flat_map(|resource_metrics| resource_metrics.scope_metrics)
+        .flat_map(|scope_metrics| scope_metrics.metrics)
+        .collect::<Vec<_>>();
+    assert_eq!(1, metrics.len());
+    assert_eq!("requests", metrics[0].name);
+    assert!(matches!(metrics[0].data, Some(Data::Sum(ref sum)) if sum.is_monotonic));
+}
+
+#[tokio::test]
+async fn http_protobuf_rejects_unsupported_metrics() {
+    let in_addr = next_addr();
+    let config = build_config("http_protobuf", &format!("http://{in_addr}"));
+    let (sink, _) = config.build(SinkContext::default()).await.unwrap();
+
+    let (_rx, trigger, server) = build_test_server(in_addr);
+    tokio::spawn(server);
+
+    let (batch, mut receiver) = BatchNotifier::new_with_receiver();
+    let metric = Metric::new(
+        "latency",
+        MetricKind::Incremental,
+        MetricValue::Distribution {
+            samples: vector_lib::samples![1.0 => 1],
+            statistic: StatisticKind::Histogram,
+        },
+    )
+    .with_batch_notifier(&batch);
+    drop(batch);
+
+    sink.run_events([Event::from(metric)]).await.unwrap();
+    drop(trigger);
+
+    assert_eq!(receiver.try_recv(), Ok(BatchStatus::Rejected));
+}
+
+#[tokio::test]
+async fn http_protobuf_healthcheck() {
+    let in_addr = next_addr();
+    let config = build_config("http_protobuf", &format!("http://{in_addr}"));
+    let (_, healthcheck) = config.build(SinkContext::default()).await.unwrap();
+
+    let (rx, trigger, server) = build_test_server(in_addr);
+    tokio::spawn(server);
+
+    healthcheck.await.unwrap();
+    drop(trigger);
+
+    let requests = get_received::<ExportLogsServiceRequest>(
+        rx,
+        |parts| assert_eq!("/v1/logs", parts.uri.path()),
+        false,
+    )
+    .await;
+    assert_eq!(vec![ExportLogsServiceRequest::default()], requests);
+}
+
+#[tokio::test]
+async fn http_protobuf_healthcheck_fails_on_error_status() {
+    let in_addr = next_addr();
+    let config = build_config("http_protobuf", &format!("http://{in_addr}"));
+    let (_, healthcheck) = config.build(SinkContext::default()).await.unwrap();
+
+    let (_rx, _trigger, server) = build_test_server_status(in_addr, StatusCode::NOT_FOUND);
+    tokio::spawn(server);
+
+    assert!(healthcheck.await.is_err());
+}
+
+#[tokio::test]
+async fn grpc_delivers_logs() {
+    let in_addr = next_addr();
+    let config = build_config("grpc", &format!("http://{in_addr}"));
+    let (sink, _) = config.build(SinkContext::default()).await.unwrap();
+
+    let (rx, trigger, server) = build_test_server_generic(in_addr, || {
+        hyper::Response::builder()
+            .header("grpc-status", "0") // OK
+            .header("content-type", "application/grpc")
+            // An empty `ExportLogsServiceResponse`, preceded by the gRPC message header.
+            .body(hyper::Body::from(vec![0u8; GRPC_HEADER_SIZE]))
+            .unwrap()
+    });
+    tokio::spawn(server);
+
+    let (batch, mut receiver) = BatchNotifier::new_with_receiver();
+    let (input_lines, events) = random_lines_with_stream(8, 10, Some(batch));
+
+    run_and_assert_sink_compliance(sink, events, &HTTP_SINK_TAGS).await;
+    drop(trigger);
+
+    assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
+
+    let requests = get_received::<ExportLogsServiceRequest>(
+        rx,
+        |parts| {
+            assert_eq!(Method::POST, parts.method);
+            assert_eq!(
+                "/opentelemetry.proto.collector.logs.v1.LogsService/Export",
+                parts.uri.path()
+            );
+            assert_eq!(
+                "application/grpc",
+                parts.headers.get("content-type").unwrap().to_str().unwrap()
+            );
+        },
+        true,
+    )
+    .await;
+
+    assert_eq!(input_lines, log_bodies(requests));
+}
@@ -0,0 +1,167 @@
+use futures::stream;
+use tokio_postgres::NoTls;
+use vector_lib::event::{BatchNotifier, BatchStatus, Event, LogEvent};
+
+use super::PostgresConfig;
+use crate::{
+    sinks::prelude::*,
+    test_util::{
+        components::{run_and_assert_sink_compliance, SINK_TAGS},
+        random_string, trace_init,
+    },
+};
+
+fn pg_url() -> String {
+    std::env::var("PG_URL").unwrap_or_else(|_| {
+        let host = std::env::var("PG_HOST").unwrap_or_else(|_| "localhost".into());
+        format!("postgres://vector:vector@{host}/postgres")
+    })
+}
+
+async fn connect() -> tokio_postgres::Client {
+    let (client, connection) = tokio_postgres::connect(&pg_url(), NoTls)
+        .await
+        .expect("Cannot connect to PostgreSQL");
+    tokio::spawn(connection);
+    client
+}
+
+async fn create_table(client: &tokio_postgres::Client) -> String {
+    let table = format!("test_{}", random_string(10).to_lowercase());
+    client
+        .batch_execute(&format!(
+            "CREATE TABLE {table} (id integer PRIMARY KEY, message text NOT NULL, \
+             level text DEFAULT 'info')"
+        ))
+        .await
+        .expect("Cannot create the table");
+    table
+}
+
+fn config(table: &str, extra: &str) -> PostgresConfig {
+    toml::from_str(&format!(
+        r#"
+        endpoint = "{}"
+        table = "{table}"
+        {extra}
+        "#,
+        pg_url()
+    ))
+    .unwrap()
+}
+
+fn event(id: i64, message: &str, batch: &BatchNotifier) -> Event {
+    let mut log = LogEvent::from(message).with_batch_notifier(batch);
+    log.insert("id", id);
+    log.remove_timestamp();
+    log.into()
+}
+
+async fn select(client: &tokio_postgres::Client, table: &str) -> Vec<(i32, String, String)> {
+    client
+        .query(
+            &format!("SELECT id, message, level FROM {table} ORDER BY id"),
+            &[],
+        )
+        .await
+        .expect("Cannot select the rows")
+        .iter()
+        .map(|row| (row.get(0), row.get(1), row.get(2)))
+        .collect()
+}
+
+#[tokio::test]
+async fn inserts_events() {
+    trace_init();
+
+    let client = connect().await;
+    let table = create_table(&client).await;
+    let (sink, _) = config(&table, "")
+        .build(SinkContext::default())
+        .await
+        .unwrap();
+
+    let (batch, mut receiver) = BatchNotifier::new_with_receiver();
+    let events = vec![event(1, "first", &batch), event(2, "second", &batch)];
+    drop(batch);
+    run_and_assert_sink_compliance(sink, stream::iter(events), &SINK_TAGS).await;
+
+    assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
+    assert_eq!(
+        select(&client, &table).await,
+        vec![
+            (1, "first".into(), "info".into()),
+            (2, "second".into(), "info".into())
+        ]
+    );
+}
+
+#[tokio::test]
+async fn rejects_invalid_rows() {
+    trace_init();
+
+    let client = connect().await;
+    let table = create_table(&client).await;
+    let (sink, _) = config(&table, "")
+        .build(SinkContext::default())
+        .await
+        .unwrap();
+
+    let (valid_batch, mut valid_receiver) = BatchNotifier::new_with_receiver();
+    let (invalid_batch, mut invalid_receiver) = BatchNotifier::new_with_receiver();
+    let events = vec![
+        event(1, "first", &valid_batch),
+        event(1, "duplicate", &invalid_batch),
+        event(2, "second", &valid_batch),
+    ];
+    drop(valid_batch);
+    drop(invalid_batch);
+    sink.run(stream::iter(events).map(Into::into))
+        .await
+        .unwrap();
+
+    assert_eq!(valid_receiver.try_recv(), Ok(BatchStatus::Delivered));
+    assert_eq!(invalid_receiver.try_recv(), Ok(BatchStatus::Rejected));
+    assert_eq!(
+        select(&client, &table).await,
+        vec![
+            (1, "first".into(), "info".into()),
+            (2, "second".into(), "info".into())
+        ]
+    );
+}
+
+#[tokio::test]
+async fn updates_conflicting_rows() {
+    trace_init();
+
+    let client = connect().await;
+    let table = create_table(&client).await;
+    client
+        .execute(
+            &format!("INSERT INTO {table} (id, message, level) VALUES (1, 'old', 'debug')"),
+            &[],
+        )
+        .await
+        .unwrap();
+    let (sink, _) = config(
+        &table,
+        r#"on_conflict.columns = ["id"]
+        on_conflict.action = "update""#,
+    )
+    .build(SinkContext::default())
+    .await
+    .unwrap();
+
+    let (batch, mut receiver) = BatchNotifier::new_with_receiver();
+    let events = vec![event(1, "new", &batch)];
+    drop(batch);
+    run_and_assert_sink_compliance(sink, stream::iter(events), &SINK_TAGS).await;
+
+    assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
+    // Only the columns matching a field of the events are updated.
+    assert_eq!(
+        select(&client, &table).await,
+        vec![(1, "new".into(), "debug".into())]
+    );
+}
@@ -0,0 +1,12 @@
+//! `postgres` sink.
+//!
+//! Inserts log events into [PostgreSQL](https://www.postgresql.org/) tables, mapping the
+//! top-level fields of each event to the columns of the same name.
+mod config;
+mod service;
+mod sink;
+
+#[cfg(all(test, feature = "postgres-integration-tests"))]
+mod integration_tests;
+
+pub use self::config::PostgresConfig;
@@ -0,0 +1,386 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    fmt::Write as _,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
+};
+
+use futures::future::BoxFuture;
+use snafu::{ResultExt, Snafu};
+use tokio::sync::Mutex;
+use tokio_postgres::{Client, Error as PgError};
+
+use super::config::{ConflictAction, OnConflictConfig, PostgresConnector};
+use crate::{internal_events::PostgresRowRejected, sinks::prelude::*};
+
+/// Columns are looked up in the catalog rather than `information_schema` so that the table
+/// name is resolved with the search path of the connection, exactly as PostgreSQL resolves it
+/// when inserting rows.
+const DESCRIBE_TABLE_QUERY: &str = "SELECT $1::text::regclass::text, a.attname::text \
+    FROM pg_attribute a \
+    WHERE a.attrelid = $1::text::regclass AND a.attnum > 0 \
+    AND NOT a.attisdropped AND a.attgenerated = '' \
+    ORDER BY a.attnum";
+
+#[derive(Clone)]
+pub(super) struct PostgresRequest {
+    pub(super) table: String,
+    /// The top-level fields found in any of the rows.
+    pub(super) fields: BTreeSet<String>,
+    /// Each row encoded as a JSON object.
+    pub(super) rows: Vec<String>,
+    pub(super) finalizers: EventFinalizers,
+    /// The finalizers of each row, updated when a row is rejected on its own.
+    pub(super) row_finalizers: Vec<EventFinalizers>,
+    /// The number of leading rows already inserted or rejected by an earlier attempt, which are
+    /// skipped when the request is retried.
+    pub(super) inserted: Arc<AtomicUsize>,
+    pub(super) metadata: RequestMetadata,
+}
+
+impl Finalizable for PostgresRequest {
+    fn take_finalizers(&mut self) -> EventFinalizers {
+        std::mem::take(&mut self.finalizers)
+    }
+}
+
+impl MetaDescriptive for PostgresRequest {
+    fn get_metadata(&self) -> &RequestMetadata {
+        &self.metadata
+    }
+
+    fn metadata_mut(&mut self) -> &mut RequestMetadata {
+        &mut self.metadata
+    }
+}
+
+pub(super) struct PostgresResponse {
+    metadata: RequestMetadata,
+}
+
+impl DriverResponse for PostgresResponse {
+    fn event_status(&self) -> EventStatus {
+        EventStatus::Delivered
+    }
+
+    fn events_sent(&self) -> &GroupedCountByteSize {
+        self.metadata.events_estimated_json_encoded_byte_size()
+    }
+
+    fn bytes_sent(&self) -> Option<usize> {
+        Some(self.metadata.request_encoded_size())
+    }
+}
+
+#[derive(Debug, Snafu)]
+pub(super) enum PostgresError {
+    #[snafu(display("Failed to connect: {}", source))]
+    Connect { source: PgError },
+    #[snafu(display("Failed to describe table {:?}: {}", table, source))]
+    DescribeTable { table: String, source: PgError },
+    #[snafu(display("No field of the events matches a column of table {}", table))]
+    NoColumns { table: String },
+    #[snafu(display("Failed to insert rows: {}", source))]
+    Insert { source: PgError },
+}
+
+impl PostgresError {
+    fn source_error(&self) -> Option<&PgError> {
+        match self {
+            Self::Connect { source }
+            | Self::DescribeTable { source, .. }
+            | Self::Insert { source } => Some(source),
+            Self::NoColumns { .. } => None,
+        }
+    }
+}
+
+/// Errors without an SQLSTATE code come from the connection itself, while the codes below
+/// denote conditions that clear up on their own: lost connections, serialization failures and
+/// deadlocks, exhausted resources, server shutdowns and lock timeouts.
+fn is_transient(error: &PgError) -> bool {
+    match error.code() {
+        None => true,
+        Some(code) => {
+            let code = code.code();
+            code.starts_with("08")
+                || code.starts_with("40")
+                || code.starts_with("53")
+                || matches!(code, "57P01" | "57P02" | "57P03" | "55P03")
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+pub(super) struct PostgresRetryLogic;
+
+impl RetryLogic for PostgresRetryLogic {
+    type Error = PostgresError;
+    type Response = PostgresResponse;
+
+    fn is_retriable_error(&self, error: &Self::Error) -> bool {
+        error.source_error().is_some_and(is_transient)
+    }
+}
+
+/// The columns of a table, along with its name as quoted by PostgreSQL.
+struct TableInfo {
+    name: String,
+    columns: Vec<String>,
+}
+
+#[derive(Clone)]
+pub(super) struct PostgresService {
+    connector: Arc<PostgresConnector>,
+    client: Arc<Mutex<Option<Arc<Client>>>>,
+    tables: Arc<Mutex<HashMap<String, Arc<TableInfo>>>>,
+    on_conflict: Option<OnConflictConfig>,
+}
+
+impl PostgresService {
+    pub(super) fn new(
+        connector: Arc<PostgresConnector>,
+        on_conflict: Option<OnConflictConfig>,
+    ) -> Self {
+        Self {
+            connector,
+            client: Arc::default(),
+            tables: Arc::default(),
+            on_conflict,
+        }
+    }
+
+    /// Returns the shared client, connecting again if the connection was lost.
+    async fn client(&self) -> Result<Arc<Client>, PostgresError> {
+        let mut client = self.client.lock().await;
+        match client.as_ref() {
+            Some(client) if !client.is_closed() => Ok(Arc::clone(client)),
+            _ => {
+                let connected = Arc::new(self.connector.connect().await.context(ConnectSnafu)?);
+                *client = Some(Arc::clone(&connected));
+                Ok(connected)
+            }
+        }
+    }
+
+    async fn table_info(
+        &self,
+        client: &Client,
+        table: &str,
+    ) -> Result<Arc<TableInfo>, PostgresError> {
+        if let Some(info) = self.tables.lock().await.get(table) {
+            return Ok(Arc::clone(info));
+        }
+
+        let rows = client
+            .query(DESCRIBE_TABLE_QUERY, &[&table])
+            .await
+            .context(DescribeTableSnafu { table })?;
+        let info = Arc::new(TableInfo {
+            name: rows
+                .first()
+                .map_or_else(|| table.to_string(), |row| row.get(0)),
+            columns: rows.iter().map(|row| row.get(1)).collect(),
+        });
+        self.tables
+            .lock()
+            .await
+            .insert(table.to_string(), Arc::clone(&info));
+        Ok(info)
+    }
+
+    async fn insert(&self, request: &PostgresRequest) -> Result<(), PostgresError> {
+        let client = self.client().await?;
+        let table = self.table_info(&client, &request.table).await?;
+        let columns = table
+            .columns
+            .iter()
+            .filter(|column| request.fields.contains(*column))
+            .collect::<Vec<_>>();
+        if columns.is_empty() {
+            return Err(PostgresError::NoColumns {
+                table: table.name.clone(),
+            });
+        }
+        let statement = insert_statement(&table.name, &columns, self.on_conflict.as_ref());
+
+        let inserted = request.inserted.load(Ordering::Acquire);
+        let rows = &request.rows[inserted..];
+        let result = match client.execute(&statement, &[&rows]).await {
+            Err(error) if !is_transient(&error) => {
+                // A single invalid row fails the whole batch, so the rows are inserted one by one
+                // to only reject the invalid ones.
+                self.insert_rows(&client, &statement, request).await
+            }
+            result => result.map(|_| ()),
+        };
+        if result.is_err() {
+            // The table may have been altered since it was described.
+            self.tables.lock().await.remove(&request.table);
+        }
+        result.context(InsertSnafu)
+    }
+
+    async fn insert_rows(
+        &self,
+        client: &Client,
+        statement: &str,
+        request: &PostgresRequest,
+    ) -> Result<(), PgError> {
+        let inserted = request.inserted.load(Ordering::Acquire);
+        let rows = request.rows.iter().zip(&request.row_finalizers);
+        for (index, (row, finalizers)) in rows.enumerate().skip(inserted) {
+            match client.execute(statement, &[&vec![row]]).await {
+                Ok(_) => {}
+                // The rows before this one aren't inserted again when the request is retried.
+                Err(error) if is_transient(&error) => return Err(error),
+                Err(error) => {
+                    finalizers.update_status(EventStatus::Rejected);
+                    emit!(PostgresRowRejected { error });
+                }
+            }
+            request.inserted.store(index + 1, Ordering::Release);
+        }
+        Ok(())
+    }
+}
+
+impl Service<PostgresRequest> for PostgresService {
+    type Response = PostgresResponse;
+    type Error = PostgresError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, mut request: PostgresRequest) -> Self::Future {
+        let service = self.clone();
+
+        Box::pin(async move {
+            service.insert(&request).await?;
+            Ok(PostgresResponse {
+                metadata: std::mem::take(request.metadata_mut()),
+            })
+        })
+    }
+}
+
+/// Builds the statement inserting the rows passed as an array of JSON objects, whose fields are
+/// converted to the types of the matching columns by `json_populate_record`.
+fn insert_statement(
+    table: &str,
+    columns: &[&String],
+    on_conflict: Option<&OnConflictConfig>,
+) -> String {
+    let column_list = columns
+        .iter()
+        .map(|column| quote_identifier(column))
+        .collect::<Vec<_>>();
+
+    let mut statement = format!(
+        "INSERT INTO {table} ({}) SELECT {} FROM unnest($1::text[]) AS input(data) \
+         CROSS JOIN LATERAL json_populate_record(NULL::{table}, input.data::json) AS record",
+        column_list.join(", "),
+        column_list
+            .iter()
+            .map(|column| format!("record.{column}"))
+            .collect::<Vec<_>>()
+            .join(", "),
+    );
+
+    if let Some(on_conflict) = on_conflict {
+        statement.push_str(" ON CONFLICT");
+        if !on_conflict.columns.is_empty() {
+            let target = on_conflict
+                .columns
+                .iter()
+                .map(|column| quote_identifier(column))
+                .collect::<Vec<_>>();
+            _ = write!(statement, " ({})", target.join(", "));
+        }
+
+        let updated = columns
+            .iter()
+            .filter(|column| !on_conflict.columns.contains(**column))
+            .map(|column| {
+                let column = quote_identifier(column);
+                format!("{column} = EXCLUDED.{column}")
+            })
+            .collect::<Vec<_>>();
+        match on_conflict.action {
+            ConflictAction::Update if !updated.is_empty() => {
+                _ = write!(statement, " DO UPDATE SET {}", updated.join(", "));
+            }
+            _ => statement.push_str(" DO NOTHING"),
+        }
+    }
+
+    statement
+}
+
+fn quote_identifier(identifier: &str) -> String {
+    format!("\"{}\"", identifier.replace('"', "\"\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn columns(names: &[&str]) -> Vec<String> {
+        names.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn insert_statement_without_conflict() {
+        let columns = columns(&["id", "message"]);
+        assert_eq!(
+            insert_statement("events", &columns.iter().collect::<Vec<_>>(), None),
+            "INSERT INTO events (\"id\", \"message\") SELECT record.\"id\", record.\"message\" \
+             FROM unnest($1::text[]) AS input(data) CROSS JOIN LATERAL \
+             json_populate_record(NULL::events, input.data::json) AS record"
+        );
+    }
+
+    #[test]
+    fn insert_statement_updates_conflicting_rows() {
+        let columns = columns(&["id", "message", "level"]);
+        let on_conflict = OnConflictConfig {
+            columns: vec!["id".into()],
+            action: ConflictAction::Update,
+        };
+        let statement = insert_statement(
+            "logs.events",
+            &columns.iter().collect::<Vec<_>>(),
+            Some(&on_conflict),
+        );
+        assert!(statement.ends_with(
+            " ON CONFLICT (\"id\") DO UPDATE SET \"message\" = EXCLUDED.\"message\", \
+             \"level\" = EXCLUDED.\"level\""
+        ));
+    }
+
+    #[test]
+    fn insert_statement_ignores_conflicting_rows() {
+        let columns = columns(&["id"]);
+        let on_conflict = OnConflictConfig {
+            columns: vec!["id".into()],
+            action: ConflictAction::Update,
+        };
+        let statement = insert_statement(
+            "events",
+            &columns.iter().collect::<Vec<_>>(),
+            Some(&on_conflict),
+        );
+        assert!(statement.ends_with(" ON CONFLICT (\"id\") DO NOTHING"));
+    }
+
+    #[test]
+    fn quotes_identifiers() {
+        assert_eq!(quote_identifier("Message"), "\"Message\"");
+        assert_eq!(quote_identifier("a\"b"), "\"a\"\"b\"");
+    }
+}
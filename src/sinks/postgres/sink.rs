@@ -0,0 +1,181 @@
+//! Implementation of the `postgres` sink.
+
+use std::{collections::BTreeSet, num::NonZeroUsize};
+
+use super::service::PostgresRequest;
+use crate::sinks::prelude::*;
+
+pub(super) struct PostgresSink<S> {
+    batch_settings: BatcherSettings,
+    service: S,
+    table: Template,
+    transformer: Transformer,
+}
+
+impl<S> PostgresSink<S>
+where
+    S: Service<PostgresRequest> + Send + 'static,
+    S::Future: Send + 'static,
+    S::Response: DriverResponse + Send + 'static,
+    S::Error: std::fmt::Debug + Into<crate::Error> + Send,
+{
+    pub(super) const fn new(
+        batch_settings: BatcherSettings,
+        service: S,
+        table: Template,
+        transformer: Transformer,
+    ) -> Self {
+        Self {
+            batch_settings,
+            service,
+            table,
+            transformer,
+        }
+    }
+
+    async fn run_inner(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
+        let batch_settings = self.batch_settings;
+        let transformer = self.transformer;
+
+        input
+            .batched_partitioned(TablePartitioner { table: self.table }, || {
+                batch_settings.as_byte_size_config()
+            })
+            .filter_map(|(table, events)| async move { table.map(move |table| (table, events)) })
+            .filter_map(|(table, events)| {
+                let request = build_request(table, events, &transformer);
+                async {
+                    match request {
+                        Err(error) => {
+                            emit!(SinkRequestBuildError { error });
+                            None
+                        }
+                        Ok(request) => Some(request),
+                    }
+                }
+            })
+            .into_driver(self.service)
+            .protocol("postgres")
+            .run()
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl<S> StreamSink<Event> for PostgresSink<S>
+where
+    S: Service<PostgresRequest> + Send + 'static,
+    S::Future: Send + 'static,
+    S::Response: DriverResponse + Send + 'static,
+    S::Error: std::fmt::Debug + Into<crate::Error> + Send,
+{
+    async fn run(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
+        self.run_inner(input).await
+    }
+}
+
+/// Encodes each event as the JSON object of a row, keeping the finalizers of each row apart so
+/// that the rows rejected by PostgreSQL can be finalized on their own.
+fn build_request(
+    table: String,
+    events: Vec<Event>,
+    transformer: &Transformer,
+) -> Result<PostgresRequest, serde_json::Error> {
+    let metadata_builder = RequestMetadataBuilder::from_events(&events);
+
+    let mut fields = BTreeSet::new();
+    let mut rows = Vec::with_capacity(events.len());
+    let mut row_finalizers = Vec::with_capacity(events.len());
+    for mut event in events {
+        row_finalizers.push(event.take_finalizers());
+
+        transformer.transform(&mut event);
+        let log = event.into_log();
+        if let Some(object) = log.as_map() {
+            fields.extend(object.keys().map(ToString::to_string));
+        }
+        rows.push(serde_json::to_string(&log)?);
+    }
+
+    let byte_size = rows.iter().map(String::len).sum::<usize>();
+    let metadata = metadata_builder
+        .with_request_size(NonZeroUsize::new(byte_size).unwrap_or(NonZeroUsize::MIN));
+
+    Ok(PostgresRequest {
+        table,
+        fields,
+        rows,
+        finalizers: row_finalizers.iter().cloned().collect(),
+        row_finalizers,
+        inserted: Default::default(),
+        metadata,
+    })
+}
+
+/// Partitions events by the name of the table they are inserted into.
+struct TablePartitioner {
+    table: Template,
+}
+
+impl Partitioner for TablePartitioner {
+    type Item = Event;
+    type Key = Option<String>;
+
+    fn partition(&self, item: &Self::Item) -> Self::Key {
+        self.table
+            .render_string(item)
+            .map_err(|error| {
+                emit!(TemplateRenderingError {
+                    error,
+                    field: Some("table"),
+                    drop_event: true,
+                });
+            })
+            .ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vector_lib::event::{BatchNotifier, BatchStatus};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn builds_rows_with_their_own_finalizers() {
+        let (batch, mut receiver) = BatchNotifier::new_with_receiver();
+        let events = vec![
+            Event::Log(LogEvent::from("first").with_batch_notifier(&batch)),
+            Event::Log({
+                let mut log = LogEvent::from("second").with_batch_notifier(&batch);
+                log.insert("level", "info");
+                log
+            }),
+        ];
+        drop(batch);
+
+        let transformer = Transformer::new(None, Some(vec!["timestamp".into()]), None).unwrap();
+        let request = build_request("events".into(), events, &transformer).unwrap();
+
+        assert_eq!(request.table, "events");
+        assert_eq!(
+            request.fields.into_iter().collect::<Vec<_>>(),
+            vec!["level", "message"]
+        );
+        assert_eq!(
+            request.rows,
+            vec![
+                r#"{"message":"first"}"#,
+                r#"{"level":"info","message":"second"}"#
+            ]
+        );
+        assert_eq!(request.row_finalizers.len(), 2);
+        assert_eq!(request.finalizers.len(), 2);
+
+        request.row_finalizers[1].update_status(EventStatus::Rejected);
+        request.finalizers.update_status(EventStatus::Delivered);
+        drop(request.row_finalizers);
+        drop(request.finalizers);
+        assert_eq!(receiver.try_recv(), Ok(BatchStatus::Rejected));
+    }
+}
@@ -2,11 +2,16 @@ use std::{collections::BTreeMap, fmt::Write as _};
 
 use chrono::Utc;
 use indexmap::map::IndexMap;
+use prost::Message;
 use vector_lib::event::metric::{samples_to_buckets, MetricSketch, MetricTags, Quantile};
+use vector_lib::metrics::{ExponentialBucket, NativeHistogram};
 use vector_lib::prometheus::parser::{proto, METRIC_NAME_LABEL};
 
 use crate::{
-    event::metric::{Metric, MetricKind, MetricValue, StatisticKind},
+    event::{
+        metric::{Metric, MetricKind, MetricValue, StatisticKind},
+        MetricExemplar,
+    },
     sinks::util::{encode_namespace, statistic::DistributionStatistic},
 };
 
@@ -27,6 +32,56 @@ pub(super) trait MetricCollector {
         extra: Option<(&str, String)>,
     );
 
+    /// Emits a native histogram. Formats without native histograms get its explicit buckets.
+    fn emit_native_histogram(
+        &mut self,
+        timestamp_millis: Option<i64>,
+        name: &str,
+        histogram: &NativeHistogram,
+        tags: Option<&MetricTags>,
+    ) {
+        let mut bucket_count = 0.0;
+        for bucket in histogram.to_buckets() {
+            bucket_count += bucket.count as f64;
+            self.emit_value(
+                timestamp_millis,
+                name,
+                "_bucket",
+                bucket_count,
+                tags,
+                Some(("le", bucket.upper_limit.to_string())),
+            );
+        }
+        self.emit_value(
+            timestamp_millis,
+            name,
+            "_bucket",
+            histogram.count,
+            tags,
+            Some(("le", "+Inf".to_string())),
+        );
+        self.emit_value(timestamp_millis, name, "_sum", histogram.sum, tags, None);
+        self.emit_value(
+            timestamp_millis,
+            name,
+            "_count",
+            histogram.count,
+            tags,
+            None,
+        );
+    }
+
+    /// Attaches an exemplar to an already emitted value. Formats without exemplars drop it.
+    fn emit_exemplar(
+        &mut self,
+        _name: &str,
+        _suffix: &str,
+        _tags: Option<&MetricTags>,
+        _extra: Option<(&str, String)>,
+        _exemplar: &MetricExemplar,
+    ) {
+    }
+
     fn finish(self) -> Self::Output;
 
     fn encode_metric(
@@ -212,8 +267,31 @@ pub(super) trait MetricCollector {
                             None,
                         );
                     }
+                    MetricSketch::NativeHistogram(histogram) => {
+                        self.emit_native_histogram(timestamp, name, histogram, tags);
+                    }
                 },
             }
+
+            for exemplar in metric.metadata().exemplars() {
+                match metric.value() {
+                    MetricValue::Counter { .. }
+                    | MetricValue::Gauge { .. }
+                    | MetricValue::Sketch {
+                        sketch: MetricSketch::NativeHistogram(_),
+                    } => self.emit_exemplar(name, "", tags, None, exemplar),
+                    MetricValue::AggregatedHistogram { buckets, .. } => {
+                        // The exemplar goes to the first bucket that counts its value.
+                        let le = buckets
+                            .iter()
+                            .map(|bucket| bucket.upper_limit)
+                            .find(|limit| !limit.is_infinite() && exemplar.value <= *limit)
+                            .map_or_else(|| "+Inf".to_string(), |limit| limit.to_string());
+                        self.emit_exemplar(name, "_bucket", tags, Some(("le", le)), exemplar);
+                    }
+                    _ => {}
+                }
+            }
         }
     }
 }
@@ -316,8 +394,15 @@ impl StringCollector {
 
 type Labels = Vec<proto::Label>;
 
+#[derive(Default)]
+struct Series {
+    samples: Vec<proto::Sample>,
+    exemplars: Vec<proto::Exemplar>,
+    histograms: Vec<proto::Histogram>,
+}
+
 pub(super) struct TimeSeries {
-    buffer: IndexMap<Labels, Vec<proto::Sample>>,
+    buffer: IndexMap<Labels, Series>,
     metadata: IndexMap<String, proto::MetricMetadata>,
     timestamp: Option<i64>,
 }
@@ -393,14 +478,98 @@ impl MetricCollector for TimeSeries {
         self.buffer
             .entry(Self::make_labels(tags, name, suffix, extra))
             .or_default()
+            .samples
             .push(proto::Sample { value, timestamp });
     }
 
+    fn emit_native_histogram(
+        &mut self,
+        timestamp_millis: Option<i64>,
+        name: &str,
+        histogram: &NativeHistogram,
+        tags: Option<&MetricTags>,
+    ) {
+        let timestamp = timestamp_millis.unwrap_or_else(|| self.default_timestamp());
+        let integral = is_integral(histogram);
+        let (positive_spans, positive_deltas, positive_counts) =
+            native_buckets(&histogram.positive, integral);
+        let (negative_spans, negative_deltas, negative_counts) =
+            native_buckets(&histogram.negative, integral);
+        let span = |(offset, length)| proto::BucketSpan { offset, length };
+        let (count, zero_count) = if integral {
+            (
+                proto::histogram::Count::CountInt(histogram.count as u64),
+                proto::histogram::ZeroCount::ZeroCountInt(histogram.zero_count as u64),
+            )
+        } else {
+            (
+                proto::histogram::Count::CountFloat(histogram.count),
+                proto::histogram::ZeroCount::ZeroCountFloat(histogram.zero_count),
+            )
+        };
+
+        self.buffer
+            .entry(Self::make_labels(tags, name, "", None))
+            .or_default()
+            .histograms
+            .push(proto::Histogram {
+                count: Some(count),
+                sum: histogram.sum,
+                schema: histogram.schema,
+                zero_threshold: histogram.zero_threshold,
+                zero_count: Some(zero_count),
+                negative_spans: negative_spans.into_iter().map(span).collect(),
+                negative_deltas,
+                negative_counts,
+                positive_spans: positive_spans.into_iter().map(span).collect(),
+                positive_deltas,
+                positive_counts,
+                reset_hint: proto::histogram::ResetHint::Unknown as i32,
+                timestamp,
+            });
+    }
+
+    fn emit_exemplar(
+        &mut self,
+        name: &str,
+        suffix: &str,
+        tags: Option<&MetricTags>,
+        extra: Option<(&str, String)>,
+        exemplar: &MetricExemplar,
+    ) {
+        if let Some(series) = self
+            .buffer
+            .get_mut(&Self::make_labels(tags, name, suffix, extra))
+        {
+            let mut labels = exemplar
+                .labels
+                .iter()
+                .map(|(name, value)| proto::Label {
+                    name: name.clone(),
+                    value: value.clone(),
+                })
+                .collect::<Labels>();
+            labels.sort();
+            series.exemplars.push(proto::Exemplar {
+                labels,
+                value: exemplar.value,
+                timestamp: exemplar
+                    .timestamp
+                    .map_or(0, |timestamp| timestamp.timestamp_millis()),
+            });
+        }
+    }
+
     fn finish(self) -> proto::WriteRequest {
         let timeseries = self
             .buffer
             .into_iter()
-            .map(|(labels, samples)| proto::TimeSeries { labels, samples })
+            .map(|(labels, series)| proto::TimeSeries {
+                labels,
+                samples: series.samples,
+                exemplars: series.exemplars,
+                histograms: series.histograms,
+            })
             .collect::<Vec<_>>();
         let metadata = self
             .metadata
@@ -429,10 +598,320 @@ const fn prometheus_metric_type(metric_value: &MetricValue) -> proto::MetricType
         } => MetricType::Summary,
         MetricValue::AggregatedHistogram { .. } => MetricType::Histogram,
         MetricValue::AggregatedSummary { .. } => MetricType::Summary,
+        MetricValue::Sketch {
+            sketch: MetricSketch::NativeHistogram(_),
+        } => MetricType::Histogram,
         MetricValue::Sketch { .. } => MetricType::Summary,
     }
 }
 
+/// Native histograms with only whole counts are sent as integer histograms, whose bucket counts
+/// are encoded as deltas.
+fn is_integral(histogram: &NativeHistogram) -> bool {
+    [histogram.count, histogram.zero_count]
+        .into_iter()
+        .chain(histogram.positive.iter().map(|bucket| bucket.count))
+        .chain(histogram.negative.iter().map(|bucket| bucket.count))
+        .all(|count| count.fract() == 0.0)
+}
+
+/// Splits native histogram buckets into spans and either the deltas between consecutive integer
+/// counts or the float counts themselves.
+fn native_buckets(
+    buckets: &[ExponentialBucket],
+    integral: bool,
+) -> (Vec<(i32, u32)>, Vec<i64>, Vec<f64>) {
+    let (spans, counts) = NativeHistogram::buckets_to_spans(buckets);
+    if integral {
+        let mut previous = 0;
+        let deltas = counts
+            .into_iter()
+            .map(|count| {
+                let count = count as i64;
+                let delta = count - previous;
+                previous = count;
+                delta
+            })
+            .collect();
+        (spans, deltas, Vec::new())
+    } else {
+        (spans, Vec::new(), counts)
+    }
+}
+
+/// A metric family of the protobuf exposition format, with its metrics keyed by labels and
+/// timestamp.
+struct Family {
+    r#type: proto::client::MetricType,
+    help: String,
+    metrics: IndexMap<(Vec<(String, String)>, Option<i64>), proto::client::Metric>,
+}
+
+/// Collects metrics into the protobuf exposition format of Prometheus: a sequence of
+/// length-delimited `MetricFamily` messages.
+pub(super) struct ProtobufCollector {
+    // BTreeMap ensures we get sorted output, as in the text format.
+    families: BTreeMap<String, Family>,
+}
+
+impl ProtobufCollector {
+    fn labels(tags: Option<&MetricTags>) -> Vec<(String, String)> {
+        let mut labels = tags
+            .map(|tags| {
+                tags.iter_single()
+                    .map(|(key, value)| (key.to_string(), value.to_string()))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        labels.sort();
+        labels
+    }
+
+    fn metric(
+        &mut self,
+        timestamp_millis: Option<i64>,
+        name: &str,
+        tags: Option<&MetricTags>,
+    ) -> (proto::client::MetricType, &mut proto::client::Metric) {
+        let family = self
+            .families
+            .get_mut(name)
+            .expect("metric metadata not encoded");
+        let labels = Self::labels(tags);
+        let metric = family
+            .metrics
+            .entry((labels.clone(), timestamp_millis))
+            .or_insert_with(|| proto::client::Metric {
+                label: labels
+                    .into_iter()
+                    .map(|(name, value)| proto::client::LabelPair {
+                        name: Some(name),
+                        value: Some(value),
+                    })
+                    .collect(),
+                timestamp_ms: timestamp_millis,
+                ..Default::default()
+            });
+        (family.r#type, metric)
+    }
+}
+
+impl MetricCollector for ProtobufCollector {
+    type Output = Vec<u8>;
+
+    fn new() -> Self {
+        Self {
+            families: BTreeMap::new(),
+        }
+    }
+
+    fn emit_metadata(&mut self, name: &str, fullname: &str, value: &MetricValue) {
+        use proto::client::MetricType;
+
+        if !self.families.contains_key(fullname) {
+            let r#type = match prometheus_metric_type(value) {
+                proto::MetricType::Counter => MetricType::Counter,
+                proto::MetricType::Histogram => MetricType::Histogram,
+                proto::MetricType::Summary => MetricType::Summary,
+                _ => MetricType::Gauge,
+            };
+            let family = Family {
+                r#type,
+                help: name.into(),
+                metrics: IndexMap::new(),
+            };
+            self.families.insert(fullname.into(), family);
+        }
+    }
+
+    fn emit_value(
+        &mut self,
+        timestamp_millis: Option<i64>,
+        name: &str,
+        suffix: &str,
+        value: f64,
+        tags: Option<&MetricTags>,
+        extra: Option<(&str, String)>,
+    ) {
+        use proto::client::{Bucket, Counter, Gauge, MetricType, Quantile};
+
+        let (r#type, metric) = self.metric(timestamp_millis, name, tags);
+        match (r#type, suffix) {
+            (MetricType::Counter, "") => {
+                metric.counter.get_or_insert_with(Counter::default).value = Some(value);
+            }
+            (MetricType::Gauge, "") => metric.gauge = Some(Gauge { value: Some(value) }),
+            (MetricType::Histogram, "_bucket") => {
+                // The `+Inf` bucket is implied by the count in this format.
+                if let Some(upper_bound) = extra
+                    .and_then(|(_, le)| le.parse::<f64>().ok())
+                    .filter(|upper_bound| upper_bound.is_finite())
+                {
+                    metric
+                        .histogram
+                        .get_or_insert_with(Default::default)
+                        .bucket
+                        .push(Bucket {
+                            cumulative_count: Some(value as u64),
+                            upper_bound: Some(upper_bound),
+                            ..Default::default()
+                        });
+                }
+            }
+            (MetricType::Summary, "") => {
+                if let Some(quantile) = extra.and_then(|(_, quantile)| quantile.parse().ok()) {
+                    metric
+                        .summary
+                        .get_or_insert_with(Default::default)
+                        .quantile
+                        .push(Quantile {
+                            quantile: Some(quantile),
+                            value: Some(value),
+                        });
+                }
+            }
+            (MetricType::Histogram, "_sum") => {
+                metric
+                    .histogram
+                    .get_or_insert_with(Default::default)
+                    .sample_sum = Some(value);
+            }
+            (MetricType::Histogram, "_count") => {
+                metric
+                    .histogram
+                    .get_or_insert_with(Default::default)
+                    .sample_count = Some(value as u64);
+            }
+            (MetricType::Summary, "_sum") => {
+                metric
+                    .summary
+                    .get_or_insert_with(Default::default)
+                    .sample_sum = Some(value);
+            }
+            (MetricType::Summary, "_count") => {
+                metric
+                    .summary
+                    .get_or_insert_with(Default::default)
+                    .sample_count = Some(value as u64);
+            }
+            // Other series, such as the `_min` and `_max` of distributions, have no place in the
+            // metric family.
+            _ => {}
+        }
+    }
+
+    fn emit_native_histogram(
+        &mut self,
+        timestamp_millis: Option<i64>,
+        name: &str,
+        histogram: &NativeHistogram,
+        tags: Option<&MetricTags>,
+    ) {
+        let integral = is_integral(histogram);
+        let (positive_spans, positive_delta, positive_count) =
+            native_buckets(&histogram.positive, integral);
+        let (negative_spans, negative_delta, negative_count) =
+            native_buckets(&histogram.negative, integral);
+        let span = |(offset, length)| proto::client::BucketSpan {
+            offset: Some(offset),
+            length: Some(length),
+        };
+        let mut positive_span = positive_spans.into_iter().map(span).collect::<Vec<_>>();
+        if positive_span.is_empty() && negative_spans.is_empty() {
+            // An empty span tells a native histogram without observations apart from a classic one.
+            positive_span.push(span((0, 0)));
+        }
+
+        let (_, metric) = self.metric(timestamp_millis, name, tags);
+        metric.histogram = Some(proto::client::Histogram {
+            sample_count: integral.then_some(histogram.count as u64),
+            sample_count_float: (!integral).then_some(histogram.count),
+            sample_sum: Some(histogram.sum),
+            schema: Some(histogram.schema),
+            zero_threshold: Some(histogram.zero_threshold),
+            zero_count: integral.then_some(histogram.zero_count as u64),
+            zero_count_float: (!integral).then_some(histogram.zero_count),
+            negative_span: negative_spans.into_iter().map(span).collect(),
+            negative_delta,
+            negative_count,
+            positive_span,
+            positive_delta,
+            positive_count,
+            ..Default::default()
+        });
+    }
+
+    fn emit_exemplar(
+        &mut self,
+        name: &str,
+        suffix: &str,
+        tags: Option<&MetricTags>,
+        extra: Option<(&str, String)>,
+        exemplar: &MetricExemplar,
+    ) {
+        let Some(family) = self.families.get_mut(name) else {
+            return;
+        };
+        let labels = Self::labels(tags);
+        let exemplar = proto::client::Exemplar {
+            label: exemplar
+                .labels
+                .iter()
+                .map(|(name, value)| proto::client::LabelPair {
+                    name: Some(name.clone()),
+                    value: Some(value.clone()),
+                })
+                .collect(),
+            value: Some(exemplar.value),
+            timestamp: exemplar.timestamp.map(|timestamp| prost_types::Timestamp {
+                seconds: timestamp.timestamp(),
+                nanos: timestamp.timestamp_subsec_nanos() as i32,
+            }),
+        };
+
+        // Exemplars are attached to every metric of the series, whatever its timestamp.
+        for metric in family
+            .metrics
+            .iter_mut()
+            .filter(|((metric_labels, _), _)| *metric_labels == labels)
+            .map(|(_, metric)| metric)
+        {
+            match (&mut metric.counter, &mut metric.histogram, suffix) {
+                (Some(counter), _, "") => counter.exemplar = Some(exemplar.clone()),
+                (_, Some(histogram), "") => histogram.exemplars.push(exemplar.clone()),
+                (_, Some(histogram), "_bucket") => {
+                    let upper_bound = extra.as_ref().and_then(|(_, le)| le.parse::<f64>().ok());
+                    if let Some(bucket) = histogram
+                        .bucket
+                        .iter_mut()
+                        .find(|bucket| bucket.upper_bound == upper_bound)
+                    {
+                        bucket.exemplar = Some(exemplar.clone());
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn finish(self) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        for (name, family) in self.families {
+            let family = proto::client::MetricFamily {
+                name: Some(name),
+                help: Some(family.help),
+                r#type: Some(family.r#type as i32),
+                metric: family.metrics.into_values().collect(),
+                unit: None,
+            };
+            family
+                .encode_length_delimited(&mut buffer)
+                .expect("buffer has enough capacity");
+        }
+        buffer
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::BTreeSet;
@@ -490,6 +969,8 @@ mod tests {
                                 value: $svalue,
                                 timestamp: $timestamp,
                             }],
+                            exemplars: vec![],
+                            histograms: vec![],
                         },
                     )*
                 ],
@@ -744,6 +1225,174 @@ mod tests {
         );
     }
 
+    #[test]
+    fn encodes_histogram_request_with_exemplar() {
+        let exemplar = MetricExemplar {
+            labels: [("trace_id".to_string(), "abc".to_string())].into(),
+            value: 2.05,
+            timestamp: Some(timestamp()),
+        };
+        let mut metric = Metric::new(
+            "requests".to_owned(),
+            MetricKind::Absolute,
+            MetricValue::AggregatedHistogram {
+                buckets: vector_lib::buckets![1.0 => 1, 2.1 => 2, 3.0 => 3],
+                count: 6,
+                sum: 11.5,
+            },
+        )
+        .with_timestamp(Some(timestamp()));
+        metric.metadata_mut().set_exemplars(vec![exemplar]);
+
+        let mut expected = write_request!(
+            "vector_requests", "requests", Histogram [
+                    "_bucket" @ 1612325106789 = 1.0 ["le" => "1"],
+                    "_bucket" @ 1612325106789 = 3.0 ["le" => "2.1"],
+                    "_bucket" @ 1612325106789 = 6.0 ["le" => "3"],
+                    "_bucket" @ 1612325106789 = 6.0 ["le" => "+Inf"],
+                    "_sum" @ 1612325106789 = 11.5 [],
+                    "_count" @ 1612325106789 = 6.0 []
+                ]
+        );
+        expected.timeseries[1].exemplars.push(proto::Exemplar {
+            labels: vec![proto::Label {
+                name: "trace_id".into(),
+                value: "abc".into(),
+            }],
+            value: 2.05,
+            timestamp: 1612325106789,
+        });
+        assert_eq!(
+            encode_one::<TimeSeries>(Some("vector"), &[], &[], &metric),
+            expected
+        );
+    }
+
+    fn native_histogram() -> Metric {
+        Metric::new(
+            "requests".to_owned(),
+            MetricKind::Absolute,
+            MetricValue::from(NativeHistogram {
+                schema: 0,
+                zero_threshold: 0.001,
+                zero_count: 1.0,
+                count: 6.0,
+                sum: 11.5,
+                positive: vec![
+                    ExponentialBucket {
+                        index: 1,
+                        count: 2.0,
+                    },
+                    ExponentialBucket {
+                        index: 2,
+                        count: 3.0,
+                    },
+                ],
+                negative: vec![],
+            }),
+        )
+        .with_timestamp(Some(timestamp()))
+    }
+
+    #[test]
+    fn encodes_native_histogram_text() {
+        assert_eq!(
+            encode_one::<StringCollector>(Some("vector"), &[], &[], &native_histogram()),
+            indoc! {r#"
+                # HELP vector_requests requests
+                # TYPE vector_requests histogram
+                vector_requests_bucket{le="0.001"} 1 1612325106789
+                vector_requests_bucket{le="2"} 3 1612325106789
+                vector_requests_bucket{le="4"} 6 1612325106789
+                vector_requests_bucket{le="+Inf"} 6 1612325106789
+                vector_requests_sum 11.5 1612325106789
+                vector_requests_count 6 1612325106789
+            "#}
+        );
+    }
+
+    #[test]
+    fn encodes_native_histogram_request() {
+        let request = encode_one::<TimeSeries>(Some("vector"), &[], &[], &native_histogram());
+        assert_eq!(request.timeseries.len(), 1);
+        assert!(request.timeseries[0].samples.is_empty());
+        assert_eq!(
+            request.timeseries[0].histograms,
+            vec![proto::Histogram {
+                count: Some(proto::histogram::Count::CountInt(6)),
+                sum: 11.5,
+                schema: 0,
+                zero_threshold: 0.001,
+                zero_count: Some(proto::histogram::ZeroCount::ZeroCountInt(1)),
+                positive_spans: vec![proto::BucketSpan {
+                    offset: 1,
+                    length: 2,
+                }],
+                positive_deltas: vec![2, 1],
+                timestamp: 1612325106789,
+                ..Default::default()
+            }]
+        );
+    }
+
+    #[test]
+    fn encodes_native_histogram_protobuf() {
+        let exemplar = MetricExemplar {
+            labels: [("trace_id".to_string(), "abc".to_string())].into(),
+            value: 3.5,
+            timestamp: Some(timestamp()),
+        };
+        let mut metric = native_histogram();
+        metric.metadata_mut().set_exemplars(vec![exemplar]);
+        let encoded = encode_one::<ProtobufCollector>(Some("vector"), &[], &[], &metric);
+
+        let family =
+            proto::client::MetricFamily::decode_length_delimited(encoded.as_slice()).unwrap();
+        assert_eq!(family.name(), "vector_requests");
+        assert_eq!(family.r#type(), proto::client::MetricType::Histogram);
+        assert_eq!(family.metric.len(), 1);
+        assert_eq!(family.metric[0].timestamp_ms, Some(1612325106789));
+
+        let histogram = family.metric[0].histogram.as_ref().unwrap();
+        assert_eq!(histogram.sample_count, Some(6));
+        assert_eq!(histogram.sample_sum, Some(11.5));
+        assert_eq!(histogram.zero_count, Some(1));
+        assert_eq!(
+            histogram.positive_span,
+            vec![proto::client::BucketSpan {
+                offset: Some(1),
+                length: Some(2),
+            }]
+        );
+        assert_eq!(histogram.positive_delta, vec![2, 1]);
+        assert_eq!(histogram.exemplars.len(), 1);
+        assert_eq!(histogram.exemplars[0].value, Some(3.5));
+    }
+
+    #[test]
+    fn encodes_counter_protobuf() {
+        let encoded = encode_counter::<ProtobufCollector>();
+        let family =
+            proto::client::MetricFamily::decode_length_delimited(encoded.as_slice()).unwrap();
+        assert_eq!(family.name(), "vector_hits");
+        assert_eq!(family.r#type(), proto::client::MetricType::Counter);
+        assert_eq!(
+            family.metric,
+            vec![proto::client::Metric {
+                label: vec![proto::client::LabelPair {
+                    name: Some("code".into()),
+                    value: Some("200".into()),
+                }],
+                counter: Some(proto::client::Counter {
+                    value: Some(10.0),
+                    ..Default::default()
+                }),
+                timestamp_ms: Some(1612325106789),
+                ..Default::default()
+            }]
+        );
+    }
+
     fn encode_histogram<T: MetricCollector>(add_inf_bound: bool) -> T::Output {
         let bounds = if add_inf_bound {
             &[1.0, 2.1, 3.0, f64::INFINITY][..]
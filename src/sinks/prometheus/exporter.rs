@@ -32,7 +32,7 @@ use vector_lib::{
     ByteSizeOf, EstimatedJsonEncodedSizeOf,
 };
 
-use super::collector::{MetricCollector, StringCollector};
+use super::collector::{MetricCollector, ProtobufCollector, StringCollector};
 use crate::{
     config::{AcknowledgementsConfig, GenerateConfig, Input, Resource, SinkConfig, SinkContext},
     event::{
@@ -52,10 +52,17 @@ const MIN_FLUSH_PERIOD_SECS: u64 = 1;
 
 const LOCK_FAILED: &str = "Prometheus exporter data lock is poisoned";
 
+const TEXT_CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+const PROTOBUF_CONTENT_TYPE: &str =
+    "application/vnd.google.protobuf; proto=io.prometheus.client.MetricFamily; encoding=delimited";
+
 #[derive(Debug, Snafu)]
 enum BuildError {
     #[snafu(display("Flush period for sets must be greater or equal to {} secs", min))]
     FlushPeriodTooShort { min: u64 },
+    #[snafu(display("OAuth2 authentication can not be used to authenticate scrapes"))]
+    OAuth2AuthNotSupported,
 }
 
 /// Configuration for the `prometheus_exporter` sink.
@@ -198,6 +205,10 @@ impl SinkConfig for PrometheusExporterConfig {
             }));
         }
 
+        if matches!(self.auth, Some(Auth::OAuth2 { .. })) {
+            return Err(Box::new(BuildError::OAuth2AuthNotSupported));
+        }
+
         validate_quantiles(&self.quantiles)?;
 
         let sink = PrometheusExporter::new(self.clone());
@@ -323,6 +334,8 @@ fn authorized<T: HttpBody>(req: &Request<T>, auth: &Option<Auth>) -> bool {
                 Auth::Bearer { token } => {
                     HeaderValue::from_str(format!("Bearer {}", token.inner()).as_str())
                 }
+                // Rejected when building the sink, as there is no token to compare against.
+                Auth::OAuth2 { .. } => return false,
             };
 
             if let Ok(encoded_credentials) = encoded_credentials {
@@ -374,28 +387,23 @@ impl Handler {
                     .map(|(_, (metric, _))| metric.estimated_json_encoded_size_of())
                     .sum();
 
-                let mut collector = StringCollector::new();
-
-                for (_, (metric, _)) in metrics.iter() {
-                    collector.encode_metric(
-                        self.default_namespace.as_deref(),
-                        &self.buckets,
-                        &self.quantiles,
-                        metric,
-                    );
-                }
+                let (body, content_type) = if accepts_protobuf(&req) {
+                    let body = self.collect::<ProtobufCollector>(metrics.iter());
+                    (body, PROTOBUF_CONTENT_TYPE)
+                } else {
+                    let body = self.collect::<StringCollector>(metrics.iter());
+                    (body.into_bytes(), TEXT_CONTENT_TYPE)
+                };
 
                 drop(metrics);
 
-                let body = collector.finish();
                 let body_size = body.size_of();
 
                 *response.body_mut() = body.into();
 
-                response.headers_mut().insert(
-                    "Content-Type",
-                    HeaderValue::from_static("text/plain; version=0.0.4"),
-                );
+                response
+                    .headers_mut()
+                    .insert("Content-Type", HeaderValue::from_static(content_type));
 
                 self.events_sent.emit(CountByteSize(count, byte_size));
                 self.bytes_sent.emit(ByteSize(body_size));
@@ -408,6 +416,37 @@ impl Handler {
 
         response
     }
+
+    fn collect<'a, C: MetricCollector>(
+        &self,
+        metrics: impl Iterator<Item = (&'a MetricRef, &'a (Metric, MetricMetadata))>,
+    ) -> C::Output {
+        let mut collector = C::new();
+        for (_, (metric, _)) in metrics {
+            collector.encode_metric(
+                self.default_namespace.as_deref(),
+                &self.buckets,
+                &self.quantiles,
+                metric,
+            );
+        }
+        collector.finish()
+    }
+}
+
+/// Whether the scraper asked for the protobuf exposition format, which is the only one that
+/// carries native histograms.
+fn accepts_protobuf<T>(req: &Request<T>) -> bool {
+    req.headers()
+        .get_all(http::header::ACCEPT)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|media_range| {
+            let mut params = media_range.split(';').map(str::trim);
+            params.next() == Some("application/vnd.google.protobuf")
+                && params.any(|param| param == "proto=io.prometheus.client.MetricFamily")
+        })
 }
 
 impl PrometheusExporter {
@@ -1,6 +1,9 @@
 use std::fmt;
 
-use crate::sinks::prelude::*;
+use crate::{
+    codecs::EncoderKind,
+    sinks::{prelude::*, util::encoding::ValidatedBatch},
+};
 
 use super::partitioner::{S3KeyPartitioner, S3PartitionKey};
 
@@ -33,7 +36,10 @@ where
     Svc::Future: Send + 'static,
     Svc::Response: DriverResponse + Send + 'static,
     Svc::Error: fmt::Debug + Into<crate::Error> + Send,
-    RB: RequestBuilder<(S3PartitionKey, Vec<Event>)> + Send + Sync + 'static,
+    RB: RequestBuilder<(S3PartitionKey, ValidatedBatch), Encoder = (Transformer, EncoderKind)>
+        + Send
+        + Sync
+        + 'static,
     RB::Error: fmt::Display + Send,
     RB::Request: Finalizable + MetaDescriptive + Send,
 {
@@ -41,10 +47,17 @@ where
         let partitioner = self.partitioner;
         let settings = self.batcher_settings;
         let request_builder = self.request_builder;
+        let encoder = request_builder.encoder().clone();
 
         input
             .batched_partitioned(partitioner, || settings.as_byte_size_config())
             .filter_map(|(key, batch)| async move { key.map(move |k| (k, batch)) })
+            .filter_map(|(key, batch)| {
+                // Events which can't be encoded are rejected before the request builder takes the
+                // finalizers of the batch.
+                let batch = ValidatedBatch::new(&encoder, batch);
+                future::ready((!batch.is_empty()).then_some((key, batch)))
+            })
             .request_builder(default_request_builder_concurrency_limit(), request_builder)
             .filter_map(|request| async move {
                 match request {
@@ -68,7 +81,10 @@ where
     Svc::Future: Send + 'static,
     Svc::Response: DriverResponse + Send + 'static,
     Svc::Error: fmt::Debug + Into<crate::Error> + Send,
-    RB: RequestBuilder<(S3PartitionKey, Vec<Event>)> + Send + Sync + 'static,
+    RB: RequestBuilder<(S3PartitionKey, ValidatedBatch), Encoder = (Transformer, EncoderKind)>
+        + Send
+        + Sync
+        + 'static,
     RB::Error: fmt::Display + Send,
     RB::Request: Finalizable + MetaDescriptive + Send,
 {
@@ -7,7 +7,12 @@ use vector_lib::codecs::encoding::Framer;
 use vector_lib::request_metadata::GroupedCountByteSize;
 use vector_lib::{config::telemetry, EstimatedJsonEncodedSizeOf};
 
+#[cfg(feature = "codecs-parquet")]
+use vector_lib::codecs::encoding::{BatchSerializer, ParquetSchema, ParquetSerializer};
+
 use crate::{codecs::Transformer, event::Event, internal_events::EncoderWriteError};
+#[cfg(feature = "codecs-parquet")]
+use crate::{event::EventStatus, internal_events::EncoderSchemaMismatchError};
 
 pub trait Encoder<T> {
     /// Encodes the input into the provided writer.
@@ -28,70 +33,229 @@ impl Encoder<Vec<Event>> for (Transformer, crate::codecs::Encoder<Framer>) {
         events: Vec<Event>,
         writer: &mut dyn io::Write,
     ) -> io::Result<(usize, GroupedCountByteSize)> {
-        let mut encoder = self.1.clone();
-        let mut bytes_written = 0;
-        let mut n_events_pending = events.len();
-        let batch_prefix = encoder.batch_prefix();
-        write_all(writer, n_events_pending, batch_prefix)?;
-        bytes_written += batch_prefix.len();
-
-        let mut byte_size = telemetry().create_request_count_byte_size();
-
-        for (position, mut event) in events.into_iter().with_position() {
-            self.0.transform(&mut event);
-
-            // Ensure the json size is calculated after any fields have been removed
-            // by the transformer.
-            byte_size.add_event(&event, event.estimated_json_encoded_size_of());
-
-            let mut bytes = BytesMut::new();
-            match position {
-                Position::Last | Position::Only => {
-                    encoder
-                        .serialize(event, &mut bytes)
-                        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
-                }
-                _ => {
-                    encoder
-                        .encode(event, &mut bytes)
-                        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
-                }
-            }
-            write_all(writer, n_events_pending, &bytes)?;
-            bytes_written += bytes.len();
-            n_events_pending -= 1;
-        }
+        encode_framed(&self.0, &self.1, events, writer)
+    }
+}
+
+/// A batch of events from which the events that can't be encoded have been dropped.
+///
+/// Parquet batches are transformed and validated against the schema inferred from them when the
+/// batch is built, and that schema is then used to encode them.
+#[cfg(feature = "codecs-parquet")]
+pub struct ValidatedBatch {
+    pub events: Vec<Event>,
+    schema: Option<ParquetSchema>,
+}
 
-        let batch_suffix = encoder.batch_suffix();
-        assert!(n_events_pending == 0);
-        write_all(writer, 0, batch_suffix)?;
-        bytes_written += batch_suffix.len();
+#[cfg(feature = "codecs-parquet")]
+impl ValidatedBatch {
+    /// Drops the events which can't be encoded by the given encoder, rejecting their finalizers.
+    ///
+    /// This must be called before the finalizers are taken from the events.
+    pub fn new(
+        encoder: &(Transformer, crate::codecs::EncoderKind),
+        mut events: Vec<Event>,
+    ) -> Self {
+        let schema = match &encoder.1 {
+            crate::codecs::EncoderKind::Framed(_) => None,
+            crate::codecs::EncoderKind::Batch(BatchSerializer::Parquet(serializer)) => {
+                reject_invalid_parquet(&encoder.0, serializer, &mut events)
+            }
+        };
+        Self { events, schema }
+    }
 
-        Ok((bytes_written, byte_size))
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
     }
 }
 
-impl Encoder<Event> for (Transformer, crate::codecs::Encoder<()>) {
+#[cfg(feature = "codecs-parquet")]
+impl Encoder<ValidatedBatch> for (Transformer, crate::codecs::EncoderKind) {
     fn encode_input(
         &self,
-        mut event: Event,
+        batch: ValidatedBatch,
         writer: &mut dyn io::Write,
     ) -> io::Result<(usize, GroupedCountByteSize)> {
-        let mut encoder = self.1.clone();
-        self.0.transform(&mut event);
+        match (&self.1, batch.schema) {
+            (crate::codecs::EncoderKind::Framed(encoder), _) => {
+                encode_framed(&self.0, encoder, batch.events, writer)
+            }
+            (crate::codecs::EncoderKind::Batch(BatchSerializer::Parquet(_)), Some(schema)) => {
+                encode_parquet(&schema, batch.events, writer)
+            }
+            (crate::codecs::EncoderKind::Batch(BatchSerializer::Parquet(_)), None) => {
+                Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "no event of the batch matches the Parquet schema",
+                ))
+            }
+        }
+    }
+}
+
+fn encode_framed(
+    transformer: &Transformer,
+    encoder: &crate::codecs::Encoder<Framer>,
+    events: Vec<Event>,
+    writer: &mut dyn io::Write,
+) -> io::Result<(usize, GroupedCountByteSize)> {
+    let mut encoder = encoder.clone();
+    let mut bytes_written = 0;
+    let mut n_events_pending = events.len();
+    let batch_prefix = encoder.batch_prefix();
+    write_all(writer, n_events_pending, batch_prefix)?;
+    bytes_written += batch_prefix.len();
+
+    let mut byte_size = telemetry().create_request_count_byte_size();
 
-        let mut byte_size = telemetry().create_request_count_byte_size();
+    for (position, mut event) in events.into_iter().with_position() {
+        transformer.transform(&mut event);
+
+        // Ensure the json size is calculated after any fields have been removed
+        // by the transformer.
         byte_size.add_event(&event, event.estimated_json_encoded_size_of());
 
         let mut bytes = BytesMut::new();
-        encoder
-            .serialize(event, &mut bytes)
-            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
-        write_all(writer, 1, &bytes)?;
-        Ok((bytes.len(), byte_size))
+        match position {
+            Position::Last | Position::Only => {
+                encoder
+                    .serialize(event, &mut bytes)
+                    .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+            }
+            _ => {
+                encoder
+                    .encode(event, &mut bytes)
+                    .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+            }
+        }
+        write_all(writer, n_events_pending, &bytes)?;
+        bytes_written += bytes.len();
+        n_events_pending -= 1;
+    }
+
+    let batch_suffix = encoder.batch_suffix();
+    assert!(n_events_pending == 0);
+    write_all(writer, 0, batch_suffix)?;
+    bytes_written += batch_suffix.len();
+
+    Ok((bytes_written, byte_size))
+}
+
+/// Transforms the events and drops the ones that don't match the schema inferred from the batch,
+/// rejecting their finalizers, so that the remaining ones can be encoded as a single Parquet file.
+///
+/// Returns the schema the remaining events were validated against, if it could be inferred.
+#[cfg(feature = "codecs-parquet")]
+fn reject_invalid_parquet(
+    transformer: &Transformer,
+    serializer: &ParquetSerializer,
+    events: &mut Vec<Event>,
+) -> Option<ParquetSchema> {
+    for event in events.iter_mut() {
+        transformer.transform(event);
+    }
+
+    match serializer.schema(events) {
+        Ok(schema) => {
+            events.retain_mut(|event| match schema.validate(event) {
+                Ok(()) => true,
+                Err(error) => {
+                    emit!(EncoderSchemaMismatchError {
+                        error: &error,
+                        count: 1
+                    });
+                    event
+                        .metadata_mut()
+                        .take_finalizers()
+                        .update_status(EventStatus::Rejected);
+                    false
+                }
+            });
+            Some(schema)
+        }
+        Err(error) => {
+            emit!(EncoderSchemaMismatchError {
+                error: &error,
+                count: events.len(),
+            });
+            for mut event in events.drain(..) {
+                event
+                    .metadata_mut()
+                    .take_finalizers()
+                    .update_status(EventStatus::Rejected);
+            }
+            None
+        }
+    }
+}
+
+/// Encodes the events as a single Parquet file.
+///
+/// The events are expected to have been transformed and validated against the schema by
+/// [`reject_invalid_parquet`].
+#[cfg(feature = "codecs-parquet")]
+fn encode_parquet(
+    schema: &ParquetSchema,
+    events: Vec<Event>,
+    writer: &mut dyn io::Write,
+) -> io::Result<(usize, GroupedCountByteSize)> {
+    let mut byte_size = telemetry().create_request_count_byte_size();
+    for event in &events {
+        byte_size.add_event(event, event.estimated_json_encoded_size_of());
+    }
+
+    let bytes = schema
+        .encode(&events)
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+    write_all(writer, events.len(), &bytes)?;
+
+    Ok((bytes.len(), byte_size))
+}
+
+impl Encoder<Event> for (Transformer, crate::codecs::Encoder<()>) {
+    fn encode_input(
+        &self,
+        event: Event,
+        writer: &mut dyn io::Write,
+    ) -> io::Result<(usize, GroupedCountByteSize)> {
+        encode_single(&self.0, self.1.clone(), event, writer)
     }
 }
 
+/// Encodes an event sent to a Kafka topic, which serializers such as the Schema Registry one
+/// name the subject of the event after.
+impl Encoder<(String, Event)> for (Transformer, crate::codecs::Encoder<()>) {
+    fn encode_input(
+        &self,
+        (topic, event): (String, Event),
+        writer: &mut dyn io::Write,
+    ) -> io::Result<(usize, GroupedCountByteSize)> {
+        let mut encoder = self.1.clone();
+        encoder.set_topic(&topic);
+        encode_single(&self.0, encoder, event, writer)
+    }
+}
+
+fn encode_single(
+    transformer: &Transformer,
+    mut encoder: crate::codecs::Encoder<()>,
+    mut event: Event,
+    writer: &mut dyn io::Write,
+) -> io::Result<(usize, GroupedCountByteSize)> {
+    transformer.transform(&mut event);
+
+    let mut byte_size = telemetry().create_request_count_byte_size();
+    byte_size.add_event(&event, event.estimated_json_encoded_size_of());
+
+    let mut bytes = BytesMut::new();
+    encoder
+        .serialize(event, &mut bytes)
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+    write_all(writer, 1, &bytes)?;
+    Ok((bytes.len(), byte_size))
+}
+
 /// Write the buffer to the writer. If the operation fails, emit an internal event which complies with the
 /// instrumentation spec- as this necessitates both an Error and EventsDropped event.
 ///
@@ -375,4 +539,49 @@ mod tests {
         assert_eq!(String::from_utf8(writer).unwrap(), r"value");
         assert_eq!(CountByteSize(1, input_json_size), json_size.size().unwrap());
     }
+
+    #[cfg(feature = "codecs-parquet")]
+    #[tokio::test]
+    async fn test_reject_invalid_parquet_events() {
+        use vector_lib::codecs::encoding::{
+            ParquetColumn, ParquetColumnType, ParquetSerializerConfig, ParquetSerializerOptions,
+        };
+        use vector_lib::event::{BatchNotifier, BatchStatus};
+
+        let serializer = ParquetSerializerConfig::new(ParquetSerializerOptions {
+            schema: vec![ParquetColumn {
+                name: "message".to_owned(),
+                column_type: ParquetColumnType::String,
+                required: true,
+            }],
+            ..Default::default()
+        })
+        .build()
+        .unwrap();
+        let encoding = (
+            Transformer::default(),
+            crate::codecs::EncoderKind::Batch(BatchSerializer::Parquet(serializer)),
+        );
+
+        let (valid_batch, valid_receiver) = BatchNotifier::new_with_receiver();
+        let (invalid_batch, invalid_receiver) = BatchNotifier::new_with_receiver();
+        let events = vec![
+            Event::Log(LogEvent::from("valid")).with_batch_notifier(&valid_batch),
+            Event::Log(LogEvent::from(BTreeMap::from([(
+                KeyString::from("count"),
+                Value::from(1),
+            )])))
+            .with_batch_notifier(&invalid_batch),
+        ];
+        drop((valid_batch, invalid_batch));
+
+        let batch = ValidatedBatch::new(&encoding, events);
+        assert_eq!(batch.events.len(), 1);
+        assert_eq!(invalid_receiver.await, BatchStatus::Rejected);
+
+        let mut writer = Vec::new();
+        let (_, json_size) = encoding.encode_input(batch, &mut writer).unwrap();
+        assert_eq!(json_size.size().unwrap().0, 1);
+        assert_eq!(valid_receiver.await, BatchStatus::Delivered);
+    }
 }
@@ -20,6 +20,7 @@ use snafu::{ResultExt, Snafu};
 use tower::{Service, ServiceBuilder};
 use tower_http::decompression::DecompressionLayer;
 use vector_lib::configurable::configurable_component;
+use vector_lib::finalization::DeliveryError;
 use vector_lib::stream::batcher::limiter::ItemBatchSize;
 use vector_lib::{ByteSizeOf, EstimatedJsonEncodedSizeOf};
 
@@ -689,6 +690,18 @@ impl DriverResponse for HttpResponse {
     fn bytes_sent(&self) -> Option<usize> {
         Some(self.raw_byte_size)
     }
+
+    fn delivery_error(&self) -> Option<DeliveryError> {
+        let status = self.http_response.status();
+        (!status.is_success()).then(|| DeliveryError {
+            message: format!(
+                "{}: {}",
+                status,
+                String::from_utf8_lossy(self.http_response.body())
+            ),
+            status_code: Some(status.as_u16()),
+        })
+    }
 }
 
 /// Creates a `RetryLogic` for use with `HttpResponse`.
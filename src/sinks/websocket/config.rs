@@ -84,6 +84,10 @@ impl GenerateConfig for WebSocketSinkConfig {
 #[typetag::serde(name = "websocket")]
 impl SinkConfig for WebSocketSinkConfig {
     async fn build(&self, _cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
+        if matches!(self.auth, Some(Auth::OAuth2 { .. })) {
+            return Err("OAuth2 authentication is not supported by the `websocket` sink.".into());
+        }
+
         let connector = self.build_connector()?;
         let ws_sink = WebSocketSink::new(self, connector.clone())?;
 
@@ -582,10 +582,9 @@ mod tests {
                                                     );
                                                 }
                                             }
-                                            Auth::Basic {
-                                                user: _user,
-                                                password: _password,
-                                            } => { /* Not needed for tests at the moment */ }
+                                            Auth::Basic { .. } | Auth::OAuth2 { .. } => {
+                                                /* Not needed for tests at the moment */
+                                            }
                                         }
                                     }
                                     Ok(res)
@@ -6,16 +6,15 @@ use std::{
 };
 
 use chrono::Utc;
-use futures::StreamExt;
+use futures::{future::BoxFuture, FutureExt, StreamExt};
 use smallvec::SmallVec;
 use snafu::Snafu;
 use tokio::{
     io::{AsyncRead, BufReader},
     process::Command,
     sync::mpsc::{channel, Sender},
-    time::{self, sleep, Duration, Instant},
+    time::{sleep, sleep_until, Duration, Instant},
 };
-use tokio_stream::wrappers::IntervalStream;
 use tokio_util::codec::FramedRead;
 use vector_lib::codecs::{
     decoding::{DeserializerConfig, FramingConfig},
@@ -23,7 +22,7 @@ use vector_lib::codecs::{
 };
 use vector_lib::configurable::configurable_component;
 use vector_lib::internal_event::{ByteSize, BytesReceived, InternalEventHandle as _, Protocol};
-use vector_lib::{config::LegacyKey, EstimatedJsonEncodedSizeOf};
+use vector_lib::{config::LegacyKey, EstimatedJsonEncodedSizeOf, TimeZone};
 use vrl::path::OwnedValuePath;
 use vrl::value::Kind;
 
@@ -42,9 +41,12 @@ use crate::{
 use vector_lib::config::{log_schema, LogNamespace};
 use vector_lib::lookup::{owned_value_path, path};
 
+mod schedule;
 #[cfg(test)]
 mod tests;
 
+use schedule::Schedule;
+
 /// Configuration for the `exec` source.
 #[configurable_component(source("exec", "Collect output from a process running on the host."))]
 #[derive(Clone, Debug)]
@@ -117,9 +119,84 @@ pub enum Mode {
 pub struct ScheduledConfig {
     /// The interval, in seconds, between scheduled command runs.
     ///
-    /// If the command takes longer than `exec_interval_secs` to run, it is killed.
+    /// This is ignored when `cron` is set.
     #[serde(default = "default_exec_interval_secs")]
     exec_interval_secs: u64,
+
+    /// A cron expression of when to run the command.
+    ///
+    /// The expression is made of five fields: minute, hour, day of the month, month, and day of
+    /// the week. Each field is either `*`, a value, a range such as `1-5`, or a comma-separated
+    /// list of them, each optionally followed by a step such as `/10`. Months and days of the week
+    /// can also be given by their three-letter English names. The `@hourly`, `@daily`, `@weekly`,
+    /// `@monthly`, and `@yearly` shorthands are also supported.
+    ///
+    /// When set, `exec_interval_secs` is ignored.
+    #[serde(default)]
+    #[configurable(metadata(docs::examples = "0 2 * * *", docs::examples = "*/5 * * * *"))]
+    cron: Option<String>,
+
+    /// The timezone the `cron` expression is evaluated in.
+    ///
+    /// This can refer to any valid timezone as defined in the [TZ database][tzdb], or "local" which
+    /// refers to the system local timezone. It defaults to the [globally configured
+    /// timezone](https://vector.dev/docs/reference/configuration/global-options/#timezone), or the
+    /// system local timezone.
+    ///
+    /// [tzdb]: https://en.wikipedia.org/wiki/List_of_tz_database_time_zones
+    #[serde(default)]
+    #[configurable(metadata(docs::examples = "local", docs::examples = "America/New_York"))]
+    timezone: Option<TimeZone>,
+
+    /// The maximum random delay, in seconds, added to each scheduled run.
+    ///
+    /// This spreads out the runs of the instances of Vector sharing the same schedule.
+    #[serde(default)]
+    #[configurable(metadata(docs::human_name = "Maximum Jitter"))]
+    max_jitter_secs: u64,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    overlap_policy: OverlapPolicy,
+
+    /// The maximum amount of time, in seconds, that the command can run before it is killed.
+    ///
+    /// If not set, the command can run until its next run is due, and the `overlap_policy`
+    /// applies.
+    #[serde(default)]
+    #[configurable(metadata(docs::human_name = "Timeout"))]
+    timeout_secs: Option<u64>,
+}
+
+impl Default for ScheduledConfig {
+    fn default() -> Self {
+        Self {
+            exec_interval_secs: default_exec_interval_secs(),
+            cron: None,
+            timezone: None,
+            max_jitter_secs: 0,
+            overlap_policy: OverlapPolicy::default(),
+            timeout_secs: None,
+        }
+    }
+}
+
+/// What to do when a scheduled run is due while the previous run of the command is still running.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum OverlapPolicy {
+    /// Skip the new run.
+    Skip,
+
+    /// Start the new run once the previous run finishes.
+    ///
+    /// At most one run is queued, so the runs due while a run is already queued are skipped.
+    Queue,
+
+    /// Kill the previous run, reporting it as timed out, and start the new run.
+    #[default]
+    Kill,
 }
 
 /// Configuration options for streaming commands.
@@ -143,15 +220,15 @@ pub enum ExecConfigError {
     CommandEmpty,
     #[snafu(display("The maximum buffer size must be greater than zero"))]
     ZeroBuffer,
+    #[snafu(display("Invalid cron expression: {}", message))]
+    InvalidCron { message: String },
 }
 
 impl Default for ExecConfig {
     fn default() -> Self {
         ExecConfig {
             mode: Mode::Scheduled,
-            scheduled: Some(ScheduledConfig {
-                exec_interval_secs: default_exec_interval_secs(),
-            }),
+            scheduled: Some(ScheduledConfig::default()),
             streaming: None,
             command: vec!["echo".to_owned(), "Hello World!".to_owned()],
             environment: None,
@@ -226,11 +303,8 @@ impl ExecConfig {
         self.command.join(" ")
     }
 
-    const fn exec_interval_secs_or_default(&self) -> u64 {
-        match &self.scheduled {
-            None => default_exec_interval_secs(),
-            Some(config) => config.exec_interval_secs,
-        }
+    fn scheduled_or_default(&self) -> ScheduledConfig {
+        self.scheduled.clone().unwrap_or_default()
     }
 
     const fn respawn_on_exit_or_default(&self) -> bool {
@@ -264,12 +338,16 @@ impl SourceConfig for ExecConfig {
 
         match &self.mode {
             Mode::Scheduled => {
-                let exec_interval_secs = self.exec_interval_secs_or_default();
+                let scheduled = self.scheduled_or_default();
+                let schedule = Schedule::new(&scheduled, cx.globals.timezone)
+                    .map_err(|message| ExecConfigError::InvalidCron { message })?;
 
                 Ok(Box::pin(run_scheduled(
                     self.clone(),
                     hostname,
-                    exec_interval_secs,
+                    schedule,
+                    scheduled.overlap_policy,
+                    scheduled.timeout_secs.map(Duration::from_secs),
                     decoder,
                     cx.shutdown,
                     cx.out,
@@ -345,51 +423,105 @@ impl SourceConfig for ExecConfig {
     }
 }
 
+type CommandRun = BoxFuture<'static, Result<Option<ExitStatus>, Error>>;
+
+#[allow(clippy::too_many_arguments)]
 async fn run_scheduled(
     config: ExecConfig,
     hostname: Option<String>,
-    exec_interval_secs: u64,
+    schedule: Schedule,
+    overlap_policy: OverlapPolicy,
+    timeout: Option<Duration>,
     decoder: Decoder,
-    shutdown: ShutdownSignal,
+    mut shutdown: ShutdownSignal,
     out: SourceSender,
     log_namespace: LogNamespace,
 ) -> Result<(), ()> {
     debug!("Starting scheduled exec runs.");
-    let schedule = Duration::from_secs(exec_interval_secs);
+    let command = config.command_line();
+    let run_shutdown = shutdown.clone();
 
-    let mut interval = IntervalStream::new(time::interval(schedule)).take_until(shutdown.clone());
+    let start_run = || -> (CommandRun, Instant) {
+        let run = run_command(
+            config.clone(),
+            hostname.clone(),
+            decoder.clone(),
+            run_shutdown.clone(),
+            out.clone(),
+            log_namespace,
+        );
+        (run.boxed(), Instant::now())
+    };
+    let handle_output = |output: Result<Option<ExitStatus>, Error>| {
+        if let Err(error) = output {
+            emit!(ExecFailedError {
+                command: command.as_str(),
+                error,
+            });
+        }
+    };
+    // Dropping a run kills the command.
+    let kill_run = |started: Instant| {
+        emit!(ExecTimeoutError {
+            command: command.as_str(),
+            elapsed_seconds: started.elapsed().as_secs(),
+        });
+    };
 
-    while interval.next().await.is_some() {
-        // Wait for our task to finish, wrapping it in a timeout
-        let timeout = tokio::time::timeout(
-            schedule,
-            run_command(
-                config.clone(),
-                hostname.clone(),
-                decoder.clone(),
-                shutdown.clone(),
-                out.clone(),
-                log_namespace,
-            ),
-        )
-        .await;
+    let mut due = schedule.first();
+    let mut run_at = due.map(|due| due.at + schedule.jitter());
+    let mut current: Option<(CommandRun, Instant)> = None;
+    let mut queued = false;
+
+    loop {
+        let deadline = current
+            .as_ref()
+            .zip(timeout)
+            .map(|((_, started), timeout)| *started + timeout);
 
-        match timeout {
-            Ok(output) => {
-                if let Err(command_error) = output {
-                    emit!(ExecFailedError {
-                        command: config.command_line().as_str(),
-                        error: command_error,
-                    });
+        tokio::select! {
+            _ = &mut shutdown => {
+                // The running command is signaled to shut down, let it finish.
+                if let Some((run, _)) = current.take() {
+                    handle_output(run.await);
                 }
+                break;
             }
-            Err(error) => {
-                emit!(ExecTimeoutError {
-                    command: config.command_line().as_str(),
-                    elapsed_seconds: schedule.as_secs(),
-                    error,
-                });
+            output = async { current.as_mut().expect("run is in progress").0.as_mut().await }, if current.is_some() => {
+                current = None;
+                handle_output(output);
             }
+            _ = async { sleep_until(deadline.expect("run has a deadline")).await }, if deadline.is_some() => {
+                let (_, started) = current.take().expect("run is in progress");
+                kill_run(started);
+            }
+            _ = async { sleep_until(run_at.expect("run is due")).await }, if run_at.is_some() => {
+                due = due.and_then(|due| schedule.next(due));
+                run_at = due.map(|due| due.at + schedule.jitter());
+
+                match (&current, overlap_policy) {
+                    (None, _) => current = Some(start_run()),
+                    (Some(_), OverlapPolicy::Skip) => {
+                        warn!(
+                            message = "Skipping scheduled run, the previous run is still running.",
+                            command = %command,
+                            internal_log_rate_limit = true,
+                        );
+                    }
+                    (Some(_), OverlapPolicy::Queue) => queued = true,
+                    (Some(_), OverlapPolicy::Kill) => {
+                        let (_, started) = current.take().expect("run is in progress");
+                        kill_run(started);
+                        current = Some(start_run());
+                    }
+                }
+                continue;
+            }
+            else => break,
+        }
+
+        if current.is_none() && std::mem::take(&mut queued) {
+            current = Some(start_run());
         }
     }
 
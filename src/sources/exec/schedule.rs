@@ -0,0 +1,277 @@
+use chrono::{DateTime, Datelike, Duration as ChronoDuration, NaiveDateTime, Timelike, Utc};
+use rand::Rng;
+use tokio::time::{Duration, Instant};
+use vector_lib::TimeZone;
+
+use super::ScheduledConfig;
+
+/// When a scheduled command runs.
+pub(super) struct Schedule {
+    kind: ScheduleKind,
+    max_jitter: Duration,
+}
+
+enum ScheduleKind {
+    Interval(Duration),
+    Cron {
+        cron: CronSchedule,
+        timezone: TimeZone,
+    },
+}
+
+impl Schedule {
+    pub(super) fn new(
+        config: &ScheduledConfig,
+        global_timezone: Option<TimeZone>,
+    ) -> Result<Self, String> {
+        let kind = match &config.cron {
+            Some(expression) => ScheduleKind::Cron {
+                cron: expression.parse()?,
+                timezone: config
+                    .timezone
+                    .or(global_timezone)
+                    .unwrap_or(TimeZone::Local),
+            },
+            None => ScheduleKind::Interval(Duration::from_secs(config.exec_interval_secs)),
+        };
+
+        Ok(Self {
+            kind,
+            max_jitter: Duration::from_secs(config.max_jitter_secs),
+        })
+    }
+
+    /// When the first run is due. Interval schedules run immediately.
+    pub(super) fn first(&self) -> Option<Due> {
+        match &self.kind {
+            ScheduleKind::Interval(_) => Some(Due {
+                at: Instant::now(),
+                time: Utc::now(),
+            }),
+            ScheduleKind::Cron { cron, timezone } => cron_next(cron, timezone, Utc::now()),
+        }
+    }
+
+    /// When the run following the run due at `previous` is due.
+    pub(super) fn next(&self, previous: Due) -> Option<Due> {
+        match &self.kind {
+            // Runs missed while the source was stalled aren't caught up on.
+            ScheduleKind::Interval(interval) => {
+                let at = (previous.at + *interval).max(Instant::now());
+                Some(Due {
+                    at,
+                    time: Utc::now() + (at - Instant::now()),
+                })
+            }
+            // The timer can fire slightly before the wall-clock time of the run, which must not
+            // be due again.
+            ScheduleKind::Cron { cron, timezone } => {
+                cron_next(cron, timezone, previous.time.max(Utc::now()))
+            }
+        }
+    }
+
+    /// A random delay to add to a run, up to the configured maximum jitter.
+    pub(super) fn jitter(&self) -> Duration {
+        if self.max_jitter.is_zero() {
+            Duration::ZERO
+        } else {
+            rand::thread_rng().gen_range(Duration::ZERO..=self.max_jitter)
+        }
+    }
+}
+
+/// When a run is due, both on the runtime's clock and on the wall clock.
+#[derive(Clone, Copy, Debug)]
+pub(super) struct Due {
+    pub(super) at: Instant,
+    pub(super) time: DateTime<Utc>,
+}
+
+fn cron_next(cron: &CronSchedule, timezone: &TimeZone, after: DateTime<Utc>) -> Option<Due> {
+    let time = match timezone {
+        TimeZone::Local => cron.next_after(&chrono::Local, after),
+        TimeZone::Named(tz) => cron.next_after(tz, after),
+    }?;
+    let now = Utc::now();
+    Some(Due {
+        at: Instant::now() + (time - now).to_std().unwrap_or_default(),
+        time,
+    })
+}
+
+/// A cron expression, made of the sets of minutes, hours, days of the month, months, and days of
+/// the week that it matches.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) struct CronSchedule {
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    /// Whether both the day of the month and the day of the week are restricted, in which case a
+    /// day matches if either of them does.
+    either_day: bool,
+}
+
+/// How far ahead to look for a time matching the expression, such as `0 0 30 2 *`, which never
+/// matches.
+const MAX_SEARCH_DAYS: i64 = 366 * 5;
+
+const MONTH_NAMES: [&str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+const DAY_NAMES: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+impl std::str::FromStr for CronSchedule {
+    type Err = String;
+
+    fn from_str(expression: &str) -> Result<Self, Self::Err> {
+        let expression = match expression.trim() {
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            "@monthly" => "0 0 1 * *",
+            "@weekly" => "0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@hourly" => "0 * * * *",
+            expression => expression,
+        };
+
+        let fields = expression.split_whitespace().collect::<Vec<_>>();
+        let [minutes, hours, days_of_month, months, days_of_week] = fields[..] else {
+            return Err(format!(
+                "Expected 5 fields in cron expression {expression:?}, found {}.",
+                fields.len()
+            ));
+        };
+
+        let mut days_of_week_set = parse_field(days_of_week, 0, 7, &DAY_NAMES, 0)?;
+        // Both 0 and 7 are Sunday.
+        if days_of_week_set & (1 << 7) != 0 {
+            days_of_week_set = (days_of_week_set | 1) & !(1 << 7);
+        }
+
+        Ok(Self {
+            minutes: parse_field(minutes, 0, 59, &[], 0)?,
+            hours: parse_field(hours, 0, 23, &[], 0)?,
+            days_of_month: parse_field(days_of_month, 1, 31, &[], 0)?,
+            months: parse_field(months, 1, 12, &MONTH_NAMES, 1)?,
+            days_of_week: days_of_week_set,
+            either_day: !days_of_month.starts_with('*') && !days_of_week.starts_with('*'),
+        })
+    }
+}
+
+/// Parses a field of a cron expression into the set of values it matches, as a bit mask.
+///
+/// Each element of the comma-separated field is `*`, a value, or a range `a-b`, optionally
+/// followed by a step `/n`. Values can also be the given names, the first one being `name_base`.
+fn parse_field(
+    field: &str,
+    min: u32,
+    max: u32,
+    names: &[&str],
+    name_base: u32,
+) -> Result<u64, String> {
+    let value = |value: &str| {
+        names
+            .iter()
+            .position(|name| name.eq_ignore_ascii_case(value))
+            .map(|index| index as u32 + name_base)
+            .or_else(|| value.parse().ok())
+            .filter(|value| (min..=max).contains(value))
+            .ok_or_else(|| format!("Invalid value {value:?} in cron field {field:?}."))
+    };
+
+    let mut set = 0;
+    for element in field.split(',') {
+        let (range, step) = match element.split_once('/') {
+            Some((range, step)) => match step.parse::<u32>() {
+                Ok(step) if step > 0 => (range, step),
+                _ => return Err(format!("Invalid step {step:?} in cron field {field:?}.")),
+            },
+            None => (element, 1),
+        };
+
+        let (start, end) = match range.split_once('-') {
+            _ if range == "*" => (min, max),
+            Some((start, end)) => (value(start)?, value(end)?),
+            // Like in other cron implementations, `n/step` ranges from `n` to the maximum.
+            None if step > 1 => (value(range)?, max),
+            None => {
+                let value = value(range)?;
+                (value, value)
+            }
+        };
+        if start > end {
+            return Err(format!("Invalid range {range:?} in cron field {field:?}."));
+        }
+
+        for value in (start..=end).step_by(step as usize) {
+            set |= 1 << value;
+        }
+    }
+
+    Ok(set)
+}
+
+const fn contains(set: u64, value: u32) -> bool {
+    set & (1 << value) != 0
+}
+
+impl CronSchedule {
+    /// The first time strictly after `now` that matches the expression in the given timezone.
+    pub(super) fn next_after<Tz: chrono::TimeZone>(
+        &self,
+        timezone: &Tz,
+        now: DateTime<Utc>,
+    ) -> Option<DateTime<Utc>> {
+        let mut local = now.with_timezone(timezone).naive_local();
+        loop {
+            local = self.next_local_after(local)?;
+            // Local times skipped by a daylight saving time change never occur, and repeated ones
+            // only run once.
+            if let Some(time) = timezone.from_local_datetime(&local).earliest() {
+                let time = time.with_timezone(&Utc);
+                if time > now {
+                    return Some(time);
+                }
+            }
+        }
+    }
+
+    fn next_local_after(&self, after: NaiveDateTime) -> Option<NaiveDateTime> {
+        let start = after.with_second(0)?.with_nanosecond(0)? + ChronoDuration::minutes(1);
+        let limit = start + ChronoDuration::days(MAX_SEARCH_DAYS);
+
+        let mut time = start;
+        while time < limit {
+            if !contains(self.months, time.month()) {
+                let (year, month) = match time.month() {
+                    12 => (time.year() + 1, 1),
+                    month => (time.year(), month + 1),
+                };
+                time = chrono::NaiveDate::from_ymd_opt(year, month, 1)?.and_hms_opt(0, 0, 0)?;
+            } else if !self.day_matches(time) {
+                time = (time.date() + ChronoDuration::days(1)).and_hms_opt(0, 0, 0)?;
+            } else if !contains(self.hours, time.hour()) {
+                time = time.with_minute(0)? + ChronoDuration::hours(1);
+            } else if !contains(self.minutes, time.minute()) {
+                time += ChronoDuration::minutes(1);
+            } else {
+                return Some(time);
+            }
+        }
+
+        None
+    }
+
+    fn day_matches(&self, time: NaiveDateTime) -> bool {
+        let day_of_month = contains(self.days_of_month, time.day());
+        let day_of_week = contains(self.days_of_week, time.weekday().num_days_from_sunday());
+        if self.either_day {
+            day_of_month || day_of_week
+        } else {
+            day_of_month && day_of_week
+        }
+    }
+}
@@ -3,6 +3,7 @@ use crate::{event::LogEvent, test_util::trace_init};
 use bytes::Bytes;
 use std::ffi::OsStr;
 use std::io::Cursor;
+use tokio::time;
 use vector_lib::event::EventMetadata;
 use vrl::value;
 
@@ -435,6 +436,160 @@ async fn test_graceful_shutdown() {
     }
 }
 
+#[test]
+fn test_cron_next_after() {
+    use chrono::{TimeZone as _, Utc};
+    use schedule::CronSchedule;
+
+    let next = |expression: &str, now| {
+        expression
+            .parse::<CronSchedule>()
+            .unwrap()
+            .next_after(&Utc, now)
+            .unwrap()
+    };
+    let now = Utc.with_ymd_and_hms(2024, 10, 17, 12, 34, 56).unwrap();
+
+    assert_eq!(
+        next("*/5 * * * *", now),
+        Utc.with_ymd_and_hms(2024, 10, 17, 12, 35, 0).unwrap()
+    );
+    assert_eq!(
+        next("0 2 * * *", now),
+        Utc.with_ymd_and_hms(2024, 10, 18, 2, 0, 0).unwrap()
+    );
+    assert_eq!(
+        next("@monthly", now),
+        Utc.with_ymd_and_hms(2024, 11, 1, 0, 0, 0).unwrap()
+    );
+    // 2024-10-19 is a Saturday.
+    assert_eq!(
+        next("30 9 * * sat,sun", now),
+        Utc.with_ymd_and_hms(2024, 10, 19, 9, 30, 0).unwrap()
+    );
+    // When both days are restricted, either of them matches.
+    assert_eq!(
+        next("0 0 1 * 5", now),
+        Utc.with_ymd_and_hms(2024, 10, 18, 0, 0, 0).unwrap()
+    );
+    assert_eq!(
+        next("0 0 29 feb *", now),
+        Utc.with_ymd_and_hms(2028, 2, 29, 0, 0, 0).unwrap()
+    );
+}
+
+#[test]
+fn test_cron_next_after_timezone() {
+    use chrono::{TimeZone as _, Utc};
+    use schedule::CronSchedule;
+
+    let cron = "30 2 * * *".parse::<CronSchedule>().unwrap();
+    let timezone = chrono_tz::Europe::Paris;
+
+    assert_eq!(
+        cron.next_after(
+            &timezone,
+            Utc.with_ymd_and_hms(2024, 10, 17, 12, 0, 0).unwrap()
+        ),
+        Some(Utc.with_ymd_and_hms(2024, 10, 18, 0, 30, 0).unwrap())
+    );
+    // 02:30 doesn't occur on 2024-03-31 in Paris.
+    assert_eq!(
+        cron.next_after(
+            &timezone,
+            Utc.with_ymd_and_hms(2024, 3, 30, 12, 0, 0).unwrap()
+        ),
+        Some(Utc.with_ymd_and_hms(2024, 4, 1, 0, 30, 0).unwrap())
+    );
+    // 02:30 occurs twice on 2024-10-27 in Paris, but only runs once.
+    assert_eq!(
+        cron.next_after(
+            &timezone,
+            Utc.with_ymd_and_hms(2024, 10, 27, 0, 30, 0).unwrap()
+        ),
+        Some(Utc.with_ymd_and_hms(2024, 10, 28, 1, 30, 0).unwrap())
+    );
+}
+
+#[test]
+fn test_cron_schedule_next_after_early_wake() {
+    use chrono::Duration as ChronoDuration;
+
+    let config = ScheduledConfig {
+        cron: Some("* * * * *".to_owned()),
+        timezone: Some(TimeZone::Named(chrono_tz::UTC)),
+        ..Default::default()
+    };
+    let schedule = Schedule::new(&config, None).unwrap();
+
+    // The timer firing before the run is due, the next run is computed before its wall-clock
+    // time, but is still the one following it.
+    let due = schedule.first().unwrap();
+    let next = schedule.next(due).unwrap();
+    assert_eq!(next.time, due.time + ChronoDuration::minutes(1));
+    assert!(next.at > due.at);
+}
+
+#[test]
+fn test_cron_invalid() {
+    use schedule::CronSchedule;
+
+    for expression in [
+        "",
+        "* * * *",
+        "* * * * * *",
+        "60 * * * *",
+        "* 24 * * *",
+        "* * 0 * *",
+        "* * * 13 *",
+        "* * * * 8",
+        "*/0 * * * *",
+        "5-1 * * * *",
+        "* * * foo *",
+    ] {
+        assert!(
+            expression.parse::<CronSchedule>().is_err(),
+            "{expression:?} should be invalid"
+        );
+    }
+    assert_eq!(
+        "0 0 * * 7".parse::<CronSchedule>(),
+        "0 0 * * sun".parse::<CronSchedule>()
+    );
+}
+
+#[tokio::test]
+#[cfg(target_os = "linux")]
+async fn test_scheduled_timeout() {
+    trace_init();
+    let mut config = standard_scheduled_test_config();
+    config.command = vec!["sleep".to_owned(), "10".to_owned()];
+    let hostname = Some("Some.Machine".to_string());
+    let decoder = Default::default();
+    let (trigger, shutdown, _) = ShutdownSignal::new_wired();
+    let (tx, _rx) = SourceSender::new_test();
+    let schedule = Schedule::new(&ScheduledConfig::default(), None).unwrap();
+
+    let run = tokio::spawn(run_scheduled(
+        config,
+        hostname,
+        schedule,
+        OverlapPolicy::Kill,
+        Some(Duration::from_millis(100)),
+        decoder,
+        shutdown,
+        tx,
+        LogNamespace::Legacy,
+    ));
+
+    // The command is killed after the timeout, so shutting down doesn't wait for it.
+    sleep(Duration::from_millis(500)).await;
+    let start = Instant::now();
+    drop(trigger);
+    run.await.unwrap().unwrap();
+    assert!(start.elapsed() < Duration::from_secs(5));
+}
+
 fn standard_scheduled_test_config() -> ExecConfig {
     Default::default()
 }
@@ -12,7 +12,12 @@ use vector_lib::codecs::{BytesDeserializer, BytesDeserializerConfig};
 use vector_lib::configurable::configurable_component;
 use vector_lib::file_source::{
     calculate_ignore_before,
-    paths_provider::glob::{Glob, MatchOptions},
+    paths_provider::{
+        glob::{Glob, MatchOptions},
+        manifest::Manifest,
+        watch::Watch,
+        PathsProvider,
+    },
     Checkpointer, FileFingerprint, FileServer, FingerprintStrategy, Fingerprinter, Line, ReadFrom,
     ReadFromConfig,
 };
@@ -55,6 +60,38 @@ enum BuildError {
     },
 }
 
+/// Configuration for how the files to read are discovered.
+#[configurable_component]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[serde(tag = "strategy", rename_all = "snake_case")]
+#[configurable(metadata(
+    docs::enum_tag_description = "The strategy used to discover the files to read."
+))]
+pub enum DiscoveryConfig {
+    /// Search for the files matching the `include` patterns every `glob_minimum_cooldown_ms`.
+    #[default]
+    Glob,
+
+    /// Search for the files matching the `include` patterns once, then watch the directories
+    /// they are in for files being created, renamed, or removed.
+    ///
+    /// This avoids searching through large directory trees every `glob_minimum_cooldown_ms`.
+    /// It relies on the file system notifications of the operating system, such as inotify on
+    /// Linux, so it may not work with network file systems.
+    Watch,
+
+    /// Read the paths of the files from a manifest file.
+    ///
+    /// The manifest lists one path per line. Empty lines and lines starting with `#` are
+    /// ignored, and relative paths are relative to the directory of the manifest. The manifest
+    /// is read again when it changes, and `include` is ignored.
+    Manifest {
+        /// The path of the manifest file.
+        #[configurable(metadata(docs::examples = "/etc/vector/files.txt"))]
+        path: PathBuf,
+    },
+}
+
 /// Configuration for the `file` source.
 #[serde_as]
 #[configurable_component(source("file", "Collect logs from files."))]
@@ -74,6 +111,10 @@ pub struct FileConfig {
     #[configurable(metadata(docs::examples = "/var/log/binary-file.log"))]
     pub exclude: Vec<PathBuf>,
 
+    #[configurable(derived)]
+    #[serde(default, skip_serializing_if = "crate::serde::is_default")]
+    pub discovery: DiscoveryConfig,
+
     /// Overrides the name of the log field used to add the file path to each event.
     ///
     /// The value is the full path to the file where the event was read message.
@@ -319,6 +360,31 @@ pub enum FingerprintConfig {
     /// [inode]: https://en.wikipedia.org/wiki/Inode
     #[serde(rename = "device_and_inode")]
     DevInode,
+
+    /// Use the [device and inode][inode], along with a checksum of the lines at the beginning of
+    /// the file, as the identifier.
+    ///
+    /// Unlike `device_and_inode`, this tells apart a new file that reuses the inode of a deleted
+    /// file. A file whose beginning is rewritten, such as with `copytruncate` rotation, is read
+    /// again from the beginning.
+    ///
+    /// [inode]: https://en.wikipedia.org/wiki/Inode
+    #[serde(rename = "device_and_inode_checksum")]
+    DevInodeChecksum {
+        /// The number of bytes to skip ahead (or ignore) when reading the data used for generating the checksum.
+        ///
+        /// This can be helpful if all files share a common header that should be skipped.
+        #[serde(default = "default_ignored_header_bytes")]
+        #[configurable(metadata(docs::type_unit = "bytes"))]
+        ignored_header_bytes: usize,
+
+        /// The number of lines to read for generating the checksum.
+        ///
+        /// If the file has less than this amount of lines, it won’t be read at all.
+        #[serde(default = "default_lines")]
+        #[configurable(metadata(docs::type_unit = "lines"))]
+        lines: usize,
+    },
 }
 
 impl Default for FingerprintConfig {
@@ -361,6 +427,32 @@ impl From<FingerprintConfig> for FingerprintStrategy {
                 }
             }
             FingerprintConfig::DevInode => FingerprintStrategy::DevInode,
+            FingerprintConfig::DevInodeChecksum {
+                ignored_header_bytes,
+                lines,
+            } => FingerprintStrategy::DevInodeChecksum {
+                ignored_header_bytes,
+                lines,
+            },
+        }
+    }
+}
+
+/// The paths provider of the configured discovery strategy.
+enum FilePathsProvider {
+    Glob(Glob<FileSourceInternalEventsEmitter>),
+    Watch(Watch<FileSourceInternalEventsEmitter>),
+    Manifest(Manifest<FileSourceInternalEventsEmitter>),
+}
+
+impl PathsProvider for FilePathsProvider {
+    type IntoIter = Vec<PathBuf>;
+
+    fn paths(&self) -> Self::IntoIter {
+        match self {
+            Self::Glob(provider) => provider.paths(),
+            Self::Watch(provider) => provider.paths(),
+            Self::Manifest(provider) => provider.paths(),
         }
     }
 }
@@ -376,6 +468,7 @@ impl Default for FileConfig {
         Self {
             include: vec![PathBuf::from("/var/log/**/*.log")],
             exclude: vec![],
+            discovery: DiscoveryConfig::default(),
             file_key: default_file_key(),
             start_at_beginning: None,
             ignore_checkpoints: None,
@@ -406,6 +499,17 @@ impl Default for FileConfig {
 
 impl_generate_config_from_default!(FileConfig);
 
+impl FileConfig {
+    /// The fingerprinter identifying the files read by this source.
+    pub(crate) fn fingerprinter(&self) -> Fingerprinter {
+        Fingerprinter {
+            strategy: self.fingerprint.clone().into(),
+            max_line_length: self.max_line_bytes,
+            ignore_not_found: self.ignore_not_found,
+        }
+    }
+}
+
 #[async_trait::async_trait]
 #[typetag::serde(name = "file")]
 impl SourceConfig for FileConfig {
@@ -505,8 +609,9 @@ pub fn file_source(
     acknowledgements: bool,
     log_namespace: LogNamespace,
 ) -> super::Source {
-    // the include option must be specified but also must contain at least one entry.
-    if config.include.is_empty() {
+    // the include option must be specified but also must contain at least one entry, unless the
+    // paths are read from a manifest.
+    if config.include.is_empty() && !matches!(config.discovery, DiscoveryConfig::Manifest { .. }) {
         error!(message = "`include` configuration option must contain at least one file pattern.");
         return Box::pin(future::ready(Err(())));
     }
@@ -528,13 +633,33 @@ pub fn file_source(
         include_file_metric_tag: config.internal_metrics.include_file_tag,
     };
 
-    let paths_provider = Glob::new(
-        &config.include,
-        &exclude_patterns,
-        MatchOptions::default(),
-        emitter.clone(),
-    )
-    .expect("invalid glob patterns");
+    let paths_provider = match &config.discovery {
+        DiscoveryConfig::Glob => Glob::new(
+            &config.include,
+            &exclude_patterns,
+            MatchOptions::default(),
+            emitter.clone(),
+        )
+        .map(FilePathsProvider::Glob),
+        DiscoveryConfig::Watch => Watch::new(
+            &config.include,
+            &exclude_patterns,
+            MatchOptions::default(),
+            emitter.clone(),
+        )
+        .map(FilePathsProvider::Watch),
+        DiscoveryConfig::Manifest { path } => Manifest::new(
+            path.clone(),
+            &exclude_patterns,
+            MatchOptions::default(),
+            emitter.clone(),
+        )
+        .map(FilePathsProvider::Manifest),
+    };
+    let Some(paths_provider) = paths_provider else {
+        error!(message = "Invalid file patterns, or the file system can't be watched.");
+        return Box::pin(future::ready(Err(())));
+    };
 
     let encoding_charset = config.encoding.clone().map(|e| e.charset);
 
@@ -556,11 +681,7 @@ pub fn file_source(
         line_delimiter: line_delimiter_as_bytes,
         data_dir,
         glob_minimum_cooldown,
-        fingerprinter: Fingerprinter {
-            strategy: config.fingerprint.clone().into(),
-            max_line_length: config.max_line_bytes,
-            ignore_not_found: config.ignore_not_found,
-        },
+        fingerprinter: config.fingerprinter(),
         oldest_first: config.oldest_first,
         remove_after: config.remove_after_secs.map(Duration::from_secs),
         emitter,
@@ -1209,6 +1330,72 @@ mod tests {
         assert_eq!(goodbye_i, n);
     }
 
+    #[tokio::test]
+    async fn file_discovery_watch() {
+        let dir = tempdir().unwrap();
+        let config = file::FileConfig {
+            include: vec![dir.path().join("*.log")],
+            discovery: DiscoveryConfig::Watch,
+            ..test_default_file_config(&dir)
+        };
+
+        let path = dir.path().join("file.log");
+        let ignored_path = dir.path().join("file.txt");
+
+        let received = run_file_source(&config, false, NoAcks, LogNamespace::Legacy, async {
+            sleep_500_millis().await; // The directory must be watched before the files are created
+
+            let mut file = File::create(&path).unwrap();
+            let mut ignored_file = File::create(&ignored_path).unwrap();
+            writeln!(&mut file, "hello").unwrap();
+            writeln!(&mut ignored_file, "ignored").unwrap();
+
+            sleep_500_millis().await;
+        })
+        .await;
+
+        assert_eq!(extract_messages_value(received), vec!["hello".into()]);
+    }
+
+    #[tokio::test]
+    async fn file_discovery_manifest() {
+        let dir = tempdir().unwrap();
+        let manifest_path = dir.path().join("manifest");
+        let config = file::FileConfig {
+            include: vec![],
+            discovery: DiscoveryConfig::Manifest {
+                path: manifest_path.clone(),
+            },
+            ..test_default_file_config(&dir)
+        };
+
+        let path1 = dir.path().join("file1");
+        let path2 = dir.path().join("file2");
+        fs::write(&manifest_path, "file1\n").unwrap();
+
+        let received = run_file_source(&config, false, NoAcks, LogNamespace::Legacy, async {
+            let mut file1 = File::create(&path1).unwrap();
+            let mut file2 = File::create(&path2).unwrap();
+
+            sleep_500_millis().await; // The files must be observed at their original lengths before writing to them
+
+            writeln!(&mut file1, "listed").unwrap();
+            writeln!(&mut file2, "not listed yet").unwrap();
+
+            sleep_500_millis().await;
+
+            fs::write(&manifest_path, "file1\nfile2\n").unwrap();
+
+            sleep_500_millis().await;
+        })
+        .await;
+
+        assert_eq!(
+            extract_messages_value(received),
+            vec!["listed".into(), "not listed yet".into()]
+        );
+    }
+
     // https://github.com/vectordotdev/vector/issues/8363
     #[tokio::test]
     async fn file_read_empty_lines() {
@@ -1299,6 +1486,39 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn file_truncate_device_and_inode() {
+        let dir = tempdir().unwrap();
+        let config = file::FileConfig {
+            include: vec![dir.path().join("*")],
+            fingerprint: FingerprintConfig::DevInode,
+            ..test_default_file_config(&dir)
+        };
+        let path = dir.path().join("file");
+        let received = run_file_source(&config, false, NoAcks, LogNamespace::Legacy, async {
+            let mut file = File::create(&path).unwrap();
+
+            sleep_500_millis().await; // The files must be observed at its original length before writing to it
+
+            writeln!(&mut file, "before truncation").unwrap();
+
+            sleep_500_millis().await; // The writes must be observed before truncating
+
+            file.set_len(0).unwrap();
+            file.seek(std::io::SeekFrom::Start(0)).unwrap();
+
+            sleep_500_millis().await; // The truncate must be observed before writing again
+
+            writeln!(&mut file, "after").unwrap();
+
+            sleep_500_millis().await;
+        })
+        .await;
+
+        let received = extract_messages_value(received);
+        assert_eq!(received, vec!["before truncation".into(), "after".into()]);
+    }
+
     #[tokio::test]
     async fn file_rotate() {
         let n = 5;
@@ -0,0 +1,212 @@
+//! Checkpoint of the `http_client` source.
+//!
+//! The pagination progress and the time of the last successful scrape are saved in the data
+//! directory so that a restarted source resumes where it left off. When acknowledgements are
+//! enabled, the state is only saved once the events received along with it have been delivered.
+//! The checkpoint is written by a
+//! background task, off the runtime threads, which only writes the latest state when several are
+//! saved while it is busy.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use tokio::{sync::watch, task::JoinHandle};
+use vector_lib::{
+    event::{BatchNotifier, BatchStatus, Event},
+    finalizer::OrderedFinalizer,
+};
+
+use super::pagination::{Position, Progress};
+use crate::internal_events::HttpClientCheckpointError;
+
+const CHECKPOINT_FILENAME: &str = "checkpoint.json";
+const TMP_CHECKPOINT_FILENAME: &str = "checkpoint.new.json";
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(tag = "version", rename_all = "snake_case")]
+enum Checkpoint {
+    #[serde(rename = "1")]
+    V1 {
+        #[serde(default)]
+        position: Option<Position>,
+        #[serde(default)]
+        received: usize,
+        #[serde(default)]
+        last_run: Option<DateTime<Utc>>,
+    },
+}
+
+/// The state of the source kept across restarts.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(super) struct State {
+    pub(super) progress: Progress,
+    pub(super) last_run: Option<DateTime<Utc>>,
+}
+
+/// A change to the state of the source, brought by the response to a request.
+#[derive(Clone, Debug, Default)]
+pub(super) struct Update {
+    /// The pagination progress after the response, when pagination is enabled.
+    pub(super) progress: Option<Progress>,
+    /// The new time of the last successful scrape, when the response was the last page.
+    pub(super) last_run: Option<DateTime<Utc>>,
+}
+
+/// How the state gets saved.
+pub(super) enum Checkpoints {
+    /// Saved as soon as a page is received.
+    Immediate(Mutex<Checkpointer>),
+    /// Saved once the events of the page are delivered.
+    Acknowledged(OrderedFinalizer<Update>),
+}
+
+impl Checkpoints {
+    pub(super) fn new(mut checkpointer: Checkpointer, acknowledgements: bool) -> Self {
+        if acknowledgements {
+            let (finalizer, mut ack_stream) = OrderedFinalizer::<Update>::new(None);
+            tokio::spawn(async move {
+                while let Some((status, update)) = ack_stream.next().await {
+                    if status == BatchStatus::Delivered {
+                        checkpointer.update(update);
+                    }
+                }
+            });
+            Self::Acknowledged(finalizer)
+        } else {
+            Self::Immediate(Mutex::new(checkpointer))
+        }
+    }
+
+    /// Saves the change to the state brought by the response the given events were decoded from.
+    pub(super) fn save(&self, update: Update, events: &mut Vec<Event>) {
+        match self {
+            Self::Immediate(checkpointer) => {
+                checkpointer.lock().expect("Data poisoned.").update(update);
+            }
+            Self::Acknowledged(finalizer) => {
+                let (batch, receiver) = BatchNotifier::new_with_receiver();
+                *events = std::mem::take(events)
+                    .into_iter()
+                    .map(|event| event.with_batch_notifier(&batch))
+                    .collect();
+                finalizer.add(update, receiver);
+            }
+        }
+    }
+}
+
+/// Saves the state of the source in its data directory.
+pub(super) struct Checkpointer {
+    saved: State,
+    writer: watch::Sender<State>,
+}
+
+impl Checkpointer {
+    /// Creates a checkpointer for the given data directory, reading the state saved there, if any.
+    ///
+    /// The task writing the checkpoint is returned along with it, and completes once the
+    /// checkpointer is dropped and the last state saved has been written.
+    pub(super) fn new(data_dir: &Path) -> (Self, JoinHandle<()>) {
+        let stable_file_path = data_dir.join(CHECKPOINT_FILENAME);
+        let tmp_file_path = data_dir.join(TMP_CHECKPOINT_FILENAME);
+
+        let saved = match read_checkpoint(&stable_file_path) {
+            Ok(Some(Checkpoint::V1 {
+                position,
+                received,
+                last_run,
+            })) => State {
+                progress: Progress { position, received },
+                last_run,
+            },
+            Ok(None) => State::default(),
+            Err(error) => {
+                emit!(HttpClientCheckpointError {
+                    error,
+                    path: &stable_file_path,
+                });
+                State::default()
+            }
+        };
+
+        let (writer, states) = watch::channel(saved.clone());
+        let writer_task = tokio::spawn(write_checkpoints(stable_file_path, tmp_file_path, states));
+
+        (Self { saved, writer }, writer_task)
+    }
+
+    pub(super) const fn state(&self) -> &State {
+        &self.saved
+    }
+
+    fn update(&mut self, update: Update) {
+        let state = State {
+            progress: update
+                .progress
+                .unwrap_or_else(|| self.saved.progress.clone()),
+            last_run: update.last_run.max(self.saved.last_run),
+        };
+        if self.saved == state {
+            return;
+        }
+
+        self.saved = state.clone();
+        self.writer.send_replace(state);
+    }
+}
+
+/// Writes the states sent by the checkpointer, until it is dropped.
+async fn write_checkpoints(
+    stable_file_path: PathBuf,
+    tmp_file_path: PathBuf,
+    mut states: watch::Receiver<State>,
+) {
+    while states.changed().await.is_ok() {
+        let state = states.borrow_and_update().clone();
+        let checkpoint = Checkpoint::V1 {
+            position: state.progress.position,
+            received: state.progress.received,
+            last_run: state.last_run,
+        };
+
+        let stable = stable_file_path.clone();
+        let tmp = tmp_file_path.clone();
+        let result =
+            tokio::task::spawn_blocking(move || write_checkpoint(&stable, &tmp, &checkpoint))
+                .await
+                .unwrap_or_else(|error| Err(io::Error::other(error)));
+        if let Err(error) = result {
+            emit!(HttpClientCheckpointError {
+                error,
+                path: &stable_file_path,
+            });
+        }
+    }
+}
+
+fn write_checkpoint(
+    stable_file_path: &Path,
+    tmp_file_path: &Path,
+    checkpoint: &Checkpoint,
+) -> io::Result<()> {
+    // Write to a temporary file first, so that a valid checkpoint remains if Vector stops midway
+    // through.
+    let mut file = io::BufWriter::new(fs::File::create(tmp_file_path)?);
+    serde_json::to_writer(&mut file, checkpoint)?;
+    file.into_inner()?.sync_all()?;
+    fs::rename(tmp_file_path, stable_file_path)
+}
+
+fn read_checkpoint(path: &Path) -> io::Result<Option<Checkpoint>> {
+    match fs::read(path) {
+        Ok(data) => Ok(Some(serde_json::from_slice(&data)?)),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(error),
+    }
+}
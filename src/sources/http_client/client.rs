@@ -2,20 +2,30 @@
 //! Calls an endpoint at an interval, decoding the HTTP responses into events.
 
 use bytes::{Bytes, BytesMut};
-use chrono::Utc;
+use chrono::{DateTime, SecondsFormat, Utc};
 use futures_util::FutureExt;
 use http::{response::Parts, Uri};
+use regex::Regex;
 use serde_with::serde_as;
 use snafu::ResultExt;
-use std::{collections::HashMap, time::Duration};
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::{Arc, LazyLock, Mutex},
+    time::Duration,
+};
 use tokio_util::codec::Decoder as _;
 
+use super::{
+    checkpoint::{Checkpointer, Checkpoints, State, Update},
+    pagination::{Pager, PaginationConfig, Paginator},
+};
 use crate::sources::util::http_client;
 use crate::{
     codecs::{Decoder, DecodingConfig},
-    config::{SourceConfig, SourceContext},
+    config::{SourceAcknowledgementsConfig, SourceConfig, SourceContext},
     http::Auth,
-    serde::{default_decoding, default_framing_message_based},
+    serde::{bool_or_struct, default_decoding, default_framing_message_based},
     sources,
     sources::util::{
         http::HttpMethod,
@@ -73,6 +83,9 @@ pub struct HttpClientConfig {
     ///
     /// The parameters provided in this option are appended to any parameters
     /// manually provided in the `endpoint` option.
+    ///
+    /// Values can refer to the time of the last successful scrape, as described for the `body`
+    /// option.
     #[serde(default)]
     #[configurable(metadata(
         docs::additional_props_description = "A query string parameter and it's value(s)."
@@ -104,6 +117,23 @@ pub struct HttpClientConfig {
     #[serde(default = "default_http_method")]
     pub method: HttpMethod,
 
+    /// The body of the HTTP requests.
+    ///
+    /// Any occurrence of `{{ last_run }}` is replaced by the time at which the last successful
+    /// scrape started, as an RFC 3339 timestamp, so that only new data is requested. When
+    /// pagination is enabled, a scrape only succeeds once the last page is received, and a scrape
+    /// stopped by `pagination.max_pages` is continued by the next ones, which keep using the same
+    /// time. That time is saved in the data directory so that it is kept across restarts, once the
+    /// events of the scrape are delivered if acknowledgements are enabled. Until a scrape
+    /// succeeds, the time at which the source first started is used.
+    ///
+    /// The `Content-Type` header of the requests can be set with the `headers` option.
+    #[configurable(metadata(
+        docs::examples = r#"{"query": {"range": {"@timestamp": {"gt": "{{ last_run }}"}}}}"#
+    ))]
+    #[serde(default)]
+    pub body: Option<String>,
+
     /// TLS configuration.
     #[configurable(derived)]
     pub tls: Option<TlsConfig>,
@@ -112,12 +142,47 @@ pub struct HttpClientConfig {
     #[configurable(derived)]
     pub auth: Option<Auth>,
 
+    /// Pagination of the responses.
+    ///
+    /// When set, each scrape requests the following pages of the response in turn, and the
+    /// position of the next page is saved in the data directory so that it is picked up from
+    /// after a restart. When acknowledgements are enabled, the position is only saved once the
+    /// events of the page have been delivered.
+    ///
+    /// Scrapes then run one at a time, and the scrapes due while the previous one is still
+    /// running are skipped.
+    #[configurable(derived)]
+    #[serde(default)]
+    pub pagination: Option<PaginationConfig>,
+
+    /// The directory used to persist the pagination position, and the time of the last successful
+    /// scrape.
+    ///
+    /// By default, the [global `data_dir` option][global_data_dir] is used.
+    /// Make sure the running user has write permissions to this directory.
+    ///
+    /// If this directory is specified, then Vector will attempt to create it.
+    ///
+    /// [global_data_dir]: https://vector.dev/docs/reference/configuration/global-options/#data_dir
+    #[serde(default)]
+    #[configurable(metadata(docs::examples = "/var/lib/vector"))]
+    #[configurable(metadata(docs::human_name = "Data Directory"))]
+    pub data_dir: Option<PathBuf>,
+
+    #[configurable(derived)]
+    #[serde(default, deserialize_with = "bool_or_struct")]
+    pub acknowledgements: SourceAcknowledgementsConfig,
+
     /// The namespace to use for logs. This overrides the global setting.
     #[configurable(metadata(docs::hidden))]
     #[serde(default)]
     pub log_namespace: Option<bool>,
 }
 
+/// Matches the references to the time of the last successful scrape in the request templates.
+static LAST_RUN_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\{\{\s*last_run\s*\}\}").unwrap());
+
 const fn default_http_method() -> HttpMethod {
     HttpMethod::Get
 }
@@ -125,6 +190,7 @@ const fn default_http_method() -> HttpMethod {
 fn query_examples() -> HashMap<String, Vec<String>> {
     HashMap::<_, _>::from_iter([
         ("field".to_owned(), vec!["value".to_owned()]),
+        ("since".to_owned(), vec!["{{ last_run }}".to_owned()]),
         (
             "fruit".to_owned(),
             vec!["mango".to_owned(), "papaya".to_owned(), "kiwi".to_owned()],
@@ -161,8 +227,12 @@ impl Default for HttpClientConfig {
             framing: default_framing_message_based(),
             headers: HashMap::new(),
             method: default_http_method(),
+            body: None,
             tls: None,
             auth: None,
+            pagination: None,
+            data_dir: None,
+            acknowledgements: SourceAcknowledgementsConfig::default(),
             log_namespace: None,
         }
     }
@@ -176,10 +246,10 @@ impl SourceConfig for HttpClientConfig {
     async fn build(&self, cx: SourceContext) -> Result<sources::Source> {
         // build the url
         let endpoints = [self.endpoint.clone()];
+        // the query is added at each scrape, as it may refer to the last run
         let urls = endpoints
             .iter()
             .map(|s| s.parse::<Uri>().context(sources::UriParseSnafu))
-            .map(|r| r.map(|uri| build_url(&uri, &self.query)))
             .collect::<std::result::Result<Vec<Uri>, sources::BuildError>>()?;
 
         let tls = TlsSettings::from_options(&self.tls)?;
@@ -191,10 +261,39 @@ impl SourceConfig for HttpClientConfig {
 
         let content_type = self.decoding.content_type(&self.framing).to_string();
 
-        // the only specific context needed is the codec decoding
+        // the checkpoint is only needed when there is some state to keep across restarts
+        let mut state = State::default();
+        let mut checkpoints = None;
+        let mut checkpoint_writer = None;
+        if self.pagination.is_some() || self.uses_last_run() {
+            let data_dir = cx
+                .globals
+                .resolve_and_make_data_subdir(self.data_dir.as_ref(), cx.key.id())?;
+            let (checkpointer, writer) = Checkpointer::new(&data_dir);
+            state = checkpointer.state().clone();
+            let acknowledgements = cx.do_acknowledgements(self.acknowledgements);
+            checkpoints = Some(Arc::new(Checkpoints::new(checkpointer, acknowledgements)));
+            checkpoint_writer = Some(writer);
+        }
+
+        let paginator = self
+            .pagination
+            .clone()
+            .map(|pagination| Paginator::new(pagination, state.progress));
+
+        // the specific context needed is the codec decoding, the request templates, and the
+        // pagination state
         let context = HttpClientContext {
             decoder,
             log_namespace,
+            query: self.query.clone(),
+            body: self.body.clone(),
+            last_run: Arc::new(Mutex::new(state.last_run.unwrap_or_else(Utc::now))),
+            run_started: Arc::new(Mutex::new(None)),
+            scrape_started: Utc::now(),
+            checkpoints,
+            paginator,
+            pager: None,
         };
 
         warn_if_interval_too_low(self.timeout, self.interval);
@@ -211,7 +310,17 @@ impl SourceConfig for HttpClientConfig {
             shutdown: cx.shutdown,
         };
 
-        Ok(call(inputs, context, cx.out, self.method).boxed())
+        let method = self.method;
+        Ok(async move {
+            let result = call(inputs, context, cx.out, method).await;
+            // The checkpointer goes away along with the scrapes, once their events are
+            // acknowledged, and the last state saved is then written.
+            if let Some(writer) = checkpoint_writer {
+                _ = writer.await;
+            }
+            result
+        }
+        .boxed())
     }
 
     fn outputs(&self, global_log_namespace: LogNamespace) -> Vec<SourceOutput> {
@@ -231,11 +340,19 @@ impl SourceConfig for HttpClientConfig {
     }
 
     fn can_acknowledge(&self) -> bool {
-        false
+        true
     }
 }
 
 impl HttpClientConfig {
+    /// Returns whether the request templates refer to the time of the last successful scrape.
+    fn uses_last_run(&self) -> bool {
+        self.body
+            .iter()
+            .chain(self.query.values().flatten())
+            .any(|template| LAST_RUN_REGEX.is_match(template))
+    }
+
     pub fn get_decoding_config(&self, log_namespace: Option<LogNamespace>) -> DecodingConfig {
         let decoding = self.decoding.clone();
         let framing = self.framing.clone();
@@ -251,6 +368,16 @@ impl HttpClientConfig {
 pub struct HttpClientContext {
     pub decoder: Decoder,
     pub log_namespace: LogNamespace,
+    query: HashMap<String, Vec<String>>,
+    body: Option<String>,
+    last_run: Arc<Mutex<DateTime<Utc>>>,
+    /// When the pages of a response are requested over several scrapes, because of
+    /// `pagination.max_pages`, the time at which the first of these scrapes started.
+    run_started: Arc<Mutex<Option<DateTime<Utc>>>>,
+    scrape_started: DateTime<Utc>,
+    checkpoints: Option<Arc<Checkpoints>>,
+    paginator: Option<Arc<Paginator>>,
+    pager: Option<Pager>,
 }
 
 impl HttpClientContext {
@@ -275,29 +402,107 @@ impl HttpClientContext {
         }
         events
     }
+
+    /// Renders the references to the last run in a request template.
+    fn render(&self, template: &str) -> String {
+        let last_run = self.last_run.lock().expect("Data poisoned.");
+        LAST_RUN_REGEX
+            .replace_all(
+                template,
+                last_run.to_rfc3339_opts(SecondsFormat::Secs, true).as_str(),
+            )
+            .into_owned()
+    }
+
+    /// Moves the time of the last run to the start of the run whose last page was just received,
+    /// and returns it.
+    fn complete_run(&self) -> DateTime<Utc> {
+        self.run_started.lock().expect("Data poisoned.").take();
+        let mut last_run = self.last_run.lock().expect("Data poisoned.");
+        *last_run = (*last_run).max(self.scrape_started);
+        *last_run
+    }
 }
 
 impl HttpClientBuilder for HttpClientContext {
     type Context = HttpClientContext;
 
-    /// No additional context from request data is needed from this particular client.
+    /// Starts a new scrape, keeping track of its pages if pagination is enabled.
+    ///
+    /// A scrape picking up the pages of a run that didn't fit in the previous one is considered
+    /// to have started along with that run.
     fn build(&self, _uri: &Uri) -> Self::Context {
-        self.clone()
+        let mut context = self.clone();
+        context.scrape_started = match &self.paginator {
+            Some(_) => *self
+                .run_started
+                .lock()
+                .expect("Data poisoned.")
+                .get_or_insert_with(Utc::now),
+            None => Utc::now(),
+        };
+        context.pager = self.paginator.clone().map(Pager::new);
+        context
+    }
+
+    /// Each scrape picks up from the page the previous one stopped at, so they can't overlap.
+    fn sequential(&self) -> bool {
+        self.paginator.is_some()
     }
 }
 
 impl http_client::HttpClientContext for HttpClientContext {
     /// Decodes the HTTP response body into events per the decoder configured.
-    fn on_response(&mut self, _url: &Uri, _header: &Parts, body: &Bytes) -> Option<Vec<Event>> {
+    fn on_response(&mut self, url: &Uri, header: &Parts, body: &Bytes) -> Option<Vec<Event>> {
         // get the body into a byte array
         let mut buf = BytesMut::new();
         buf.extend_from_slice(body);
 
-        let events = self.decode_events(&mut buf);
+        let mut events = self.decode_events(&mut buf);
+
+        let progress = self
+            .pager
+            .as_mut()
+            .map(|pager| pager.on_response(url, header, body, &mut events));
+        // the run is only complete once there are no more pages, even if this scrape stops here
+        let last_run = match &self.pager {
+            Some(pager) if pager.has_next_page() => None,
+            _ => Some(self.complete_run()),
+        };
+        if let Some(checkpoints) = &self.checkpoints {
+            checkpoints.save(Update { progress, last_run }, &mut events);
+        }
 
         Some(events)
     }
 
+    fn start_url(&mut self, url: &Uri) -> Uri {
+        let query: HashMap<String, Vec<String>> = self
+            .query
+            .iter()
+            .map(|(key, values)| {
+                let values = values.iter().map(|value| self.render(value)).collect();
+                (key.clone(), values)
+            })
+            .collect();
+        let url = build_url(url, &query);
+
+        match &mut self.pager {
+            Some(pager) => pager.start_url(&url),
+            None => url,
+        }
+    }
+
+    fn next_url(&mut self) -> Option<Uri> {
+        self.pager.as_mut().and_then(Pager::next_url)
+    }
+
+    fn request_body(&self) -> Option<Bytes> {
+        self.body
+            .as_deref()
+            .map(|body| Bytes::from(self.render(body)))
+    }
+
     /// Enriches events with source_type, timestamp
     fn enrich_events(&mut self, events: &mut Vec<Event>) {
         let now = Utc::now();
@@ -59,9 +59,13 @@ async fn invalid_endpoint() {
         framing: default_framing_message_based(),
         headers: HashMap::new(),
         method: HttpMethod::Get,
+        body: None,
         auth: None,
         tls: None,
         log_namespace: None,
+        pagination: None,
+        data_dir: None,
+        acknowledgements: Default::default(),
     })
     .await;
 }
@@ -78,9 +82,13 @@ async fn collected_logs_bytes() {
         framing: default_framing_message_based(),
         headers: HashMap::new(),
         method: HttpMethod::Get,
+        body: None,
         auth: None,
         tls: None,
         log_namespace: None,
+        pagination: None,
+        data_dir: None,
+        acknowledgements: Default::default(),
     })
     .await;
     // panics if not log event
@@ -103,9 +111,13 @@ async fn collected_logs_json() {
         framing: default_framing_message_based(),
         headers: HashMap::new(),
         method: HttpMethod::Get,
+        body: None,
         auth: None,
         tls: None,
         log_namespace: None,
+        pagination: None,
+        data_dir: None,
+        acknowledgements: Default::default(),
     })
     .await;
     // panics if not log event
@@ -128,9 +140,13 @@ async fn collected_metrics_native_json() {
         framing: default_framing_message_based(),
         headers: HashMap::new(),
         method: HttpMethod::Get,
+        body: None,
         auth: None,
         tls: None,
         log_namespace: None,
+        pagination: None,
+        data_dir: None,
+        acknowledgements: Default::default(),
     })
     .await;
 
@@ -158,9 +174,13 @@ async fn collected_trace_native_json() {
         framing: default_framing_message_based(),
         headers: HashMap::new(),
         method: HttpMethod::Get,
+        body: None,
         auth: None,
         tls: None,
         log_namespace: None,
+        pagination: None,
+        data_dir: None,
+        acknowledgements: Default::default(),
     })
     .await;
 
@@ -183,9 +203,13 @@ async fn unauthorized_no_auth() {
         framing: default_framing_message_based(),
         headers: HashMap::new(),
         method: HttpMethod::Get,
+        body: None,
         auth: None,
         tls: None,
         log_namespace: None,
+        pagination: None,
+        data_dir: None,
+        acknowledgements: Default::default(),
     })
     .await;
 }
@@ -202,12 +226,16 @@ async fn unauthorized_wrong_auth() {
         framing: default_framing_message_based(),
         headers: HashMap::new(),
         method: HttpMethod::Get,
+        body: None,
         tls: None,
         auth: Some(Auth::Basic {
             user: "white_rabbit".to_string(),
             password: "morpheus".to_string().into(),
         }),
         log_namespace: None,
+        pagination: None,
+        data_dir: None,
+        acknowledgements: Default::default(),
     })
     .await;
 }
@@ -224,12 +252,16 @@ async fn authorized() {
         framing: default_framing_message_based(),
         headers: HashMap::new(),
         method: HttpMethod::Get,
+        body: None,
         tls: None,
         auth: Some(Auth::Basic {
             user: "user".to_string(),
             password: "pass".to_string().into(),
         }),
         log_namespace: None,
+        pagination: None,
+        data_dir: None,
+        acknowledgements: Default::default(),
     })
     .await;
 }
@@ -246,12 +278,16 @@ async fn tls_invalid_ca() {
         framing: default_framing_message_based(),
         headers: HashMap::new(),
         method: HttpMethod::Get,
+        body: None,
         tls: Some(TlsConfig {
             ca_file: Some("tests/data/http-client/certs/invalid-ca-cert.pem".into()),
             ..Default::default()
         }),
         auth: None,
         log_namespace: None,
+        pagination: None,
+        data_dir: None,
+        acknowledgements: Default::default(),
     })
     .await;
 }
@@ -268,12 +304,16 @@ async fn tls_valid() {
         framing: default_framing_message_based(),
         headers: HashMap::new(),
         method: HttpMethod::Get,
+        body: None,
         tls: Some(TlsConfig {
             ca_file: Some(tls::TEST_PEM_CA_PATH.into()),
             ..Default::default()
         }),
         auth: None,
         log_namespace: None,
+        pagination: None,
+        data_dir: None,
+        acknowledgements: Default::default(),
     })
     .await;
 }
@@ -291,9 +331,13 @@ async fn shutdown() {
         framing: default_framing_message_based(),
         headers: HashMap::new(),
         method: HttpMethod::Get,
+        body: None,
         tls: None,
         auth: None,
         log_namespace: None,
+        pagination: None,
+        data_dir: None,
+        acknowledgements: Default::default(),
     };
 
     // build the context for the source and get a SourceShutdownCoordinator to signal with
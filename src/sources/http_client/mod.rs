@@ -1,5 +1,9 @@
 #[cfg(feature = "sources-http_client")]
+mod checkpoint;
+#[cfg(feature = "sources-http_client")]
 pub mod client;
+#[cfg(feature = "sources-http_client")]
+mod pagination;
 
 #[cfg(test)]
 mod tests;
@@ -8,3 +12,4 @@ mod tests;
 mod integration_tests;
 
 pub use client::HttpClientConfig;
+pub use pagination::{PaginationConfig, PaginationStrategy};
@@ -0,0 +1,383 @@
+//! Pagination of the responses of the `http_client` source.
+//!
+//! The position of the next page to request is kept across scrapes, and saved in the checkpoint
+//! of the source after every page so that a restarted source resumes where it left off.
+//!
+//! When a scrape reaches the last page, the next scrape requests that page again to pick up the
+//! items added to it since, skipping the ones already received. As each scrape starts from the
+//! page the previous one stopped at, scrapes run one at a time.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use bytes::Bytes;
+use http::{header::LINK, response::Parts, Uri};
+use serde::{Deserialize, Serialize};
+use vector_lib::{
+    configurable::configurable_component, event::Event, lookup::lookup_v2::ConfigValuePath,
+};
+use vrl::value::Value;
+
+use crate::sources::util::http_client;
+
+const fn default_max_pages() -> usize {
+    100
+}
+
+fn default_offset_parameter() -> String {
+    "offset".to_owned()
+}
+
+fn default_limit_parameter() -> String {
+    "limit".to_owned()
+}
+
+const fn default_limit() -> u64 {
+    100
+}
+
+/// Pagination configuration.
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub struct PaginationConfig {
+    #[serde(flatten)]
+    #[configurable(derived)]
+    pub strategy: PaginationStrategy,
+
+    /// The maximum number of pages to request per scrape.
+    ///
+    /// Once this many pages have been requested, the next scrape picks up from the following page.
+    #[serde(default = "default_max_pages")]
+    #[configurable(metadata(docs::type_unit = "pages"))]
+    pub max_pages: usize,
+}
+
+/// Pagination strategy.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(tag = "strategy", rename_all = "snake_case")]
+#[configurable(metadata(docs::enum_tag_description = "The pagination strategy to use."))]
+pub enum PaginationStrategy {
+    /// Follow the URL in the `next` relation of the `Link` response header.
+    ///
+    /// Paging stops when a response has no such relation.
+    LinkHeader,
+
+    /// Read a cursor from the response body, and pass it as a query parameter of the next request.
+    ///
+    /// The response body must be a JSON object. Paging stops when the cursor is missing, `null`,
+    /// or empty.
+    BodyCursor {
+        /// The path of the cursor in the response body.
+        #[configurable(metadata(docs::examples = "next_cursor"))]
+        #[configurable(metadata(docs::examples = "meta.pagination.next"))]
+        cursor_path: ConfigValuePath,
+
+        /// The query parameter to pass the cursor in.
+        #[configurable(metadata(docs::examples = "cursor"))]
+        cursor_parameter: String,
+    },
+
+    /// Request successive ranges of items through offset and limit query parameters.
+    ///
+    /// Paging stops when a response has fewer events than the limit.
+    Offset {
+        /// The query parameter to pass the offset of the first item of the page in.
+        #[serde(default = "default_offset_parameter")]
+        offset_parameter: String,
+
+        /// The query parameter to pass the number of items per page in.
+        #[serde(default = "default_limit_parameter")]
+        limit_parameter: String,
+
+        /// The number of items to request per page.
+        #[serde(default = "default_limit")]
+        limit: u64,
+    },
+}
+
+/// The position of a page, as saved in the checkpoint.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(tag = "strategy", rename_all = "snake_case")]
+pub(super) enum Position {
+    LinkHeader { url: String },
+    BodyCursor { cursor: String },
+    Offset { offset: u64 },
+}
+
+/// The page to request next, along with the number of items of that page already received.
+///
+/// Items are only ever received from the page at the position once it was the last page.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(super) struct Progress {
+    pub(super) position: Option<Position>,
+    pub(super) received: usize,
+}
+
+/// The pagination state shared by all the scrapes of a source.
+pub(super) struct Paginator {
+    config: PaginationConfig,
+    progress: Mutex<Progress>,
+}
+
+impl Paginator {
+    /// Creates the pagination state, resuming from the given progress saved in the checkpoint.
+    pub(super) fn new(config: PaginationConfig, progress: Progress) -> Arc<Self> {
+        Arc::new(Self {
+            config,
+            progress: Mutex::new(progress),
+        })
+    }
+
+    fn progress(&self) -> Progress {
+        self.progress.lock().expect("Data poisoned.").clone()
+    }
+
+    /// Moves past the page the last events were received from.
+    fn advance(&self, progress: Progress) {
+        *self.progress.lock().expect("Data poisoned.") = progress;
+    }
+}
+
+/// The pagination state of a single scrape.
+#[derive(Clone)]
+pub(super) struct Pager {
+    paginator: Arc<Paginator>,
+    base_url: Option<Uri>,
+    current: Option<Position>,
+    received: usize,
+    next: Option<Position>,
+    pages: usize,
+}
+
+impl Pager {
+    pub(super) fn new(paginator: Arc<Paginator>) -> Self {
+        Self {
+            paginator,
+            base_url: None,
+            current: None,
+            received: 0,
+            next: None,
+            pages: 0,
+        }
+    }
+
+    /// Returns the URL of the page to start the scrape from.
+    pub(super) fn start_url(&mut self, url: &Uri) -> Uri {
+        self.base_url = Some(url.clone());
+        let progress = self.paginator.progress();
+        self.current = progress.position;
+        self.received = progress.received;
+        self.url_for(self.current.as_ref())
+    }
+
+    /// Works out the position of the page following the one in the given response, and returns
+    /// the progress to save so that the next scrape, or a restarted source, picks up from there.
+    ///
+    /// When there is no next page, the position of the last page is kept along with the number
+    /// of items received from it, and the events of the items received by a previous scrape are
+    /// removed.
+    pub(super) fn on_response(
+        &mut self,
+        url: &Uri,
+        header: &Parts,
+        body: &Bytes,
+        events: &mut Vec<Event>,
+    ) -> Progress {
+        self.pages += 1;
+        let skipped = self.received.min(events.len());
+        events.drain(..skipped);
+        self.received = skipped + events.len();
+
+        self.next = match &self.paginator.config.strategy {
+            PaginationStrategy::LinkHeader => header
+                .headers
+                .get_all(LINK)
+                .iter()
+                .filter_map(|value| value.to_str().ok())
+                .find_map(next_link)
+                .and_then(|link| resolve_link(url, link))
+                .map(|url| Position::LinkHeader { url }),
+            PaginationStrategy::BodyCursor { cursor_path, .. } => {
+                body_cursor(body, cursor_path).map(|cursor| Position::BodyCursor { cursor })
+            }
+            PaginationStrategy::Offset { limit, .. } => {
+                let offset = match self.current {
+                    Some(Position::Offset { offset }) => offset,
+                    _ => 0,
+                };
+                let offset = Position::Offset {
+                    offset: offset + self.received as u64,
+                };
+                if (self.received as u64) < *limit {
+                    // The offset still moves past the items of the last page, so that the
+                    // next scrape only requests the ones added since.
+                    self.current = Some(offset);
+                    self.received = 0;
+                    None
+                } else {
+                    Some(offset)
+                }
+            }
+        };
+
+        let progress = match &self.next {
+            Some(next) => Progress {
+                position: Some(next.clone()),
+                received: 0,
+            },
+            None => Progress {
+                position: self.current.clone(),
+                received: self.received,
+            },
+        };
+        self.paginator.advance(progress.clone());
+        progress
+    }
+
+    /// Returns whether the last response has a next page, whether or not this scrape requests it.
+    pub(super) const fn has_next_page(&self) -> bool {
+        self.next.is_some()
+    }
+
+    /// Returns the URL of the next page to request in this scrape, if any.
+    pub(super) fn next_url(&mut self) -> Option<Uri> {
+        if self.pages >= self.paginator.config.max_pages {
+            return None;
+        }
+        let next = self.next.take()?;
+        let url = self.url_for(Some(&next));
+        self.current = Some(next);
+        self.received = 0;
+        Some(url)
+    }
+
+    fn url_for(&self, position: Option<&Position>) -> Uri {
+        let base_url = self
+            .base_url
+            .clone()
+            .expect("the scrape must have been started");
+        match (&self.paginator.config.strategy, position) {
+            (PaginationStrategy::LinkHeader, Some(Position::LinkHeader { url })) => {
+                url.parse().unwrap_or(base_url)
+            }
+            (
+                PaginationStrategy::BodyCursor {
+                    cursor_parameter, ..
+                },
+                Some(Position::BodyCursor { cursor }),
+            ) => http_client::build_url(
+                &base_url,
+                &HashMap::from([(cursor_parameter.clone(), vec![cursor.clone()])]),
+            ),
+            (
+                PaginationStrategy::Offset {
+                    offset_parameter,
+                    limit_parameter,
+                    limit,
+                },
+                position,
+            ) => {
+                let offset = match position {
+                    Some(Position::Offset { offset }) => *offset,
+                    _ => 0,
+                };
+                http_client::build_url(
+                    &base_url,
+                    &HashMap::from([
+                        (offset_parameter.clone(), vec![offset.to_string()]),
+                        (limit_parameter.clone(), vec![limit.to_string()]),
+                    ]),
+                )
+            }
+            // Either the first page, or a position saved with another strategy.
+            _ => base_url,
+        }
+    }
+}
+
+/// Finds the target of the `next` relation in a `Link` header value, as defined in RFC 8288.
+fn next_link(value: &str) -> Option<&str> {
+    value.split(',').find_map(|link| {
+        let (target, params) = link.trim().strip_prefix('<')?.split_once('>')?;
+        params
+            .split(';')
+            .filter_map(|param| param.trim().split_once('='))
+            .any(|(name, value)| {
+                name.trim().eq_ignore_ascii_case("rel")
+                    && value
+                        .trim()
+                        .trim_matches('"')
+                        .split_whitespace()
+                        .any(|rel| rel.eq_ignore_ascii_case("next"))
+            })
+            .then_some(target)
+    })
+}
+
+/// Resolves a link target, which may be relative, against the URL of the response it came in.
+fn resolve_link(url: &Uri, link: &str) -> Option<String> {
+    let base = url::Url::parse(&url.to_string()).ok()?;
+    let resolved = base.join(link).ok()?;
+    Some(resolved.to_string())
+}
+
+fn body_cursor(body: &Bytes, path: &ConfigValuePath) -> Option<String> {
+    let body: serde_json::Value = serde_json::from_slice(body).ok()?;
+    let cursor = match Value::from(body).get(&path.0)? {
+        Value::Bytes(bytes) => String::from_utf8_lossy(bytes).into_owned(),
+        Value::Integer(integer) => integer.to_string(),
+        _ => return None,
+    };
+    (!cursor.is_empty()).then_some(cursor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_link_is_found() {
+        assert_eq!(
+            next_link(
+                r#"<https://example.com/?page=1>; rel="prev", <https://example.com/?page=3>; rel="next""#
+            ),
+            Some("https://example.com/?page=3")
+        );
+        assert_eq!(
+            next_link(r#"</items?page=2>; rel="next last""#),
+            Some("/items?page=2")
+        );
+        assert_eq!(
+            next_link(r#"<https://example.com/?page=1>; rel="prev""#),
+            None
+        );
+    }
+
+    #[test]
+    fn relative_link_is_resolved() {
+        let url = Uri::from_static("http://example.com/api/items?page=1");
+        assert_eq!(
+            resolve_link(&url, "/api/items?page=2").as_deref(),
+            Some("http://example.com/api/items?page=2")
+        );
+    }
+
+    #[test]
+    fn body_cursor_is_read() {
+        let path = ConfigValuePath::try_from("meta.next".to_owned()).unwrap();
+        let cursor = |body: &'static str| body_cursor(&Bytes::from(body), &path);
+
+        assert_eq!(
+            cursor(r#"{"meta": {"next": "abc"}}"#).as_deref(),
+            Some("abc")
+        );
+        assert_eq!(cursor(r#"{"meta": {"next": 42}}"#).as_deref(), Some("42"));
+        assert_eq!(cursor(r#"{"meta": {"next": ""}}"#), None);
+        assert_eq!(cursor(r#"{"meta": {"next": null}}"#), None);
+        assert_eq!(cursor(r#"{"items": []}"#), None);
+        assert_eq!(cursor("not json"), None);
+    }
+}
@@ -1,5 +1,5 @@
 use http::Uri;
-use std::collections::HashMap;
+use std::{collections::HashMap, path::Path};
 use tokio::time::Duration;
 use vector_lib::config::LogNamespace;
 use warp::{http::HeaderMap, Filter};
@@ -13,10 +13,12 @@ use vector_lib::codecs::decoding::{
 use vector_lib::codecs::CharacterDelimitedDecoderConfig;
 use vector_lib::event::Event;
 
-use super::HttpClientConfig;
+use super::{HttpClientConfig, PaginationConfig, PaginationStrategy};
 use crate::test_util::{
-    components::{run_and_assert_source_compliance, HTTP_PULL_SOURCE_TAGS},
-    next_addr, test_generate_config, wait_for_tcp,
+    components::{
+        run_and_assert_source_compliance, run_and_assert_source_compliance_n, HTTP_PULL_SOURCE_TAGS,
+    },
+    next_addr, temp_dir, test_generate_config, wait_for, wait_for_tcp,
 };
 
 pub(crate) const INTERVAL: Duration = Duration::from_secs(1);
@@ -34,6 +36,17 @@ pub(crate) async fn run_compliance(config: HttpClientConfig) -> Vec<Event> {
     events
 }
 
+/// Waits for the checkpoint saved in the given data directory to match `predicate`, as it is
+/// written in the background.
+async fn wait_for_checkpoint(data_dir: &Path, predicate: fn(&str) -> bool) {
+    let path = data_dir.join("default/checkpoint.json");
+    wait_for(move || {
+        let checkpoint = std::fs::read_to_string(&path).unwrap_or_default();
+        async move { predicate(&checkpoint) }
+    })
+    .await;
+}
+
 #[test]
 fn http_client_generate_config() {
     test_generate_config::<HttpClientConfig>();
@@ -93,9 +106,13 @@ async fn bytes_decoding() {
         framing: default_framing_message_based(),
         headers: HashMap::new(),
         method: HttpMethod::Get,
+        body: None,
         tls: None,
         auth: None,
         log_namespace: None,
+        pagination: None,
+        data_dir: None,
+        acknowledgements: Default::default(),
     })
     .await;
 }
@@ -122,9 +139,13 @@ async fn json_decoding_newline_delimited() {
         framing: FramingConfig::NewlineDelimited(Default::default()),
         headers: HashMap::new(),
         method: HttpMethod::Get,
+        body: None,
         tls: None,
         auth: None,
         log_namespace: None,
+        pagination: None,
+        data_dir: None,
+        acknowledgements: Default::default(),
     })
     .await;
 }
@@ -156,9 +177,13 @@ async fn json_decoding_character_delimited() {
         }),
         headers: HashMap::new(),
         method: HttpMethod::Get,
+        body: None,
         tls: None,
         auth: None,
         log_namespace: None,
+        pagination: None,
+        data_dir: None,
+        acknowledgements: Default::default(),
     })
     .await;
 }
@@ -190,9 +215,13 @@ async fn request_query_applied() {
         framing: default_framing_message_based(),
         headers: HashMap::new(),
         method: HttpMethod::Get,
+        body: None,
         tls: None,
         auth: None,
         log_namespace: None,
+        pagination: None,
+        data_dir: None,
+        acknowledgements: Default::default(),
     })
     .await;
 
@@ -254,9 +283,13 @@ async fn headers_applied() {
             vec!["bazz".to_string(), "bizz".to_string()],
         )]),
         method: HttpMethod::Get,
+        body: None,
         auth: None,
         tls: None,
         log_namespace: None,
+        pagination: None,
+        data_dir: None,
+        acknowledgements: Default::default(),
     })
     .await;
 }
@@ -283,9 +316,306 @@ async fn accept_header_override() {
         framing: default_framing_message_based(),
         headers: HashMap::from([("ACCEPT".to_string(), vec!["application/json".to_string()])]),
         method: HttpMethod::Get,
+        body: None,
         auth: None,
         tls: None,
         log_namespace: None,
+        pagination: None,
+        data_dir: None,
+        acknowledgements: Default::default(),
+    })
+    .await;
+}
+
+/// Pages linked through the `Link` header should be requested in turn, and the position of the
+/// last one saved. The following scrapes request the last page again, without receiving its
+/// items twice.
+#[tokio::test]
+async fn link_header_pagination() {
+    let in_addr = next_addr();
+
+    let dummy_endpoint = warp::path!("endpoint")
+        .and(warp::query::<HashMap<String, String>>())
+        .map(|query: HashMap<String, String>| {
+            let page: u32 = query.get("page").map_or(1, |page| page.parse().unwrap());
+            let mut response = warp::http::Response::builder();
+            if page < 3 {
+                response = response.header(
+                    "Link",
+                    format!(r#"</endpoint?page={}>; rel="next""#, page + 1),
+                );
+            }
+            response.body(format!(r#"{{"page": {}}}"#, page)).unwrap()
+        });
+
+    tokio::spawn(warp::serve(dummy_endpoint).run(in_addr));
+    wait_for_tcp(in_addr).await;
+
+    let data_dir = temp_dir();
+    let events = run_and_assert_source_compliance(
+        HttpClientConfig {
+            endpoint: format!("http://{}/endpoint", in_addr),
+            interval: INTERVAL,
+            timeout: TIMEOUT,
+            decoding: DeserializerConfig::Json(Default::default()),
+            pagination: Some(PaginationConfig {
+                strategy: PaginationStrategy::LinkHeader,
+                max_pages: 10,
+            }),
+            data_dir: Some(data_dir.clone()),
+            ..Default::default()
+        },
+        Duration::from_secs(3),
+        &HTTP_PULL_SOURCE_TAGS,
+    )
+    .await;
+
+    let pages: Vec<_> = events
+        .into_iter()
+        .map(|event| event.into_log().get("page").unwrap().as_integer().unwrap())
+        .collect();
+    assert_eq!(pages, vec![1, 2, 3]);
+
+    wait_for_checkpoint(&data_dir, |checkpoint| {
+        checkpoint.contains("/endpoint?page=3") && checkpoint.contains(r#""received":1"#)
+    })
+    .await;
+}
+
+/// Offset pagination should resume from the saved position, and stop at the first short page.
+#[tokio::test]
+async fn offset_pagination_resumes() {
+    let in_addr = next_addr();
+
+    let dummy_endpoint = warp::path!("endpoint")
+        .and(warp::query::<HashMap<String, String>>())
+        .map(|query: HashMap<String, String>| {
+            let offset: usize = query["offset"].parse().unwrap();
+            let limit: usize = query["limit"].parse().unwrap();
+            (offset..(offset + limit).min(6))
+                .map(|item| format!(r#"{{"item": {}}}"#, item))
+                .collect::<Vec<_>>()
+                .join("\n")
+        });
+
+    tokio::spawn(warp::serve(dummy_endpoint).run(in_addr));
+    wait_for_tcp(in_addr).await;
+
+    let data_dir = temp_dir();
+    std::fs::create_dir_all(data_dir.join("default")).unwrap();
+    std::fs::write(
+        data_dir.join("default/checkpoint.json"),
+        r#"{"version":"1","position":{"strategy":"offset","offset":1}}"#,
+    )
+    .unwrap();
+
+    let events = run_and_assert_source_compliance_n(
+        HttpClientConfig {
+            endpoint: format!("http://{}/endpoint", in_addr),
+            decoding: DeserializerConfig::Json(Default::default()),
+            framing: FramingConfig::NewlineDelimited(Default::default()),
+            pagination: Some(PaginationConfig {
+                strategy: PaginationStrategy::Offset {
+                    offset_parameter: "offset".to_string(),
+                    limit_parameter: "limit".to_string(),
+                    limit: 2,
+                },
+                max_pages: 10,
+            }),
+            data_dir: Some(data_dir.clone()),
+            ..Default::default()
+        },
+        5,
+        &HTTP_PULL_SOURCE_TAGS,
+    )
+    .await;
+
+    let items: Vec<_> = events
+        .into_iter()
+        .map(|event| event.into_log().get("item").unwrap().as_integer().unwrap())
+        .collect();
+    assert_eq!(items, vec![1, 2, 3, 4, 5]);
+
+    wait_for_checkpoint(&data_dir, |checkpoint| checkpoint.contains(r#""offset":6"#)).await;
+}
+
+/// A scrape outlasting the interval should not be overlapped by the following one, which would
+/// request the pages it has not finished with yet.
+#[tokio::test]
+async fn paginated_scrape_outlasting_interval() {
+    let in_addr = next_addr();
+
+    let dummy_endpoint = warp::path!("endpoint")
+        .and(warp::query::<HashMap<String, String>>())
+        .and_then(|query: HashMap<String, String>| async move {
+            tokio::time::sleep(Duration::from_millis(300)).await;
+            let offset: usize = query["offset"].parse().unwrap();
+            let body = if offset < 4 {
+                format!(r#"{{"item": {}}}"#, offset)
+            } else {
+                String::new()
+            };
+            Ok::<_, warp::Rejection>(body)
+        });
+
+    tokio::spawn(warp::serve(dummy_endpoint).run(in_addr));
+    wait_for_tcp(in_addr).await;
+
+    let events = run_and_assert_source_compliance(
+        HttpClientConfig {
+            endpoint: format!("http://{}/endpoint", in_addr),
+            interval: Duration::from_millis(500),
+            timeout: TIMEOUT,
+            decoding: DeserializerConfig::Json(Default::default()),
+            framing: FramingConfig::NewlineDelimited(Default::default()),
+            pagination: Some(PaginationConfig {
+                strategy: PaginationStrategy::Offset {
+                    offset_parameter: "offset".to_string(),
+                    limit_parameter: "limit".to_string(),
+                    limit: 1,
+                },
+                max_pages: 10,
+            }),
+            data_dir: Some(temp_dir()),
+            ..Default::default()
+        },
+        Duration::from_secs(3),
+        &HTTP_PULL_SOURCE_TAGS,
+    )
+    .await;
+
+    let items: Vec<_> = events
+        .into_iter()
+        .map(|event| event.into_log().get("item").unwrap().as_integer().unwrap())
+        .collect();
+    assert_eq!(items, vec![0, 1, 2, 3]);
+}
+
+/// A run whose pages take several scrapes because of `max_pages` should render the same last run
+/// in all of them, and only move it forward once its last page has been received.
+#[tokio::test]
+async fn last_run_advanced_once_all_pages_received() {
+    let in_addr = next_addr();
+
+    let dummy_endpoint = warp::path!("endpoint")
+        .and(warp::query::<HashMap<String, String>>())
+        .map(|query: HashMap<String, String>| {
+            let offset: usize = query["offset"].parse().unwrap();
+            if offset < 3 {
+                format!(r#"{{"item": {}, "since": "{}"}}"#, offset, query["since"])
+            } else {
+                String::new()
+            }
+        });
+
+    tokio::spawn(warp::serve(dummy_endpoint).run(in_addr));
+    wait_for_tcp(in_addr).await;
+
+    let data_dir = temp_dir();
+    std::fs::create_dir_all(data_dir.join("default")).unwrap();
+    std::fs::write(
+        data_dir.join("default/checkpoint.json"),
+        r#"{"version":"1","last_run":"2024-01-01T00:00:00Z"}"#,
+    )
+    .unwrap();
+
+    let events = run_and_assert_source_compliance(
+        HttpClientConfig {
+            endpoint: format!("http://{}/endpoint", in_addr),
+            interval: Duration::from_millis(200),
+            timeout: TIMEOUT,
+            query: HashMap::from([("since".to_string(), vec!["{{ last_run }}".to_string()])]),
+            decoding: DeserializerConfig::Json(Default::default()),
+            framing: FramingConfig::NewlineDelimited(Default::default()),
+            pagination: Some(PaginationConfig {
+                strategy: PaginationStrategy::Offset {
+                    offset_parameter: "offset".to_string(),
+                    limit_parameter: "limit".to_string(),
+                    limit: 1,
+                },
+                max_pages: 1,
+            }),
+            data_dir: Some(data_dir.clone()),
+            ..Default::default()
+        },
+        Duration::from_secs(2),
+        &HTTP_PULL_SOURCE_TAGS,
+    )
+    .await;
+
+    let items: Vec<_> = events
+        .into_iter()
+        .map(|event| {
+            let log = event.into_log();
+            assert_eq!(log["since"], "2024-01-01T00:00:00Z".into());
+            log.get("item").unwrap().as_integer().unwrap()
+        })
+        .collect();
+    assert_eq!(items, vec![0, 1, 2]);
+
+    wait_for_checkpoint(&data_dir, |checkpoint| {
+        checkpoint.contains(r#""offset":3"#) && !checkpoint.contains("2024-01-01T00:00:00Z")
+    })
+    .await;
+}
+
+/// The request body should be sent, and the references to the last run in the body and query
+/// rendered, starting from the last run saved in the checkpoint.
+#[tokio::test]
+async fn request_body_and_last_run_applied() {
+    let in_addr = next_addr();
+
+    let dummy_endpoint = warp::path!("endpoint")
+        .and(warp::post())
+        .and(warp::query::<HashMap<String, String>>())
+        .and(warp::body::bytes())
+        .map(|query: HashMap<String, String>, body: bytes::Bytes| {
+            let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+            format!(
+                r#"{{"query_since": "{}", "body_since": {}}}"#,
+                query["since"], body["since"]
+            )
+        });
+
+    tokio::spawn(warp::serve(dummy_endpoint).run(in_addr));
+    wait_for_tcp(in_addr).await;
+
+    let data_dir = temp_dir();
+    std::fs::create_dir_all(data_dir.join("default")).unwrap();
+    std::fs::write(
+        data_dir.join("default/checkpoint.json"),
+        r#"{"version":"1","last_run":"2024-01-01T00:00:00Z"}"#,
+    )
+    .unwrap();
+
+    let events = run_compliance(HttpClientConfig {
+        endpoint: format!("http://{}/endpoint", in_addr),
+        interval: INTERVAL,
+        timeout: TIMEOUT,
+        query: HashMap::from([("since".to_string(), vec!["{{ last_run }}".to_string()])]),
+        decoding: DeserializerConfig::Json(Default::default()),
+        method: HttpMethod::Post,
+        body: Some(r#"{"since": "{{last_run}}"}"#.to_string()),
+        data_dir: Some(data_dir.clone()),
+        ..Default::default()
+    })
+    .await;
+
+    let since: Vec<_> = events
+        .into_iter()
+        .map(|event| {
+            let log = event.into_log();
+            let query_since = log.get("query_since").unwrap().to_string_lossy();
+            let body_since = log.get("body_since").unwrap().to_string_lossy();
+            assert_eq!(query_since, body_since);
+            chrono::DateTime::parse_from_rfc3339(&query_since).expect("a valid timestamp");
+            query_since.into_owned()
+        })
+        .collect();
+    assert_eq!(since[0], "2024-01-01T00:00:00Z");
+
+    wait_for_checkpoint(&data_dir, |checkpoint| {
+        checkpoint.contains(r#""last_run":"#) && !checkpoint.contains("2024-01-01T00:00:00Z")
     })
     .await;
 }
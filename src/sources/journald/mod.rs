@@ -58,6 +58,10 @@ use crate::{
     SourceSender,
 };
 
+mod native;
+
+use native::StartNativeReader;
+
 const BATCH_TIMEOUT: Duration = Duration::from_millis(10);
 
 const CHECKPOINT_FILENAME: &str = "checkpoint.txt";
@@ -159,7 +163,8 @@ pub struct JournaldConfig {
 
     /// A list of extra command line arguments to pass to `journalctl`.
     ///
-    /// If specified, it is merged to the command line arguments as-is.
+    /// If specified, it is merged to the command line arguments as-is. This is ignored by the
+    /// `native` reader.
     #[serde(default)]
     #[configurable(metadata(docs::examples = "--merge"))]
     pub extra_args: Vec<String>,
@@ -171,6 +176,10 @@ pub struct JournaldConfig {
     #[configurable(metadata(docs::type_unit = "events"))]
     pub batch_size: usize,
 
+    #[configurable(derived)]
+    #[serde(default, skip_serializing_if = "crate::serde::is_default")]
+    pub reader: JournaldReader,
+
     /// The full path of the `journalctl` executable.
     ///
     /// If not set, a search is done for the `journalctl` path.
@@ -179,14 +188,17 @@ pub struct JournaldConfig {
 
     /// The full path of the journal directory.
     ///
-    /// If not set, `journalctl` uses the default system journal path.
+    /// If not set, `journalctl` uses the default system journal path. The `native` reader reads
+    /// the files of the default journal directories, `/var/log/journal` and `/run/log/journal`.
     #[serde(default)]
     pub journal_directory: Option<PathBuf>,
 
     /// The [journal namespace][journal-namespace].
     ///
     /// This value is passed to `journalctl` through the [`--namespace` option][journalctl-namespace-option].
-    /// If not set, `journalctl` uses the default namespace.
+    /// If not set, `journalctl` uses the default namespace. The `native` reader reads the journal
+    /// files of the namespace from the default journal directories, unless `journal_directory` is
+    /// set.
     ///
     /// [journal-namespace]: https://www.freedesktop.org/software/systemd/man/systemd-journald.service.html#Journal%20Namespaces
     /// [journalctl-namespace-option]: https://www.freedesktop.org/software/systemd/man/journalctl.html#--namespace=NAMESPACE
@@ -223,6 +235,23 @@ const fn default_batch_size() -> usize {
     16
 }
 
+/// The method used to read the journal.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum JournaldReader {
+    /// Run `journalctl`, and read the entries from its output.
+    #[default]
+    Journalctl,
+
+    /// Read the journal files directly, without running `journalctl`.
+    ///
+    /// The journal files are checked for new entries, and the journal directories for newly
+    /// rotated files, every 250 milliseconds. Journal data compressed with XZ, which `journald`
+    /// no longer uses by default, is not supported.
+    Native,
+}
+
 fn matches_examples() -> HashMap<String, Vec<String>> {
     HashMap::<_, _>::from_iter([
         (
@@ -309,6 +338,7 @@ impl Default for JournaldConfig {
             exclude_matches: Default::default(),
             data_dir: None,
             batch_size: default_batch_size(),
+            reader: Default::default(),
             journalctl_path: None,
             journal_directory: None,
             journal_namespace: None,
@@ -357,19 +387,29 @@ impl SourceConfig for JournaldConfig {
         let mut checkpoint_path = data_dir;
         checkpoint_path.push(CHECKPOINT_FILENAME);
 
-        let journalctl_path = self
-            .journalctl_path
-            .clone()
-            .unwrap_or_else(|| JOURNALCTL.clone());
-
-        let starter = StartJournalctl::new(
-            journalctl_path,
-            self.journal_directory.clone(),
-            self.journal_namespace.clone(),
-            self.current_boot_only,
-            self.since_now,
-            self.extra_args.clone(),
-        );
+        let starter = match self.reader {
+            JournaldReader::Journalctl => {
+                let journalctl_path = self
+                    .journalctl_path
+                    .clone()
+                    .unwrap_or_else(|| JOURNALCTL.clone());
+
+                Starter::Journalctl(StartJournalctl::new(
+                    journalctl_path,
+                    self.journal_directory.clone(),
+                    self.journal_namespace.clone(),
+                    self.current_boot_only,
+                    self.since_now,
+                    self.extra_args.clone(),
+                ))
+            }
+            JournaldReader::Native => Starter::Native(StartNativeReader::new(
+                self.journal_directory.clone(),
+                self.journal_namespace.clone(),
+                self.current_boot_only,
+                self.since_now,
+            )),
+        };
 
         let batch_size = self.batch_size;
         let acknowledgements = cx.do_acknowledgements(self.acknowledgements);
@@ -415,7 +455,7 @@ struct JournaldSource {
     remap_priority: bool,
     out: SourceSender,
     acknowledgements: bool,
-    starter: StartJournalctl,
+    starter: Starter,
     log_namespace: LogNamespace,
     emit_cursor: bool,
 }
@@ -458,9 +498,20 @@ impl JournaldSource {
                 break;
             }
 
-            info!("Starting journalctl.");
             let cursor = checkpointer.lock().await.cursor.clone();
-            match self.starter.start(cursor.as_deref()) {
+            let started = match &mut self.starter {
+                Starter::Journalctl(starter) => {
+                    info!("Starting journalctl.");
+                    starter
+                        .start(cursor.as_deref())
+                        .map(|(stream, running)| (stream, Some(running)))
+                }
+                Starter::Native(starter) => {
+                    info!("Starting to read the journal files.");
+                    Ok((starter.start(cursor.as_deref()), None))
+                }
+            };
+            match started {
                 Ok((stream, running)) => {
                     if !self.run_stream(stream, &finalizer, shutdown.clone()).await {
                         return;
@@ -473,7 +524,7 @@ impl JournaldSource {
                 }
             }
 
-            // journalctl process should never stop,
+            // journalctl process and the journal files reader should never stop,
             // so it is an error if we reach here.
             tokio::select! {
                 _ = &mut shutdown => break,
@@ -642,6 +693,12 @@ impl<'a> Batch<'a> {
 
 type JournalStream = BoxStream<'static, Result<Bytes, BoxedFramingError>>;
 
+/// Starts reading the journal, in the configured way.
+enum Starter {
+    Journalctl(StartJournalctl),
+    Native(StartNativeReader),
+}
+
 struct StartJournalctl {
     path: PathBuf,
     journal_dir: Option<PathBuf>,
@@ -0,0 +1,885 @@
+//! Reads the systemd journal files directly, without running `journalctl`.
+//!
+//! The entries are read from the entry arrays of each journal file, merged by their timestamps,
+//! and encoded like the JSON output of `journalctl`, so that they go through the same decoding
+//! and filtering as the records read from `journalctl`.
+//!
+//! See the [journal file format][format] for the layout of the files.
+//!
+//! [format]: https://systemd.io/JOURNAL_FILE_FORMAT/
+
+use std::{
+    collections::{HashSet, VecDeque},
+    fs::{self, File},
+    io,
+    os::unix::fs::{FileExt, MetadataExt},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use bytes::Bytes;
+use futures::{stream, StreamExt};
+use serde_json::{Map as JsonMap, Value as JsonValue};
+use tokio::sync::mpsc;
+
+use super::{JournalStream, CURSOR, RECEIVED_TIMESTAMP};
+use crate::internal_events::JournaldReadError;
+
+/// How often the journal files are checked for new entries, and the directories for new files.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+const CHANNEL_CAPACITY: usize = 1024;
+
+const BOOT_ID_PATH: &str = "/proc/sys/kernel/random/boot_id";
+const DEFAULT_JOURNAL_DIRECTORIES: [&str; 2] = ["/var/log/journal", "/run/log/journal"];
+const JOURNAL_EXTENSION: &str = "journal";
+
+const HEADER_SIGNATURE: &[u8; 8] = b"LPKSHHRH";
+/// The size of the header up to and including `tail_entry_monotonic`, which all the supported
+/// versions of the format have.
+const HEADER_MIN_SIZE: usize = 208;
+const STATE_ARCHIVED: u8 = 2;
+
+const INCOMPATIBLE_COMPRESSED_XZ: u32 = 1 << 0;
+const INCOMPATIBLE_COMPRESSED_LZ4: u32 = 1 << 1;
+const INCOMPATIBLE_KEYED_HASH: u32 = 1 << 2;
+const INCOMPATIBLE_COMPRESSED_ZSTD: u32 = 1 << 3;
+const INCOMPATIBLE_COMPACT: u32 = 1 << 4;
+const INCOMPATIBLE_SUPPORTED: u32 = INCOMPATIBLE_COMPRESSED_XZ
+    | INCOMPATIBLE_COMPRESSED_LZ4
+    | INCOMPATIBLE_KEYED_HASH
+    | INCOMPATIBLE_COMPRESSED_ZSTD
+    | INCOMPATIBLE_COMPACT;
+
+const OBJECT_HEADER_SIZE: usize = 16;
+const OBJECT_DATA: u8 = 1;
+const OBJECT_ENTRY: u8 = 3;
+const OBJECT_ENTRY_ARRAY: u8 = 6;
+
+const OBJECT_COMPRESSED_XZ: u8 = 1 << 0;
+const OBJECT_COMPRESSED_LZ4: u8 = 1 << 1;
+const OBJECT_COMPRESSED_ZSTD: u8 = 1 << 2;
+const OBJECT_COMPRESSION_MASK: u8 =
+    OBJECT_COMPRESSED_XZ | OBJECT_COMPRESSED_LZ4 | OBJECT_COMPRESSED_ZSTD;
+
+const ENTRY_ITEMS_OFFSET: usize = 64;
+const ENTRY_ARRAY_ITEMS_OFFSET: usize = 24;
+const DATA_PAYLOAD_OFFSET: usize = 64;
+const COMPACT_DATA_PAYLOAD_OFFSET: usize = 72;
+
+pub(super) struct StartNativeReader {
+    journal_dir: Option<PathBuf>,
+    journal_namespace: Option<String>,
+    current_boot_only: bool,
+    since_now: bool,
+}
+
+impl StartNativeReader {
+    pub(super) const fn new(
+        journal_dir: Option<PathBuf>,
+        journal_namespace: Option<String>,
+        current_boot_only: bool,
+        since_now: bool,
+    ) -> Self {
+        Self {
+            journal_dir,
+            journal_namespace,
+            current_boot_only,
+            since_now,
+        }
+    }
+
+    /// Starts reading the journal files after the entry at `checkpoint`, following new entries
+    /// until the returned stream is dropped.
+    pub(super) fn start(&self, checkpoint: Option<&str>) -> JournalStream {
+        let cursor = checkpoint.and_then(|checkpoint| {
+            let cursor = Cursor::parse(checkpoint);
+            if cursor.is_none() {
+                warn!(
+                    message = "Ignoring invalid journal cursor.",
+                    cursor = %checkpoint,
+                );
+            }
+            cursor
+        });
+
+        let boot_id = self.current_boot_only.then(current_boot_id).flatten();
+        let reader = NativeReader {
+            directories: self.directories(),
+            boot_id,
+            since_now: self.since_now && checkpoint.is_none(),
+            cursor,
+            files: Vec::new(),
+            known: HashSet::new(),
+        };
+
+        let (tx, mut rx) = mpsc::channel(CHANNEL_CAPACITY);
+        tokio::task::spawn_blocking(move || reader.run(tx));
+
+        stream::poll_fn(move |cx| rx.poll_recv(cx)).map(Ok).boxed()
+    }
+
+    /// The directories holding the journal files to read.
+    ///
+    /// Like `journalctl`, the files of the default journal directories are stored in a
+    /// subdirectory named after the machine ID, suffixed with the namespace if any.
+    fn directories(&self) -> Vec<PathBuf> {
+        if let Some(dir) = &self.journal_dir {
+            return vec![dir.clone()];
+        }
+
+        let suffix = self
+            .journal_namespace
+            .as_ref()
+            .map(|namespace| format!(".{namespace}"));
+        DEFAULT_JOURNAL_DIRECTORIES
+            .iter()
+            .filter_map(|dir| fs::read_dir(dir).ok())
+            .flatten()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| {
+                let name = entry.file_name();
+                let name = name.to_string_lossy();
+                match &suffix {
+                    Some(suffix) => name.ends_with(suffix.as_str()),
+                    None => !name.contains('.'),
+                }
+            })
+            .map(|entry| entry.path())
+            .collect()
+    }
+}
+
+/// Reads the boot ID of the running system, to only read the entries logged since it booted.
+fn current_boot_id() -> Option<[u8; 16]> {
+    let boot_id = fs::read_to_string(BOOT_ID_PATH)
+        .ok()
+        .and_then(|boot_id| parse_id128(&boot_id.trim().replace('-', "")));
+    if boot_id.is_none() {
+        warn!(
+            message = "Could not read the current boot ID, reading the entries of all boots.",
+            path = %BOOT_ID_PATH,
+        );
+    }
+    boot_id
+}
+
+fn parse_id128(hex: &str) -> Option<[u8; 16]> {
+    (hex.len() == 32)
+        .then(|| u128::from_str_radix(hex, 16).ok())
+        .flatten()
+        .map(u128::to_be_bytes)
+}
+
+fn format_id128(id: &[u8; 16]) -> String {
+    format!("{:032x}", u128::from_be_bytes(*id))
+}
+
+/// The position of an entry, in the format of the cursors of `journalctl`.
+struct Cursor {
+    seqnum_id: [u8; 16],
+    seqnum: u64,
+    realtime: u64,
+}
+
+impl Cursor {
+    fn parse(cursor: &str) -> Option<Self> {
+        let (mut seqnum_id, mut seqnum, mut realtime) = (None, None, None);
+        for field in cursor.split(';') {
+            match field.split_once('=')? {
+                ("s", value) => seqnum_id = parse_id128(value),
+                ("i", value) => seqnum = u64::from_str_radix(value, 16).ok(),
+                ("t", value) => realtime = u64::from_str_radix(value, 16).ok(),
+                _ => {}
+            }
+        }
+        Some(Self {
+            seqnum_id: seqnum_id?,
+            seqnum: seqnum?,
+            realtime: realtime?,
+        })
+    }
+
+    /// Whether the entry comes after the entry at this cursor.
+    ///
+    /// Entries with the same sequence number ID are compared by their sequence number, and other
+    /// entries by their timestamp.
+    fn precedes(&self, seqnum_id: &[u8; 16], entry: &Entry) -> bool {
+        if *seqnum_id == self.seqnum_id {
+            entry.seqnum > self.seqnum
+        } else {
+            entry.realtime > self.realtime
+        }
+    }
+}
+
+struct NativeReader {
+    directories: Vec<PathBuf>,
+    boot_id: Option<[u8; 16]>,
+    since_now: bool,
+    cursor: Option<Cursor>,
+    files: Vec<JournalFile>,
+    /// The device and inode numbers of the journal files found in the directories, so that each
+    /// file is only opened once, including after it is renamed on rotation.
+    known: HashSet<(u64, u64)>,
+}
+
+impl NativeReader {
+    fn run(mut self, tx: mpsc::Sender<Bytes>) {
+        let mut skip_existing = self.since_now;
+        while !tx.is_closed() {
+            self.scan(skip_existing);
+            skip_existing = false;
+
+            self.files.retain_mut(|file| match file.refresh() {
+                Ok(()) => true,
+                Err(error) => {
+                    report(error, &file.path);
+                    false
+                }
+            });
+
+            while let Some((index, entry)) = self.next_entry() {
+                let file = &self.files[index];
+                if self.boot_id.is_some_and(|boot_id| entry.boot_id != boot_id)
+                    || self
+                        .cursor
+                        .as_ref()
+                        .is_some_and(|cursor| !cursor.precedes(&file.header.seqnum_id, &entry))
+                {
+                    continue;
+                }
+
+                match file.read_record(&entry) {
+                    Ok(record) => {
+                        if tx.blocking_send(record).is_err() {
+                            return;
+                        }
+                    }
+                    Err(error) => report(error, &file.path),
+                }
+            }
+
+            // Rotated files are archived, and no longer written to.
+            self.files.retain(|file| !file.is_exhausted());
+
+            std::thread::sleep(POLL_INTERVAL);
+        }
+    }
+
+    /// Opens the journal files added to the directories since the last scan.
+    fn scan(&mut self, skip_existing: bool) {
+        let mut found = HashSet::new();
+        for dir in &self.directories {
+            let entries = match fs::read_dir(dir) {
+                Ok(entries) => entries,
+                Err(error) if error.kind() == io::ErrorKind::NotFound => continue,
+                Err(error) => {
+                    report(error, dir);
+                    continue;
+                }
+            };
+
+            for entry in entries.filter_map(|entry| entry.ok()) {
+                let path = entry.path();
+                if path
+                    .extension()
+                    .map_or(true, |ext| ext != JOURNAL_EXTENSION)
+                {
+                    continue;
+                }
+                let Ok(metadata) = entry.metadata() else {
+                    continue;
+                };
+                let id = (metadata.dev(), metadata.ino());
+                if !metadata.is_file() || self.known.contains(&id) {
+                    found.insert(id);
+                    continue;
+                }
+
+                match JournalFile::open(path) {
+                    Ok(mut file) => {
+                        if skip_existing {
+                            file.skip_to_end();
+                        }
+                        debug!(message = "Reading journal file.", path = ?file.path);
+                        self.files.push(file);
+                        found.insert(id);
+                    }
+                    // The file is still being created, try again on the next scan.
+                    Err(error) if error.kind() == io::ErrorKind::UnexpectedEof => {}
+                    Err(error) => {
+                        report(error, &entry.path());
+                        found.insert(id);
+                    }
+                }
+            }
+        }
+
+        // Forgetting the removed files lets their inode numbers be reused by new files.
+        self.known = found;
+    }
+
+    /// Takes the earliest entry of all the files, returning the index of its file.
+    fn next_entry(&mut self) -> Option<(usize, Entry)> {
+        let mut next: Option<(usize, (u64, u64))> = None;
+        let mut index = 0;
+        while index < self.files.len() {
+            match self.files[index].peek() {
+                Ok(Some(entry)) => {
+                    let order = (entry.realtime, entry.seqnum);
+                    if next.map_or(true, |(_, next_order)| order < next_order) {
+                        next = Some((index, order));
+                    }
+                    index += 1;
+                }
+                Ok(None) => index += 1,
+                Err(error) => {
+                    // The files before it keep their index.
+                    let file = self.files.remove(index);
+                    report(error, &file.path);
+                }
+            }
+        }
+
+        next.and_then(|(index, _)| Some((index, self.files[index].head.take()?)))
+    }
+}
+
+fn report(error: io::Error, path: &Path) {
+    let error = io::Error::new(error.kind(), format!("{}: {error}", path.display()));
+    emit!(JournaldReadError {
+        error: Box::new(error),
+    });
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn read_u32(buf: &[u8], at: usize) -> u32 {
+    u32::from_le_bytes(buf[at..at + 4].try_into().expect("slice of 4 bytes"))
+}
+
+fn read_u64(buf: &[u8], at: usize) -> u64 {
+    u64::from_le_bytes(buf[at..at + 8].try_into().expect("slice of 8 bytes"))
+}
+
+fn read_id128(buf: &[u8], at: usize) -> [u8; 16] {
+    buf[at..at + 16].try_into().expect("slice of 16 bytes")
+}
+
+struct Header {
+    incompatible_flags: u32,
+    state: u8,
+    seqnum_id: [u8; 16],
+    header_size: u64,
+    arena_size: u64,
+    n_entries: u64,
+    entry_array_offset: u64,
+}
+
+impl Header {
+    fn parse(buf: &[u8]) -> io::Result<Self> {
+        if &buf[..8] != HEADER_SIGNATURE {
+            return Err(invalid_data("Not a journal file.".into()));
+        }
+
+        let header = Self {
+            incompatible_flags: read_u32(buf, 12),
+            state: buf[16],
+            seqnum_id: read_id128(buf, 72),
+            header_size: read_u64(buf, 88),
+            arena_size: read_u64(buf, 96),
+            n_entries: read_u64(buf, 152),
+            entry_array_offset: read_u64(buf, 176),
+        };
+        if header.incompatible_flags & !INCOMPATIBLE_SUPPORTED != 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!(
+                    "Unsupported journal file features: {:#x}.",
+                    header.incompatible_flags & !INCOMPATIBLE_SUPPORTED
+                ),
+            ));
+        }
+        Ok(header)
+    }
+
+    const fn is_compact(&self) -> bool {
+        self.incompatible_flags & INCOMPATIBLE_COMPACT != 0
+    }
+}
+
+/// The fixed part of an entry object.
+struct Entry {
+    seqnum: u64,
+    realtime: u64,
+    monotonic: u64,
+    boot_id: [u8; 16],
+    xor_hash: u64,
+    /// The offsets of the data objects holding the fields of the entry.
+    data: Vec<u64>,
+}
+
+struct JournalFile {
+    path: PathBuf,
+    file: File,
+    header: Header,
+    /// The entry array, and the index in that array, where the next entries are listed.
+    position: Option<(u64, usize)>,
+    listed: u64,
+    pending: VecDeque<u64>,
+    head: Option<Entry>,
+}
+
+impl JournalFile {
+    fn open(path: PathBuf) -> io::Result<Self> {
+        let file = File::open(&path)?;
+        let header = read_header(&file)?;
+        let mut file = Self {
+            path,
+            file,
+            header,
+            position: None,
+            listed: 0,
+            pending: VecDeque::new(),
+            head: None,
+        };
+        file.list_entries()?;
+        Ok(file)
+    }
+
+    /// Re-reads the header and lists the entries added since the last refresh.
+    fn refresh(&mut self) -> io::Result<()> {
+        self.header = read_header(&self.file)?;
+        self.list_entries()
+    }
+
+    fn skip_to_end(&mut self) {
+        self.pending.clear();
+        self.head = None;
+    }
+
+    /// Whether the file is archived, and all of its entries were read.
+    fn is_exhausted(&self) -> bool {
+        self.header.state == STATE_ARCHIVED
+            && self.listed >= self.header.n_entries
+            && self.pending.is_empty()
+            && self.head.is_none()
+    }
+
+    fn peek(&mut self) -> io::Result<Option<&Entry>> {
+        if self.head.is_none() {
+            if let Some(offset) = self.pending.pop_front() {
+                self.head = Some(self.read_entry(offset)?);
+            }
+        }
+        Ok(self.head.as_ref())
+    }
+
+    fn read_at(&self, offset: u64, len: usize) -> io::Result<Vec<u8>> {
+        let mut buf = vec![0; len];
+        self.file.read_exact_at(&mut buf, offset)?;
+        Ok(buf)
+    }
+
+    /// Reads the object at `offset`, checking that it has the expected type and lies within the
+    /// arena of the file.
+    fn read_object(&self, offset: u64, object_type: u8, len: Option<usize>) -> io::Result<Vec<u8>> {
+        let header = self.read_at(offset, OBJECT_HEADER_SIZE)?;
+        let size = read_u64(&header, 8);
+        let arena_end = self.header.header_size + self.header.arena_size;
+        if header[0] != object_type
+            || size < OBJECT_HEADER_SIZE as u64
+            || offset.checked_add(size).map_or(true, |end| end > arena_end)
+        {
+            return Err(invalid_data(format!(
+                "Invalid object at offset {offset}, expected type {object_type}."
+            )));
+        }
+
+        let len = len.map_or(size as usize, |len| len.min(size as usize));
+        self.read_at(offset, len)
+    }
+
+    /// Lists the offsets of the entries added to the entry arrays.
+    fn list_entries(&mut self) -> io::Result<()> {
+        if self.position.is_none() && self.header.entry_array_offset != 0 {
+            self.position = Some((self.header.entry_array_offset, 0));
+        }
+        let item_size = if self.header.is_compact() { 4 } else { 8 };
+
+        while self.listed < self.header.n_entries {
+            let Some((offset, index)) = self.position else {
+                break;
+            };
+
+            let array =
+                self.read_object(offset, OBJECT_ENTRY_ARRAY, Some(ENTRY_ARRAY_ITEMS_OFFSET))?;
+            let capacity =
+                (read_u64(&array, 8) as usize).saturating_sub(ENTRY_ARRAY_ITEMS_OFFSET) / item_size;
+            let next_array = read_u64(&array, 16);
+
+            if index < capacity {
+                let items = self.read_at(
+                    offset + (ENTRY_ARRAY_ITEMS_OFFSET + index * item_size) as u64,
+                    (capacity - index) * item_size,
+                )?;
+                let mut index = index;
+                for item in items.chunks_exact(item_size) {
+                    let entry = match item_size {
+                        4 => read_u32(item, 0) as u64,
+                        _ => read_u64(item, 0),
+                    };
+                    if entry == 0 || self.listed >= self.header.n_entries {
+                        break;
+                    }
+                    self.pending.push_back(entry);
+                    self.listed += 1;
+                    index += 1;
+                }
+                self.position = Some((offset, index));
+                if index < capacity {
+                    break;
+                }
+            }
+
+            // The array is full, continue with the next one once it is linked.
+            if next_array == 0 {
+                break;
+            }
+            self.position = Some((next_array, 0));
+        }
+
+        Ok(())
+    }
+
+    fn read_entry(&self, offset: u64) -> io::Result<Entry> {
+        let object = self.read_object(offset, OBJECT_ENTRY, None)?;
+        if object.len() < ENTRY_ITEMS_OFFSET {
+            return Err(invalid_data(format!("Truncated entry at offset {offset}.")));
+        }
+
+        let items = &object[ENTRY_ITEMS_OFFSET..];
+        let data = if self.header.is_compact() {
+            items
+                .chunks_exact(4)
+                .map(|item| read_u32(item, 0) as u64)
+                .collect()
+        } else {
+            // Regular items also hold the hash of the data object.
+            items
+                .chunks_exact(16)
+                .map(|item| read_u64(item, 0))
+                .collect()
+        };
+
+        Ok(Entry {
+            seqnum: read_u64(&object, 16),
+            realtime: read_u64(&object, 24),
+            monotonic: read_u64(&object, 32),
+            boot_id: read_id128(&object, 40),
+            xor_hash: read_u64(&object, 56),
+            data,
+        })
+    }
+
+    /// Reads the payload of the data object at `offset`, which is a `FIELD=value` pair.
+    fn read_data(&self, offset: u64) -> io::Result<Vec<u8>> {
+        let object = self.read_object(offset, OBJECT_DATA, None)?;
+        let payload_offset = if self.header.is_compact() {
+            COMPACT_DATA_PAYLOAD_OFFSET
+        } else {
+            DATA_PAYLOAD_OFFSET
+        };
+        let payload = object
+            .get(payload_offset..)
+            .ok_or_else(|| invalid_data(format!("Truncated data object at offset {offset}.")))?;
+
+        match object[1] & OBJECT_COMPRESSION_MASK {
+            0 => Ok(payload.to_vec()),
+            OBJECT_COMPRESSED_ZSTD => zstd::stream::decode_all(payload),
+            OBJECT_COMPRESSED_LZ4 => {
+                // The payload is prefixed with its uncompressed size.
+                let size = payload
+                    .get(..8)
+                    .and_then(|size| i32::try_from(read_u64(size, 0)).ok())
+                    .ok_or_else(|| {
+                        invalid_data(format!("Invalid LZ4 data object at offset {offset}."))
+                    })?;
+                lz4::block::decompress(&payload[8..], Some(size))
+            }
+            OBJECT_COMPRESSED_XZ => Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "XZ compressed journal data is not supported.",
+            )),
+            flags => Err(invalid_data(format!(
+                "Invalid compression flags {flags:#x} of data object at offset {offset}."
+            ))),
+        }
+    }
+
+    /// Encodes the entry like the JSON output of `journalctl`.
+    fn read_record(&self, entry: &Entry) -> io::Result<Bytes> {
+        let mut record = JsonMap::new();
+        for offset in &entry.data {
+            let data = self.read_data(*offset)?;
+            let Some(separator) = data.iter().position(|&byte| byte == b'=') else {
+                continue;
+            };
+            let (field, value) = (&data[..separator], &data[separator + 1..]);
+            // Like `journalctl`, values that aren't valid UTF-8 are encoded as arrays of bytes.
+            let value = match std::str::from_utf8(value) {
+                Ok(value) => JsonValue::from(value),
+                Err(_) => JsonValue::from(value.to_vec()),
+            };
+            record
+                .entry(String::from_utf8_lossy(field))
+                .or_insert(value);
+        }
+
+        record.insert(
+            CURSOR.into(),
+            format!(
+                "s={};i={:x};b={};m={:x};t={:x};x={:x}",
+                format_id128(&self.header.seqnum_id),
+                entry.seqnum,
+                format_id128(&entry.boot_id),
+                entry.monotonic,
+                entry.realtime,
+                entry.xor_hash
+            )
+            .into(),
+        );
+        record.insert(RECEIVED_TIMESTAMP.into(), entry.realtime.to_string().into());
+        record.insert(
+            "__MONOTONIC_TIMESTAMP".into(),
+            entry.monotonic.to_string().into(),
+        );
+        record
+            .entry("_BOOT_ID")
+            .or_insert_with(|| format_id128(&entry.boot_id).into());
+
+        serde_json::to_vec(&record)
+            .map(Bytes::from)
+            .map_err(io::Error::from)
+    }
+}
+
+fn read_header(file: &File) -> io::Result<Header> {
+    let mut buf = [0; HEADER_MIN_SIZE];
+    file.read_exact_at(&mut buf, 0)?;
+    Header::parse(&buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::tempdir;
+    use tokio::time::timeout;
+
+    use super::*;
+
+    const TEST_SEQNUM_ID: [u8; 16] = [7; 16];
+    const TEST_BOOT_ID: [u8; 16] = [9; 16];
+    const TEST_HEADER_SIZE: u64 = 256;
+    const TEST_ARRAY_CAPACITY: usize = 4;
+
+    /// Writes a regular, uncompressed journal file holding the given entries, with their fields
+    /// in `FIELD=value` form.
+    ///
+    /// The entries are listed in a single entry array, and the file can be rewritten with more
+    /// entries while being read, as the objects already written keep their offset.
+    fn write_journal(path: &Path, file_id: u8, state: u8, entries: &[(u64, &[&str])]) {
+        assert!(entries.len() <= TEST_ARRAY_CAPACITY);
+
+        let mut arena = Vec::new();
+        let array_size = ENTRY_ARRAY_ITEMS_OFFSET + TEST_ARRAY_CAPACITY * 8;
+        let mut array = object_header(OBJECT_ENTRY_ARRAY, array_size);
+        array.resize(array_size, 0);
+        arena.extend(array);
+
+        let offset = |arena: &Vec<u8>| TEST_HEADER_SIZE + arena.len() as u64;
+        for (index, (seqnum, fields)) in entries.iter().enumerate() {
+            let mut data_offsets = Vec::new();
+            for field in *fields {
+                data_offsets.push(offset(&arena));
+                let size = DATA_PAYLOAD_OFFSET + field.len();
+                arena.extend(object_header(OBJECT_DATA, size));
+                arena.resize(arena.len() + DATA_PAYLOAD_OFFSET - OBJECT_HEADER_SIZE, 0);
+                arena.extend(field.as_bytes());
+                arena.resize(arena.len().next_multiple_of(8), 0);
+            }
+
+            let entry_offset = offset(&arena);
+            arena.extend(object_header(
+                OBJECT_ENTRY,
+                ENTRY_ITEMS_OFFSET + data_offsets.len() * 16,
+            ));
+            arena.extend(seqnum.to_le_bytes());
+            arena.extend((1_578_529_839_000_000 + seqnum).to_le_bytes());
+            arena.extend(seqnum.to_le_bytes());
+            arena.extend(TEST_BOOT_ID);
+            arena.extend(0u64.to_le_bytes());
+            for data_offset in data_offsets {
+                arena.extend(data_offset.to_le_bytes());
+                arena.extend(0u64.to_le_bytes());
+            }
+
+            let item = ENTRY_ARRAY_ITEMS_OFFSET + index * 8;
+            arena[item..item + 8].copy_from_slice(&entry_offset.to_le_bytes());
+        }
+
+        let mut header = vec![0; TEST_HEADER_SIZE as usize];
+        header[..8].copy_from_slice(HEADER_SIGNATURE);
+        header[16] = state;
+        header[24..40].copy_from_slice(&[file_id; 16]);
+        header[72..88].copy_from_slice(&TEST_SEQNUM_ID);
+        header[88..96].copy_from_slice(&TEST_HEADER_SIZE.to_le_bytes());
+        header[96..104].copy_from_slice(&(arena.len() as u64).to_le_bytes());
+        header[152..160].copy_from_slice(&(entries.len() as u64).to_le_bytes());
+        header[176..184].copy_from_slice(&TEST_HEADER_SIZE.to_le_bytes());
+
+        // Write the objects before the header that lists them.
+        let file = fs::OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(path)
+            .unwrap();
+        file.write_all_at(&arena, TEST_HEADER_SIZE).unwrap();
+        file.write_all_at(&header, 0).unwrap();
+    }
+
+    fn object_header(object_type: u8, size: usize) -> Vec<u8> {
+        let mut header = vec![object_type, 0, 0, 0, 0, 0, 0, 0];
+        header.extend((size as u64).to_le_bytes());
+        header
+    }
+
+    async fn next_record(stream: &mut JournalStream) -> JsonMap<String, JsonValue> {
+        let record = timeout(Duration::from_secs(5), stream.next())
+            .await
+            .expect("No record read")
+            .unwrap()
+            .unwrap();
+        serde_json::from_slice(&record).unwrap()
+    }
+
+    fn reader(dir: &Path, since_now: bool) -> StartNativeReader {
+        StartNativeReader::new(Some(dir.to_path_buf()), None, false, since_now)
+    }
+
+    #[tokio::test]
+    async fn reads_and_follows_journal() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("system.journal");
+        let first: &[&str] = &["MESSAGE=first", "_SYSTEMD_UNIT=unit.service"];
+        write_journal(&path, 1, 1, &[(1, first)]);
+
+        let mut stream = reader(dir.path(), false).start(None);
+
+        let record = next_record(&mut stream).await;
+        assert_eq!(record["MESSAGE"], "first");
+        assert_eq!(record["_SYSTEMD_UNIT"], "unit.service");
+        assert_eq!(record["__REALTIME_TIMESTAMP"], "1578529839000001");
+        assert_eq!(record["_BOOT_ID"], format_id128(&TEST_BOOT_ID));
+        let cursor = record["__CURSOR"].as_str().unwrap();
+        assert!(cursor.starts_with(&format!("s={};i=1;", format_id128(&TEST_SEQNUM_ID))));
+
+        // New entries are followed.
+        write_journal(&path, 1, 1, &[(1, first), (2, &["MESSAGE=second"])]);
+        assert_eq!(next_record(&mut stream).await["MESSAGE"], "second");
+
+        // Rotated files are archived, and the new entries go to a new file.
+        let archived = dir.path().join("system@0000.journal");
+        fs::rename(&path, &archived).unwrap();
+        write_journal(
+            &archived,
+            1,
+            STATE_ARCHIVED,
+            &[(1, first), (2, &["MESSAGE=second"])],
+        );
+        write_journal(&path, 2, 1, &[(3, &["MESSAGE=third"])]);
+        assert_eq!(next_record(&mut stream).await["MESSAGE"], "third");
+    }
+
+    #[tokio::test]
+    async fn merges_journal_files() {
+        let dir = tempdir().unwrap();
+        write_journal(
+            &dir.path().join("system.journal"),
+            1,
+            1,
+            &[(1, &["MESSAGE=1"]), (3, &["MESSAGE=3"])],
+        );
+        write_journal(
+            &dir.path().join("user-1000.journal"),
+            2,
+            1,
+            &[
+                (2, &["MESSAGE=2"]),
+                (4, &["MESSAGE=4", "MESSAGE=duplicate"]),
+            ],
+        );
+
+        let mut stream = reader(dir.path(), false).start(None);
+
+        for message in ["1", "2", "3", "4"] {
+            assert_eq!(next_record(&mut stream).await["MESSAGE"], message);
+        }
+    }
+
+    #[tokio::test]
+    async fn starts_after_cursor() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("system.journal");
+        write_journal(
+            &path,
+            1,
+            1,
+            &[
+                (1, &["MESSAGE=1"]),
+                (2, &["MESSAGE=2"]),
+                (3, &["MESSAGE=3"]),
+            ],
+        );
+
+        let mut stream = reader(dir.path(), false).start(None);
+        next_record(&mut stream).await;
+        let cursor = next_record(&mut stream).await["__CURSOR"].clone();
+        drop(stream);
+
+        let mut stream = reader(dir.path(), false).start(cursor.as_str());
+        assert_eq!(next_record(&mut stream).await["MESSAGE"], "3");
+    }
+
+    #[tokio::test]
+    async fn starts_at_end_since_now() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("system.journal");
+        write_journal(&path, 1, 1, &[(1, &["MESSAGE=1"])]);
+
+        let mut stream = reader(dir.path(), true).start(None);
+        tokio::time::sleep(POLL_INTERVAL * 2).await;
+        write_journal(&path, 1, 1, &[(1, &["MESSAGE=1"]), (2, &["MESSAGE=2"])]);
+
+        assert_eq!(next_record(&mut stream).await["MESSAGE"], "2");
+    }
+
+    #[test]
+    fn parses_cursor() {
+        let cursor = Cursor::parse(
+            "s=739ad463348b4ceca5a9e69c95a3c93f;i=4ece7;b=6c7c6013a8674f7e9ad2ff5a1fbd7f6a;m=2c5fbf2f5;t=5b7e6a9de8bb0;x=ed66a4b1e0e8e5a3",
+        )
+        .unwrap();
+        assert_eq!(
+            format_id128(&cursor.seqnum_id),
+            "739ad463348b4ceca5a9e69c95a3c93f"
+        );
+        assert_eq!(cursor.seqnum, 0x4ece7);
+        assert_eq!(cursor.realtime, 0x5b7e6a9de8bb0);
+
+        assert!(Cursor::parse("1").is_none());
+    }
+}
@@ -22,7 +22,7 @@ use rdkafka::{
     error::KafkaError,
     message::{BorrowedMessage, Headers as _, Message},
     types::RDKafkaErrorCode,
-    ClientConfig, ClientContext, Statistics, TopicPartitionList,
+    ClientConfig, ClientContext, Offset, Statistics, TopicPartitionList,
 };
 use serde_with::serde_as;
 use snafu::{ResultExt, Snafu};
@@ -57,12 +57,12 @@ use crate::{
         log_schema, LogSchema, SourceAcknowledgementsConfig, SourceConfig, SourceContext,
         SourceOutput,
     },
-    event::{BatchNotifier, BatchStatus, Event, Value},
+    event::{BatchNotifier, BatchStatus, Event, KafkaOffset, Value},
     internal_events::{
         KafkaBytesReceived, KafkaEventsReceived, KafkaOffsetUpdateError, KafkaReadError,
         StreamClosedError,
     },
-    kafka,
+    kafka::{self, ConsumerRegistration},
     serde::{bool_or_struct, default_decoding, default_framing_message_based},
     shutdown::ShutdownSignal,
     SourceSender,
@@ -107,7 +107,9 @@ pub struct KafkaSourceConfig {
 
     /// The Kafka topics names to read events from.
     ///
-    /// Regular expression syntax is supported if the topic begins with `^`.
+    /// Regular expression syntax is supported if the topic begins with `^`. Topics created after
+    /// the source started that match a regular expression are consumed once discovered, see
+    /// `topic_metadata_refresh_interval_ms`.
     #[configurable(metadata(
         docs::examples = "^(prefix1|prefix2)-.+",
         docs::examples = "topic-1",
@@ -115,6 +117,17 @@ pub struct KafkaSourceConfig {
     ))]
     topics: Vec<String>,
 
+    /// The interval at which the topics and partitions of the cluster are refreshed.
+    ///
+    /// This bounds how long it takes to start consuming a new topic matching a regular expression
+    /// in `topics`. By default, the librdkafka default of five minutes is used.
+    #[serde_as(as = "Option<serde_with::DurationMilliSeconds<u64>>")]
+    #[serde(default)]
+    #[configurable(metadata(docs::examples = 30000, docs::examples = 60000))]
+    #[configurable(metadata(docs::advanced))]
+    #[configurable(metadata(docs::human_name = "Topic Metadata Refresh Interval"))]
+    topic_metadata_refresh_interval_ms: Option<Duration>,
+
     /// The consumer group name to be used to consume events from Kafka.
     #[configurable(metadata(docs::examples = "consumer-group-name"))]
     group_id: String,
@@ -126,6 +139,14 @@ pub struct KafkaSourceConfig {
     #[configurable(metadata(docs::examples = "example_auto_offset_reset_values()"))]
     auto_offset_reset: String,
 
+    /// If offsets for consumer group do not exist, start consuming from the first message at or
+    /// after this timestamp, instead of using `auto_offset_reset`.
+    ///
+    /// Partitions without any message at or after this timestamp are consumed from their end.
+    #[serde(default)]
+    #[configurable(metadata(docs::examples = "2024-11-20T08:00:00Z"))]
+    start_at_timestamp: Option<DateTime<Utc>>,
+
     /// The Kafka session timeout.
     #[serde_as(as = "serde_with::DurationMilliSeconds<u64>")]
     #[configurable(metadata(docs::examples = 5000, docs::examples = 10000))]
@@ -348,6 +369,7 @@ impl SourceConfig for KafkaSourceConfig {
 
         Ok(Box::pin(kafka_source(
             self.clone(),
+            cx.key.id().into(),
             consumer,
             callback_rx,
             decoder,
@@ -423,6 +445,7 @@ impl SourceConfig for KafkaSourceConfig {
 #[allow(clippy::too_many_arguments)]
 async fn kafka_source(
     config: KafkaSourceConfig,
+    source_id: Arc<str>,
     consumer: StreamConsumer<KafkaSourceContext>,
     callback_rx: UnboundedReceiver<KafkaCallback>,
     decoder: Decoder,
@@ -440,6 +463,12 @@ async fn kafka_source(
         .set(Arc::downgrade(&consumer))
         .expect("Error setting up consumer context.");
 
+    // Lets the sinks producing in transactions commit the offsets of the consumed events.
+    let registration = Arc::new(kafka::register_consumer(&source_id, {
+        let consumer = Arc::downgrade(&consumer);
+        move || consumer.upgrade()?.group_metadata()
+    }));
+
     // EOF signal allowing the coordination task to tell the kafka client task when all partitions have reached EOF
     let (eof_tx, eof_rx) = eof.then(oneshot::channel::<()>).unzip();
 
@@ -455,8 +484,14 @@ async fn kafka_source(
         let drain_timeout_ms = config
             .drain_timeout_ms
             .map_or(config.session_timeout_ms / 2, Duration::from_millis);
-        let consumer_state =
-            ConsumerStateInner::<Consuming>::new(config, decoder, out, log_namespace, span);
+        let consumer_state = ConsumerStateInner::<Consuming>::new(
+            config,
+            decoder,
+            out,
+            log_namespace,
+            registration,
+            span,
+        );
         tokio::spawn(async move {
             coordinate_kafka_callbacks(
                 consumer,
@@ -505,6 +540,7 @@ struct ConsumerStateInner<S> {
     decoder: Decoder,
     out: SourceSender,
     log_namespace: LogNamespace,
+    registration: Arc<ConsumerRegistration>,
     consumer_state: S,
 }
 struct Consuming {
@@ -566,6 +602,7 @@ impl ConsumerStateInner<Consuming> {
         decoder: Decoder,
         out: SourceSender,
         log_namespace: LogNamespace,
+        registration: Arc<ConsumerRegistration>,
         span: Span,
     ) -> Self {
         Self {
@@ -573,6 +610,7 @@ impl ConsumerStateInner<Consuming> {
             decoder,
             out,
             log_namespace,
+            registration,
             consumer_state: Consuming { span },
         }
     }
@@ -591,6 +629,7 @@ impl ConsumerStateInner<Consuming> {
         exit_eof: bool,
     ) -> (oneshot::Sender<()>, tokio::task::AbortHandle) {
         let keys = self.config.keys();
+        let registration = Arc::clone(&self.registration);
         let decoder = self.decoder.clone();
         let log_namespace = self.log_namespace;
         let mut out = self.out.clone();
@@ -622,7 +661,9 @@ impl ConsumerStateInner<Consuming> {
 
                     ack = ack_stream.next() => match ack {
                         Some((status, entry)) => {
-                            if status == BatchStatus::Delivered {
+                            // Once a sink commits the offsets in its transactions, storing them
+                            // here would have them committed again outside of transactions.
+                            if status == BatchStatus::Delivered && !registration.is_transactional() {
                                 if let Err(error) =  consumer.store_offset(&entry.topic, entry.partition, entry.offset) {
                                     emit!(KafkaOffsetUpdateError { error });
                                 }
@@ -654,7 +695,7 @@ impl ConsumerStateInner<Consuming> {
                                 topic: msg.topic(),
                                 partition: msg.partition(),
                             });
-                            parse_message(msg, decoder.clone(), &keys, &mut out, acknowledgements, &finalizer, log_namespace).await;
+                            parse_message(msg, decoder.clone(), &keys, registration.source_id(), &mut out, acknowledgements, &finalizer, log_namespace).await;
                         }
                     },
                 )
@@ -679,6 +720,7 @@ impl ConsumerStateInner<Consuming> {
             decoder: self.decoder,
             out: self.out,
             log_namespace: self.log_namespace,
+            registration: self.registration,
             consumer_state: Draining::new(sig, shutdown, self.consumer_state.span),
         };
 
@@ -729,6 +771,7 @@ impl ConsumerStateInner<Draining> {
                     decoder: self.decoder,
                     out: self.out,
                     log_namespace: self.log_namespace,
+                    registration: self.registration,
                     consumer_state: Consuming {
                         span: self.consumer_state.span,
                     },
@@ -951,16 +994,18 @@ fn drive_kafka_consumer(
     });
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn parse_message(
     msg: BorrowedMessage<'_>,
     decoder: Decoder,
     keys: &'_ Keys,
+    source_id: &Arc<str>,
     out: &mut SourceSender,
     acknowledgements: bool,
     finalizer: &Option<OrderedFinalizer<FinalizerEntry>>,
     log_namespace: LogNamespace,
 ) {
-    if let Some((count, stream)) = parse_stream(&msg, decoder, keys, log_namespace) {
+    if let Some((count, stream)) = parse_stream(&msg, decoder, keys, source_id, log_namespace) {
         let (batch, receiver) = BatchNotifier::new_with_receiver();
         let mut stream = stream.map(|event| {
             // All acknowledgements flow through the normal Finalizer stream so
@@ -993,11 +1038,18 @@ fn parse_stream<'a>(
     msg: &BorrowedMessage<'a>,
     decoder: Decoder,
     keys: &'a Keys,
+    source_id: &Arc<str>,
     log_namespace: LogNamespace,
 ) -> Option<(usize, impl Stream<Item = Event> + 'a)> {
     let payload = msg.payload()?; // skip messages with empty payload
 
     let rmsg = ReceivedMessage::from(msg);
+    let kafka_offset = KafkaOffset {
+        source_id: Arc::clone(source_id),
+        topic: msg.topic().into(),
+        partition: msg.partition(),
+        offset: msg.offset(),
+    };
 
     let payload = Cursor::new(Bytes::copy_from_slice(payload));
 
@@ -1015,6 +1067,7 @@ fn parse_stream<'a>(
                     });
                     for mut event in events {
                         rmsg.apply(keys, &mut event, log_namespace);
+                        event.metadata_mut().set_kafka_offset(kafka_offset.clone());
                         yield event;
                     }
                 },
@@ -1222,6 +1275,13 @@ fn create_consumer(
         .set("statistics.interval.ms", "1000")
         .set("client.id", "vector");
 
+    if let Some(interval) = config.topic_metadata_refresh_interval_ms {
+        client_config.set(
+            "topic.metadata.refresh.interval.ms",
+            interval.as_millis().to_string(),
+        );
+    }
+
     config.auth.apply(&mut client_config)?;
 
     if let Some(librdkafka_options) = &config.librdkafka_options {
@@ -1235,6 +1295,9 @@ fn create_consumer(
         .create_with_context::<_, StreamConsumer<_>>(KafkaSourceContext::new(
             config.metrics.topic_lag_metric,
             acknowledgements,
+            config
+                .start_at_timestamp
+                .map(|timestamp| (timestamp.timestamp_millis(), config.socket_timeout_ms)),
             callbacks,
             Span::current(),
         ))
@@ -1269,12 +1332,17 @@ struct KafkaSourceContext {
 
     /// A weak reference to the consumer, so that we can commit offsets during a rebalance operation
     consumer: OnceLock<Weak<StreamConsumer<KafkaSourceContext>>>,
+
+    /// The timestamp, in milliseconds, to start consuming partitions without committed offsets
+    /// from, along with the timeout of the requests looking up their offsets.
+    start_at_timestamp: Option<(i64, Duration)>,
 }
 
 impl KafkaSourceContext {
     fn new(
         expose_lag_metrics: bool,
         acknowledgements: bool,
+        start_at_timestamp: Option<(i64, Duration)>,
         callbacks: UnboundedSender<KafkaCallback>,
         span: Span,
     ) -> Self {
@@ -1286,6 +1354,61 @@ impl KafkaSourceContext {
             acknowledgements,
             consumer: OnceLock::default(),
             callbacks,
+            start_at_timestamp,
+        }
+    }
+
+    /// Sets the offsets of the partitions being assigned that have no committed offset to the
+    /// first message at or after `start_at_timestamp`, so that they are consumed from there.
+    fn seek_to_start_timestamp(&self, tpl: &TopicPartitionList) {
+        // TODO  workaround for https://github.com/fede1024/rust-rdkafka/issues/681
+        if tpl.capacity() == 0 {
+            return;
+        }
+        let Some((timestamp, timeout)) = self.start_at_timestamp else {
+            return;
+        };
+        let Some(consumer) = self
+            .consumer
+            .get()
+            .expect("Consumer reference was not initialized.")
+            .upgrade()
+        else {
+            return;
+        };
+
+        let committed = match consumer.committed_offsets(tpl.clone(), timeout) {
+            Ok(committed) => committed,
+            Err(error) => {
+                emit!(KafkaOffsetUpdateError { error });
+                return;
+            }
+        };
+        let mut timestamps = TopicPartitionList::new();
+        for elem in committed.elements() {
+            if elem.offset() == Offset::Invalid {
+                timestamps
+                    .add_partition_offset(elem.topic(), elem.partition(), Offset::Offset(timestamp))
+                    .expect("timestamp is a valid offset");
+            }
+        }
+        if timestamps.count() == 0 {
+            return;
+        }
+
+        let offsets = match consumer.offsets_for_times(timestamps, timeout) {
+            Ok(offsets) => offsets,
+            Err(error) => {
+                emit!(KafkaOffsetUpdateError { error });
+                return;
+            }
+        };
+        for elem in offsets.elements() {
+            if let Some(mut assigned) = tpl.find_partition(elem.topic(), elem.partition()) {
+                if let Err(error) = assigned.set_offset(elem.offset()) {
+                    emit!(KafkaOffsetUpdateError { error });
+                }
+            }
         }
     }
 
@@ -1371,7 +1494,10 @@ impl ClientContext for KafkaSourceContext {
 impl ConsumerContext for KafkaSourceContext {
     fn pre_rebalance(&self, rebalance: &Rebalance) {
         match rebalance {
-            Rebalance::Assign(tpl) => self.consume_partitions(tpl),
+            Rebalance::Assign(tpl) => {
+                self.seek_to_start_timestamp(tpl);
+                self.consume_partitions(tpl);
+            }
 
             Rebalance::Revoke(tpl) => {
                 // TODO  workaround for https://github.com/fede1024/rust-rdkafka/issues/681
@@ -1676,6 +1802,32 @@ mod integration_test {
         send_receive(true, |n| n >= 2, 2, LogNamespace::Vector).await;
     }
 
+    #[tokio::test]
+    async fn consumes_from_start_timestamp() {
+        let topic = format!("test-topic-{}", random_string(10));
+        let group_id = format!("test-group-{}", random_string(10));
+
+        send_events(topic.clone(), 1, 5).await;
+        sleep(Duration::from_millis(10)).await;
+        let start_at = send_events(topic.clone(), 1, 3).await;
+
+        let mut config = make_config(&topic, &group_id, LogNamespace::Legacy, None);
+        config.start_at_timestamp = Some(start_at);
+
+        let (tx, rx) = SourceSender::new_test();
+        let (trigger_shutdown, shutdown_done) =
+            spawn_kafka(tx, config, true, false, LogNamespace::Legacy);
+        let events = collect_n(rx, 3).await;
+        tokio::task::yield_now().await;
+        drop(trigger_shutdown);
+        shutdown_done.await;
+
+        for event in events {
+            assert!(event.as_log()["offset"].as_integer().unwrap() >= 5);
+        }
+        assert_eq!(fetch_tpl_offset(&group_id, &topic, 0), Offset::from_raw(8));
+    }
+
     async fn send_receive(
         acknowledgements: bool,
         error_at: impl Fn(usize) -> bool,
@@ -1826,6 +1978,7 @@ mod integration_test {
 
         tokio::spawn(kafka_source(
             config,
+            "kafka".into(),
             consumer,
             callback_rx,
             decoder,
@@ -304,6 +304,29 @@ impl Default for Config {
     }
 }
 
+impl Config {
+    /// The data directory configured for this source, if any.
+    pub(crate) const fn data_dir(&self) -> Option<&PathBuf> {
+        self.data_dir.as_ref()
+    }
+
+    /// The fingerprinter identifying the log files read by this source.
+    pub(crate) const fn fingerprinter(&self) -> Fingerprinter {
+        fingerprinter(self.fingerprint_lines, self.max_line_bytes)
+    }
+}
+
+const fn fingerprinter(fingerprint_lines: usize, max_line_bytes: usize) -> Fingerprinter {
+    Fingerprinter {
+        strategy: FingerprintStrategy::FirstLinesChecksum {
+            ignored_header_bytes: 0,
+            lines: fingerprint_lines,
+        },
+        max_line_length: max_line_bytes,
+        ignore_not_found: true,
+    }
+}
+
 #[async_trait::async_trait]
 #[typetag::serde(name = "kubernetes_logs")]
 impl SourceConfig for Config {
@@ -801,16 +824,7 @@ impl Source {
             // The shape of the log files is well-known in the Kubernetes
             // environment, so we pick the a specially crafted fingerprinter
             // for the log files.
-            fingerprinter: Fingerprinter {
-                strategy: FingerprintStrategy::FirstLinesChecksum {
-                    // Max line length to expect during fingerprinting, see the
-                    // explanation above.
-                    ignored_header_bytes: 0,
-                    lines: fingerprint_lines,
-                },
-                max_line_length: max_line_bytes,
-                ignore_not_found: true,
-            },
+            fingerprinter: fingerprinter(fingerprint_lines, max_line_bytes),
             oldest_first,
             // We do not remove the log files, `kubelet` is responsible for it.
             remove_after: None,
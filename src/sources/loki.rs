@@ -0,0 +1,600 @@
+use std::{collections::HashMap, net::SocketAddr};
+
+use bytes::Bytes;
+use chrono::{DateTime, TimeZone, Utc};
+use loki_logproto::logproto;
+use prost::Message;
+use serde::Deserialize;
+use snafu::{ResultExt, Snafu};
+use vector_lib::codecs::BytesDeserializerConfig;
+use vector_lib::config::{LegacyKey, LogNamespace};
+use vector_lib::configurable::configurable_component;
+use vector_lib::lookup::{owned_value_path, path};
+use vector_lib::schema::Definition;
+use vrl::value::{kind::Collection, Kind, ObjectMap, Value};
+use warp::http::{header::CONTENT_TYPE, HeaderMap, StatusCode};
+
+use crate::{
+    config::{
+        log_schema, GenerateConfig, Resource, SourceAcknowledgementsConfig, SourceConfig,
+        SourceContext, SourceOutput,
+    },
+    event::{Event, LogEvent},
+    http::KeepaliveConfig,
+    internal_events::LokiPushRequestParseError,
+    serde::bool_or_struct,
+    sources::{
+        self,
+        util::{http::HttpMethod, ErrorMessage, HttpSource, HttpSourceAuthConfig},
+    },
+    tls::TlsEnableableConfig,
+};
+
+/// The path Loki clients push to.
+const PUSH_PATH: &str = "loki/api/v1/push";
+
+/// The header carrying the tenant ID in multi-tenant Loki deployments.
+const TENANT_ID_HEADER: &str = "X-Scope-OrgID";
+
+/// Configuration for the `loki` source.
+#[configurable_component(source("loki", "Receive logs pushed with the Loki push API."))]
+#[derive(Clone, Debug)]
+pub struct LokiConfig {
+    /// The socket address to accept connections on.
+    ///
+    /// The address _must_ include a port.
+    #[configurable(metadata(docs::examples = "0.0.0.0:3100"))]
+    address: SocketAddr,
+
+    #[configurable(derived)]
+    tls: Option<TlsEnableableConfig>,
+
+    #[configurable(derived)]
+    #[configurable(metadata(docs::advanced))]
+    auth: Option<HttpSourceAuthConfig>,
+
+    #[configurable(derived)]
+    #[serde(default, deserialize_with = "bool_or_struct")]
+    acknowledgements: SourceAcknowledgementsConfig,
+
+    /// The namespace to use for logs. This overrides the global setting.
+    #[configurable(metadata(docs::hidden))]
+    #[serde(default)]
+    log_namespace: Option<bool>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    keepalive: KeepaliveConfig,
+}
+
+impl LokiConfig {
+    /// Builds the `schema::Definition` for this source using the provided `LogNamespace`.
+    fn schema_definition(&self, log_namespace: LogNamespace) -> Definition {
+        BytesDeserializerConfig
+            .schema_definition(log_namespace)
+            .with_standard_vector_source_metadata()
+            .with_source_metadata(
+                Self::NAME,
+                log_schema()
+                    .timestamp_key()
+                    .cloned()
+                    .map(LegacyKey::Overwrite),
+                &owned_value_path!("timestamp"),
+                Kind::timestamp(),
+                Some("timestamp"),
+            )
+            .with_source_metadata(
+                Self::NAME,
+                Some(LegacyKey::Overwrite(owned_value_path!("labels"))),
+                &owned_value_path!("labels"),
+                Kind::object(Collection::empty().with_unknown(Kind::bytes())),
+                None,
+            )
+            .with_source_metadata(
+                Self::NAME,
+                Some(LegacyKey::Overwrite(owned_value_path!(
+                    "structured_metadata"
+                ))),
+                &owned_value_path!("structured_metadata"),
+                Kind::object(Collection::empty().with_unknown(Kind::bytes())).or_undefined(),
+                None,
+            )
+            .with_source_metadata(
+                Self::NAME,
+                Some(LegacyKey::Overwrite(owned_value_path!("tenant_id"))),
+                &owned_value_path!("tenant_id"),
+                Kind::bytes().or_undefined(),
+                None,
+            )
+    }
+}
+
+impl GenerateConfig for LokiConfig {
+    fn generate_config() -> toml::Value {
+        toml::Value::try_from(Self {
+            address: "0.0.0.0:3100".parse().unwrap(),
+            tls: None,
+            auth: None,
+            acknowledgements: SourceAcknowledgementsConfig::default(),
+            log_namespace: None,
+            keepalive: KeepaliveConfig::default(),
+        })
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "loki")]
+impl SourceConfig for LokiConfig {
+    async fn build(&self, cx: SourceContext) -> crate::Result<sources::Source> {
+        let source = LokiSource {
+            log_namespace: cx.log_namespace(self.log_namespace),
+        };
+        source.run(
+            self.address,
+            PUSH_PATH,
+            HttpMethod::Post,
+            StatusCode::NO_CONTENT,
+            true,
+            &self.tls,
+            &self.auth,
+            cx,
+            self.acknowledgements,
+            self.keepalive.clone(),
+        )
+    }
+
+    fn outputs(&self, global_log_namespace: LogNamespace) -> Vec<SourceOutput> {
+        let schema_def = self.schema_definition(global_log_namespace.merge(self.log_namespace));
+        vec![SourceOutput::new_maybe_logs(
+            BytesDeserializerConfig.output_type(),
+            schema_def,
+        )]
+    }
+
+    fn resources(&self) -> Vec<Resource> {
+        vec![Resource::tcp(self.address)]
+    }
+
+    fn can_acknowledge(&self) -> bool {
+        true
+    }
+}
+
+#[derive(Debug, Snafu)]
+enum ParseError {
+    #[snafu(display("Could not decompress push request: {}", source))]
+    Snappy { source: snap::Error },
+    #[snafu(display("Could not decode protobuf push request: {}", source))]
+    Protobuf { source: prost::DecodeError },
+    #[snafu(display("Could not decode JSON push request: {}", source))]
+    Json { source: serde_json::Error },
+    #[snafu(display("Invalid stream labels {:?}", labels))]
+    Labels { labels: String },
+    #[snafu(display("Invalid entry timestamp {:?}", timestamp))]
+    Timestamp { timestamp: String },
+}
+
+/// A JSON push request, as sent with `Content-Type: application/json`.
+#[derive(Deserialize)]
+struct JsonPushRequest {
+    streams: Vec<JsonStream>,
+}
+
+#[derive(Deserialize)]
+struct JsonStream {
+    #[serde(default)]
+    stream: HashMap<String, String>,
+    values: Vec<JsonEntry>,
+}
+
+/// (<Timestamp in nanos>, <Line>, <Structured metadata>)
+#[derive(Deserialize)]
+struct JsonEntry(String, String, #[serde(default)] HashMap<String, String>);
+
+#[derive(Clone)]
+struct LokiSource {
+    log_namespace: LogNamespace,
+}
+
+impl LokiSource {
+    fn decode_body(&self, body: Bytes, header_map: &HeaderMap) -> Result<Vec<Event>, ParseError> {
+        let tenant_id = header_map
+            .get(TENANT_ID_HEADER)
+            .and_then(|value| value.to_str().ok());
+        let is_json = header_map
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.split(';').next())
+            .is_some_and(|mime| mime.trim().eq_ignore_ascii_case("application/json"));
+        let now = Utc::now();
+
+        let mut events = Vec::new();
+        if is_json {
+            let request: JsonPushRequest = serde_json::from_slice(&body).context(JsonSnafu)?;
+            for stream in request.streams {
+                let labels = into_object(stream.stream);
+                for JsonEntry(timestamp, line, structured_metadata) in stream.values {
+                    let timestamp = timestamp
+                        .parse::<i64>()
+                        .map(|nanos| Utc.timestamp_nanos(nanos))
+                        .map_err(|_| ParseError::Timestamp { timestamp })?;
+                    events.push(self.build_event(
+                        line,
+                        timestamp,
+                        &labels,
+                        into_object(structured_metadata),
+                        tenant_id,
+                        now,
+                    ));
+                }
+            }
+        } else {
+            // Protobuf push requests are always compressed with the raw snappy format.
+            let body = snap::raw::Decoder::new()
+                .decompress_vec(&body)
+                .context(SnappySnafu)?;
+            let request = logproto::PushRequest::decode(body.as_slice()).context(ProtobufSnafu)?;
+            for stream in request.streams {
+                let labels = parse_labels(&stream.labels).ok_or_else(|| ParseError::Labels {
+                    labels: stream.labels.clone(),
+                })?;
+                for entry in stream.entries {
+                    let timestamp = entry
+                        .timestamp
+                        .as_ref()
+                        .and_then(|ts| {
+                            let nanos = u32::try_from(ts.nanos).ok()?;
+                            Utc.timestamp_opt(ts.seconds, nanos).single()
+                        })
+                        .ok_or_else(|| ParseError::Timestamp {
+                            timestamp: format!("{:?}", entry.timestamp),
+                        })?;
+                    let structured_metadata = entry
+                        .structured_metadata
+                        .into_iter()
+                        .map(|pair| (pair.name.into(), Value::from(pair.value)))
+                        .collect();
+                    events.push(self.build_event(
+                        entry.line,
+                        timestamp,
+                        &labels,
+                        structured_metadata,
+                        tenant_id,
+                        now,
+                    ));
+                }
+            }
+        }
+
+        Ok(events)
+    }
+
+    fn build_event(
+        &self,
+        line: String,
+        timestamp: DateTime<Utc>,
+        labels: &ObjectMap,
+        structured_metadata: ObjectMap,
+        tenant_id: Option<&str>,
+        now: DateTime<Utc>,
+    ) -> Event {
+        let mut log = match self.log_namespace {
+            LogNamespace::Vector => LogEvent::from(Value::from(line)),
+            LogNamespace::Legacy => {
+                let mut log = LogEvent::default();
+                log.maybe_insert(log_schema().message_key_target_path(), line);
+                log
+            }
+        };
+
+        self.log_namespace.insert_source_metadata(
+            LokiConfig::NAME,
+            &mut log,
+            log_schema().timestamp_key().map(LegacyKey::Overwrite),
+            path!("timestamp"),
+            timestamp,
+        );
+        self.log_namespace.insert_source_metadata(
+            LokiConfig::NAME,
+            &mut log,
+            Some(LegacyKey::Overwrite(path!("labels"))),
+            path!("labels"),
+            labels.clone(),
+        );
+        if !structured_metadata.is_empty() {
+            self.log_namespace.insert_source_metadata(
+                LokiConfig::NAME,
+                &mut log,
+                Some(LegacyKey::Overwrite(path!("structured_metadata"))),
+                path!("structured_metadata"),
+                structured_metadata,
+            );
+        }
+        if let Some(tenant_id) = tenant_id {
+            self.log_namespace.insert_source_metadata(
+                LokiConfig::NAME,
+                &mut log,
+                Some(LegacyKey::Overwrite(path!("tenant_id"))),
+                path!("tenant_id"),
+                tenant_id.to_owned(),
+            );
+        }
+        self.log_namespace
+            .insert_standard_vector_source_metadata(&mut log, LokiConfig::NAME, now);
+
+        log.into()
+    }
+}
+
+impl HttpSource for LokiSource {
+    fn build_events(
+        &self,
+        body: Bytes,
+        header_map: &HeaderMap,
+        _query_parameters: &HashMap<String, String>,
+        _full_path: &str,
+    ) -> Result<Vec<Event>, ErrorMessage> {
+        self.decode_body(body, header_map).map_err(|error| {
+            emit!(LokiPushRequestParseError { error: &error });
+            ErrorMessage::new(StatusCode::BAD_REQUEST, error.to_string())
+        })
+    }
+}
+
+fn into_object(map: HashMap<String, String>) -> ObjectMap {
+    map.into_iter()
+        .map(|(name, value)| (name.into(), Value::from(value)))
+        .collect()
+}
+
+/// Parses stream labels sent in the Prometheus selector format, e.g. `{job="app", env="prod"}`.
+fn parse_labels(input: &str) -> Option<ObjectMap> {
+    let mut chars = input
+        .trim()
+        .strip_prefix('{')?
+        .strip_suffix('}')?
+        .chars()
+        .peekable();
+    let mut labels = ObjectMap::new();
+
+    loop {
+        while chars.next_if(|c| c.is_whitespace() || *c == ',').is_some() {}
+        if chars.peek().is_none() {
+            return Some(labels);
+        }
+
+        let mut name = String::new();
+        while let Some(c) = chars.next_if(|c| *c != '=') {
+            name.push(c);
+        }
+        let name = name.trim();
+        if name.is_empty() || chars.next() != Some('=') {
+            return None;
+        }
+
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        if chars.next() != Some('"') {
+            return None;
+        }
+        let mut value = String::new();
+        loop {
+            match chars.next()? {
+                '"' => break,
+                '\\' => match chars.next()? {
+                    'n' => value.push('\n'),
+                    't' => value.push('\t'),
+                    c => value.push(c),
+                },
+                c => value.push(c),
+            }
+        }
+
+        labels.insert(name.into(), value.into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+
+    use futures::Stream;
+    use loki_logproto::util::{Batch, Entry, Stream as LokiStream};
+    use similar_asserts::assert_eq;
+    use vector_lib::event::EventStatus;
+    use vector_lib::lookup::{event_path, metadata_path};
+
+    use super::*;
+    use crate::{
+        test_util::{
+            components::{assert_source_compliance, HTTP_PUSH_SOURCE_TAGS},
+            next_addr, spawn_collect_n, wait_for_tcp,
+        },
+        SourceSender,
+    };
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<LokiConfig>();
+    }
+
+    #[test]
+    fn parses_labels() {
+        assert_eq!(
+            parse_labels(r#"{job="app", path="C:\\logs", msg="say \"hi\""}"#),
+            Some(ObjectMap::from([
+                ("job".into(), Value::from("app")),
+                ("path".into(), Value::from(r"C:\logs")),
+                ("msg".into(), Value::from(r#"say "hi""#)),
+            ]))
+        );
+        assert_eq!(parse_labels("{}"), Some(ObjectMap::new()));
+        assert_eq!(parse_labels(r#"{job="app""#), None);
+        assert_eq!(parse_labels(r#"{job=app}"#), None);
+    }
+
+    async fn source(log_namespace: bool) -> (impl Stream<Item = Event> + Unpin, SocketAddr) {
+        let (sender, recv) = SourceSender::new_test_finalize(EventStatus::Delivered);
+        let address = next_addr();
+        let context = SourceContext::new_test(sender, None);
+        tokio::spawn(async move {
+            LokiConfig {
+                address,
+                tls: None,
+                auth: None,
+                acknowledgements: true.into(),
+                log_namespace: Some(log_namespace),
+                keepalive: Default::default(),
+            }
+            .build(context)
+            .await
+            .unwrap()
+            .await
+            .unwrap()
+        });
+        wait_for_tcp(address).await;
+        (recv, address)
+    }
+
+    async fn send(
+        address: SocketAddr,
+        content_type: &str,
+        tenant_id: Option<&str>,
+        body: Vec<u8>,
+    ) -> u16 {
+        let mut request = reqwest::Client::new()
+            .post(format!("http://{}/{}", address, PUSH_PATH))
+            .header("Content-Type", content_type);
+        if let Some(tenant_id) = tenant_id {
+            request = request.header(TENANT_ID_HEADER, tenant_id);
+        }
+        request.body(body).send().await.unwrap().status().as_u16()
+    }
+
+    #[tokio::test]
+    async fn receives_protobuf_push() {
+        assert_source_compliance(&HTTP_PUSH_SOURCE_TAGS, async {
+            let (rx, addr) = source(false).await;
+            let body = Batch(vec![LokiStream(
+                HashMap::from([("job".to_owned(), "app".to_owned())]),
+                vec![
+                    Entry(1_700_000_000_123_456_789, "first".to_owned(), vec![]),
+                    Entry(
+                        1_700_000_001_000_000_000,
+                        "second".to_owned(),
+                        vec![("trace_id".to_owned(), "abc".to_owned())],
+                    ),
+                ],
+            )])
+            .encode();
+            let body = snap::raw::Encoder::new().compress_vec(&body).unwrap();
+
+            let events = spawn_collect_n(
+                async move {
+                    assert_eq!(
+                        204,
+                        send(addr, "application/x-protobuf", Some("tenant-a"), body).await
+                    )
+                },
+                rx,
+                2,
+            )
+            .await;
+
+            let log = events[0].as_log();
+            assert_eq!(*log.get_message().unwrap(), "first".into());
+            assert_eq!(
+                log[log_schema().timestamp_key().unwrap().to_string()],
+                Utc.timestamp_nanos(1_700_000_000_123_456_789).into()
+            );
+            assert_eq!(log["labels.job"], "app".into());
+            assert_eq!(log["tenant_id"], "tenant-a".into());
+            assert!(log.get("structured_metadata").is_none());
+            assert_eq!(*log.get_source_type().unwrap(), "loki".into());
+
+            let log = events[1].as_log();
+            assert_eq!(*log.get_message().unwrap(), "second".into());
+            assert_eq!(log["structured_metadata.trace_id"], "abc".into());
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn receives_json_push() {
+        assert_source_compliance(&HTTP_PUSH_SOURCE_TAGS, async {
+            let (rx, addr) = source(true).await;
+            let body = serde_json::json!({
+                "streams": [{
+                    "stream": { "job": "app", "env": "prod" },
+                    "values": [
+                        ["1700000000123456789", "first", { "trace_id": "abc" }],
+                        ["1700000001000000000", "second"],
+                    ],
+                }],
+            });
+
+            let events = spawn_collect_n(
+                async move {
+                    assert_eq!(
+                        204,
+                        send(
+                            addr,
+                            "application/json",
+                            None,
+                            body.to_string().into_bytes()
+                        )
+                        .await
+                    )
+                },
+                rx,
+                2,
+            )
+            .await;
+
+            let log = events[0].as_log();
+            assert_eq!(*log.value(), "first".into());
+            assert_eq!(
+                log.get(metadata_path!("loki", "timestamp")).unwrap(),
+                &Value::from(Utc.timestamp_nanos(1_700_000_000_123_456_789))
+            );
+            assert_eq!(
+                log.get(metadata_path!("loki", "labels", "env")).unwrap(),
+                &Value::from("prod")
+            );
+            assert_eq!(
+                log.get(metadata_path!("loki", "structured_metadata", "trace_id"))
+                    .unwrap(),
+                &Value::from("abc")
+            );
+            assert!(log.get(metadata_path!("loki", "tenant_id")).is_none());
+            assert!(log.get(event_path!("labels")).is_none());
+
+            let log = events[1].as_log();
+            assert_eq!(*log.value(), "second".into());
+            assert!(log
+                .get(metadata_path!("loki", "structured_metadata"))
+                .is_none());
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_push() {
+        let (_rx, addr) = source(false).await;
+
+        assert_eq!(
+            400,
+            send(addr, "application/x-protobuf", None, b"not snappy".to_vec()).await
+        );
+        assert_eq!(
+            400,
+            send(
+                addr,
+                "application/json",
+                None,
+                br#"{"streams":[{"stream":{},"values":[["now","line"]]}]}"#.to_vec()
+            )
+            .await
+        );
+    }
+}
@@ -0,0 +1,381 @@
+use std::time::Duration;
+
+use rand::Rng;
+use rumqttc::{MqttOptions, TlsConfiguration, Transport};
+use snafu::{ResultExt, Snafu};
+use vector_lib::codecs::decoding::{DeserializerConfig, FramingConfig};
+use vector_lib::config::{LegacyKey, LogNamespace, SourceAcknowledgementsConfig};
+use vector_lib::configurable::configurable_component;
+use vector_lib::lookup::{lookup_v2::OptionalValuePath, owned_value_path};
+use vector_lib::tls::TlsError;
+use vrl::value::Kind;
+
+use crate::{
+    codecs::DecodingConfig,
+    common::mqtt::MqttQoS,
+    config::{SourceConfig, SourceContext, SourceOutput},
+    serde::{bool_or_struct, default_decoding, default_framing_message_based},
+    sources::{mqtt::source::MqttSource, Source},
+    tls::{MaybeTlsSettings, TlsEnableableConfig},
+};
+
+#[derive(Debug, Snafu)]
+pub(super) enum ConfigurationError {
+    #[snafu(display("Client ID is not allowed to be empty."))]
+    EmptyClientId,
+    #[snafu(display("A client ID is required when `clean_session` is disabled."))]
+    PersistentSessionWithoutClientId,
+    #[snafu(display("Username and password must be either both provided or both missing."))]
+    InvalidCredentials,
+    #[snafu(display("At least one topic filter is required."))]
+    NoTopics,
+    #[snafu(display("TLS error: {}", source))]
+    Tls { source: TlsError },
+}
+
+/// Configuration for the `mqtt` source.
+///
+/// When acknowledgements are enabled, messages received with the `atleastonce`
+/// or `exactlyonce` quality of service are only acknowledged to the broker once
+/// their events are delivered or rejected, in the order they were received.
+/// When their events fail to be delivered, the source reconnects to the broker
+/// without acknowledging them, so that the broker delivers them again once the
+/// session is resumed. This requires `clean_session` to be disabled, along with
+/// a fixed `client_id`: with a clean session, the messages left unacknowledged
+/// are lost, and the delivery is at most once.
+#[configurable_component(source("mqtt", "Collect events from MQTT brokers."))]
+#[derive(Clone, Debug, Derivative)]
+#[derivative(Default)]
+#[serde(deny_unknown_fields)]
+pub struct MqttSourceConfig {
+    /// MQTT server address (The broker’s domain name or IP address).
+    #[configurable(metadata(docs::examples = "mqtt.example.com", docs::examples = "127.0.0.1"))]
+    #[derivative(Default(value = "\"localhost\".into()"))]
+    pub host: String,
+
+    /// TCP port of the MQTT server to connect to.
+    #[serde(default = "default_port")]
+    #[derivative(Default(value = "default_port()"))]
+    pub port: u16,
+
+    /// MQTT username.
+    pub user: Option<String>,
+
+    /// MQTT password.
+    pub password: Option<String>,
+
+    /// MQTT client ID.
+    ///
+    /// The broker only resumes a persistent session for the same client ID, so a
+    /// fixed client ID is required when `clean_session` is disabled. A random
+    /// client ID is generated if it is not set.
+    pub client_id: Option<String>,
+
+    /// Connection keep-alive interval.
+    #[serde(default = "default_keep_alive")]
+    #[derivative(Default(value = "default_keep_alive()"))]
+    pub keep_alive: u16,
+
+    /// If set to true, the MQTT session is cleaned on login.
+    ///
+    /// When set to false, the broker keeps the subscriptions and queues the
+    /// messages published while Vector is disconnected. This requires a fixed
+    /// `client_id`.
+    #[serde(default = "default_clean_session")]
+    #[derivative(Default(value = "default_clean_session()"))]
+    pub clean_session: bool,
+
+    #[configurable(derived)]
+    pub tls: Option<TlsEnableableConfig>,
+
+    /// The MQTT topic filters to subscribe to.
+    ///
+    /// The `+` and `#` wildcards match a single topic level and any number of
+    /// topic levels respectively.
+    #[configurable(metadata(docs::examples = "sensors/+/temperature"))]
+    #[configurable(metadata(docs::examples = "factory/#"))]
+    #[derivative(Default(value = "vec![\"vector\".into()]"))]
+    pub topics: Vec<String>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub quality_of_service: MqttQoS,
+
+    /// Overrides the name of the log field used to add the topic of the message to each event.
+    ///
+    /// The value is the topic the message was published to, not the filter it matched.
+    ///
+    /// By default, `"topic"` is used.
+    #[serde(default = "default_topic_key")]
+    #[derivative(Default(value = "default_topic_key()"))]
+    pub topic_key: OptionalValuePath,
+
+    /// Overrides the name of the log field used to add the quality of service the message was
+    /// received with to each event.
+    ///
+    /// The value is `0`, `1`, or `2`, and is the lower of the quality of service the message was
+    /// published with and the one of the subscription.
+    ///
+    /// By default, `"qos"` is used.
+    #[serde(default = "default_qos_key")]
+    #[derivative(Default(value = "default_qos_key()"))]
+    pub qos_key: OptionalValuePath,
+
+    #[configurable(derived)]
+    #[serde(default = "default_framing_message_based")]
+    #[derivative(Default(value = "default_framing_message_based()"))]
+    pub framing: FramingConfig,
+
+    #[configurable(derived)]
+    #[serde(default = "default_decoding")]
+    #[derivative(Default(value = "default_decoding()"))]
+    pub decoding: DeserializerConfig,
+
+    #[configurable(derived)]
+    #[serde(default, deserialize_with = "bool_or_struct")]
+    pub acknowledgements: SourceAcknowledgementsConfig,
+
+    /// The namespace to use for logs. This overrides the global setting.
+    #[configurable(metadata(docs::hidden))]
+    #[serde(default)]
+    pub log_namespace: Option<bool>,
+}
+
+const fn default_port() -> u16 {
+    1883
+}
+
+const fn default_keep_alive() -> u16 {
+    60
+}
+
+const fn default_clean_session() -> bool {
+    true
+}
+
+fn default_topic_key() -> OptionalValuePath {
+    OptionalValuePath::from(owned_value_path!("topic"))
+}
+
+fn default_qos_key() -> OptionalValuePath {
+    OptionalValuePath::from(owned_value_path!("qos"))
+}
+
+impl_generate_config_from_default!(MqttSourceConfig);
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "mqtt")]
+impl SourceConfig for MqttSourceConfig {
+    async fn build(&self, cx: SourceContext) -> crate::Result<Source> {
+        let log_namespace = cx.log_namespace(self.log_namespace);
+        let acknowledgements = cx.do_acknowledgements(self.acknowledgements);
+        let options = self.build_options(acknowledgements)?;
+        let decoder =
+            DecodingConfig::new(self.framing.clone(), self.decoding.clone(), log_namespace)
+                .build()?;
+
+        let source = MqttSource::new(self, options, decoder, log_namespace, acknowledgements);
+        Ok(Box::pin(source.run(cx.out, cx.shutdown)))
+    }
+
+    fn outputs(&self, global_log_namespace: LogNamespace) -> Vec<SourceOutput> {
+        let log_namespace = global_log_namespace.merge(self.log_namespace);
+        let schema_definition = self
+            .decoding
+            .schema_definition(log_namespace)
+            .with_standard_vector_source_metadata()
+            .with_source_metadata(
+                Self::NAME,
+                self.topic_key.path.clone().map(LegacyKey::InsertIfEmpty),
+                &owned_value_path!("topic"),
+                Kind::bytes(),
+                None,
+            )
+            .with_source_metadata(
+                Self::NAME,
+                self.qos_key.path.clone().map(LegacyKey::InsertIfEmpty),
+                &owned_value_path!("qos"),
+                Kind::integer(),
+                None,
+            );
+
+        vec![SourceOutput::new_maybe_logs(
+            self.decoding.output_type(),
+            schema_definition,
+        )]
+    }
+
+    fn can_acknowledge(&self) -> bool {
+        true
+    }
+}
+
+impl MqttSourceConfig {
+    fn build_options(&self, acknowledgements: bool) -> Result<MqttOptions, ConfigurationError> {
+        // A session kept for a random client ID can't be resumed, and is only
+        // left behind on the broker.
+        if !self.clean_session && self.client_id.is_none() {
+            return Err(ConfigurationError::PersistentSessionWithoutClientId);
+        }
+        let client_id = self.client_id.clone().unwrap_or_else(|| {
+            let hash = rand::thread_rng()
+                .sample_iter(&rand_distr::Alphanumeric)
+                .take(6)
+                .map(char::from)
+                .collect::<String>();
+            format!("vectorSource{hash}")
+        });
+
+        if client_id.is_empty() {
+            return Err(ConfigurationError::EmptyClientId);
+        }
+        if self.topics.is_empty() {
+            return Err(ConfigurationError::NoTopics);
+        }
+        let tls = MaybeTlsSettings::from_config(&self.tls, false).context(TlsSnafu)?;
+        let mut options = MqttOptions::new(&client_id, &self.host, self.port);
+        options.set_keep_alive(Duration::from_secs(self.keep_alive.into()));
+        options.set_clean_session(self.clean_session);
+        // Messages are acknowledged by the source once their events are delivered.
+        options.set_manual_acks(acknowledgements);
+        match (&self.user, &self.password) {
+            (Some(user), Some(password)) => {
+                options.set_credentials(user, password);
+            }
+            (None, None) => {}
+            _ => return Err(ConfigurationError::InvalidCredentials),
+        }
+        if let Some(tls) = tls.tls() {
+            let ca = tls.authorities_pem().flatten().collect();
+            let client_auth = tls.identity_pem();
+            let alpn = Some(vec!["mqtt".into()]);
+            options.set_transport(Transport::Tls(TlsConfiguration::Simple {
+                ca,
+                client_auth,
+                alpn,
+            }));
+        }
+        Ok(options)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vector_lib::lookup::OwnedTargetPath;
+    use vector_lib::schema::Definition;
+    use vrl::value::kind::Collection;
+
+    use super::*;
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<MqttSourceConfig>();
+    }
+
+    #[test]
+    fn requires_topics() {
+        let config = MqttSourceConfig {
+            topics: Vec::new(),
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.build_options(false),
+            Err(ConfigurationError::NoTopics)
+        ));
+    }
+
+    #[test]
+    fn requires_user_and_password() {
+        let config = MqttSourceConfig {
+            user: Some("vector".into()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.build_options(false),
+            Err(ConfigurationError::InvalidCredentials)
+        ));
+    }
+
+    #[test]
+    fn persistent_session_requires_client_id() {
+        let config = MqttSourceConfig {
+            clean_session: false,
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.build_options(false),
+            Err(ConfigurationError::PersistentSessionWithoutClientId)
+        ));
+
+        let config = MqttSourceConfig {
+            clean_session: false,
+            client_id: Some("vector".into()),
+            ..Default::default()
+        };
+        assert!(!config.build_options(false).unwrap().clean_session());
+    }
+
+    #[test]
+    fn manual_acks_with_acknowledgements() {
+        let config = MqttSourceConfig::default();
+        assert!(config.build_options(true).unwrap().manual_acks());
+        assert!(!config.build_options(false).unwrap().manual_acks());
+    }
+
+    #[test]
+    fn output_schema_definition_vector_namespace() {
+        let config = MqttSourceConfig {
+            log_namespace: Some(true),
+            ..Default::default()
+        };
+
+        let definition = config
+            .outputs(LogNamespace::Vector)
+            .remove(0)
+            .schema_definition(true);
+
+        let expected_definition =
+            Definition::new_with_default_metadata(Kind::bytes(), [LogNamespace::Vector])
+                .with_meaning(OwnedTargetPath::event_root(), "message")
+                .with_metadata_field(
+                    &owned_value_path!("vector", "source_type"),
+                    Kind::bytes(),
+                    None,
+                )
+                .with_metadata_field(
+                    &owned_value_path!("vector", "ingest_timestamp"),
+                    Kind::timestamp(),
+                    None,
+                )
+                .with_metadata_field(&owned_value_path!("mqtt", "topic"), Kind::bytes(), None)
+                .with_metadata_field(&owned_value_path!("mqtt", "qos"), Kind::integer(), None);
+
+        assert_eq!(definition, Some(expected_definition));
+    }
+
+    #[test]
+    fn output_schema_definition_legacy_namespace() {
+        let config = MqttSourceConfig::default();
+
+        let definition = config
+            .outputs(LogNamespace::Legacy)
+            .remove(0)
+            .schema_definition(true);
+
+        let expected_definition = Definition::new_with_default_metadata(
+            Kind::object(Collection::empty()),
+            [LogNamespace::Legacy],
+        )
+        .with_event_field(
+            &owned_value_path!("message"),
+            Kind::bytes(),
+            Some("message"),
+        )
+        .with_event_field(&owned_value_path!("timestamp"), Kind::timestamp(), None)
+        .with_event_field(&owned_value_path!("source_type"), Kind::bytes(), None)
+        .with_event_field(&owned_value_path!("topic"), Kind::bytes(), None)
+        .with_event_field(&owned_value_path!("qos"), Kind::integer(), None);
+
+        assert_eq!(definition, Some(expected_definition));
+    }
+}
@@ -0,0 +1,144 @@
+use std::time::Duration;
+
+use futures::StreamExt;
+use rumqttc::{AsyncClient, Event, MqttOptions, Outgoing, QoS};
+use vector_lib::config::log_schema;
+
+use crate::config::{SourceConfig, SourceContext};
+use crate::event::EventStatus;
+use crate::sources::mqtt::MqttSourceConfig;
+use crate::test_util::components::{assert_source_compliance, SOURCE_TAGS};
+use crate::test_util::{collect_n, random_lines, random_string, trace_init};
+use crate::SourceSender;
+
+fn mqtt_broker_address() -> String {
+    std::env::var("MQTT_BROKER_ADDRESS").unwrap_or_else(|_| "emqx".into())
+}
+
+fn mqtt_broker_port() -> u16 {
+    std::env::var("MQTT_BROKER_PORT")
+        .unwrap_or_else(|_| "1883".into())
+        .parse::<u16>()
+        .expect("Cannot parse as u16")
+}
+
+async fn publish(topic: &str, messages: &[String]) {
+    let options = MqttOptions::new(
+        format!("integration-test-producer-{}", random_string(6)),
+        mqtt_broker_address(),
+        mqtt_broker_port(),
+    );
+    let (client, mut eventloop) = AsyncClient::new(options, messages.len() + 1);
+    for message in messages {
+        client
+            .publish(topic, QoS::AtLeastOnce, false, message.clone())
+            .await
+            .expect("Cannot publish the message");
+    }
+    client.disconnect().await.expect("Cannot disconnect");
+
+    loop {
+        match eventloop.poll().await.expect("Cannot publish the messages") {
+            Event::Outgoing(Outgoing::Disconnect) => break,
+            _ => continue,
+        }
+    }
+}
+
+async fn receive_messages(acknowledgements: bool) {
+    trace_init();
+
+    let prefix = format!("vector-source-{}", random_string(6));
+    let config = MqttSourceConfig {
+        host: mqtt_broker_address(),
+        port: mqtt_broker_port(),
+        topics: vec![format!("{prefix}/+/temperature")],
+        acknowledgements: acknowledgements.into(),
+        ..Default::default()
+    };
+
+    let events = assert_source_compliance(&SOURCE_TAGS, async move {
+        let (tx, rx) = SourceSender::new_test_finalize(EventStatus::Delivered);
+        let source = config
+            .build(SourceContext::new_test(tx, None))
+            .await
+            .expect("Cannot build the source");
+        tokio::spawn(source);
+        // Wait for the subscription to be made.
+        tokio::time::sleep(Duration::from_secs(1)).await;
+
+        let messages = random_lines(100).take(10).collect::<Vec<_>>();
+        publish(&format!("{prefix}/line-1/temperature"), &messages).await;
+
+        let events = collect_n(rx, messages.len()).await;
+        for (event, message) in events.iter().zip(&messages) {
+            let log = event.as_log();
+            assert_eq!(
+                log[log_schema().message_key().unwrap().to_string()],
+                message.as_str().into()
+            );
+        }
+        events
+    })
+    .await;
+
+    for event in events {
+        let log = event.as_log();
+        assert_eq!(log["topic"], format!("{prefix}/line-1/temperature").into());
+        assert_eq!(log["qos"], 1.into());
+        assert_eq!(log["source_type"], "mqtt".into());
+    }
+}
+
+#[tokio::test]
+async fn mqtt_source_receives_messages() {
+    receive_messages(false).await;
+}
+
+#[tokio::test]
+async fn mqtt_source_receives_messages_with_acknowledgements() {
+    receive_messages(true).await;
+}
+
+#[tokio::test]
+async fn mqtt_source_receives_errored_messages_again() {
+    trace_init();
+
+    let topic = format!("vector-source-{}/errored", random_string(6));
+    let config = MqttSourceConfig {
+        host: mqtt_broker_address(),
+        port: mqtt_broker_port(),
+        topics: vec![topic.clone()],
+        client_id: Some(format!("vector-source-{}", random_string(6))),
+        clean_session: false,
+        acknowledgements: true.into(),
+        ..Default::default()
+    };
+
+    let (tx, mut rx) = SourceSender::new_test();
+    let source = config
+        .build(SourceContext::new_test(tx, None))
+        .await
+        .expect("Cannot build the source");
+    tokio::spawn(source);
+    // Wait for the subscription to be made.
+    tokio::time::sleep(Duration::from_secs(1)).await;
+
+    let message = random_string(20);
+    publish(&topic, &[message.clone()]).await;
+
+    // The message is left unacknowledged when its events fail to be delivered, and the broker
+    // delivers it again once the source has reconnected.
+    for status in [EventStatus::Errored, EventStatus::Delivered] {
+        let mut event = tokio::time::timeout(Duration::from_secs(10), rx.next())
+            .await
+            .expect("Timed out waiting for the message")
+            .expect("The source stopped");
+        let log = event.as_log();
+        assert_eq!(
+            log[log_schema().message_key().unwrap().to_string()],
+            message.as_str().into()
+        );
+        event.metadata_mut().update_status(status);
+    }
+}
@@ -0,0 +1,236 @@
+use std::time::Duration;
+
+use bytes::BytesMut;
+use chrono::Utc;
+use futures::StreamExt;
+use rumqttc::{
+    AsyncClient, Event as MqttEvent, Incoming, MqttOptions, Outgoing, Publish, QoS,
+    SubscribeFilter, SubscribeReasonCode,
+};
+use tokio_util::codec::Decoder as _;
+use vector_lib::codecs::StreamDecodingError as _;
+use vector_lib::config::{LegacyKey, LogNamespace};
+use vector_lib::finalizer::OrderedFinalizer;
+use vector_lib::internal_event::{
+    ByteSize, BytesReceived, CountByteSize, EventsReceived, InternalEventHandle as _, Protocol,
+    Registered,
+};
+use vector_lib::lookup::{lookup_v2::OptionalValuePath, path};
+use vector_lib::EstimatedJsonEncodedSizeOf;
+
+use crate::{
+    codecs::Decoder,
+    event::{BatchNotifier, BatchStatus, Event},
+    internal_events::{MqttAckError, MqttReceiveError, MqttSubscribeError, StreamClosedError},
+    shutdown::ShutdownSignal,
+    sources::mqtt::MqttSourceConfig,
+    SourceSender,
+};
+
+/// How long to wait before polling the connection again after it failed.
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+/// How long to wait for the pending acknowledgements to be sent when shutting down.
+const DISCONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+pub(super) struct MqttSource {
+    options: MqttOptions,
+    filters: Vec<SubscribeFilter>,
+    topic_key: OptionalValuePath,
+    qos_key: OptionalValuePath,
+    decoder: Decoder,
+    log_namespace: LogNamespace,
+    acknowledgements: bool,
+}
+
+impl MqttSource {
+    pub(super) fn new(
+        config: &MqttSourceConfig,
+        options: MqttOptions,
+        decoder: Decoder,
+        log_namespace: LogNamespace,
+        acknowledgements: bool,
+    ) -> Self {
+        let qos = config.quality_of_service.into();
+        Self {
+            options,
+            filters: config
+                .topics
+                .iter()
+                .map(|topic| SubscribeFilter::new(topic.clone(), qos))
+                .collect(),
+            topic_key: config.topic_key.clone(),
+            qos_key: config.qos_key.clone(),
+            decoder,
+            log_namespace,
+            acknowledgements,
+        }
+    }
+
+    pub(super) async fn run(
+        self,
+        mut out: SourceSender,
+        mut shutdown: ShutdownSignal,
+    ) -> Result<(), ()> {
+        let (client, mut connection) = AsyncClient::new(self.options.clone(), 1024);
+        let (finalizer, mut ack_stream) = OrderedFinalizer::<(u64, Publish)>::maybe_new(
+            self.acknowledgements,
+            Some(shutdown.clone()),
+        );
+        let events_received = register!(EventsReceived);
+        let bytes_received = register!(BytesReceived::from(Protocol::TCP));
+        // Messages are only acknowledged on the connection they were received on, the broker
+        // delivers the ones left unacknowledged again when a persistent session is resumed.
+        let mut connection_id: u64 = 0;
+
+        loop {
+            tokio::select! {
+                _ = &mut shutdown => break,
+                entry = ack_stream.next() => match entry {
+                    Some((_, (received_on, _))) if received_on != connection_id => {}
+                    // The message is left unacknowledged, along with the ones received after it,
+                    // and the connection dropped, so that the broker delivers them again.
+                    Some((BatchStatus::Errored, (_, publish))) if publish.qos != QoS::AtMostOnce => {
+                        warn!(
+                            message = "Events failed to be delivered, reconnecting to receive them again.",
+                            internal_log_rate_limit = true,
+                        );
+                        connection_id += 1;
+                        if let Err(error) = client.disconnect().await {
+                            emit!(MqttAckError { error });
+                        }
+                    }
+                    Some((_, (_, publish))) => handle_ack(&client, publish).await,
+                    None => {}
+                },
+                event = connection.poll() => match event {
+                    Ok(MqttEvent::Incoming(Incoming::ConnAck(connack))) => {
+                        connection_id += 1;
+                        // The subscriptions are kept by the broker when a
+                        // persistent session is resumed.
+                        if !connack.session_present {
+                            if let Err(error) = client.subscribe_many(self.filters.clone()).await {
+                                error!(message = "Failed to subscribe.", %error);
+                                return Err(());
+                            }
+                        }
+                    }
+                    Ok(MqttEvent::Incoming(Incoming::SubAck(suback))) => {
+                        for (filter, code) in self.filters.iter().zip(suback.return_codes) {
+                            if matches!(code, SubscribeReasonCode::Failure) {
+                                emit!(MqttSubscribeError { topic: &filter.path });
+                            }
+                        }
+                    }
+                    Ok(MqttEvent::Incoming(Incoming::Publish(publish))) => {
+                        bytes_received.emit(ByteSize(publish.payload.len()));
+                        let (batch, receiver) =
+                            BatchNotifier::maybe_new_with_receiver(finalizer.is_some());
+                        let events = self.decode(&publish, &batch, &events_received);
+                        drop(batch); // Drop last reference to batch acknowledgement finalizer
+                        let count = events.len();
+
+                        out.send_batch(events).await.map_err(|_| {
+                            emit!(StreamClosedError { count });
+                        })?;
+                        if let (Some(finalizer), Some(receiver)) = (&finalizer, receiver) {
+                            finalizer.add((connection_id, publish), receiver);
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(error) => {
+                        emit!(MqttReceiveError { error });
+                        // The connection is attempted again on the next poll.
+                        tokio::time::sleep(RECONNECT_DELAY).await;
+                    }
+                },
+            }
+        }
+
+        // Flush the acknowledgements already requested before disconnecting.
+        if client.disconnect().await.is_ok() {
+            _ = tokio::time::timeout(DISCONNECT_TIMEOUT, async {
+                loop {
+                    match connection.poll().await {
+                        Ok(MqttEvent::Outgoing(Outgoing::Disconnect)) | Err(_) => break,
+                        Ok(_) => {}
+                    }
+                }
+            })
+            .await;
+        }
+
+        Ok(())
+    }
+
+    fn decode(
+        &self,
+        publish: &Publish,
+        batch: &Option<BatchNotifier>,
+        events_received: &Registered<EventsReceived>,
+    ) -> Vec<Event> {
+        let now = Utc::now();
+        let mut decoder = self.decoder.clone();
+        let mut buffer = BytesMut::from(publish.payload.as_ref());
+        let mut events = Vec::new();
+        loop {
+            match decoder.decode_eof(&mut buffer) {
+                Ok(Some((decoded, _byte_size))) => {
+                    events_received.emit(CountByteSize(
+                        decoded.len(),
+                        decoded.estimated_json_encoded_size_of(),
+                    ));
+                    events.extend(decoded.into_iter().map(|mut event| {
+                        if let Event::Log(ref mut log) = event {
+                            self.log_namespace.insert_standard_vector_source_metadata(
+                                log,
+                                MqttSourceConfig::NAME,
+                                now,
+                            );
+                            self.log_namespace.insert_source_metadata(
+                                MqttSourceConfig::NAME,
+                                log,
+                                self.topic_key.path.as_ref().map(LegacyKey::InsertIfEmpty),
+                                path!("topic"),
+                                publish.topic.as_str(),
+                            );
+                            self.log_namespace.insert_source_metadata(
+                                MqttSourceConfig::NAME,
+                                log,
+                                self.qos_key.path.as_ref().map(LegacyKey::InsertIfEmpty),
+                                path!("qos"),
+                                qos_level(publish.qos),
+                            );
+                        }
+                        event.with_batch_notifier_option(batch)
+                    }));
+                }
+                Ok(None) => break,
+                Err(error) => {
+                    // Error is logged by `crate::codecs::Decoder`, no further
+                    // handling is needed here.
+                    if !error.can_continue() {
+                        break;
+                    }
+                }
+            }
+        }
+        events
+    }
+}
+
+async fn handle_ack(client: &AsyncClient, publish: Publish) {
+    // Rejected events are acknowledged as well, as delivering their message
+    // again wouldn't change their status.
+    if let Err(error) = client.ack(&publish).await {
+        emit!(MqttAckError { error });
+    }
+}
+
+const fn qos_level(qos: QoS) -> i64 {
+    match qos {
+        QoS::AtMostOnce => 0,
+        QoS::AtLeastOnce => 1,
+        QoS::ExactlyOnce => 2,
+    }
+}
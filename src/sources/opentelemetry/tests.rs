@@ -7,11 +7,26 @@ use prost::Message;
 use similar_asserts::assert_eq;
 use tonic::Request;
 use vector_lib::config::LogNamespace;
+use vector_lib::event::{
+    metric::{Bucket, MetricSketch},
+    Metric, MetricKind, MetricValue,
+};
 use vector_lib::lookup::path;
+use vector_lib::metric_tags;
+use vector_lib::metrics::{ExponentialBucket, NativeHistogram};
 use vector_lib::opentelemetry::proto::{
-    collector::logs::v1::{logs_service_client::LogsServiceClient, ExportLogsServiceRequest},
+    collector::{
+        logs::v1::{logs_service_client::LogsServiceClient, ExportLogsServiceRequest},
+        metrics::v1::{metrics_service_client::MetricsServiceClient, ExportMetricsServiceRequest},
+    },
     common::v1::{any_value, AnyValue, InstrumentationScope, KeyValue},
     logs::v1::{LogRecord, ResourceLogs, ScopeLogs},
+    metrics::v1::{
+        exponential_histogram_data_point::Buckets, metric::Data, number_data_point,
+        AggregationTemporality, ExponentialHistogram, ExponentialHistogramDataPoint, Gauge,
+        Histogram, HistogramDataPoint, Metric as OtelMetric, NumberDataPoint, ResourceMetrics,
+        ScopeMetrics, Sum,
+    },
     resource::v1::Resource as OtelResource,
 };
 use vrl::value;
@@ -21,7 +36,7 @@ use crate::config::OutputId;
 use crate::{
     config::{SourceConfig, SourceContext},
     event::{into_event_stream, Event, EventStatus, LogEvent, ObjectMap, Value},
-    sources::opentelemetry::{GrpcConfig, HttpConfig, OpentelemetryConfig, LOGS},
+    sources::opentelemetry::{GrpcConfig, HttpConfig, OpentelemetryConfig, LOGS, METRICS},
     test_util::{
         self,
         components::{assert_source_compliance, SOURCE_TAGS},
@@ -445,6 +460,275 @@ async fn http_headers() {
     .await;
 }
 
+fn string_kv(key: &str, value: &str) -> KeyValue {
+    KeyValue {
+        key: key.into(),
+        value: Some(AnyValue {
+            value: Some(any_value::Value::StringValue(value.into())),
+        }),
+    }
+}
+
+fn metrics_request(metrics: Vec<OtelMetric>) -> ExportMetricsServiceRequest {
+    ExportMetricsServiceRequest {
+        resource_metrics: vec![ResourceMetrics {
+            resource: Some(OtelResource {
+                attributes: vec![string_kv("service.name", "checkout")],
+                dropped_attributes_count: 0,
+            }),
+            scope_metrics: vec![ScopeMetrics {
+                scope: Some(InstrumentationScope {
+                    name: "some.scope.name".into(),
+                    version: "1.2.3".into(),
+                    attributes: vec![],
+                    dropped_attributes_count: 0,
+                }),
+                metrics,
+                schema_url: "v1".into(),
+            }],
+            schema_url: "v1".into(),
+        }],
+    }
+}
+
+fn data_point(value: number_data_point::Value) -> NumberDataPoint {
+    NumberDataPoint {
+        attributes: vec![string_kv("host", "a")],
+        time_unix_nano: 1,
+        value: Some(value),
+        ..Default::default()
+    }
+}
+
+fn expected_metric(name: &str, kind: MetricKind, value: MetricValue) -> Metric {
+    Metric::new(name, kind, value)
+        .with_tags(Some(metric_tags!(
+            "resources.service.name" => "checkout",
+            "scope.name" => "some.scope.name",
+            "scope.version" => "1.2.3",
+            "host" => "a",
+        )))
+        .with_timestamp(Some(Utc.timestamp_nanos(1)))
+}
+
+#[tokio::test]
+async fn receive_grpc_metrics() {
+    assert_source_compliance(&SOURCE_TAGS, async {
+        let grpc_addr = next_addr();
+        let http_addr = next_addr();
+
+        let source = OpentelemetryConfig {
+            grpc: GrpcConfig {
+                address: grpc_addr,
+                tls: Default::default(),
+            },
+            http: HttpConfig {
+                address: http_addr,
+                tls: Default::default(),
+                keepalive: Default::default(),
+                headers: Default::default(),
+            },
+            acknowledgements: Default::default(),
+            log_namespace: Default::default(),
+        };
+
+        let (sender, metrics_output, _) = new_source(EventStatus::Delivered, METRICS.to_string());
+        let server = source
+            .build(SourceContext::new_test(sender, None))
+            .await
+            .unwrap();
+        tokio::spawn(server);
+        test_util::wait_for_tcp(grpc_addr).await;
+
+        let mut client = MetricsServiceClient::connect(format!("http://{}", grpc_addr))
+            .await
+            .unwrap();
+        let req = Request::new(metrics_request(vec![
+            OtelMetric {
+                name: "requests".into(),
+                data: Some(Data::Sum(Sum {
+                    data_points: vec![data_point(number_data_point::Value::AsInt(10))],
+                    aggregation_temporality: AggregationTemporality::Cumulative as i32,
+                    is_monotonic: true,
+                })),
+                ..Default::default()
+            },
+            OtelMetric {
+                name: "queue_size".into(),
+                data: Some(Data::Gauge(Gauge {
+                    data_points: vec![data_point(number_data_point::Value::AsDouble(2.5))],
+                })),
+                ..Default::default()
+            },
+            OtelMetric {
+                name: "latency".into(),
+                data: Some(Data::Histogram(Histogram {
+                    data_points: vec![HistogramDataPoint {
+                        attributes: vec![string_kv("host", "a")],
+                        time_unix_nano: 1,
+                        count: 6,
+                        sum: Some(10.0),
+                        bucket_counts: vec![1, 2, 3],
+                        explicit_bounds: vec![1.0, 2.0],
+                        ..Default::default()
+                    }],
+                    aggregation_temporality: AggregationTemporality::Delta as i32,
+                })),
+                ..Default::default()
+            },
+            OtelMetric {
+                name: "payload_size".into(),
+                data: Some(Data::ExponentialHistogram(ExponentialHistogram {
+                    data_points: vec![ExponentialHistogramDataPoint {
+                        attributes: vec![string_kv("host", "a")],
+                        time_unix_nano: 1,
+                        count: 5,
+                        sum: Some(13.5),
+                        scale: 0,
+                        zero_count: 1,
+                        zero_threshold: 0.001,
+                        positive: Some(Buckets {
+                            offset: 1,
+                            bucket_counts: vec![4],
+                        }),
+                        ..Default::default()
+                    }],
+                    aggregation_temporality: AggregationTemporality::Delta as i32,
+                })),
+                ..Default::default()
+            },
+        ]));
+        _ = client.export(req).await;
+
+        let output = test_util::collect_ready(metrics_output).await;
+        assert_eq!(output.len(), 4);
+        let metrics = output
+            .iter()
+            .map(|event| event.as_metric())
+            .collect::<Vec<_>>();
+
+        let expected = [
+            expected_metric(
+                "requests",
+                MetricKind::Absolute,
+                MetricValue::Counter { value: 10.0 },
+            ),
+            expected_metric(
+                "queue_size",
+                MetricKind::Absolute,
+                MetricValue::Gauge { value: 2.5 },
+            ),
+            expected_metric(
+                "latency",
+                MetricKind::Incremental,
+                MetricValue::AggregatedHistogram {
+                    buckets: vec![
+                        Bucket {
+                            upper_limit: 1.0,
+                            count: 1,
+                        },
+                        Bucket {
+                            upper_limit: 2.0,
+                            count: 2,
+                        },
+                        Bucket {
+                            upper_limit: f64::INFINITY,
+                            count: 3,
+                        },
+                    ],
+                    count: 6,
+                    sum: 10.0,
+                },
+            ),
+            // The OTLP bucket `(2, 4]` at index 1 is the native histogram bucket at index 2.
+            expected_metric(
+                "payload_size",
+                MetricKind::Incremental,
+                MetricValue::Sketch {
+                    sketch: MetricSketch::NativeHistogram(NativeHistogram {
+                        schema: 0,
+                        zero_threshold: 0.001,
+                        zero_count: 1.0,
+                        count: 5.0,
+                        sum: 13.5,
+                        positive: vec![ExponentialBucket {
+                            index: 2,
+                            count: 4.0,
+                        }],
+                        negative: Vec::new(),
+                    }),
+                },
+            ),
+        ];
+        for (actual, expected) in metrics.iter().zip(expected.iter()) {
+            assert_eq!(actual.series(), expected.series());
+            assert_eq!(actual.data(), expected.data());
+        }
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn receive_http_metrics() {
+    assert_source_compliance(&SOURCE_TAGS, async {
+        let grpc_addr = next_addr();
+        let http_addr = next_addr();
+
+        let source = OpentelemetryConfig {
+            grpc: GrpcConfig {
+                address: grpc_addr,
+                tls: Default::default(),
+            },
+            http: HttpConfig {
+                address: http_addr,
+                tls: Default::default(),
+                keepalive: Default::default(),
+                headers: Default::default(),
+            },
+            acknowledgements: Default::default(),
+            log_namespace: Default::default(),
+        };
+
+        let (sender, metrics_output, _) = new_source(EventStatus::Delivered, METRICS.to_string());
+        let server = source
+            .build(SourceContext::new_test(sender, None))
+            .await
+            .unwrap();
+        tokio::spawn(server);
+        test_util::wait_for_tcp(http_addr).await;
+
+        let req = metrics_request(vec![OtelMetric {
+            name: "connections".into(),
+            data: Some(Data::Sum(Sum {
+                data_points: vec![data_point(number_data_point::Value::AsInt(-3))],
+                aggregation_temporality: AggregationTemporality::Delta as i32,
+                is_monotonic: false,
+            })),
+            ..Default::default()
+        }]);
+        let _res = reqwest::Client::new()
+            .post(format!("http://{}/v1/metrics", http_addr))
+            .header("Content-Type", "application/x-protobuf")
+            .body(req.encode_to_vec())
+            .send()
+            .await
+            .expect("Failed to send metrics to Opentelemetry Collector.");
+
+        let mut output = test_util::collect_ready(metrics_output).await;
+        assert_eq!(output.len(), 1);
+        let actual = output.pop().unwrap().into_metric();
+        // A non-monotonic sum can decrease, so it is received as a gauge.
+        let expected = expected_metric(
+            "connections",
+            MetricKind::Incremental,
+            MetricValue::Gauge { value: -3.0 },
+        );
+        assert_eq!(actual.series(), expected.series());
+        assert_eq!(actual.data(), expected.data());
+    })
+    .await;
+}
+
 pub(super) fn new_source(
     status: EventStatus,
     event_name: String,
@@ -1,13 +1,12 @@
 use std::cmp::Ordering;
 
 use chrono::{DateTime, TimeZone, Utc};
-#[cfg(feature = "sources-prometheus-remote-write")]
-use vector_lib::prometheus::parser::proto;
-use vector_lib::prometheus::parser::{GroupKind, MetricGroup, ParserError};
+use vector_lib::metrics::{ExponentialBucket, NativeHistogram};
+use vector_lib::prometheus::parser::{proto, GroupKind, MetricGroup, ParserError};
 
 use crate::event::{
     metric::{Bucket, Metric, MetricKind, MetricTags, MetricValue, Quantile},
-    Event,
+    Event, MetricExemplar,
 };
 
 fn utc_timestamp(timestamp: Option<i64>, default: DateTime<Utc>) -> DateTime<Utc> {
@@ -19,6 +18,66 @@ fn utc_timestamp(timestamp: Option<i64>, default: DateTime<Utc>) -> DateTime<Utc
         .unwrap_or(default)
 }
 
+fn with_exemplars(mut metric: Metric, exemplars: Vec<proto::Exemplar>) -> Metric {
+    if !exemplars.is_empty() {
+        let exemplars = exemplars
+            .into_iter()
+            .map(|exemplar| MetricExemplar {
+                labels: exemplar
+                    .labels
+                    .into_iter()
+                    .map(|label| (label.name, label.value))
+                    .collect(),
+                value: exemplar.value,
+                // A zero timestamp means the exemplar has none.
+                timestamp: (exemplar.timestamp != 0)
+                    .then(|| utc_timestamp(Some(exemplar.timestamp), Utc::now())),
+            })
+            .collect();
+        metric.metadata_mut().set_exemplars(exemplars);
+    }
+    metric
+}
+
+/// Converts the buckets of a native histogram, sent either as deltas between the counts of
+/// consecutive buckets for integer histograms or as absolute counts for float histograms.
+fn exponential_buckets(
+    spans: Vec<proto::BucketSpan>,
+    deltas: Vec<i64>,
+    counts: Vec<f64>,
+) -> Vec<ExponentialBucket> {
+    let spans = spans.into_iter().map(|span| (span.offset, span.length));
+    if deltas.is_empty() {
+        NativeHistogram::buckets_from_spans(spans, counts)
+    } else {
+        let counts = deltas.into_iter().scan(0_i64, |count, delta| {
+            *count += delta;
+            Some(*count as f64)
+        });
+        NativeHistogram::buckets_from_spans(spans, counts)
+    }
+}
+
+fn native_histogram(histogram: proto::Histogram) -> NativeHistogram {
+    NativeHistogram {
+        schema: histogram.schema,
+        zero_threshold: histogram.zero_threshold,
+        zero_count: histogram.zero_count_value(),
+        count: histogram.count_value(),
+        sum: histogram.sum,
+        positive: exponential_buckets(
+            histogram.positive_spans,
+            histogram.positive_deltas,
+            histogram.positive_counts,
+        ),
+        negative: exponential_buckets(
+            histogram.negative_spans,
+            histogram.negative_deltas,
+            histogram.negative_counts,
+        ),
+    }
+}
+
 #[cfg(any(test, feature = "sources-prometheus-scrape"))]
 pub(super) fn parse_text(packet: &str) -> Result<Vec<Event>, ParserError> {
     vector_lib::prometheus::parser::parse_text(packet)
@@ -71,7 +130,7 @@ fn reparse_groups(
                     .with_timestamp(Some(utc_timestamp(key.timestamp, start)))
                     .with_tags(tags.as_option());
 
-                    result.push(counter.into());
+                    result.push(with_exemplars(counter, metric.exemplars).into());
                 }
             }
             GroupKind::Gauge(metrics) | GroupKind::Untyped(metrics) => {
@@ -89,13 +148,26 @@ fn reparse_groups(
                     .with_timestamp(Some(utc_timestamp(key.timestamp, start)))
                     .with_tags(tags.as_option());
 
-                    result.push(gauge.into());
+                    result.push(with_exemplars(gauge, metric.exemplars).into());
                 }
             }
             GroupKind::Histogram(metrics) => {
                 for (key, metric) in metrics {
                     let tags = combine_tags(key.labels, tag_overrides.clone());
 
+                    if let Some(histogram) = metric.native {
+                        let histogram = Metric::new(
+                            group.name.clone(),
+                            metric_kind,
+                            native_histogram(histogram).into(),
+                        )
+                        .with_timestamp(Some(utc_timestamp(key.timestamp, start)))
+                        .with_tags(tags.as_option());
+
+                        result.push(with_exemplars(histogram, metric.exemplars).into());
+                        continue;
+                    }
+
                     let mut buckets = metric.buckets;
                     buckets.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
                     for i in (1..buckets.len()).rev() {
@@ -108,54 +180,52 @@ fn reparse_groups(
                         buckets.pop();
                     }
 
-                    result.push(
-                        Metric::new(
-                            group.name.clone(),
-                            metric_kind,
-                            MetricValue::AggregatedHistogram {
-                                buckets: buckets
-                                    .into_iter()
-                                    .map(|b| Bucket {
-                                        upper_limit: b.bucket,
-                                        count: b.count,
-                                    })
-                                    .collect(),
-                                count: metric.count,
-                                sum: metric.sum,
-                            },
-                        )
-                        .with_timestamp(Some(utc_timestamp(key.timestamp, start)))
-                        .with_tags(tags.as_option())
-                        .into(),
-                    );
+                    let histogram = Metric::new(
+                        group.name.clone(),
+                        metric_kind,
+                        MetricValue::AggregatedHistogram {
+                            buckets: buckets
+                                .into_iter()
+                                .map(|b| Bucket {
+                                    upper_limit: b.bucket,
+                                    count: b.count,
+                                })
+                                .collect(),
+                            count: metric.count,
+                            sum: metric.sum,
+                        },
+                    )
+                    .with_timestamp(Some(utc_timestamp(key.timestamp, start)))
+                    .with_tags(tags.as_option());
+
+                    result.push(with_exemplars(histogram, metric.exemplars).into());
                 }
             }
             GroupKind::Summary(metrics) => {
                 for (key, metric) in metrics {
                     let tags = combine_tags(key.labels, tag_overrides.clone());
 
-                    result.push(
-                        Metric::new(
-                            group.name.clone(),
-                            // Summaries are always absolute: aggregating them makes no sense
-                            MetricKind::Absolute,
-                            MetricValue::AggregatedSummary {
-                                quantiles: metric
-                                    .quantiles
-                                    .into_iter()
-                                    .map(|q| Quantile {
-                                        quantile: q.quantile,
-                                        value: q.value,
-                                    })
-                                    .collect(),
-                                count: metric.count,
-                                sum: metric.sum,
-                            },
-                        )
-                        .with_timestamp(Some(utc_timestamp(key.timestamp, start)))
-                        .with_tags(tags.as_option())
-                        .into(),
-                    );
+                    let summary = Metric::new(
+                        group.name.clone(),
+                        // Summaries are always absolute: aggregating them makes no sense
+                        MetricKind::Absolute,
+                        MetricValue::AggregatedSummary {
+                            quantiles: metric
+                                .quantiles
+                                .into_iter()
+                                .map(|q| Quantile {
+                                    quantile: q.quantile,
+                                    value: q.value,
+                                })
+                                .collect(),
+                            count: metric.count,
+                            sum: metric.sum,
+                        },
+                    )
+                    .with_timestamp(Some(utc_timestamp(key.timestamp, start)))
+                    .with_tags(tags.as_option());
+
+                    result.push(with_exemplars(summary, metric.exemplars).into());
                 }
             }
         }
@@ -1277,4 +1347,61 @@ mod test {
             ]),
         );
     }
+
+    #[test]
+    fn test_native_histogram() {
+        let histogram = proto::Histogram {
+            count: Some(proto::histogram::Count::CountInt(9)),
+            sum: 20.0,
+            schema: 0,
+            zero_threshold: 0.001,
+            zero_count: Some(proto::histogram::ZeroCount::ZeroCountInt(1)),
+            positive_spans: vec![
+                proto::BucketSpan {
+                    offset: 1,
+                    length: 2,
+                },
+                proto::BucketSpan {
+                    offset: 1,
+                    length: 1,
+                },
+            ],
+            positive_deltas: vec![2, 1, -2],
+            negative_spans: vec![proto::BucketSpan {
+                offset: 0,
+                length: 1,
+            }],
+            negative_counts: vec![2.0],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            native_histogram(histogram),
+            NativeHistogram {
+                schema: 0,
+                zero_threshold: 0.001,
+                zero_count: 1.0,
+                count: 9.0,
+                sum: 20.0,
+                positive: vec![
+                    ExponentialBucket {
+                        index: 1,
+                        count: 2.0
+                    },
+                    ExponentialBucket {
+                        index: 2,
+                        count: 3.0
+                    },
+                    ExponentialBucket {
+                        index: 4,
+                        count: 1.0
+                    },
+                ],
+                negative: vec![ExponentialBucket {
+                    index: 0,
+                    count: 2.0
+                }],
+            }
+        );
+    }
 }
@@ -7,13 +7,16 @@
 //!       - Implements the HttpClient trait
 //!   - Call call() supplying the generic inputs for calling and the source-specific
 //!     context.
+//!   - Optionally, page through the results by returning the URL of the next page from the
+//!     context.
 
 use bytes::Bytes;
-use futures_util::{stream, FutureExt, StreamExt, TryFutureExt};
+use futures_util::{stream, StreamExt};
 use http::{response::Parts, Uri};
 use hyper::{Body, Request};
 use std::time::Duration;
-use std::{collections::HashMap, future::ready};
+use std::{collections::HashMap, sync::Arc};
+use tokio::time::MissedTickBehavior;
 use tokio_stream::wrappers::IntervalStream;
 use vector_lib::json_size::JsonSize;
 
@@ -65,6 +68,12 @@ pub(crate) trait HttpClientBuilder {
 
     /// Called before the HTTP request is made to build out the context.
     fn build(&self, url: &Uri) -> Self::Context;
+
+    /// (Optional) Whether the scrapes share some state and must run one at a time, in which case
+    /// the intervals elapsing while a scrape is still running are skipped.
+    fn sequential(&self) -> bool {
+        false
+    }
 }
 
 /// Methods that allow context-specific behavior during the scraping procedure.
@@ -79,6 +88,23 @@ pub(crate) trait HttpClientContext {
     // metadata. This function should be used rather than internal enrichment so
     // that accurate byte count metrics can be emitted.
     fn enrich_events(&mut self, _events: &mut Vec<Event>) {}
+
+    /// (Optional) Called before the first request of a scrape to pick the URL to request, which
+    /// allows resuming from a previously saved page.
+    fn start_url(&mut self, url: &Uri) -> Uri {
+        url.clone()
+    }
+
+    /// (Optional) Called after a response has been handled to get the URL of the next page to
+    /// request in the same scrape, if any.
+    fn next_url(&mut self) -> Option<Uri> {
+        None
+    }
+
+    /// (Optional) Returns the body to send with the requests of the scrape.
+    fn request_body(&self) -> Option<Bytes> {
+        None
+    }
 }
 
 /// Builds a url for the HTTP requests.
@@ -123,9 +149,12 @@ pub(crate) fn warn_if_interval_too_low(timeout: Duration, interval: Duration) {
 ///   - The HTTP request is built per the options in provided generic inputs.
 ///   - The HTTP response is decoded/parsed into events by the specific context.
 ///   - The events are then sent to the output stream.
+///   - If the context returns a next page URL, that page is requested in turn, within the same
+///     scrape.
+///   - Scrapes run concurrently, unless the context builder requires them to be sequential.
 pub(crate) async fn call<
     B: HttpClientBuilder<Context = C> + Send + Clone,
-    C: HttpClientContext + Send,
+    C: HttpClientContext + Send + 'static,
 >(
     inputs: GenericHttpClientInputs,
     context_builder: B,
@@ -136,122 +165,50 @@ pub(crate) async fn call<
     // proxy and tls settings.
     let client =
         HttpClient::new(inputs.tls.clone(), &inputs.proxy).expect("Building HTTP client failed");
-    let mut stream = IntervalStream::new(tokio::time::interval(inputs.interval))
+    let options = Arc::new(RequestOptions {
+        method: http_method,
+        timeout: inputs.timeout,
+        headers: inputs.headers,
+        content_type: inputs.content_type,
+        auth: inputs.auth,
+    });
+    let urls = inputs.urls;
+    let sequential = context_builder.sequential();
+    let mut interval = tokio::time::interval(inputs.interval);
+    if sequential {
+        interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+    }
+    let scrapes = IntervalStream::new(interval)
         .take_until(inputs.shutdown)
-        .map(move |_| stream::iter(inputs.urls.clone()))
+        .map(move |_| stream::iter(urls.clone()))
         .flatten()
         .map(move |url| {
             let client = client.clone();
-            let endpoint = url.to_string();
+            let options = Arc::clone(&options);
 
             let context_builder = context_builder.clone();
             let mut context = context_builder.build(&url);
+            let first_url = context.start_url(&url);
 
-            let mut builder = match http_method {
-                HttpMethod::Head => Request::head(&url),
-                HttpMethod::Get => Request::get(&url),
-                HttpMethod::Post => Request::post(&url),
-                HttpMethod::Put => Request::put(&url),
-                HttpMethod::Patch => Request::patch(&url),
-                HttpMethod::Delete => Request::delete(&url),
-                HttpMethod::Options => Request::options(&url),
-            };
-
-            // add user specified headers
-            for (header, values) in &inputs.headers {
-                for value in values {
-                    builder = builder.header(header, value);
+            stream::unfold((Some(first_url), context), move |(url, mut context)| {
+                let client = client.clone();
+                let options = Arc::clone(&options);
+                async move {
+                    let url = url?;
+                    let events = fetch_page(&client, &options, &url, &mut context).await;
+                    let next_url = events.as_ref().and_then(|_| context.next_url());
+                    Some((events.unwrap_or_default(), (next_url, context)))
                 }
-            }
-
-            // set ACCEPT header if not user specified
-            if !inputs.headers.contains_key(http::header::ACCEPT.as_str()) {
-                builder = builder.header(http::header::ACCEPT, &inputs.content_type);
-            }
-
-            // building an empty request should be infallible
-            let mut request = builder.body(Body::empty()).expect("error creating request");
-
-            if let Some(auth) = &inputs.auth {
-                auth.apply(&mut request);
-            }
-
-            tokio::time::timeout(inputs.timeout, client.send(request))
-                .then(move |result| async move {
-                    match result {
-                        Ok(Ok(response)) => Ok(response),
-                        Ok(Err(error)) => Err(error.into()),
-                        Err(_) => Err(format!(
-                            "Timeout error: request exceeded {}s",
-                            inputs.timeout.as_secs_f64()
-                        )
-                        .into()),
-                    }
-                })
-                .and_then(|response| async move {
-                    let (header, body) = response.into_parts();
-                    let body = hyper::body::to_bytes(body).await?;
-                    emit!(EndpointBytesReceived {
-                        byte_size: body.len(),
-                        protocol: "http",
-                        endpoint: endpoint.as_str(),
-                    });
-                    Ok((header, body))
-                })
-                .into_stream()
-                .filter_map(move |response| {
-                    ready(match response {
-                        Ok((header, body)) if header.status == hyper::StatusCode::OK => {
-                            context.on_response(&url, &header, &body).map(|mut events| {
-                                let byte_size = if events.is_empty() {
-                                    // We need to explicitly set the byte size
-                                    // to 0 since
-                                    // `estimated_json_encoded_size_of` returns
-                                    // at least 1 for an empty collection. For
-                                    // the purposes of the
-                                    // HttpClientEventsReceived event, we should
-                                    // emit 0 when there aren't any usable
-                                    // metrics.
-                                    JsonSize::zero()
-                                } else {
-                                    events.estimated_json_encoded_size_of()
-                                };
-
-                                emit!(HttpClientEventsReceived {
-                                    byte_size,
-                                    count: events.len(),
-                                    url: url.to_string()
-                                });
-
-                                // We'll enrich after receiving the events so
-                                // that the byte sizes are accurate.
-                                context.enrich_events(&mut events);
-
-                                stream::iter(events)
-                            })
-                        }
-                        Ok((header, _)) => {
-                            context.on_http_response_error(&url, &header);
-                            emit!(HttpClientHttpResponseError {
-                                code: header.status,
-                                url: url.to_string(),
-                            });
-                            None
-                        }
-                        Err(error) => {
-                            emit!(HttpClientHttpError {
-                                error,
-                                url: url.to_string()
-                            });
-                            None
-                        }
-                    })
-                })
-                .flatten()
-                .boxed()
-        })
-        .flatten_unordered(None)
-        .boxed();
+            })
+            .map(stream::iter)
+            .flatten()
+            .boxed()
+        });
+    let mut stream = if sequential {
+        scrapes.flatten().boxed()
+    } else {
+        scrapes.flatten_unordered(None).boxed()
+    };
 
     match out.send_event_stream(&mut stream).await {
         Ok(()) => {
@@ -265,3 +222,121 @@ pub(crate) async fn call<
         }
     }
 }
+
+/// The parts of the generic inputs needed to issue each request.
+struct RequestOptions {
+    method: HttpMethod,
+    timeout: Duration,
+    headers: HashMap<String, Vec<String>>,
+    content_type: String,
+    auth: Option<Auth>,
+}
+
+/// Requests a single page, returning the events decoded from the response, or `None` if the
+/// request failed or the response could not be handled.
+async fn fetch_page<C: HttpClientContext>(
+    client: &HttpClient,
+    options: &RequestOptions,
+    url: &Uri,
+    context: &mut C,
+) -> Option<Vec<Event>> {
+    let endpoint = url.to_string();
+
+    let mut builder = match options.method {
+        HttpMethod::Head => Request::head(url),
+        HttpMethod::Get => Request::get(url),
+        HttpMethod::Post => Request::post(url),
+        HttpMethod::Put => Request::put(url),
+        HttpMethod::Patch => Request::patch(url),
+        HttpMethod::Delete => Request::delete(url),
+        HttpMethod::Options => Request::options(url),
+    };
+
+    // add user specified headers
+    for (header, values) in &options.headers {
+        for value in values {
+            builder = builder.header(header, value);
+        }
+    }
+
+    // set ACCEPT header if not user specified
+    if !options.headers.contains_key(http::header::ACCEPT.as_str()) {
+        builder = builder.header(http::header::ACCEPT, &options.content_type);
+    }
+
+    let body = context.request_body().map_or_else(Body::empty, Body::from);
+
+    // building a request from a valid URL should be infallible
+    let mut request = builder.body(body).expect("error creating request");
+
+    if let Some(auth) = &options.auth {
+        auth.apply(&mut request);
+    }
+
+    let response: crate::Result<(Parts, Bytes)> = async {
+        let response = match tokio::time::timeout(options.timeout, client.send(request)).await {
+            Ok(Ok(response)) => response,
+            Ok(Err(error)) => return Err(error.into()),
+            Err(_) => {
+                return Err(format!(
+                    "Timeout error: request exceeded {}s",
+                    options.timeout.as_secs_f64()
+                )
+                .into())
+            }
+        };
+        let (header, body) = response.into_parts();
+        let body = hyper::body::to_bytes(body).await?;
+        emit!(EndpointBytesReceived {
+            byte_size: body.len(),
+            protocol: "http",
+            endpoint: endpoint.as_str(),
+        });
+        Ok((header, body))
+    }
+    .await;
+
+    match response {
+        Ok((header, body)) if header.status == hyper::StatusCode::OK => {
+            context.on_response(url, &header, &body).map(|mut events| {
+                let byte_size = if events.is_empty() {
+                    // We need to explicitly set the byte size to 0 since
+                    // `estimated_json_encoded_size_of` returns at least 1 for an
+                    // empty collection. For the purposes of the
+                    // HttpClientEventsReceived event, we should emit 0 when there
+                    // aren't any usable metrics.
+                    JsonSize::zero()
+                } else {
+                    events.estimated_json_encoded_size_of()
+                };
+
+                emit!(HttpClientEventsReceived {
+                    byte_size,
+                    count: events.len(),
+                    url: endpoint.clone()
+                });
+
+                // We'll enrich after receiving the events so that the byte sizes
+                // are accurate.
+                context.enrich_events(&mut events);
+
+                events
+            })
+        }
+        Ok((header, _)) => {
+            context.on_http_response_error(url, &header);
+            emit!(HttpClientHttpResponseError {
+                code: header.status,
+                url: endpoint,
+            });
+            None
+        }
+        Err(error) => {
+            emit!(HttpClientHttpError {
+                error,
+                url: endpoint,
+            });
+            None
+        }
+    }
+}
@@ -0,0 +1,800 @@
+use std::net::SocketAddr;
+
+use bytes::BytesMut;
+use chrono::Utc;
+use futures::{
+    future::{self, Either},
+    stream::FuturesOrdered,
+    SinkExt, StreamExt,
+};
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::{
+    handshake::server::{ErrorResponse, Request, Response},
+    protocol::Message,
+};
+use tokio_util::codec::Decoder as _;
+use tracing::Instrument;
+use vector_lib::codecs::decoding::{DeserializerConfig, FramingConfig};
+use vector_lib::configurable::configurable_component;
+use vector_lib::internal_event::{
+    ByteSize, BytesReceived, CountByteSize, EventsReceived, InternalEventHandle as _, Protocol,
+};
+use vector_lib::lookup::{lookup_v2::OptionalValuePath, owned_value_path, path};
+use vector_lib::{
+    config::{LegacyKey, LogNamespace},
+    schema::Definition,
+    EstimatedJsonEncodedSizeOf,
+};
+use vrl::value::{kind::Collection, Kind};
+use warp::http::HeaderMap;
+
+use crate::{
+    codecs::{Decoder, DecodingConfig},
+    config::{
+        log_schema, GenerateConfig, Resource, SourceAcknowledgementsConfig, SourceConfig,
+        SourceContext, SourceOutput,
+    },
+    event::{BatchNotifier, BatchStatus, Event},
+    internal_events::{
+        ConnectionOpen, OpenGauge, SocketBindError, SocketMode, SocketReceiveError,
+        StreamClosedError, TcpSocketTlsConnectionError, WsServerHandshakeError,
+        WsServerReceiveError, WsServerSendError,
+    },
+    serde::{bool_or_struct, default_decoding, default_framing_message_based},
+    shutdown::ShutdownSignal,
+    sources::{
+        http_server::{build_param_matcher, remove_duplicates, HttpConfigParamKind},
+        util::{
+            add_headers,
+            http::{HttpSourceAuth, HttpSourceAuthConfig},
+        },
+    },
+    tls::{MaybeTlsIncomingStream, MaybeTlsSettings, TlsEnableableConfig},
+    SourceSender,
+};
+
+/// Configuration for the `websocket_server` source.
+///
+/// When acknowledgements are enabled, each message received on a connection is answered, in
+/// order, with a text message such as `{"seq":1,"status":"delivered"}`, where `seq` counts the
+/// messages received on the connection, starting at 1, and `status` is either `delivered`,
+/// `errored`, or `rejected`. Messages that can't be decoded are `rejected`.
+#[configurable_component(source(
+    "websocket_server",
+    "Receive events from clients over WebSocket connections."
+))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct WebSocketServerConfig {
+    /// The socket address to listen for connections on.
+    ///
+    /// It _must_ include a port.
+    #[configurable(metadata(docs::examples = "0.0.0.0:8080"))]
+    #[configurable(metadata(docs::examples = "localhost:8080"))]
+    address: SocketAddr,
+
+    #[configurable(derived)]
+    tls: Option<TlsEnableableConfig>,
+
+    #[configurable(derived)]
+    auth: Option<HttpSourceAuthConfig>,
+
+    /// A list of HTTP headers of the WebSocket handshake request to include in the log events.
+    ///
+    /// Accepts the wildcard (`*`) character for headers matching a specified pattern.
+    ///
+    /// Specifying "*" results in all headers included in the log event.
+    ///
+    /// These headers are not included in the JSON payload if a field with a conflicting name exists.
+    #[serde(default)]
+    #[configurable(metadata(docs::examples = "User-Agent"))]
+    #[configurable(metadata(docs::examples = "X-My-Custom-Header"))]
+    #[configurable(metadata(docs::examples = "X-*"))]
+    #[configurable(metadata(docs::examples = "*"))]
+    headers: Vec<String>,
+
+    /// The event key in which the URL path of the WebSocket handshake request is stored.
+    #[serde(default = "default_path_key")]
+    #[configurable(metadata(docs::examples = "vector_websocket_path"))]
+    path_key: OptionalValuePath,
+
+    /// Overrides the name of the log field used to add the IP address of the client to each event.
+    ///
+    /// By default, the [global `log_schema.host_key` option][global_host_key] is used.
+    ///
+    /// Set to `""` to suppress this key.
+    ///
+    /// [global_host_key]: https://vector.dev/docs/reference/configuration/global-options/#log_schema.host_key
+    host_key: Option<OptionalValuePath>,
+
+    /// The maximum number of WebSocket connections that are allowed at any given time.
+    #[configurable(metadata(docs::type_unit = "connections"))]
+    connection_limit: Option<u32>,
+
+    #[configurable(derived)]
+    #[serde(default = "default_framing_message_based")]
+    framing: FramingConfig,
+
+    #[configurable(derived)]
+    #[serde(default = "default_decoding")]
+    decoding: DeserializerConfig,
+
+    #[configurable(derived)]
+    #[serde(default, deserialize_with = "bool_or_struct")]
+    acknowledgements: SourceAcknowledgementsConfig,
+
+    /// The namespace to use for logs. This overrides the global setting.
+    #[configurable(metadata(docs::hidden))]
+    #[serde(default)]
+    log_namespace: Option<bool>,
+}
+
+/// The maximum number of messages of a connection awaiting their acknowledgement, past which no
+/// more messages are read from the connection until some of them are acknowledged.
+const MAX_PENDING_ACKNOWLEDGEMENTS: usize = 1024;
+
+fn default_path_key() -> OptionalValuePath {
+    OptionalValuePath::from(owned_value_path!("path"))
+}
+
+impl Default for WebSocketServerConfig {
+    fn default() -> Self {
+        Self {
+            address: "0.0.0.0:8080".parse().unwrap(),
+            tls: None,
+            auth: None,
+            headers: Vec::new(),
+            path_key: default_path_key(),
+            host_key: None,
+            connection_limit: None,
+            framing: default_framing_message_based(),
+            decoding: default_decoding(),
+            acknowledgements: SourceAcknowledgementsConfig::default(),
+            log_namespace: None,
+        }
+    }
+}
+
+impl GenerateConfig for WebSocketServerConfig {
+    fn generate_config() -> toml::Value {
+        toml::Value::try_from(Self::default()).unwrap()
+    }
+}
+
+impl WebSocketServerConfig {
+    fn host_key(&self) -> OptionalValuePath {
+        self.host_key
+            .clone()
+            .unwrap_or(log_schema().host_key().cloned().into())
+    }
+
+    fn schema_definition(&self, log_namespace: LogNamespace) -> Definition {
+        let mut schema_definition = self
+            .decoding
+            .schema_definition(log_namespace)
+            .with_source_metadata(
+                Self::NAME,
+                self.path_key.path.clone().map(LegacyKey::InsertIfEmpty),
+                &owned_value_path!("path"),
+                Kind::bytes(),
+                None,
+            )
+            // for metadata that is added to the events dynamically from the self.headers
+            .with_source_metadata(
+                Self::NAME,
+                None,
+                &owned_value_path!("headers"),
+                Kind::object(Collection::empty().with_unknown(Kind::bytes())).or_undefined(),
+                None,
+            )
+            .with_source_metadata(
+                Self::NAME,
+                self.host_key().path.map(LegacyKey::Overwrite),
+                &owned_value_path!("host"),
+                Kind::bytes(),
+                Some("host"),
+            )
+            .with_standard_vector_source_metadata();
+
+        // for metadata that is added to the events dynamically from the self.headers
+        if log_namespace == LogNamespace::Legacy {
+            schema_definition = schema_definition.unknown_fields(Kind::bytes());
+        }
+
+        schema_definition
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "websocket_server")]
+impl SourceConfig for WebSocketServerConfig {
+    async fn build(&self, cx: SourceContext) -> crate::Result<super::Source> {
+        let log_namespace = cx.log_namespace(self.log_namespace);
+        let decoder =
+            DecodingConfig::new(self.framing.clone(), self.decoding.clone(), log_namespace)
+                .build()?;
+        let tls = MaybeTlsSettings::from_config(&self.tls, true)?;
+
+        let server = WebSocketServer {
+            auth: HttpSourceAuth::try_from(self.auth.as_ref())?,
+            headers: build_param_matcher(&remove_duplicates(self.headers.clone(), "headers"))?,
+            path_key: self.path_key.clone(),
+            host_key: self.host_key(),
+            decoder,
+            acknowledgements: cx.do_acknowledgements(self.acknowledgements),
+            log_namespace,
+        };
+
+        Ok(Box::pin(server.run(
+            self.address,
+            tls,
+            self.connection_limit,
+            cx.shutdown,
+            cx.out,
+        )))
+    }
+
+    fn outputs(&self, global_log_namespace: LogNamespace) -> Vec<SourceOutput> {
+        let log_namespace = global_log_namespace.merge(self.log_namespace);
+
+        vec![SourceOutput::new_maybe_logs(
+            self.decoding.output_type(),
+            self.schema_definition(log_namespace),
+        )]
+    }
+
+    fn resources(&self) -> Vec<Resource> {
+        vec![Resource::tcp(self.address)]
+    }
+
+    fn can_acknowledge(&self) -> bool {
+        true
+    }
+}
+
+#[derive(Clone)]
+struct WebSocketServer {
+    auth: HttpSourceAuth,
+    headers: Vec<HttpConfigParamKind>,
+    path_key: OptionalValuePath,
+    host_key: OptionalValuePath,
+    decoder: Decoder,
+    acknowledgements: bool,
+    log_namespace: LogNamespace,
+}
+
+/// The URL path and headers of the request a WebSocket connection was opened with.
+#[derive(Default)]
+struct HandshakeRequest {
+    path: String,
+    headers: HeaderMap,
+}
+
+impl WebSocketServer {
+    async fn run(
+        self,
+        address: SocketAddr,
+        tls: MaybeTlsSettings,
+        connection_limit: Option<u32>,
+        shutdown: ShutdownSignal,
+        out: SourceSender,
+    ) -> Result<(), ()> {
+        let listener = tls.bind(&address).await.map_err(|error| {
+            emit!(SocketBindError {
+                mode: SocketMode::Tcp,
+                error: &error,
+            })
+        })?;
+
+        info!(
+            message = "Listening.",
+            addr = %listener.local_addr().unwrap_or(address)
+        );
+
+        let connection_gauge = OpenGauge::new();
+
+        listener
+            .accept_stream_limited(connection_limit)
+            .take_until(shutdown.clone())
+            .for_each(|(connection, connection_permit)| {
+                let socket = match connection {
+                    Ok(socket) => socket,
+                    Err(error) => {
+                        emit!(SocketReceiveError {
+                            mode: SocketMode::Tcp,
+                            error: &error
+                        });
+                        return futures::future::ready(());
+                    }
+                };
+
+                let peer_addr = socket.peer_addr();
+                let span = info_span!("connection", %peer_addr);
+                let open_token = connection_gauge.open(|count| emit!(ConnectionOpen { count }));
+                let server = self.clone();
+                let shutdown = shutdown.clone();
+                let out = out.clone();
+
+                tokio::spawn(
+                    async move {
+                        debug!(message = "Accepted a new connection.", %peer_addr);
+                        server
+                            .handle_connection(socket, peer_addr, shutdown, out)
+                            .await;
+                        drop(open_token);
+                        drop(connection_permit);
+                    }
+                    .instrument(span.or_current()),
+                );
+                futures::future::ready(())
+            })
+            .await;
+
+        Ok(())
+    }
+
+    async fn handle_connection(
+        &self,
+        mut socket: MaybeTlsIncomingStream<TcpStream>,
+        peer_addr: SocketAddr,
+        mut shutdown: ShutdownSignal,
+        mut out: SourceSender,
+    ) {
+        tokio::select! {
+            result = socket.handshake() => {
+                if let Err(error) = result {
+                    emit!(TcpSocketTlsConnectionError { error });
+                    return;
+                }
+            },
+            _ = &mut shutdown => return,
+        };
+
+        let mut request = HandshakeRequest::default();
+        let callback = |handshake: &Request, response: Response| {
+            let authorization = handshake
+                .headers()
+                .get("authorization")
+                .and_then(|value| value.to_str().ok())
+                .map(ToOwned::to_owned);
+            if let Err(error) = self.auth.is_valid(&authorization) {
+                let mut response = ErrorResponse::new(Some(error.to_string()));
+                *response.status_mut() = error.status_code();
+                return Err(response);
+            }
+
+            request.path = handshake.uri().path().to_owned();
+            request.headers = handshake.headers().clone();
+            Ok(response)
+        };
+        let mut stream = match tokio_tungstenite::accept_hdr_async(socket, callback).await {
+            Ok(stream) => stream,
+            Err(error) => {
+                emit!(WsServerHandshakeError { error, peer_addr });
+                return;
+            }
+        };
+
+        let bytes_received = register!(BytesReceived::from(Protocol::from("websocket")));
+        let events_received = register!(EventsReceived);
+        // The acknowledgements of the messages, in the order they were received.
+        let mut pending = FuturesOrdered::new();
+        let mut acknowledged: u64 = 0;
+
+        loop {
+            tokio::select! {
+                // Messages keep being read, and pings answered, while their acknowledgements are
+                // pending, up to a limit.
+                message = stream.next(), if pending.len() < MAX_PENDING_ACKNOWLEDGEMENTS => {
+                    let data = match message {
+                        Some(Ok(Message::Text(text))) => text.into_bytes(),
+                        Some(Ok(Message::Binary(data))) => data,
+                        // Pings are answered, and the closing handshake completed, by the stream
+                        // itself.
+                        Some(Ok(_)) => continue,
+                        Some(Err(error)) => {
+                            emit!(WsServerReceiveError { error });
+                            break;
+                        }
+                        None => break,
+                    };
+                    bytes_received.emit(ByteSize(data.len()));
+
+                    let status = match self.build_events(&data) {
+                        Some(mut events) => {
+                            let count = events.len();
+                            events_received.emit(CountByteSize(
+                                count,
+                                events.estimated_json_encoded_size_of(),
+                            ));
+                            self.enrich_events(&mut events, &request, peer_addr);
+
+                            let (batch, receiver) =
+                                BatchNotifier::maybe_new_with_receiver(self.acknowledgements);
+                            if let Some(batch) = batch {
+                                for event in &mut events {
+                                    event.add_batch_notifier(batch.clone());
+                                }
+                            }
+
+                            if out.send_batch(events).await.is_err() {
+                                emit!(StreamClosedError { count });
+                                break;
+                            }
+                            receiver.map(Either::Left)
+                        }
+                        None => self
+                            .acknowledgements
+                            .then(|| Either::Right(future::ready(BatchStatus::Rejected))),
+                    };
+                    if let Some(status) = status {
+                        pending.push_back(status);
+                    }
+                }
+                Some(status) = pending.next(), if !pending.is_empty() => {
+                    acknowledged += 1;
+                    let ack = serde_json::json!({
+                        "seq": acknowledged,
+                        "status": ack_status(status),
+                    });
+                    if let Err(error) = stream.send(Message::Text(ack.to_string())).await {
+                        emit!(WsServerSendError { error });
+                        break;
+                    }
+                }
+                // A sink may hold on to the events until Vector stops, so pending acknowledgements
+                // aren't waited for past shutdown.
+                _ = &mut shutdown => {
+                    // The connection may already be gone, in which case there is nothing left to
+                    // close.
+                    _ = stream.close(None).await;
+                    break;
+                }
+            }
+        }
+
+        debug!("Connection closed.");
+    }
+
+    /// Decodes the events of a message, or returns `None` if it can't be decoded.
+    fn build_events(&self, data: &[u8]) -> Option<Vec<Event>> {
+        let mut decoder = self.decoder.clone();
+        let mut events = Vec::new();
+        let mut bytes = BytesMut::from(data);
+
+        loop {
+            match decoder.decode_eof(&mut bytes) {
+                Ok(Some((next, _))) => events.extend(next),
+                Ok(None) => break,
+                // Error is logged / emitted by `crate::codecs::Decoder`, no further
+                // handling is needed here
+                Err(_) => return None,
+            }
+        }
+
+        Some(events)
+    }
+
+    /// Enriches the log events with the metadata of the connection.
+    fn enrich_events(
+        &self,
+        events: &mut [Event],
+        request: &HandshakeRequest,
+        peer_addr: SocketAddr,
+    ) {
+        let now = Utc::now();
+        for event in events.iter_mut() {
+            if let Event::Log(log) = event {
+                self.log_namespace.insert_standard_vector_source_metadata(
+                    log,
+                    WebSocketServerConfig::NAME,
+                    now,
+                );
+
+                self.log_namespace.insert_source_metadata(
+                    WebSocketServerConfig::NAME,
+                    log,
+                    self.path_key.path.as_ref().map(LegacyKey::InsertIfEmpty),
+                    path!("path"),
+                    request.path.clone(),
+                );
+
+                self.log_namespace.insert_source_metadata(
+                    WebSocketServerConfig::NAME,
+                    log,
+                    self.host_key.path.as_ref().map(LegacyKey::Overwrite),
+                    path!("host"),
+                    peer_addr.ip().to_string(),
+                );
+            }
+        }
+
+        add_headers(
+            events,
+            &self.headers,
+            &request.headers,
+            self.log_namespace,
+            WebSocketServerConfig::NAME,
+        );
+    }
+}
+
+const fn ack_status(status: BatchStatus) -> &'static str {
+    match status {
+        BatchStatus::Delivered => "delivered",
+        BatchStatus::Errored => "errored",
+        BatchStatus::Rejected => "rejected",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+
+    use futures::{SinkExt, Stream, StreamExt};
+    use tokio_tungstenite::{
+        connect_async,
+        tungstenite::{client::IntoClientRequest, protocol::Message},
+    };
+    use vector_lib::codecs::decoding::{
+        FramingConfig, JsonDeserializerConfig, NewlineDelimitedDecoderConfig,
+    };
+    use vrl::value;
+
+    use super::WebSocketServerConfig;
+    use crate::{
+        config::{SourceConfig, SourceContext},
+        event::{Event, EventStatus},
+        sources::util::HttpSourceAuthConfig,
+        test_util::{
+            collect_n,
+            components::{assert_source_compliance, SOCKET_PUSH_SOURCE_TAGS},
+            next_addr, wait_for_tcp,
+        },
+        SourceSender,
+    };
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<WebSocketServerConfig>();
+    }
+
+    async fn source(
+        config: WebSocketServerConfig,
+        status: EventStatus,
+    ) -> (impl Stream<Item = Event>, SocketAddr) {
+        let (sender, recv) = SourceSender::new_test_finalize(status);
+        let address = next_addr();
+        let context = SourceContext::new_test(sender, None);
+
+        tokio::spawn(async move {
+            WebSocketServerConfig { address, ..config }
+                .build(context)
+                .await
+                .unwrap()
+                .await
+                .unwrap();
+        });
+        wait_for_tcp(address).await;
+        (recv, address)
+    }
+
+    #[tokio::test]
+    async fn websocket_server_receives_messages() {
+        assert_source_compliance(&SOCKET_PUSH_SOURCE_TAGS, async {
+            let (rx, address) = source(
+                WebSocketServerConfig {
+                    headers: vec!["User-Agent".to_owned()],
+                    framing: NewlineDelimitedDecoderConfig::new().into(),
+                    ..Default::default()
+                },
+                EventStatus::Delivered,
+            )
+            .await;
+
+            let mut request = format!("ws://{address}/telemetry")
+                .into_client_request()
+                .unwrap();
+            request
+                .headers_mut()
+                .insert("User-Agent", "test-client".parse().unwrap());
+            let (mut client, _) = connect_async(request).await.unwrap();
+            client
+                .send(Message::Text("first\nsecond".to_owned()))
+                .await
+                .unwrap();
+            client
+                .send(Message::Binary(b"third".to_vec()))
+                .await
+                .unwrap();
+
+            let events = collect_n(rx, 3).await;
+            let messages = events
+                .iter()
+                .map(|event| event.as_log()["message"].clone())
+                .collect::<Vec<_>>();
+            assert_eq!(
+                messages,
+                vec!["first".into(), "second".into(), "third".into()]
+            );
+
+            let log = events[0].as_log();
+            assert_eq!(log["path"], "/telemetry".into());
+            assert_eq!(log["host"], "127.0.0.1".into());
+            assert_eq!(log["User-Agent"], "test-client".into());
+            assert_eq!(log["source_type"], "websocket_server".into());
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn websocket_server_vector_namespace() {
+        let (rx, address) = source(
+            WebSocketServerConfig {
+                decoding: JsonDeserializerConfig::default().into(),
+                log_namespace: Some(true),
+                ..Default::default()
+            },
+            EventStatus::Delivered,
+        )
+        .await;
+
+        let (mut client, _) = connect_async(format!("ws://{address}/")).await.unwrap();
+        client
+            .send(Message::Text(r#"{"key":"value"}"#.to_owned()))
+            .await
+            .unwrap();
+
+        let events = collect_n(rx, 1).await;
+        let log = events[0].as_log();
+        assert_eq!(*log.value(), value!({"key": "value"}));
+
+        let meta = log.metadata().value();
+        assert_eq!(
+            meta.get(vrl::path!("websocket_server", "path")).unwrap(),
+            &value!("/")
+        );
+        assert_eq!(
+            meta.get(vrl::path!("websocket_server", "host")).unwrap(),
+            &value!("127.0.0.1")
+        );
+        assert_eq!(
+            meta.get(vrl::path!("vector", "source_type")).unwrap(),
+            &value!("websocket_server")
+        );
+    }
+
+    #[tokio::test]
+    async fn websocket_server_acknowledgements() {
+        for (status, expected) in [
+            (EventStatus::Delivered, "delivered"),
+            (EventStatus::Rejected, "rejected"),
+        ] {
+            let (rx, address) = source(
+                WebSocketServerConfig {
+                    acknowledgements: true.into(),
+                    ..Default::default()
+                },
+                status,
+            )
+            .await;
+
+            let (mut client, _) = connect_async(format!("ws://{address}/")).await.unwrap();
+            client
+                .send(Message::Text("hello".to_owned()))
+                .await
+                .unwrap();
+
+            let events = collect_n(rx, 1).await;
+            assert_eq!(events.len(), 1);
+
+            let ack = client.next().await.unwrap().unwrap();
+            let ack: serde_json::Value = serde_json::from_str(ack.to_text().unwrap()).unwrap();
+            assert_eq!(ack, serde_json::json!({ "seq": 1, "status": expected }));
+        }
+    }
+
+    #[tokio::test]
+    async fn websocket_server_reads_messages_while_acknowledgements_are_pending() {
+        let (sender, mut rx) = SourceSender::new_test();
+        let address = next_addr();
+        let context = SourceContext::new_test(sender, None);
+        tokio::spawn(async move {
+            WebSocketServerConfig {
+                address,
+                acknowledgements: true.into(),
+                ..Default::default()
+            }
+            .build(context)
+            .await
+            .unwrap()
+            .await
+            .unwrap();
+        });
+        wait_for_tcp(address).await;
+
+        let (mut client, _) = connect_async(format!("ws://{address}/")).await.unwrap();
+        for message in ["first", "second"] {
+            client
+                .send(Message::Text(message.to_owned()))
+                .await
+                .unwrap();
+        }
+        client.send(Message::Ping(b"ping".to_vec())).await.unwrap();
+
+        // Both messages are read, and the ping answered, before any of them is acknowledged.
+        let mut events = vec![rx.next().await.unwrap(), rx.next().await.unwrap()];
+        let pong = client.next().await.unwrap().unwrap();
+        assert_eq!(pong, Message::Pong(b"ping".to_vec()));
+
+        for event in &mut events {
+            event.metadata_mut().update_status(EventStatus::Delivered);
+        }
+        drop(events);
+
+        for seq in 1..=2 {
+            let ack = client.next().await.unwrap().unwrap();
+            let ack: serde_json::Value = serde_json::from_str(ack.to_text().unwrap()).unwrap();
+            assert_eq!(
+                ack,
+                serde_json::json!({ "seq": seq, "status": "delivered" })
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn websocket_server_rejects_undecodable_messages() {
+        let (_rx, address) = source(
+            WebSocketServerConfig {
+                framing: FramingConfig::Bytes,
+                decoding: JsonDeserializerConfig::default().into(),
+                acknowledgements: true.into(),
+                ..Default::default()
+            },
+            EventStatus::Delivered,
+        )
+        .await;
+
+        let (mut client, _) = connect_async(format!("ws://{address}/")).await.unwrap();
+        client
+            .send(Message::Text("not json".to_owned()))
+            .await
+            .unwrap();
+
+        let ack = client.next().await.unwrap().unwrap();
+        let ack: serde_json::Value = serde_json::from_str(ack.to_text().unwrap()).unwrap();
+        assert_eq!(ack, serde_json::json!({ "seq": 1, "status": "rejected" }));
+    }
+
+    #[tokio::test]
+    async fn websocket_server_auth() {
+        let (_rx, address) = source(
+            WebSocketServerConfig {
+                auth: Some(HttpSourceAuthConfig {
+                    username: "user".to_owned(),
+                    password: "password".to_owned().into(),
+                }),
+                ..Default::default()
+            },
+            EventStatus::Delivered,
+        )
+        .await;
+
+        let error = connect_async(format!("ws://{address}/")).await.unwrap_err();
+        assert!(
+            matches!(
+                error,
+                tokio_tungstenite::tungstenite::Error::Http(ref response)
+                    if response.status() == 401
+            ),
+            "{error}"
+        );
+
+        let mut request = format!("ws://{address}/").into_client_request().unwrap();
+        request.headers_mut().insert(
+            "Authorization",
+            "Basic dXNlcjpwYXNzd29yZA==".parse().unwrap(),
+        );
+        connect_async(request).await.unwrap();
+    }
+}
@@ -10,7 +10,7 @@ use crate::{source_sender::SourceSenderItem, SourceSender};
 use self::{
     sinks::{
         BackpressureSinkConfig, BasicSinkConfig, ErrorSinkConfig, OneshotSinkConfig,
-        PanicSinkConfig,
+        PanicSinkConfig, RejectSinkConfig,
     },
     sources::{
         BackpressureSourceConfig, BasicSourceConfig, ErrorSourceConfig, PanicSourceConfig,
@@ -108,3 +108,7 @@ pub fn oneshot_sink(tx: Sender<EventArray>) -> OneshotSinkConfig {
 pub fn panic_sink() -> PanicSinkConfig {
     PanicSinkConfig::default()
 }
+
+pub fn reject_sink() -> RejectSinkConfig {
+    RejectSinkConfig::default()
+}
@@ -12,3 +12,6 @@ pub use self::oneshot::OneshotSinkConfig;
 
 mod panic;
 pub use self::panic::PanicSinkConfig;
+
+mod reject;
+pub use self::reject::RejectSinkConfig;
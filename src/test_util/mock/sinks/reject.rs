@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use futures_util::{future::ok, stream::BoxStream, FutureExt, StreamExt};
+use vector_lib::configurable::configurable_component;
+use vector_lib::finalization::Finalizable;
+use vector_lib::{
+    config::{AcknowledgementsConfig, Input},
+    event::{DeliveryError, Event, EventStatus},
+    sink::{StreamSink, VectorSink},
+};
+
+use crate::{
+    config::{SinkConfig, SinkContext},
+    sinks::Healthcheck,
+};
+
+/// Configuration for the `test_reject` sink.
+#[configurable_component(sink("test_reject", "Test (reject)."))]
+#[derive(Clone, Debug, Default)]
+pub struct RejectSinkConfig {
+    /// Dummy field used for generating unique configurations to trigger reloads.
+    dummy: Option<String>,
+}
+
+impl_generate_config_from_default!(RejectSinkConfig);
+
+#[async_trait]
+#[typetag::serde(name = "test_reject")]
+impl SinkConfig for RejectSinkConfig {
+    async fn build(&self, _cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
+        Ok((
+            VectorSink::from_event_streamsink(RejectSink),
+            ok(()).boxed(),
+        ))
+    }
+
+    fn input(&self) -> Input {
+        Input::all()
+    }
+
+    fn acknowledgements(&self) -> &AcknowledgementsConfig {
+        &AcknowledgementsConfig::DEFAULT
+    }
+}
+
+/// A sink rejecting every event it receives, as if the destination responded with a 400 status.
+struct RejectSink;
+
+#[async_trait]
+impl StreamSink<Event> for RejectSink {
+    async fn run(self: Box<Self>, mut input: BoxStream<'_, Event>) -> Result<(), ()> {
+        let error = Arc::new(DeliveryError {
+            message: "400 Bad Request: invalid event".into(),
+            status_code: Some(400),
+        });
+        while let Some(mut event) = input.next().await {
+            event
+                .take_finalizers()
+                .update_status_with_error(EventStatus::Rejected, &error);
+        }
+
+        Ok(())
+    }
+}
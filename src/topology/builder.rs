@@ -33,6 +33,7 @@ use vector_lib::{
 };
 
 use super::{
+    dead_letter::DeadLetterTracker,
     fanout::{self, Fanout},
     schema,
     task::{Task, TaskOutput, TaskResult},
@@ -42,6 +43,7 @@ use crate::{
     config::{
         ComponentKey, Config, DataType, EnrichmentTableConfig, Input, Inputs, OutputId,
         ProxyConfig, SinkContext, SourceContext, TransformContext, TransformOuter, TransformOutput,
+        DROPPED_OUTPUT,
     },
     event::{EventArray, EventContainer},
     extra_context::ExtraContext,
@@ -585,6 +587,18 @@ impl<'a> Builder<'a> {
 
             let (trigger, tripwire) = Tripwire::new();
 
+            let dead_letter = sink.reroute_dropped.then(|| {
+                let (fanout, control) = Fanout::new();
+                self.outputs.insert(
+                    OutputId {
+                        component: key.clone(),
+                        port: Some(DROPPED_OUTPUT.to_owned()),
+                    },
+                    control,
+                );
+                DeadLetterTracker::new(key.clone(), typetag, fanout)
+            });
+
             let sink = async move {
                 debug!("Sink starting.");
 
@@ -602,8 +616,10 @@ impl<'a> Builder<'a> {
 
                 let mut rx = wrap(rx);
 
+                let (tracker, forwarder) = dead_letter.unzip();
+
                 let events_received = register!(EventsReceived);
-                sink.run(
+                let run = sink.run(
                     rx.by_ref()
                         .filter(|events: &EventArray| ready(filter_events_type(events, input_type)))
                         .inspect(|events| {
@@ -612,17 +628,40 @@ impl<'a> Builder<'a> {
                                 events.estimated_json_encoded_size_of(),
                             ))
                         })
+                        .then(move |events| {
+                            let tracker = tracker.clone();
+                            async move {
+                                match tracker {
+                                    Some(tracker) => tracker.track(events).await,
+                                    None => events,
+                                }
+                            }
+                        })
                         .take_until_if(tripwire),
-                )
-                .await
-                .map(|_| {
-                    debug!("Sink finished normally.");
-                    TaskOutput::Sink(rx)
-                })
-                .map_err(|_| {
-                    debug!("Sink finished with an error.");
-                    TaskError::Opaque
-                })
+                );
+
+                // The events rejected by the sink keep being forwarded until all the events it
+                // received have been finalized, which can outlive the sink itself.
+                let result = match forwarder {
+                    Some(forwarder) => {
+                        let (result, forwarded) = futures::join!(run, forwarder);
+                        if let Err(error) = forwarded {
+                            error!(message = "Failed to forward dropped events.", %error);
+                        }
+                        result
+                    }
+                    None => run.await,
+                };
+
+                result
+                    .map(|_| {
+                        debug!("Sink finished normally.");
+                        TaskOutput::Sink(rx)
+                    })
+                    .map_err(|_| {
+                        debug!("Sink finished with an error.");
+                        TaskError::Opaque
+                    })
             };
 
             let task = Task::new(key.clone(), typetag, sink);
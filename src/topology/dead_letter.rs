@@ -0,0 +1,223 @@
+//! Rerouting of the events a sink failed to deliver to its `dropped` output.
+//!
+//! Sinks report the outcome of delivering an event through its finalizers, and consume the event
+//! in the process. To be able to forward the events that end up rejected, a handle on each event is
+//! kept around, without any finalizers, until the sink reports the delivery status of the original
+//! one. The handle is then either discarded or annotated and sent to the `dropped` output.
+//!
+//! The data of log and trace events is shared between the handle and the original, and is only
+//! copied once either of them is modified, which for the handle only happens when the event is
+//! rejected. The number of events awaiting their delivery status is bounded, and the sink stops
+//! receiving events while that bound is reached.
+
+use std::sync::Arc;
+
+use futures::{stream::FuturesUnordered, FutureExt, StreamExt};
+use tokio::{select, sync::mpsc};
+use vector_lib::{
+    config::{log_schema, LogNamespace},
+    event::{
+        BatchNotifier, BatchStatus, BatchStatusReceiver, DeliveryError, Event, EventArray,
+        EventFinalizer, EventMutRef,
+    },
+    internal_event::{self, CountByteSize, EventsSent, InternalEventHandle as _},
+    lookup::{metadata_path, path, PathPrefix},
+    EstimatedJsonEncodedSizeOf,
+};
+
+use super::fanout::Fanout;
+use crate::config::{ComponentKey, OutputId, DROPPED_OUTPUT};
+
+type Pending = (BatchStatusReceiver, Event);
+
+/// The maximum number of events of a sink awaiting their delivery status.
+const MAX_IN_FLIGHT_EVENTS: usize = 100_000;
+
+/// The number of tracked events buffered before they are awaited by the forwarder.
+const PENDING_BUFFER_SIZE: usize = 1_000;
+
+/// Keeps track of the events sent to a sink.
+#[derive(Clone)]
+pub(super) struct DeadLetterTracker {
+    pending: mpsc::Sender<Pending>,
+}
+
+impl DeadLetterTracker {
+    /// Creates a tracker for the given sink, along with the future forwarding the events it
+    /// failed to deliver to `fanout`.
+    ///
+    /// The future completes once the tracker has been dropped and the delivery status of every
+    /// tracked event is known.
+    pub(super) fn new(
+        key: ComponentKey,
+        component_type: &'static str,
+        fanout: Fanout,
+    ) -> (Self, impl std::future::Future<Output = crate::Result<()>>) {
+        let (pending, pending_rx) = mpsc::channel(PENDING_BUFFER_SIZE);
+        let forwarder = DeadLetterForwarder {
+            key,
+            component_type,
+            fanout,
+        };
+        (Self { pending }, forwarder.run(pending_rx))
+    }
+
+    /// Starts tracking the delivery of the given events, waiting while too many of the tracked
+    /// events are awaiting their delivery status.
+    pub(super) async fn track(&self, mut events: EventArray) -> EventArray {
+        for mut event in events.iter_events_mut() {
+            // Cloning log and trace events only clones the reference to their data.
+            let mut handle = match &event {
+                EventMutRef::Log(log) => Event::from((**log).clone()),
+                EventMutRef::Metric(metric) => Event::from((**metric).clone()),
+                EventMutRef::Trace(trace) => Event::from((**trace).clone()),
+            };
+            // The handle must not delay the acknowledgement of the original event.
+            drop(handle.metadata_mut().take_finalizers());
+
+            let (batch, receiver) = BatchNotifier::new_with_receiver();
+            event
+                .metadata_mut()
+                .add_finalizer(EventFinalizer::new(batch));
+
+            // The forwarder only goes away along with the sink task, at which point there is
+            // nothing left to track.
+            _ = self.pending.send((receiver, handle)).await;
+        }
+        events
+    }
+}
+
+struct DeadLetterForwarder {
+    key: ComponentKey,
+    component_type: &'static str,
+    fanout: Fanout,
+}
+
+impl DeadLetterForwarder {
+    async fn run(mut self, mut pending_rx: mpsc::Receiver<Pending>) -> crate::Result<()> {
+        let output_id = Arc::new(OutputId {
+            component: self.key.clone(),
+            port: Some(DROPPED_OUTPUT.to_owned()),
+        });
+        let events_sent = register!(EventsSent::from(internal_event::Output(Some(
+            DROPPED_OUTPUT.into()
+        ))));
+
+        let mut in_flight = FuturesUnordered::new();
+        let mut tracking = true;
+
+        loop {
+            select! {
+                pending = pending_rx.recv(), if tracking && in_flight.len() < MAX_IN_FLIGHT_EVENTS => match pending {
+                    Some((receiver, event)) => {
+                        in_flight.push(
+                            receiver
+                                .with_error()
+                                .map(move |(status, error)| (status, error, event)),
+                        );
+                    }
+                    None => tracking = false,
+                },
+
+                Some((status, error, mut event)) = in_flight.next(), if !in_flight.is_empty() => {
+                    let reason = match status {
+                        BatchStatus::Delivered => None,
+                        BatchStatus::Errored => Some("errored"),
+                        BatchStatus::Rejected => Some("rejected"),
+                    };
+                    if let Some(reason) = reason {
+                        self.annotate_dropped(&mut event, reason, error.as_deref());
+                        event.metadata_mut().set_upstream_id(Arc::clone(&output_id));
+
+                        let events = EventArray::from(event);
+                        let count = events.len();
+                        let byte_size = events.estimated_json_encoded_size_of();
+                        self.fanout.send(events, None).await?;
+                        events_sent.emit(CountByteSize(count, byte_size));
+                    }
+                },
+
+                else => break,
+            }
+        }
+
+        Ok(())
+    }
+
+    fn dropped_data(&self, reason: &str, error: Option<&DeliveryError>) -> serde_json::Value {
+        let message = match reason {
+            "rejected" => "The event was rejected by the sink.",
+            _ => "The sink failed to deliver the event.",
+        };
+        let mut data = serde_json::json!({
+            "reason": reason,
+            "message": message,
+            "component_id": self.key,
+            "component_type": self.component_type,
+            "component_kind": "sink",
+        });
+        if let Some(error) = error {
+            data["error"] = error.message.clone().into();
+            if let Some(status_code) = error.status_code {
+                data["status_code"] = status_code.into();
+            }
+        }
+        data
+    }
+
+    fn annotate_dropped(&self, event: &mut Event, reason: &str, error: Option<&DeliveryError>) {
+        match event {
+            Event::Log(ref mut log) => match log.namespace() {
+                LogNamespace::Legacy => {
+                    if let Some(metadata_key) = log_schema().metadata_key() {
+                        log.insert(
+                            (PathPrefix::Event, metadata_key.concat(path!("dropped"))),
+                            self.dropped_data(reason, error),
+                        );
+                    }
+                }
+                LogNamespace::Vector => {
+                    log.insert(
+                        metadata_path!("vector", "dropped"),
+                        self.dropped_data(reason, error),
+                    );
+                }
+            },
+            Event::Metric(ref mut metric) => {
+                if let Some(metadata_key) = log_schema().metadata_key() {
+                    metric.replace_tag(format!("{metadata_key}.dropped.reason"), reason.into());
+                    metric.replace_tag(
+                        format!("{metadata_key}.dropped.component_id"),
+                        self.key.to_string(),
+                    );
+                    metric.replace_tag(
+                        format!("{metadata_key}.dropped.component_type"),
+                        self.component_type.into(),
+                    );
+                    metric.replace_tag(
+                        format!("{metadata_key}.dropped.component_kind"),
+                        "sink".into(),
+                    );
+                    if let Some(error) = error {
+                        metric.replace_tag(
+                            format!("{metadata_key}.dropped.error"),
+                            error.message.clone(),
+                        );
+                        if let Some(status_code) = error.status_code {
+                            metric.replace_tag(
+                                format!("{metadata_key}.dropped.status_code"),
+                                status_code.to_string(),
+                            );
+                        }
+                    }
+                }
+            }
+            Event::Trace(ref mut trace) => {
+                trace.maybe_insert(log_schema().metadata_key_target_path(), || {
+                    self.dropped_data(reason, error).into()
+                });
+            }
+        }
+    }
+}
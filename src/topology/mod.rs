@@ -12,6 +12,7 @@ pub mod schema;
 
 pub mod builder;
 mod controller;
+mod dead_letter;
 mod ready_arrays;
 mod running;
 mod task;
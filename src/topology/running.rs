@@ -496,6 +496,12 @@ impl RunningTopology {
             self.remove_inputs(key, diff, new_config).await;
         }
 
+        // Sinks rerouting the events they failed to deliver also have an output, which is
+        // recreated along with them.
+        for key in diff.sinks.removed_and_changed() {
+            self.remove_outputs(key);
+        }
+
         // Now that we've disconnected or temporarily detached the inputs to all changed/removed
         // sinks, we can actually wait for them to shutdown before collecting any buffers that are
         // marked for reuse.
@@ -563,7 +569,8 @@ impl RunningTopology {
             }
 
             for key in &diff.sinks.to_remove {
-                // Sinks only have inputs
+                // Sinks only have outputs when rerouting dropped events
+                self.outputs_tap_metadata.remove(key);
                 self.inputs_tap_metadata.remove(key);
             }
 
@@ -581,6 +588,18 @@ impl RunningTopology {
                 }
             }
 
+            for key in diff.sinks.changed_and_added() {
+                match new_pieces.tasks.get(key) {
+                    Some(task) if new_pieces.outputs.contains_key(key) => {
+                        self.outputs_tap_metadata
+                            .insert(key.clone(), ("sink", task.typetag().to_string()));
+                    }
+                    _ => {
+                        self.outputs_tap_metadata.remove(key);
+                    }
+                }
+            }
+
             for (key, input) in &new_pieces.inputs {
                 self.inputs_tap_metadata
                     .insert(key.clone(), input.1.clone());
@@ -601,6 +620,14 @@ impl RunningTopology {
             self.setup_outputs(key, new_pieces).await;
         }
 
+        // Sinks rerouting the events they failed to deliver can also feed other components.
+        for key in diff.sinks.changed_and_added() {
+            if new_pieces.outputs.contains_key(key) {
+                debug!(component = %key, "Configuring outputs for sink.");
+                self.setup_outputs(key, new_pieces).await;
+            }
+        }
+
         // Now that all possible outputs are configured, we can start wiring up inputs, starting
         // with transforms.
         for key in diff.transforms.changed_and_added() {
@@ -650,6 +677,7 @@ impl RunningTopology {
 
             let mut removals = diff.sources.to_remove.clone();
             removals.extend(diff.transforms.to_remove.iter().cloned());
+            removals.extend(diff.sinks.to_remove.iter().cloned());
             self.watch
                 .0
                 .send(TapResource {
@@ -665,8 +693,6 @@ impl RunningTopology {
                         .changed_and_added()
                         .map(|key| key.to_string())
                         .collect(),
-                    // Note, only sources and transforms are relevant. Sinks do
-                    // not have outputs to tap.
                     removals,
                 })
                 .expect("Couldn't broadcast config changes.");
@@ -1078,5 +1104,14 @@ fn get_changed_outputs(diff: &ConfigDiff, output_ids: Inputs<OutputId>) -> Vec<O
         );
     }
 
+    for sink_key in &diff.sinks.to_change {
+        changed_outputs.extend(
+            output_ids
+                .iter()
+                .filter(|id| &id.component == sink_key)
+                .cloned(),
+        );
+    }
+
     changed_outputs
 }
@@ -6,7 +6,7 @@ use vector_lib::config::SourceOutput;
 pub(super) use crate::schema::Definition;
 
 use crate::{
-    config::{ComponentKey, Config, OutputId, SinkOuter, TransformOutput},
+    config::{ComponentKey, Config, OutputId, SinkOuter, TransformOutput, DROPPED_OUTPUT},
     topology,
 };
 
@@ -90,6 +90,18 @@ pub fn possible_definitions(
 
             definitions.append(&mut transform_definition);
         }
+
+        // If the input is the `dropped` output of a sink, the events it failed to deliver are
+        // forwarded as they were received.
+        if let Some(inputs) = config.sink_dropped_inputs(key, &input.port) {
+            let mut sink_definitions = input.with_definitions(
+                possible_definitions(inputs, config, enrichment_tables.clone(), cache)?
+                    .into_iter()
+                    .map(|(_, definition)| definition),
+            );
+
+            definitions.append(&mut sink_definitions);
+        }
     }
 
     Ok(definitions)
@@ -193,6 +205,17 @@ pub(super) fn expanded_definitions(
             // Append whatever number of additional pipelines we created to the existing
             // pipeline definitions.
             definitions.append(&mut transform_definition);
+
+        // The events a sink failed to deliver are forwarded as they were received, so each of
+        // its pipelines carries on through its `dropped` output.
+        } else if let Some(inputs) = config.sink_dropped_inputs(key, &input.port) {
+            let mut sink_definitions = input.with_definitions(
+                expanded_definitions(enrichment_tables.clone(), inputs, config, cache)?
+                    .into_iter()
+                    .map(|(_, definition)| definition),
+            );
+
+            definitions.append(&mut sink_definitions);
         }
     }
 
@@ -283,6 +306,18 @@ pub(crate) fn input_definitions(
 
             definitions.append(&mut transform_definitions);
         }
+
+        // If the input is the `dropped` output of a sink, we retrieve the definitions of the
+        // sink's own inputs, as the events it failed to deliver are forwarded unchanged.
+        if let Some(inputs) = config.sink_dropped_inputs(key, &input.port) {
+            let mut sink_definitions = input.with_definitions(
+                input_definitions(inputs, config, enrichment_tables.clone(), cache)?
+                    .into_iter()
+                    .map(|(_, definition)| definition),
+            );
+
+            definitions.append(&mut sink_definitions);
+        }
     }
 
     Ok(definitions)
@@ -349,6 +384,11 @@ pub trait ComponentContainer {
 
     fn transform_inputs(&self, key: &ComponentKey) -> Option<&[OutputId]>;
 
+    /// Gets the inputs of the sink with the given key, if the given port is the output it
+    /// reroutes the events it failed to deliver to.
+    fn sink_dropped_inputs(&self, key: &ComponentKey, port: &Option<String>)
+        -> Option<&[OutputId]>;
+
     fn transform_outputs(
         &self,
         key: &ComponentKey,
@@ -421,6 +461,16 @@ impl ComponentContainer for Config {
         self.transform(key).map(|transform| &transform.inputs[..])
     }
 
+    fn sink_dropped_inputs(
+        &self,
+        key: &ComponentKey,
+        port: &Option<String>,
+    ) -> Option<&[OutputId]> {
+        self.sink(key)
+            .filter(|sink| sink.reroute_dropped && port.as_deref() == Some(DROPPED_OUTPUT))
+            .map(|sink| &sink.inputs[..])
+    }
+
     fn transform_outputs(
         &self,
         key: &ComponentKey,
@@ -471,6 +521,14 @@ mod tests {
                 self.transforms.get(key.id()).map(|v| v.0.as_slice())
             }
 
+            fn sink_dropped_inputs(
+                &self,
+                _: &ComponentKey,
+                _: &Option<String>,
+            ) -> Option<&[OutputId]> {
+                None
+            }
+
             fn transform_outputs(
                 &self,
                 key: &ComponentKey,
@@ -204,6 +204,35 @@ cli: {
 	options: _core_options
 
 	commands: {
+		"checkpoints": {
+			description: """
+				Inspect and edit the checkpoints of a `file` or `kubernetes_logs` source, such as
+				to move them to another host or to read some files again. The checkpoints are
+				read from and written to the data directory of the source, so Vector must be
+				stopped while they are edited.
+
+				The subcommands are `list`, `inspect <path>`, `export [--output <file>]`,
+				`import <file> [--replace]`, `set (--fingerprint <fingerprint> | --path <path>) <position>`,
+				and `reset (--fingerprint <fingerprint> | --path <path> | --all)`.
+				"""
+
+			example: "vector checkpoints --config /etc/vector/vector.yaml --source app_logs export --output checkpoints.json"
+
+			options: _core_config_options & {
+				"source": {
+					description: "The ID of the `file` or `kubernetes_logs` source whose checkpoints are managed"
+					type:        "string"
+				}
+			}
+
+			args: {
+				command: {
+					description: "The checkpoints command to run, e.g. `list`"
+					required:    true
+					type:        "string"
+				}
+			}
+		}
 		"graph": {
 			description: """
 				Generate a visual representation of topologies. The output is in the [DOT format](\(urls.dot_format)),
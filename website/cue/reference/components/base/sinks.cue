@@ -13,19 +13,26 @@ base: components: sinks: configuration: {
 		required: false
 		type: object: options: {
 			max_events: {
-				description:   "The maximum number of events allowed in the buffer."
+				description: """
+					The maximum number of events allowed in the buffer.
+
+					Defaults to 500 events, unless `max_size` is set.
+					"""
 				relevant_when: "type = \"memory\""
 				required:      false
-				type: uint: default: 500
+				type: uint: {}
 			}
 			max_size: {
 				description: """
-					The maximum size of the buffer on disk.
+					The maximum size of the buffer.
+
+					For disk buffers, this is the maximum size of the buffer on disk, which must be at
+					least ~256 megabytes (268435488 bytes), and is required.
 
-					Must be at least ~256 megabytes (268435488 bytes).
+					For memory buffers, this is the maximum size of the events allowed in the buffer, as
+					estimated in memory. When set along with `max_events`, both limits are enforced.
 					"""
-				relevant_when: "type = \"disk\""
-				required:      true
+				required: false
 				type: uint: unit: "bytes"
 			}
 			type: {
@@ -71,6 +78,16 @@ base: components: sinks: configuration: {
 														highest priority, and it is preferable to temporarily lose events rather than cause a
 														slowdown in the acceptance/consumption of events.
 														"""
+						overflow: """
+														Overflows to the next stage in the buffer topology.
+
+														If the current buffer stage is full, attempt to send this event to the next buffer stage.
+														That stage may also be configured overflow, and so on, but ultimately the last stage in a
+														buffer topology must use one of the other handling behaviors. This means that next stage may
+														potentially be able to buffer the event, but it may also block or drop the event.
+
+														This mode can only be used when two or more buffer stages are configured.
+														"""
 					}
 				}
 			}
@@ -199,4 +216,17 @@ base: components: sinks: configuration: {
 			}
 		}
 	}
+	reroute_dropped: {
+		description: """
+			Reroutes the events that the sink fails to deliver to its `dropped` output.
+
+			Events end up there once the sink has given up on them, for example after a
+			non-retriable response, or after exhausting its retries. The original event is forwarded
+			along with metadata describing why it was dropped, including the error and status code
+			reported by the sink when available, which allows routing them elsewhere (for example, an
+			object store) to be replayed later.
+			"""
+		required: false
+		type: bool: default: false
+	}
 }
@@ -6,8 +6,9 @@ components: sinks: [Name=string]: {
 	features: _
 
 	configuration: {
-		inputs: base.components.sinks.configuration.inputs
-		buffer: base.components.sinks.configuration.buffer
+		inputs:          base.components.sinks.configuration.inputs
+		buffer:          base.components.sinks.configuration.buffer
+		reroute_dropped: base.components.sinks.configuration.reroute_dropped
 		healthcheck: {
 			description: base.components.sinks.configuration.healthcheck.description
 			required:    base.components.sinks.configuration.healthcheck.required
@@ -664,6 +665,8 @@ components: sinks: [Name=string]: {
 		buffer_byte_size:                     components.sources.internal_metrics.output.metrics.buffer_byte_size
 		buffer_discarded_events_total:        components.sources.internal_metrics.output.metrics.buffer_discarded_events_total
 		buffer_events:                        components.sources.internal_metrics.output.metrics.buffer_events
+		buffer_overflowed_bytes_total:        components.sources.internal_metrics.output.metrics.buffer_overflowed_bytes_total
+		buffer_overflowed_events_total:       components.sources.internal_metrics.output.metrics.buffer_overflowed_events_total
 		buffer_received_events_total:         components.sources.internal_metrics.output.metrics.buffer_received_events_total
 		buffer_received_event_bytes_total:    components.sources.internal_metrics.output.metrics.buffer_received_event_bytes_total
 		buffer_sent_events_total:             components.sources.internal_metrics.output.metrics.buffer_sent_events_total
@@ -370,6 +370,15 @@ base: components: sinks: aws_kinesis_firehose: configuration: {
 						transform) and removing the message field while doing additional parsing on it, as this
 						could lead to the encoding emitting empty strings for the given event.
 						"""
+					schema_registry: """
+						Encodes an event as an Avro or Protobuf message in the [Confluent wire format][wire_format].
+
+						The schema is that of a subject in the [Schema Registry][schema_registry], and its ID is
+						prepended to each message.
+
+						[wire_format]: https://docs.confluent.io/platform/current/schema-registry/fundamentals/serdes-develop/index.html#wire-format
+						[schema_registry]: https://docs.confluent.io/platform/current/schema-registry/index.html
+						"""
 					text: """
 						Plain text encoding.
 
@@ -526,6 +535,71 @@ base: components: sinks: aws_kinesis_firehose: configuration: {
 					}
 				}
 			}
+			schema_registry: {
+				description:   "Options for the Schema Registry serializer."
+				relevant_when: "codec = \"schema_registry\""
+				required:      true
+				type: object: options: {
+					auth: {
+						description: "Basic authentication for the Schema Registry."
+						required:    false
+						type: object: options: {
+							password: {
+								description: "The password to authenticate with."
+								required:    true
+								type: string: examples: ["${SCHEMA_REGISTRY_PASSWORD}"]
+							}
+							user: {
+								description: "The username to authenticate with."
+								required:    true
+								type: string: examples: ["${SCHEMA_REGISTRY_USERNAME}"]
+							}
+						}
+					}
+					avro_schema: {
+						description: """
+							An Avro schema to register under the subject when it's first used.
+
+							When not set, events are encoded with the latest schema registered under the subject,
+							which can be an Avro or a Protobuf schema.
+							"""
+						required: false
+						type: string: examples: ["{ \"type\": \"record\", \"name\": \"log\", \"fields\": [{ \"name\": \"message\", \"type\": \"string\" }] }"]
+					}
+					message_type: {
+						description: """
+							The message type to encode events as, for Protobuf schemas.
+
+							Defaults to the first message type of the schema.
+							"""
+						required: false
+						type: string: examples: ["package.Message"]
+					}
+					subject: {
+						description: """
+							The subject whose schema events are encoded with.
+
+							When not set, the subject is named after the topic that events are sent to, following the
+							topic name strategy: `<topic>-value`. Only the `kafka` sink provides a topic.
+							"""
+						required: false
+						type: string: examples: ["logs-value"]
+					}
+					timeout_secs: {
+						description: "The timeout for requests to the Schema Registry, in seconds."
+						required:    false
+						type: uint: {
+							default: 30
+							unit:    "seconds"
+						}
+					}
+					url: {
+						description: "The URL of the Schema Registry."
+						required:    true
+						type: string: examples: ["http://localhost:8081"]
+					}
+				}
+			}
 			timestamp_format: {
 				description: "Format used for timestamp fields."
 				required:    false
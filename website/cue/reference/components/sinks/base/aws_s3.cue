@@ -236,6 +236,128 @@ base: components: sinks: aws_s3: configuration: {
 			}
 		}
 	}
+	batch_encoding: {
+		description: """
+			Encoding of each batch of events as a whole.
+
+			When set, each batch is written as a single object in this format, and events are not
+			serialized with the `encoding` codec. The other `encoding` options, such as `only_fields`,
+			still apply.
+
+			The `compression` option is ignored, as the format handles compression itself.
+			"""
+		required: false
+		type: object: options: {
+			codec: {
+				description: "The codec to use for encoding batches of events."
+				required:    true
+				type: string: enum: parquet: """
+					Encodes each batch of events as an [Apache Parquet][apache_parquet] file.
+
+					[apache_parquet]: https://parquet.apache.org/
+					"""
+			}
+			parquet: {
+				description:   "Apache Parquet-specific encoder options."
+				relevant_when: "codec = \"parquet\""
+				required:      false
+				type: object: options: {
+					compression: {
+						description: "The compression codec applied to the pages of the columns."
+						required:    false
+						type: string: {
+							default: "snappy"
+							enum: {
+								gzip: """
+									[Gzip][gzip] compression.
+
+									[gzip]: https://www.gzip.org/
+									"""
+								lz4: """
+									[LZ4][lz4] compression, without framing.
+
+									[lz4]: https://lz4.org/
+									"""
+								none: "No compression."
+								snappy: """
+									[Snappy][snappy] compression.
+
+									[snappy]: https://github.com/google/snappy/blob/main/docs/README.md
+									"""
+								zstd: """
+									[Zstandard][zstd] compression.
+
+									[zstd]: https://facebook.github.io/zstd/
+									"""
+							}
+						}
+					}
+					row_group_size: {
+						description: """
+							The maximum number of rows per row group.
+
+							Batches with more events than this are split into several row groups.
+							"""
+						required: false
+						type: uint: {
+							default: 100000
+							unit:    "rows"
+						}
+					}
+					schema: {
+						description: """
+							The columns of the Parquet files, in order.
+
+							When not set, the columns are derived from the schema definition of the events: each
+							top-level field known to the schema becomes an optional column. Fields of several types,
+							objects, and arrays are stored as JSON.
+							"""
+						required: false
+						type: array: {
+							default: []
+							items: type: object: options: {
+								name: {
+									description: """
+										The name of the column.
+
+										This is also the path of the event field the values of the column are read from.
+										"""
+									required: true
+									type: string: examples: ["message", "kubernetes.pod_name"]
+								}
+								required: {
+									description: """
+										Whether the column is required.
+
+										Events that are missing the field of a required column, or have it set to `null`, are
+										rejected.
+										"""
+									required: false
+									type: bool: default: false
+								}
+								type: {
+									description: "The type of the column."
+									required:    true
+									type: string: enum: {
+										boolean: "A boolean."
+										double: """
+											A 64-bit floating point number.
+
+											Integer values are converted.
+											"""
+										int64:     "A 64-bit signed integer."
+										json:      "Any value, stored as a JSON-encoded string."
+										string:    "A UTF-8 string."
+										timestamp: "A timestamp, stored as the number of microseconds since the Unix epoch, in UTC."
+									}
+								}
+							}
+						}
+					}
+				}
+			}
+		}
+	}
 	bucket: {
 		description: """
 			The S3 bucket name.
@@ -479,6 +601,15 @@ base: components: sinks: aws_s3: configuration: {
 						transform) and removing the message field while doing additional parsing on it, as this
 						could lead to the encoding emitting empty strings for the given event.
 						"""
+					schema_registry: """
+						Encodes an event as an Avro or Protobuf message in the [Confluent wire format][wire_format].
+
+						The schema is that of a subject in the [Schema Registry][schema_registry], and its ID is
+						prepended to each message.
+
+						[wire_format]: https://docs.confluent.io/platform/current/schema-registry/fundamentals/serdes-develop/index.html#wire-format
+						[schema_registry]: https://docs.confluent.io/platform/current/schema-registry/index.html
+						"""
 					text: """
 						Plain text encoding.
 
@@ -635,6 +766,71 @@ base: components: sinks: aws_s3: configuration: {
 					}
 				}
 			}
+			schema_registry: {
+				description:   "Options for the Schema Registry serializer."
+				relevant_when: "codec = \"schema_registry\""
+				required:      true
+				type: object: options: {
+					auth: {
+						description: "Basic authentication for the Schema Registry."
+						required:    false
+						type: object: options: {
+							password: {
+								description: "The password to authenticate with."
+								required:    true
+								type: string: examples: ["${SCHEMA_REGISTRY_PASSWORD}"]
+							}
+							user: {
+								description: "The username to authenticate with."
+								required:    true
+								type: string: examples: ["${SCHEMA_REGISTRY_USERNAME}"]
+							}
+						}
+					}
+					avro_schema: {
+						description: """
+							An Avro schema to register under the subject when it's first used.
+
+							When not set, events are encoded with the latest schema registered under the subject,
+							which can be an Avro or a Protobuf schema.
+							"""
+						required: false
+						type: string: examples: ["{ \"type\": \"record\", \"name\": \"log\", \"fields\": [{ \"name\": \"message\", \"type\": \"string\" }] }"]
+					}
+					message_type: {
+						description: """
+							The message type to encode events as, for Protobuf schemas.
+
+							Defaults to the first message type of the schema.
+							"""
+						required: false
+						type: string: examples: ["package.Message"]
+					}
+					subject: {
+						description: """
+							The subject whose schema events are encoded with.
+
+							When not set, the subject is named after the topic that events are sent to, following the
+							topic name strategy: `<topic>-value`. Only the `kafka` sink provides a topic.
+							"""
+						required: false
+						type: string: examples: ["logs-value"]
+					}
+					timeout_secs: {
+						description: "The timeout for requests to the Schema Registry, in seconds."
+						required:    false
+						type: uint: {
+							default: 30
+							unit:    "seconds"
+						}
+					}
+					url: {
+						description: "The URL of the Schema Registry."
+						required:    true
+						type: string: examples: ["http://localhost:8081"]
+					}
+				}
+			}
 			timestamp_format: {
 				description: "Format used for timestamp fields."
 				required:    false
@@ -672,7 +868,8 @@ base: components: sinks: aws_s3: configuration: {
 		description: """
 			The filename extension to use in the object key.
 
-			This overrides setting the extension based on the configured `compression`.
+			This overrides setting the extension based on the configured `compression` or
+			`batch_encoding`.
 			"""
 		required: false
 		type: string: examples: [
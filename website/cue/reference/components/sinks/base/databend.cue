@@ -31,12 +31,33 @@ base: components: sinks: databend: configuration: {
 		description: "The username and password to authenticate with. Overrides the username and password in DSN."
 		required:    false
 		type: object: options: {
+			client_id: {
+				description:   "The client identifier."
+				relevant_when: "strategy = \"oauth2\""
+				required:      true
+				type: string: examples: ["${CLIENT_ID}", "vector"]
+			}
+			client_secret: {
+				description:   "The client secret."
+				relevant_when: "strategy = \"oauth2\""
+				required:      true
+				type: string: examples: ["${CLIENT_SECRET}"]
+			}
 			password: {
 				description:   "The basic authentication password."
 				relevant_when: "strategy = \"basic\""
 				required:      true
 				type: string: examples: ["${PASSWORD}", "password"]
 			}
+			scopes: {
+				description:   "The scopes to request for the access token."
+				relevant_when: "strategy = \"oauth2\""
+				required:      false
+				type: array: {
+					default: []
+					items: type: string: examples: ["logs.write"]
+				}
+			}
 			strategy: {
 				description: "The authentication strategy to use."
 				required:    true
@@ -53,6 +74,15 @@ base: components: sinks: databend: configuration: {
 
 						The bearer token value (OAuth2, JWT, etc.) is passed as-is.
 						"""
+					oauth2: """
+						OAuth2 authentication, using the [client credentials grant][client_credentials].
+
+						An access token is requested from the token endpoint and passed as a bearer token. The
+						token is reused until shortly before it expires, or until a request using it is rejected
+						with a `401 Unauthorized` response.
+
+						[client_credentials]: https://datatracker.ietf.org/doc/html/rfc6749#section-4.4
+						"""
 				}
 			}
 			token: {
@@ -61,6 +91,12 @@ base: components: sinks: databend: configuration: {
 				required:      true
 				type: string: {}
 			}
+			token_endpoint: {
+				description:   "The URL of the token endpoint of the authorization server."
+				relevant_when: "strategy = \"oauth2\""
+				required:      true
+				type: string: examples: ["https://auth.example.com/oauth2/token"]
+			}
 			user: {
 				description:   "The basic authentication username."
 				relevant_when: "strategy = \"basic\""
@@ -219,6 +219,15 @@ base: components: sinks: file: configuration: {
 						transform) and removing the message field while doing additional parsing on it, as this
 						could lead to the encoding emitting empty strings for the given event.
 						"""
+					schema_registry: """
+						Encodes an event as an Avro or Protobuf message in the [Confluent wire format][wire_format].
+
+						The schema is that of a subject in the [Schema Registry][schema_registry], and its ID is
+						prepended to each message.
+
+						[wire_format]: https://docs.confluent.io/platform/current/schema-registry/fundamentals/serdes-develop/index.html#wire-format
+						[schema_registry]: https://docs.confluent.io/platform/current/schema-registry/index.html
+						"""
 					text: """
 						Plain text encoding.
 
@@ -375,6 +384,71 @@ base: components: sinks: file: configuration: {
 					}
 				}
 			}
+			schema_registry: {
+				description:   "Options for the Schema Registry serializer."
+				relevant_when: "codec = \"schema_registry\""
+				required:      true
+				type: object: options: {
+					auth: {
+						description: "Basic authentication for the Schema Registry."
+						required:    false
+						type: object: options: {
+							password: {
+								description: "The password to authenticate with."
+								required:    true
+								type: string: examples: ["${SCHEMA_REGISTRY_PASSWORD}"]
+							}
+							user: {
+								description: "The username to authenticate with."
+								required:    true
+								type: string: examples: ["${SCHEMA_REGISTRY_USERNAME}"]
+							}
+						}
+					}
+					avro_schema: {
+						description: """
+							An Avro schema to register under the subject when it's first used.
+
+							When not set, events are encoded with the latest schema registered under the subject,
+							which can be an Avro or a Protobuf schema.
+							"""
+						required: false
+						type: string: examples: ["{ \"type\": \"record\", \"name\": \"log\", \"fields\": [{ \"name\": \"message\", \"type\": \"string\" }] }"]
+					}
+					message_type: {
+						description: """
+							The message type to encode events as, for Protobuf schemas.
+
+							Defaults to the first message type of the schema.
+							"""
+						required: false
+						type: string: examples: ["package.Message"]
+					}
+					subject: {
+						description: """
+							The subject whose schema events are encoded with.
+
+							When not set, the subject is named after the topic that events are sent to, following the
+							topic name strategy: `<topic>-value`. Only the `kafka` sink provides a topic.
+							"""
+						required: false
+						type: string: examples: ["logs-value"]
+					}
+					timeout_secs: {
+						description: "The timeout for requests to the Schema Registry, in seconds."
+						required:    false
+						type: uint: {
+							default: 30
+							unit:    "seconds"
+						}
+					}
+					url: {
+						description: "The URL of the Schema Registry."
+						required:    true
+						type: string: examples: ["http://localhost:8081"]
+					}
+				}
+			}
 			timestamp_format: {
 				description: "Format used for timestamp fields."
 				required:    false
@@ -487,6 +561,104 @@ base: components: sinks: file: configuration: {
 			syntax: "template"
 		}
 	}
+	rotation: {
+		description: """
+			Rotation and retention configuration.
+
+			A file is rotated by renaming it with a suffix, so that the next events written to its path go
+			to a new file. Files are rotated before the next event is written to them, so a file that
+			doesn't receive any more events is left as is.
+			"""
+		required: false
+		type: object: options: {
+			interval_secs: {
+				description: """
+					The interval, in seconds, at which files are rotated.
+
+					Intervals are aligned to the Unix epoch, so an interval of `3600` rotates files at the top
+					of every hour. A file last modified in an earlier interval, such as one left by a previous
+					run of Vector, is rotated before any event is written to it.
+					"""
+				required: false
+				type: uint: {
+					examples: [86400]
+					unit: "seconds"
+				}
+			}
+			max_age_secs: {
+				description: """
+					The amount of time, in seconds, that the sink writes to a file before rotating it.
+
+					The age of a file is counted from when it was created, or, where the file system doesn't
+					record creation times, from when it was last modified before the sink opened it.
+					"""
+				required: false
+				type: uint: {
+					examples: [3600]
+					unit: "seconds"
+				}
+			}
+			max_bytes: {
+				description: """
+					The size of a file, in bytes, at which it is rotated.
+
+					The size is counted before compression, starting from the size of the file when the sink
+					opens it.
+					"""
+				required: false
+				type: uint: {
+					examples: [104857600]
+					unit: "bytes"
+				}
+			}
+			max_file_age_secs: {
+				description: """
+					The amount of time, in seconds, after which rotated files are removed.
+
+					The age of a rotated file is counted from when it was last modified.
+					"""
+				required: false
+				type: uint: {
+					examples: [604800]
+					unit: "seconds"
+				}
+			}
+			max_files: {
+				description: """
+					The number of rotated files to keep for each path.
+
+					When exceeded, the least recently modified files are removed.
+					"""
+				required: false
+				type: uint: examples: [10]
+			}
+			suffix: {
+				description: """
+					The suffix appended to the path of rotated files.
+
+					The suffix is inserted before the compression extension of the path, if any, so that
+					`/tmp/app.log.gz` is rotated to `/tmp/app.log.1.gz`.
+					"""
+				required: false
+				type: string: {
+					default: "sequence"
+					enum: {
+						sequence: """
+							A sequence number, incremented with each rotation of the path.
+
+							For example, `/tmp/app.log` is rotated to `/tmp/app.log.1`, then to `/tmp/app.log.2`.
+							"""
+						timestamp: """
+							The time of the rotation in UTC, formatted as `%Y%m%d%H%M%S`.
+
+							A sequence number is appended when the path was already rotated within the same second,
+							such as `/tmp/app.log.20241017120000.1`.
+							"""
+					}
+				}
+			}
+		}
+	}
 	timezone: {
 		description: """
 			Timezone to use for any date specifiers in template strings.
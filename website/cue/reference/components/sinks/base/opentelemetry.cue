@@ -39,12 +39,33 @@ base: components: sinks: opentelemetry: configuration: protocol: {
 				"""
 			required: false
 			type: object: options: {
+				client_id: {
+					description:   "The client identifier."
+					relevant_when: "strategy = \"oauth2\""
+					required:      true
+					type: string: examples: ["${CLIENT_ID}", "vector"]
+				}
+				client_secret: {
+					description:   "The client secret."
+					relevant_when: "strategy = \"oauth2\""
+					required:      true
+					type: string: examples: ["${CLIENT_SECRET}"]
+				}
 				password: {
 					description:   "The basic authentication password."
 					relevant_when: "strategy = \"basic\""
 					required:      true
 					type: string: examples: ["${PASSWORD}", "password"]
 				}
+				scopes: {
+					description:   "The scopes to request for the access token."
+					relevant_when: "strategy = \"oauth2\""
+					required:      false
+					type: array: {
+						default: []
+						items: type: string: examples: ["logs.write"]
+					}
+				}
 				strategy: {
 					description: "The authentication strategy to use."
 					required:    true
@@ -61,6 +82,15 @@ base: components: sinks: opentelemetry: configuration: protocol: {
 
 																			The bearer token value (OAuth2, JWT, etc.) is passed as-is.
 																			"""
+						oauth2: """
+																			OAuth2 authentication, using the [client credentials grant][client_credentials].
+
+																			An access token is requested from the token endpoint and passed as a bearer token. The
+																			token is reused until shortly before it expires, or until a request using it is rejected
+																			with a `401 Unauthorized` response.
+
+																			[client_credentials]: https://datatracker.ietf.org/doc/html/rfc6749#section-4.4
+																			"""
 					}
 				}
 				token: {
@@ -69,6 +99,12 @@ base: components: sinks: opentelemetry: configuration: protocol: {
 					required:      true
 					type: string: {}
 				}
+				token_endpoint: {
+					description:   "The URL of the token endpoint of the authorization server."
+					relevant_when: "strategy = \"oauth2\""
+					required:      true
+					type: string: examples: ["https://auth.example.com/oauth2/token"]
+				}
 				user: {
 					description:   "The basic authentication username."
 					relevant_when: "strategy = \"basic\""
@@ -144,8 +180,9 @@ base: components: sinks: opentelemetry: configuration: protocol: {
 			}
 		}
 		encoding: {
-			description: "Configures how events are encoded into raw bytes."
-			required:    true
+			description:   "Configures how events are encoded into raw bytes."
+			relevant_when: "type = \"http\""
+			required:      true
 			type: object: options: {
 				avro: {
 					description:   "Apache Avro-specific encoder options."
@@ -315,6 +352,15 @@ base: components: sinks: opentelemetry: configuration: protocol: {
 																			transform) and removing the message field while doing additional parsing on it, as this
 																			could lead to the encoding emitting empty strings for the given event.
 																			"""
+						schema_registry: """
+																			Encodes an event as an Avro or Protobuf message in the [Confluent wire format][wire_format].
+
+																			The schema is that of a subject in the [Schema Registry][schema_registry], and its ID is
+																			prepended to each message.
+
+																			[wire_format]: https://docs.confluent.io/platform/current/schema-registry/fundamentals/serdes-develop/index.html#wire-format
+																			[schema_registry]: https://docs.confluent.io/platform/current/schema-registry/index.html
+																			"""
 						text: """
 																			Plain text encoding.
 
@@ -471,6 +517,71 @@ base: components: sinks: opentelemetry: configuration: protocol: {
 						}
 					}
 				}
+				schema_registry: {
+					description:   "Options for the Schema Registry serializer."
+					relevant_when: "codec = \"schema_registry\""
+					required:      true
+					type: object: options: {
+						auth: {
+							description: "Basic authentication for the Schema Registry."
+							required:    false
+							type: object: options: {
+								password: {
+									description: "The password to authenticate with."
+									required:    true
+									type: string: examples: ["${SCHEMA_REGISTRY_PASSWORD}"]
+								}
+								user: {
+									description: "The username to authenticate with."
+									required:    true
+									type: string: examples: ["${SCHEMA_REGISTRY_USERNAME}"]
+								}
+							}
+						}
+						avro_schema: {
+							description: """
+								An Avro schema to register under the subject when it's first used.
+
+								When not set, events are encoded with the latest schema registered under the subject,
+								which can be an Avro or a Protobuf schema.
+								"""
+							required: false
+							type: string: examples: ["{ \"type\": \"record\", \"name\": \"log\", \"fields\": [{ \"name\": \"message\", \"type\": \"string\" }] }"]
+						}
+						message_type: {
+							description: """
+								The message type to encode events as, for Protobuf schemas.
+
+								Defaults to the first message type of the schema.
+								"""
+							required: false
+							type: string: examples: ["package.Message"]
+						}
+						subject: {
+							description: """
+								The subject whose schema events are encoded with.
+
+								When not set, the subject is named after the topic that events are sent to, following the
+								topic name strategy: `<topic>-value`. Only the `kafka` sink provides a topic.
+								"""
+							required: false
+							type: string: examples: ["logs-value"]
+						}
+						timeout_secs: {
+							description: "The timeout for requests to the Schema Registry, in seconds."
+							required:    false
+							type: uint: {
+								default: 30
+								unit:    "seconds"
+							}
+						}
+						url: {
+							description: "The URL of the Schema Registry."
+							required:    true
+							type: string: examples: ["http://localhost:8081"]
+						}
+					}
+				}
 				timestamp_format: {
 					description: "Format used for timestamp fields."
 					required:    false
@@ -485,9 +596,20 @@ base: components: sinks: opentelemetry: configuration: protocol: {
 				}
 			}
 		}
+		endpoint: {
+			description: """
+				The endpoint of the OTLP receiver.
+
+				This should include the protocol, host, and port. When using the `http_protobuf` protocol, the signal specific path (`/v1/logs`, `/v1/metrics`, or `/v1/traces`) is appended to it.
+				"""
+			relevant_when: "type = \"http_protobuf\" or type = \"grpc\""
+			required:      true
+			type: string: examples: ["http://localhost:4318", "https://otel-collector:4317"]
+		}
 		framing: {
-			description: "Framing configuration."
-			required:    false
+			description:   "Framing configuration."
+			relevant_when: "type = \"http\""
+			required:      false
 			type: object: options: {
 				character_delimited: {
 					description:   "Options for the character delimited encoder."
@@ -546,6 +668,7 @@ base: components: sinks: opentelemetry: configuration: protocol: {
 			deprecated:         true
 			deprecated_message: "This option has been deprecated, use `request.headers` instead."
 			description:        "A list of custom headers to add to each request."
+			relevant_when:      "type = \"http\""
 			required:           false
 			type: object: options: "*": {
 				description: "An HTTP request header and it's value."
@@ -559,7 +682,8 @@ base: components: sinks: opentelemetry: configuration: protocol: {
 
 				The HTTP method to use when making the request.
 				"""
-			required: false
+			relevant_when: "type = \"http\""
+			required:      false
 			type: string: {
 				default: "post"
 				enum: {
@@ -582,7 +706,8 @@ base: components: sinks: opentelemetry: configuration: protocol: {
 
 				If specified, the `payload_suffix` must also be specified and together they must produce a valid JSON object.
 				"""
-			required: false
+			relevant_when: "type = \"http\""
+			required:      false
 			type: string: {
 				default: ""
 				examples: ["{\"data\":"]
@@ -596,7 +721,8 @@ base: components: sinks: opentelemetry: configuration: protocol: {
 
 				If specified, the `payload_prefix` must also be specified and together they must produce a valid JSON object.
 				"""
-			required: false
+			relevant_when: "type = \"http\""
+			required:      false
 			type: string: {
 				default: ""
 				examples: ["}"]
@@ -894,7 +1020,11 @@ base: components: sinks: opentelemetry: configuration: protocol: {
 		type: {
 			description: "The communication protocol."
 			required:    true
-			type: string: enum: http: "Send data over HTTP."
+			type: string: enum: {
+				grpc:          "Send data over gRPC as OTLP export requests."
+				http:          "Send data over HTTP, encoding each event with the configured codec."
+				http_protobuf: "Send data over HTTP as OTLP protobuf export requests."
+			}
 		}
 		uri: {
 			description: """
@@ -902,7 +1032,8 @@ base: components: sinks: opentelemetry: configuration: protocol: {
 
 				This should include the protocol and host, but can also include the port, path, and any other valid part of a URI.
 				"""
-			required: true
+			relevant_when: "type = \"http\""
+			required:      true
 			type: string: examples: ["https://10.22.212.22:9000/endpoint"]
 		}
 	}
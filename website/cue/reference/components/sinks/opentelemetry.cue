@@ -14,7 +14,7 @@ components: sinks: opentelemetry: {
 	features: {
 		auto_generated:   true
 		acknowledgements: true
-		healthcheck: enabled: false
+		healthcheck: enabled: true
 	}
 
 	input: {
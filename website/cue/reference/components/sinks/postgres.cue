@@ -0,0 +1,97 @@
+package metadata
+
+components: sinks: postgres: {
+	title: "PostgreSQL"
+
+	classes: {
+		commonly_used: false
+		delivery:      "at_least_once"
+		development:   "beta"
+		egress_method: "batch"
+		service_providers: []
+		stateful: false
+	}
+
+	features: {
+		auto_generated:   true
+		acknowledgements: true
+		healthcheck: enabled: true
+		send: {
+			batch: {
+				enabled:      true
+				common:       false
+				max_bytes:    10_000_000
+				timeout_secs: 1.0
+			}
+			compression: enabled: false
+			encoding: {
+				enabled: true
+				codec: enabled: false
+			}
+			request: {
+				enabled: true
+				headers: false
+			}
+			tls: {
+				enabled:                true
+				can_verify_certificate: true
+				can_verify_hostname:    true
+				enabled_default:        false
+				enabled_by_scheme:      false
+			}
+			to: {
+				service: {
+					name:     "PostgreSQL"
+					thing:    "a \(name) database"
+					url:      urls.postgresql
+					versions: ">= 12"
+				}
+
+				interface: {
+					socket: {
+						direction: "outgoing"
+						protocols: ["tcp"]
+						ssl: "optional"
+					}
+				}
+			}
+		}
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	configuration: base.components.sinks.postgres.configuration
+
+	input: {
+		logs:    true
+		metrics: null
+		traces:  false
+	}
+
+	how_it_works: {
+		columns: {
+			title: "Mapping fields to columns"
+			body:  """
+				Each event is inserted as a row whose columns are filled with the top-level fields of
+				the same name, converted to the type of the column by PostgreSQL. Fields without a
+				matching column are ignored, columns without a matching field in any event of a batch
+				take their default value, and columns without a matching field in some events of a
+				batch are set to `NULL` for these events.
+				"""
+		}
+		rejected_rows: {
+			title: "Rejected rows"
+			body:  """
+				When a batch can't be inserted because of an invalid row, such as a row violating a
+				constraint, its rows are inserted one by one and the invalid rows are rejected.
+				Transient errors, such as lost connections or deadlocks, are retried, which can insert
+				the rows of a partially inserted batch twice. Configure `on_conflict` with a unique
+				index to make inserts idempotent.
+				"""
+		}
+	}
+}
@@ -312,6 +312,17 @@ components: sinks: prometheus_exporter: {
 				values for each name, Vector will only send the last value specified.
 				"""
 		}
+
+		native_histograms: {
+			title: "Native histograms and exemplars"
+			body: """
+				When a scrape request accepts the protobuf exposition format
+				(`application/vnd.google.protobuf; proto=io.prometheus.client.MetricFamily`),
+				metrics are exposed in that format, which carries native histograms and the
+				exemplars of counters and histograms. Otherwise, the text format is used, and native
+				histograms are exposed with explicit buckets.
+				"""
+		}
 	}
 
 	telemetry: metrics: {
@@ -81,6 +81,34 @@ base: components: sources: aws_kinesis_firehose: configuration: {
 					}
 				}
 			}
+			cef: {
+				description:   "CEF-specific decoding options."
+				relevant_when: "codec = \"cef\""
+				required:      false
+				type: object: options: {
+					lossy: {
+						description: """
+							Determines whether or not to replace invalid UTF-8 sequences instead of failing.
+
+							When true, invalid UTF-8 sequences are replaced with the [`U+FFFD REPLACEMENT CHARACTER`][U+FFFD].
+
+							[U+FFFD]: https://en.wikipedia.org/wiki/Specials_(Unicode_block)#Replacement_character
+							"""
+						required: false
+						type: bool: default: true
+					}
+					translate_custom_fields: {
+						description: """
+							Whether to name the values of custom extensions after their labels.
+
+							Custom extensions, such as `cs1`, come with a label extension, such as `cs1Label`. When
+							enabled, the pair of extensions is replaced by a single field named after the label.
+							"""
+						required: false
+						type: bool: default: false
+					}
+				}
+			}
 			codec: {
 				description: "The codec to use for decoding events."
 				required:    false
@@ -93,6 +121,22 @@ base: components: sources: aws_kinesis_firehose: configuration: {
 															[apache_avro]: https://avro.apache.org/
 															"""
 						bytes: "Uses the raw bytes as-is."
+						cef: """
+															Decodes the raw bytes as a [CEF][cef] (Common Event Format) message.
+
+															The header is decoded into the `cefVersion`, `deviceVendor`, `deviceProduct`,
+															`deviceVersion`, `deviceEventClassId`, `name`, and `severity` fields, and each extension
+															into the field named after its key.
+
+															[cef]: https://www.microfocus.com/documentation/arcsight/arcsight-smartconnectors-8.3/cef-implementation-standard/Content/CEF/Chapter%201%20What%20is%20CEF.htm
+															"""
+						csv: """
+															Decodes the raw bytes as [CSV][csv] records.
+
+															Each record of a frame becomes its own event.
+
+															[csv]: https://datatracker.ietf.org/doc/html/rfc4180
+															"""
 						gelf: """
 															Decodes the raw bytes as a [GELF][gelf] message.
 
@@ -121,6 +165,13 @@ base: components: sources: aws_kinesis_firehose: configuration: {
 
 															[json]: https://www.json.org/
 															"""
+						logfmt: """
+															Decodes the raw bytes as a [logfmt][logfmt] message.
+
+															Values are kept as strings, and keys without a value are set to `true`.
+
+															[logfmt]: https://brandur.org/logfmt
+															"""
 						native: """
 															Decodes the raw bytes as [native Protocol Buffers format][vector_native_protobuf].
 
@@ -142,6 +193,15 @@ base: components: sources: aws_kinesis_firehose: configuration: {
 
 															[protobuf]: https://protobuf.dev/
 															"""
+						schema_registry: """
+															Decodes the raw bytes as an Avro or Protobuf message in the [Confluent wire format][wire_format].
+
+															The schema of each message is fetched from the [Schema Registry][schema_registry] by the ID
+															that prefixes it, and cached.
+
+															[wire_format]: https://docs.confluent.io/platform/current/schema-registry/fundamentals/serdes-develop/index.html#wire-format
+															[schema_registry]: https://docs.confluent.io/platform/current/schema-registry/index.html
+															"""
 						syslog: """
 															Decodes the raw bytes as a Syslog message.
 
@@ -159,6 +219,69 @@ base: components: sources: aws_kinesis_firehose: configuration: {
 					}
 				}
 			}
+			csv: {
+				description:   "CSV-specific decoding options."
+				relevant_when: "codec = \"csv\""
+				required:      false
+				type: object: options: {
+					columns: {
+						description: """
+							The names of the columns, in order.
+
+							Each value of a record is stored in the field named after its column. Records with more
+							values than there are columns are rejected, and columns without a value are left out.
+
+							When `has_headers` is enabled, these names take precedence over the ones of the header.
+							"""
+						required: false
+						type: array: {
+							default: []
+							items: type: string: examples: ["timestamp", "message"]
+						}
+					}
+					delimiter: {
+						description: "The field delimiter to use when reading CSV."
+						required:    false
+						type: ascii_char: default: ","
+					}
+					double_quote: {
+						description: """
+							Enable double quote escapes.
+
+							When enabled, two consecutive quotes within a quoted value are read as a single quote.
+							"""
+						required: false
+						type: bool: default: true
+					}
+					escape: {
+						description: """
+							The escape character to use when reading CSV.
+
+							In some variants of CSV, quotes are escaped using a special escape character
+							like \\ (instead of escaping quotes by doubling them).
+
+							To use this, `double_quote` needs to be disabled as well otherwise it is ignored.
+							"""
+						required: false
+						type: ascii_char: default: "\""
+					}
+					has_headers: {
+						description: """
+							Whether the first record of each frame is a header holding the names of the columns.
+
+							This is typically used along with `bytes` framing, so that whole CSV files are decoded
+							at once.
+							"""
+						required: false
+						type: bool: default: false
+					}
+					quote: {
+						description: "The quote character to use when reading CSV."
+						required:    false
+						type: ascii_char: default: "\""
+					}
+				}
+			}
 			gelf: {
 				description:   "GELF-specific decoding options."
 				relevant_when: "codec = \"gelf\""
@@ -240,6 +363,42 @@ base: components: sources: aws_kinesis_firehose: configuration: {
 					}
 				}
 			}
+			schema_registry: {
+				description:   "Options for the Schema Registry deserializer."
+				relevant_when: "codec = \"schema_registry\""
+				required:      true
+				type: object: options: {
+					auth: {
+						description: "Basic authentication for the Schema Registry."
+						required:    false
+						type: object: options: {
+							password: {
+								description: "The password to authenticate with."
+								required:    true
+								type: string: examples: ["${SCHEMA_REGISTRY_PASSWORD}"]
+							}
+							user: {
+								description: "The username to authenticate with."
+								required:    true
+								type: string: examples: ["${SCHEMA_REGISTRY_USERNAME}"]
+							}
+						}
+					}
+					timeout_secs: {
+						description: "The timeout for requests to the Schema Registry, in seconds."
+						required:    false
+						type: uint: {
+							default: 30
+							unit:    "seconds"
+						}
+					}
+					url: {
+						description: "The URL of the Schema Registry."
+						required:    true
+						type: string: examples: ["http://localhost:8081"]
+					}
+				}
+			}
 			syslog: {
 				description:   "Syslog-specific decoding options."
 				relevant_when: "codec = \"syslog\""
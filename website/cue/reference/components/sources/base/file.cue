@@ -57,6 +57,43 @@ base: components: sources: file: configuration: {
 			type: string: examples: ["utf-16le", "utf-16be"]
 		}
 	}
+	discovery: {
+		description: "Configuration for how the files to read are discovered."
+		required:    false
+		type: object: options: {
+			path: {
+				description:   "The path of the manifest file."
+				relevant_when: "strategy = \"manifest\""
+				required:      true
+				type: string: examples: ["/etc/vector/files.txt"]
+			}
+			strategy: {
+				description: "The strategy used to discover the files to read."
+				required:    false
+				type: string: {
+					default: "glob"
+					enum: {
+						glob: "Search for the files matching the `include` patterns every `glob_minimum_cooldown_ms`."
+						manifest: """
+							Read the paths of the files from a manifest file.
+
+							The manifest lists one path per line. Empty lines and lines starting with `#` are
+							ignored, and relative paths are relative to the directory of the manifest. The manifest
+							is read again when it changes, and `include` is ignored.
+							"""
+						watch: """
+							Search for the files matching the `include` patterns once, then watch the directories
+							they are in for files being created, renamed, or removed.
+
+							This avoids searching through large directory trees every `glob_minimum_cooldown_ms`.
+							It relies on the file system notifications of the operating system, such as inotify on
+							Linux, so it may not work with network file systems.
+							"""
+					}
+				}
+			}
+		}
+	}
 	exclude: {
 		description: """
 			Array of file patterns to exclude. [Globbing](https://vector.dev/docs/reference/configuration/sources/file/#globbing) is supported.
@@ -101,7 +138,7 @@ base: components: sources: file: configuration: {
 
 					This can be helpful if all files share a common header that should be skipped.
 					"""
-				relevant_when: "strategy = \"checksum\""
+				relevant_when: "strategy = \"checksum\" or strategy = \"device_and_inode_checksum\""
 				required:      false
 				type: uint: {
 					default: 0
@@ -116,7 +153,7 @@ base: components: sources: file: configuration: {
 
 					If the file has less than this amount of lines, it won’t be read at all.
 					"""
-				relevant_when: "strategy = \"checksum\""
+				relevant_when: "strategy = \"checksum\" or strategy = \"device_and_inode_checksum\""
 				required:      false
 				type: uint: {
 					default: 1
@@ -137,6 +174,16 @@ base: components: sources: file: configuration: {
 						device_and_inode: """
 															Use the [device and inode][inode] as the identifier.
 
+															[inode]: https://en.wikipedia.org/wiki/Inode
+															"""
+						device_and_inode_checksum: """
+															Use the [device and inode][inode], along with a checksum of the lines at the beginning of
+															the file, as the identifier.
+
+															Unlike `device_and_inode`, this tells apart a new file that reuses the inode of a deleted
+															file. A file whose beginning is rewritten, such as with `copytruncate` rotation, is read
+															again from the beginning.
+
 															[inode]: https://en.wikipedia.org/wiki/Inode
 															"""
 					}
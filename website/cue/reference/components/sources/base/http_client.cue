@@ -1,16 +1,58 @@
 package metadata
 
 base: components: sources: http_client: configuration: {
+	acknowledgements: {
+		deprecated: true
+		description: """
+			Controls how acknowledgements are handled by this source.
+
+			This setting is **deprecated** in favor of enabling `acknowledgements` at the [global][global_acks] or sink level.
+
+			Enabling or disabling acknowledgements at the source level has **no effect** on acknowledgement behavior.
+
+			See [End-to-end Acknowledgements][e2e_acks] for more information on how event acknowledgement is handled.
+
+			[global_acks]: https://vector.dev/docs/reference/configuration/global-options/#acknowledgements
+			[e2e_acks]: https://vector.dev/docs/about/under-the-hood/architecture/end-to-end-acknowledgements/
+			"""
+		required: false
+		type: object: options: enabled: {
+			description: "Whether or not end-to-end acknowledgements are enabled for this source."
+			required:    false
+			type: bool: {}
+		}
+	}
 	auth: {
 		description: "HTTP Authentication."
 		required:    false
 		type: object: options: {
+			client_id: {
+				description:   "The client identifier."
+				relevant_when: "strategy = \"oauth2\""
+				required:      true
+				type: string: examples: ["${CLIENT_ID}", "vector"]
+			}
+			client_secret: {
+				description:   "The client secret."
+				relevant_when: "strategy = \"oauth2\""
+				required:      true
+				type: string: examples: ["${CLIENT_SECRET}"]
+			}
 			password: {
 				description:   "The basic authentication password."
 				relevant_when: "strategy = \"basic\""
 				required:      true
 				type: string: examples: ["${PASSWORD}", "password"]
 			}
+			scopes: {
+				description:   "The scopes to request for the access token."
+				relevant_when: "strategy = \"oauth2\""
+				required:      false
+				type: array: {
+					default: []
+					items: type: string: examples: ["logs.write"]
+				}
+			}
 			strategy: {
 				description: "The authentication strategy to use."
 				required:    true
@@ -27,6 +69,15 @@ base: components: sources: http_client: configuration: {
 
 						The bearer token value (OAuth2, JWT, etc.) is passed as-is.
 						"""
+					oauth2: """
+						OAuth2 authentication, using the [client credentials grant][client_credentials].
+
+						An access token is requested from the token endpoint and passed as a bearer token. The
+						token is reused until shortly before it expires, or until a request using it is rejected
+						with a `401 Unauthorized` response.
+
+						[client_credentials]: https://datatracker.ietf.org/doc/html/rfc6749#section-4.4
+						"""
 				}
 			}
 			token: {
@@ -35,6 +86,12 @@ base: components: sources: http_client: configuration: {
 				required:      true
 				type: string: {}
 			}
+			token_endpoint: {
+				description:   "The URL of the token endpoint of the authorization server."
+				relevant_when: "strategy = \"oauth2\""
+				required:      true
+				type: string: examples: ["https://auth.example.com/oauth2/token"]
+			}
 			user: {
 				description:   "The basic authentication username."
 				relevant_when: "strategy = \"basic\""
@@ -43,6 +100,38 @@ base: components: sources: http_client: configuration: {
 			}
 		}
 	}
+	body: {
+		description: """
+			The body of the HTTP requests.
+
+			Any occurrence of `{{ last_run }}` is replaced by the time at which the last successful
+			scrape started, as an RFC 3339 timestamp, so that only new data is requested. When
+			pagination is enabled, a scrape only succeeds once the last page is received, and a scrape
+			stopped by `pagination.max_pages` is continued by the next ones, which keep using the same
+			time. That time is saved in the data directory so that it is kept across restarts, once the
+			events of the scrape are delivered if acknowledgements are enabled. Until a scrape
+			succeeds, the time at which the source first started is used.
+
+			The `Content-Type` header of the requests can be set with the `headers` option.
+			"""
+		required: false
+		type: string: examples: ["{\"query\": {\"range\": {\"@timestamp\": {\"gt\": \"{{ last_run }}\"}}}}"]
+	}
+	data_dir: {
+		description: """
+			The directory used to persist the pagination position, and the time of the last successful
+			scrape.
+
+			By default, the [global `data_dir` option][global_data_dir] is used.
+			Make sure the running user has write permissions to this directory.
+
+			If this directory is specified, then Vector will attempt to create it.
+
+			[global_data_dir]: https://vector.dev/docs/reference/configuration/global-options/#data_dir
+			"""
+		required: false
+		type: string: examples: ["/var/lib/vector"]
+	}
 	decoding: {
 		description: "Decoder to use on the HTTP responses."
 		required:    false
@@ -75,6 +164,34 @@ base: components: sources: http_client: configuration: {
 					}
 				}
 			}
+			cef: {
+				description:   "CEF-specific decoding options."
+				relevant_when: "codec = \"cef\""
+				required:      false
+				type: object: options: {
+					lossy: {
+						description: """
+							Determines whether or not to replace invalid UTF-8 sequences instead of failing.
+
+							When true, invalid UTF-8 sequences are replaced with the [`U+FFFD REPLACEMENT CHARACTER`][U+FFFD].
+
+							[U+FFFD]: https://en.wikipedia.org/wiki/Specials_(Unicode_block)#Replacement_character
+							"""
+						required: false
+						type: bool: default: true
+					}
+					translate_custom_fields: {
+						description: """
+							Whether to name the values of custom extensions after their labels.
+
+							Custom extensions, such as `cs1`, come with a label extension, such as `cs1Label`. When
+							enabled, the pair of extensions is replaced by a single field named after the label.
+							"""
+						required: false
+						type: bool: default: false
+					}
+				}
+			}
 			codec: {
 				description: "The codec to use for decoding events."
 				required:    false
@@ -87,6 +204,22 @@ base: components: sources: http_client: configuration: {
 															[apache_avro]: https://avro.apache.org/
 															"""
 						bytes: "Uses the raw bytes as-is."
+						cef: """
+															Decodes the raw bytes as a [CEF][cef] (Common Event Format) message.
+
+															The header is decoded into the `cefVersion`, `deviceVendor`, `deviceProduct`,
+															`deviceVersion`, `deviceEventClassId`, `name`, and `severity` fields, and each extension
+															into the field named after its key.
+
+															[cef]: https://www.microfocus.com/documentation/arcsight/arcsight-smartconnectors-8.3/cef-implementation-standard/Content/CEF/Chapter%201%20What%20is%20CEF.htm
+															"""
+						csv: """
+															Decodes the raw bytes as [CSV][csv] records.
+
+															Each record of a frame becomes its own event.
+
+															[csv]: https://datatracker.ietf.org/doc/html/rfc4180
+															"""
 						gelf: """
 															Decodes the raw bytes as a [GELF][gelf] message.
 
@@ -115,6 +248,13 @@ base: components: sources: http_client: configuration: {
 
 															[json]: https://www.json.org/
 															"""
+						logfmt: """
+															Decodes the raw bytes as a [logfmt][logfmt] message.
+
+															Values are kept as strings, and keys without a value are set to `true`.
+
+															[logfmt]: https://brandur.org/logfmt
+															"""
 						native: """
 															Decodes the raw bytes as [native Protocol Buffers format][vector_native_protobuf].
 
@@ -136,6 +276,15 @@ base: components: sources: http_client: configuration: {
 
 															[protobuf]: https://protobuf.dev/
 															"""
+						schema_registry: """
+															Decodes the raw bytes as an Avro or Protobuf message in the [Confluent wire format][wire_format].
+
+															The schema of each message is fetched from the [Schema Registry][schema_registry] by the ID
+															that prefixes it, and cached.
+
+															[wire_format]: https://docs.confluent.io/platform/current/schema-registry/fundamentals/serdes-develop/index.html#wire-format
+															[schema_registry]: https://docs.confluent.io/platform/current/schema-registry/index.html
+															"""
 						syslog: """
 															Decodes the raw bytes as a Syslog message.
 
@@ -153,6 +302,69 @@ base: components: sources: http_client: configuration: {
 					}
 				}
 			}
+			csv: {
+				description:   "CSV-specific decoding options."
+				relevant_when: "codec = \"csv\""
+				required:      false
+				type: object: options: {
+					columns: {
+						description: """
+							The names of the columns, in order.
+
+							Each value of a record is stored in the field named after its column. Records with more
+							values than there are columns are rejected, and columns without a value are left out.
+
+							When `has_headers` is enabled, these names take precedence over the ones of the header.
+							"""
+						required: false
+						type: array: {
+							default: []
+							items: type: string: examples: ["timestamp", "message"]
+						}
+					}
+					delimiter: {
+						description: "The field delimiter to use when reading CSV."
+						required:    false
+						type: ascii_char: default: ","
+					}
+					double_quote: {
+						description: """
+							Enable double quote escapes.
+
+							When enabled, two consecutive quotes within a quoted value are read as a single quote.
+							"""
+						required: false
+						type: bool: default: true
+					}
+					escape: {
+						description: """
+							The escape character to use when reading CSV.
+
+							In some variants of CSV, quotes are escaped using a special escape character
+							like \\ (instead of escaping quotes by doubling them).
+
+							To use this, `double_quote` needs to be disabled as well otherwise it is ignored.
+							"""
+						required: false
+						type: ascii_char: default: "\""
+					}
+					has_headers: {
+						description: """
+							Whether the first record of each frame is a header holding the names of the columns.
+
+							This is typically used along with `bytes` framing, so that whole CSV files are decoded
+							at once.
+							"""
+						required: false
+						type: bool: default: false
+					}
+					quote: {
+						description: "The quote character to use when reading CSV."
+						required:    false
+						type: ascii_char: default: "\""
+					}
+				}
+			}
 			gelf: {
 				description:   "GELF-specific decoding options."
 				relevant_when: "codec = \"gelf\""
@@ -234,6 +446,42 @@ base: components: sources: http_client: configuration: {
 					}
 				}
 			}
+			schema_registry: {
+				description:   "Options for the Schema Registry deserializer."
+				relevant_when: "codec = \"schema_registry\""
+				required:      true
+				type: object: options: {
+					auth: {
+						description: "Basic authentication for the Schema Registry."
+						required:    false
+						type: object: options: {
+							password: {
+								description: "The password to authenticate with."
+								required:    true
+								type: string: examples: ["${SCHEMA_REGISTRY_PASSWORD}"]
+							}
+							user: {
+								description: "The username to authenticate with."
+								required:    true
+								type: string: examples: ["${SCHEMA_REGISTRY_USERNAME}"]
+							}
+						}
+					}
+					timeout_secs: {
+						description: "The timeout for requests to the Schema Registry, in seconds."
+						required:    false
+						type: uint: {
+							default: 30
+							unit:    "seconds"
+						}
+					}
+					url: {
+						description: "The URL of the Schema Registry."
+						required:    true
+						type: string: examples: ["http://localhost:8081"]
+					}
+				}
+			}
 			syslog: {
 				description:   "Syslog-specific decoding options."
 				relevant_when: "codec = \"syslog\""
@@ -497,6 +745,86 @@ base: components: sources: http_client: configuration: {
 			}
 		}
 	}
+	pagination: {
+		description: """
+			Pagination of the responses.
+
+			When set, each scrape requests the following pages of the response in turn, and the
+			position of the next page is saved in the data directory so that it is picked up from
+			after a restart. When acknowledgements are enabled, the position is only saved once the
+			events of the page have been delivered.
+
+			Scrapes then run one at a time, and the scrapes due while the previous one is still
+			running are skipped.
+			"""
+		required: false
+		type: object: options: {
+			cursor_parameter: {
+				description:   "The query parameter to pass the cursor in."
+				relevant_when: "strategy = \"body_cursor\""
+				required:      true
+				type: string: examples: ["cursor"]
+			}
+			cursor_path: {
+				description:   "The path of the cursor in the response body."
+				relevant_when: "strategy = \"body_cursor\""
+				required:      true
+				type: string: examples: ["next_cursor", "meta.pagination.next"]
+			}
+			limit: {
+				description:   "The number of items to request per page."
+				relevant_when: "strategy = \"offset\""
+				required:      false
+				type: uint: default: 100
+			}
+			limit_parameter: {
+				description:   "The query parameter to pass the number of items per page in."
+				relevant_when: "strategy = \"offset\""
+				required:      false
+				type: string: default: "limit"
+			}
+			max_pages: {
+				description: """
+					The maximum number of pages to request per scrape.
+
+					Once this many pages have been requested, the next scrape picks up from the following page.
+					"""
+				required: false
+				type: uint: {
+					default: 100
+					unit:    "pages"
+				}
+			}
+			offset_parameter: {
+				description:   "The query parameter to pass the offset of the first item of the page in."
+				relevant_when: "strategy = \"offset\""
+				required:      false
+				type: string: default: "offset"
+			}
+			strategy: {
+				description: "The pagination strategy to use."
+				required:    true
+				type: string: enum: {
+					body_cursor: """
+						Read a cursor from the response body, and pass it as a query parameter of the next request.
+
+						The response body must be a JSON object. Paging stops when the cursor is missing, `null`,
+						or empty.
+						"""
+					link_header: """
+						Follow the URL in the `next` relation of the `Link` response header.
+
+						Paging stops when a response has no such relation.
+						"""
+					offset: """
+						Request successive ranges of items through offset and limit query parameters.
+
+						Paging stops when a response has fewer events than the limit.
+						"""
+				}
+			}
+		}
+	}
 	query: {
 		description: """
 			Custom parameters for the HTTP request query string.
@@ -505,12 +833,16 @@ base: components: sources: http_client: configuration: {
 
 			The parameters provided in this option are appended to any parameters
 			manually provided in the `endpoint` option.
+
+			Values can refer to the time of the last successful scrape, as described for the `body`
+			option.
 			"""
 		required: false
 		type: object: {
 			examples: [{
 				field: ["value"]
 				fruit: ["mango", "papaya", "kiwi"]
+				since: ["{{ last_run }}"]
 			}]
 			options: "*": {
 				description: "A query string parameter and it's value(s)."
@@ -100,7 +100,8 @@ base: components: sources: journald: configuration: {
 		description: """
 			A list of extra command line arguments to pass to `journalctl`.
 
-			If specified, it is merged to the command line arguments as-is.
+			If specified, it is merged to the command line arguments as-is. This is ignored by the
+			`native` reader.
 			"""
 		required: false
 		type: array: {
@@ -147,7 +148,8 @@ base: components: sources: journald: configuration: {
 		description: """
 			The full path of the journal directory.
 
-			If not set, `journalctl` uses the default system journal path.
+			If not set, `journalctl` uses the default system journal path. The `native` reader reads
+			the files of the default journal directories, `/var/log/journal` and `/run/log/journal`.
 			"""
 		required: false
 		type: string: {}
@@ -157,7 +159,9 @@ base: components: sources: journald: configuration: {
 			The [journal namespace][journal-namespace].
 
 			This value is passed to `journalctl` through the [`--namespace` option][journalctl-namespace-option].
-			If not set, `journalctl` uses the default namespace.
+			If not set, `journalctl` uses the default namespace. The `native` reader reads the journal
+			files of the namespace from the default journal directories, unless `journal_directory` is
+			set.
 
 			[journal-namespace]: https://www.freedesktop.org/software/systemd/man/systemd-journald.service.html#Journal%20Namespaces
 			[journalctl-namespace-option]: https://www.freedesktop.org/software/systemd/man/journalctl.html#--namespace=NAMESPACE
@@ -174,6 +178,23 @@ base: components: sources: journald: configuration: {
 		required: false
 		type: string: {}
 	}
+	reader: {
+		description: "The method used to read the journal."
+		required:    false
+		type: string: {
+			default: "journalctl"
+			enum: {
+				journalctl: "Run `journalctl`, and read the entries from its output."
+				native: """
+					Read the journal files directly, without running `journalctl`.
+
+					The journal files are checked for new entries, and the journal directories for newly
+					rotated files, every 250 milliseconds. Journal data compressed with XZ, which `journald`
+					no longer uses by default, is not supported.
+					"""
+			}
+		}
+	}
 	remap_priority: {
 		deprecated:         true
 		deprecated_message: "This option has been deprecated, use the `remap` transform and `to_syslog_level` function instead."
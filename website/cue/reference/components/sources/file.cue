@@ -123,19 +123,17 @@ components: sources: file: {
 			title: "Compressed Files"
 			body: """
 				Vector will transparently detect files which have been compressed
-				using Gzip and decompress them for reading. This detection process
-				looks for the unique sequence of bytes in the Gzip header and does
-				not rely on the compressed files adhering to any kind of naming
-				convention.
-
-				One caveat with reading compressed files is that Vector is not able
-				to efficiently seek into them. Rather than implement a
-				potentially-expensive full scan as a seek mechanism, Vector
-				currently will not attempt to make further reads from a file for
-				which it has already stored a checkpoint in a previous run. For
-				this reason, users should take care to allow Vector to fully
-				process any compressed files before shutting the process down or moving the
-				files to another location on disk.
+				using Gzip, Zstandard, bzip2, or XZ and decompress them for reading.
+				This detection process looks for the unique sequence of bytes in the
+				header of each format and does not rely on the compressed files
+				adhering to any kind of naming convention.
+
+				The checkpoints of compressed files are offsets into their
+				decompressed data. Compressed files can't be seeked into, so when
+				resuming from a checkpoint Vector decompresses the file again up to
+				the checkpointed offset before reading further. Compressed files are
+				not read when `read_from` is `end`, as their end is only known once
+				they have been decompressed.
 				"""
 		}
 
@@ -209,6 +207,11 @@ components: sources: file: {
 				read it uncompressed to identify it, and then ensure it has all of
 				the data, including any written in a gap between Vector's last read
 				and the actual rotation event.
+
+				When a watched file shrinks below the position Vector has read it up
+				to, or its beginning is rewritten while it keeps the same device and
+				inode, Vector reads it again from the beginning and increments the
+				`files_truncated_total` internal metric.
 				"""
 		}
 
@@ -224,6 +227,10 @@ components: sources: file: {
 				This strategy avoids the common pitfalls associated with using device and inode
 				names since inode names can be reused across files. This enables Vector to properly
 				tail files across various rotation strategies.
+
+				The `device_and_inode_checksum` strategy combines both: files are identified by
+				their device and inode along with a checksum of their first lines, so that a new
+				file reusing the inode of a deleted one isn't mistaken for it.
 				"""
 		}
 
@@ -428,6 +435,7 @@ components: sources: file: {
 		files_added_total:     components.sources.internal_metrics.output.metrics.files_added_total
 		files_deleted_total:   components.sources.internal_metrics.output.metrics.files_deleted_total
 		files_resumed_total:   components.sources.internal_metrics.output.metrics.files_resumed_total
+		files_truncated_total: components.sources.internal_metrics.output.metrics.files_truncated_total
 		files_unwatched_total: components.sources.internal_metrics.output.metrics.files_unwatched_total
 	}
 }
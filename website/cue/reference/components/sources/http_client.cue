@@ -13,7 +13,7 @@ components: sources: http_client: {
 	}
 
 	features: {
-		acknowledgements: false
+		acknowledgements: true
 		auto_generated:   true
 		codecs: {
 			enabled:         true
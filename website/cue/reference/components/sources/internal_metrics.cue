@@ -255,6 +255,18 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		buffer_overflowed_bytes_total: {
+			description:       "The number of bytes this buffer sent to its next stage because it was full."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
+		buffer_overflowed_events_total: {
+			description:       "The number of events this buffer sent to its next stage because it was full."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 		buffer_received_event_bytes_total: {
 			description:       "The number of bytes received by this buffer."
 			type:              "counter"
@@ -556,6 +568,14 @@ components: sources: internal_metrics: {
 				file: _file
 			}
 		}
+		files_truncated_total: {
+			description:       "The total number of times Vector has restarted reading a file from its beginning because it was truncated."
+			type:              "counter"
+			default_namespace: "vector"
+			tags: _internal_metrics_tags & {
+				file: _file
+			}
+		}
 		files_unwatched_total: {
 			description:       "The total number of times Vector has stopped watching a file."
 			type:              "counter"
@@ -136,6 +136,10 @@ components: sources: journald: {
 				specify the exact location via the `journalctl_path` option. For more
 				information on this communication strategy please see
 				[issue #1473](\(urls.vector_issues)/1473).
+
+				Alternatively, setting `reader` to `native` makes Vector read the journal
+				files directly, which doesn't require the `journalctl` command to be
+				installed. The journal files must then be readable by the user running Vector.
 				"""
 		}
 		non_ascii: {
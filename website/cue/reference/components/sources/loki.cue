@@ -0,0 +1,128 @@
+package metadata
+
+components: sources: loki: {
+	_port: 3100
+
+	title: "Loki"
+
+	description: """
+		Receives log data pushed by Loki clients such as Promtail and Grafana Agent through the Loki
+		push API.
+		"""
+
+	classes: {
+		commonly_used: false
+		delivery:      "at_least_once"
+		deployment_roles: ["aggregator"]
+		development:   "beta"
+		egress_method: "batch"
+		stateful:      false
+	}
+
+	features: {
+		auto_generated:   true
+		acknowledgements: true
+		multiline: enabled: false
+		receive: {
+			from: {
+				service: services.loki
+
+				interface: socket: {
+					api: {
+						title: "Loki push API"
+						url:   urls.loki_push_api
+					}
+					direction: "incoming"
+					port:      _port
+					protocols: ["http"]
+					ssl: "optional"
+				}
+			}
+
+			tls: {
+				enabled:                true
+				can_verify_certificate: true
+				enabled_default:        false
+			}
+		}
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	installation: {
+		platform_name: null
+	}
+
+	configuration: base.components.sources.loki.configuration
+
+	output: logs: line: {
+		description: "An individual log line from a stream of a push request."
+		fields: {
+			labels: {
+				description: "The labels of the stream the line was pushed in."
+				required:    true
+				type: object: {
+					examples: [{"job": "varlogs", "filename": "/var/log/syslog"}]
+				}
+			}
+			message: {
+				description: "The log line."
+				required:    true
+				type: string: {
+					examples: ["Started GET / for 127.0.0.1"]
+				}
+			}
+			source_type: {
+				description: "The name of the source type."
+				required:    true
+				type: string: {
+					examples: ["loki"]
+				}
+			}
+			structured_metadata: {
+				description: "The structured metadata attached to the line, if any."
+				required:    false
+				type: object: {
+					examples: [{"trace_id": "0242ac120002"}]
+				}
+			}
+			tenant_id: {
+				description: "The tenant ID taken from the `X-Scope-OrgID` header, if set."
+				required:    false
+				type: string: {
+					examples: ["tenant1"]
+				}
+			}
+			timestamp: {
+				description: "The timestamp of the line."
+				required:    true
+				type: timestamp: {}
+			}
+		}
+	}
+
+	how_it_works: {
+		push_api: {
+			title: "Push API"
+			body: """
+				The source accepts push requests on the `/loki/api/v1/push` path, in either of the formats
+				of the [Loki push API](\(urls.loki_push_api)):
+
+				- Snappy-compressed protobuf, which Promtail and Grafana Agent send by default.
+				- JSON, when the request has a `Content-Type: application/json` header.
+
+				Successful requests are answered with a `204 No Content` response, like Loki does.
+				"""
+		}
+	}
+
+	telemetry: metrics: {
+		http_server_handler_duration_seconds: components.sources.internal_metrics.output.metrics.http_server_handler_duration_seconds
+		http_server_requests_received_total:  components.sources.internal_metrics.output.metrics.http_server_requests_received_total
+		http_server_responses_sent_total:     components.sources.internal_metrics.output.metrics.http_server_responses_sent_total
+	}
+}
@@ -0,0 +1,120 @@
+package metadata
+
+components: sources: mqtt: {
+	title: "MQTT"
+
+	features: {
+		auto_generated:   true
+		acknowledgements: true
+		collect: {
+			checkpoint: enabled: false
+			from: {
+				service: services.mqtt
+				interface: {
+					socket: {
+						direction: "outgoing"
+						port:      1883
+						protocols: ["tcp"]
+						ssl: "optional"
+					}
+				}
+			}
+			tls: {
+				enabled:                true
+				can_verify_certificate: true
+				can_verify_hostname:    true
+				enabled_default:        false
+				enabled_by_scheme:      false
+			}
+		}
+		multiline: enabled: false
+		codecs: {
+			enabled:         true
+			default_framing: "bytes"
+		}
+	}
+
+	classes: {
+		commonly_used: false
+		deployment_roles: ["aggregator"]
+		delivery:      "at_least_once"
+		development:   "beta"
+		egress_method: "stream"
+		stateful:      false
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	installation: {
+		platform_name: null
+	}
+
+	configuration: base.components.sources.mqtt.configuration
+
+	output: logs: record: {
+		description: "An individual MQTT message."
+		fields: {
+			message: {
+				description: "The raw payload of the MQTT message."
+				required:    true
+				type: string: {
+					examples: ["{\"line\":\"assembly-3\",\"temperature\":71.5}"]
+				}
+			}
+			qos: {
+				description: "The quality of service the MQTT message was received with."
+				required:    true
+				type: uint: {
+					examples: [0, 1, 2]
+					unit: null
+				}
+			}
+			source_type: {
+				description: "The name of the source type."
+				required:    true
+				type: string: {
+					examples: ["mqtt"]
+				}
+			}
+			timestamp: fields._current_timestamp
+			topic: {
+				description: "The topic the MQTT message was published to."
+				required:    true
+				type: string: {
+					examples: ["sensors/assembly-3/temperature"]
+				}
+			}
+		}
+	}
+
+	how_it_works: {
+		subscriptions: {
+			title: "Subscriptions"
+			body: """
+				The source subscribes to the `topics` filters with the configured
+				`quality_of_service` when it connects to the broker. When `clean_session` is
+				disabled and the broker resumes the session of the `client_id`, the subscriptions
+				kept by the broker are used as they are, along with the messages it queued while
+				Vector was disconnected.
+				"""
+		}
+		acknowledgements: {
+			title: "Acknowledgements"
+			body: """
+				When acknowledgements are enabled, messages received with the `atleastonce` or
+				`exactlyonce` quality of service are only acknowledged to the broker once their
+				events are delivered or rejected, in the order they were received. When their
+				events fail to be delivered, the source reconnects to the broker without
+				acknowledging them, so that the broker delivers them again once the session is
+				resumed, as it also does if Vector stops before they are acknowledged. This requires
+				`clean_session` to be disabled, along with a fixed `client_id`. With a clean
+				session, the messages left unacknowledged are lost, and the delivery is at most
+				once.
+				"""
+		}
+	}
+}
@@ -39,11 +39,7 @@ components: sources: opentelemetry: {
 
 	support: {
 		requirements: []
-		warnings: [
-			"""
-				The `opentelemetry` source only supports log and trace events at this time.
-				""",
-		]
+		warnings: []
 		notices: []
 	}
 
@@ -60,6 +56,12 @@ components: sources: opentelemetry: {
 				Received log events will go to this output stream. Use `<component_id>.logs` as an input to downstream transforms and sinks.
 				"""
 		},
+		{
+			name: "metrics"
+			description: """
+				Received metric events will go to this output stream. Use `<component_id>.metrics` as an input to downstream transforms and sinks.
+				"""
+		},
 		{
 			name: "traces"
 			description: """
@@ -250,6 +252,21 @@ components: sources: opentelemetry: {
 				`/usr/local/ssl/openssl.cnf` or can be specified with the `OPENSSL_CONF` environment variable.
 				"""
 		}
+		metrics: {
+			title: "Ingest OTLP metrics"
+			body: """
+				Each OTLP data point is received as an individual metric event named after its metric. Sums
+				are received as counters when they are monotonic and as gauges otherwise, histograms as
+				aggregated histograms, exponential histograms as native histograms, and summaries as
+				aggregated summaries. Data points with delta temporality are received as incremental
+				metrics, and all others as absolute metrics. Exponential histograms with a scale above 8
+				have their buckets merged down to that scale.
+
+				Resource attributes are added as tags prefixed with `resources.`, the instrumentation scope
+				as the `scope.name` and `scope.version` tags and tags prefixed with `scope.attributes.`, and
+				the data point attributes as tags without a prefix.
+				"""
+		}
 		traces: {
 			title: "Ingest OTLP traces"
 			body: """
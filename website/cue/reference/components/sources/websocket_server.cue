@@ -0,0 +1,129 @@
+package metadata
+
+components: sources: websocket_server: {
+	_port: 8080
+
+	title: "WebSocket Server"
+
+	classes: {
+		commonly_used: false
+		delivery:      "at_least_once"
+		deployment_roles: ["aggregator"]
+		development:   "beta"
+		egress_method: "stream"
+		stateful:      false
+	}
+
+	features: {
+		acknowledgements: true
+		auto_generated:   true
+		multiline: enabled: false
+		codecs: {
+			enabled:         true
+			default_framing: "bytes"
+		}
+		receive: {
+			from: {
+				service: services.websocket
+
+				interface: {
+					socket: {
+						direction: "incoming"
+						port:      _port
+						protocols: ["tcp"]
+						ssl: "optional"
+					}
+				}
+			}
+
+			tls: {
+				enabled:                true
+				can_verify_certificate: true
+				enabled_default:        false
+			}
+		}
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	installation: {
+		platform_name: null
+	}
+
+	configuration: base.components.sources.websocket_server.configuration
+
+	output: logs: line: {
+		description: "An individual message received over a WebSocket connection."
+		fields: {
+			host: {
+				description: "The IP address of the client. The key can be changed using the `host_key` configuration setting."
+				required:    true
+				type: string: {
+					examples: ["192.0.2.10"]
+				}
+			}
+			message: {
+				description: "The raw message received over the connection."
+				required:    true
+				type: string: {
+					examples: ["Hello world"]
+				}
+			}
+			path: {
+				description: "The URL path of the WebSocket handshake request. The key can be changed using the `path_key` configuration setting."
+				required:    true
+				type: string: {
+					examples: ["/", "/telemetry"]
+				}
+			}
+			source_type: {
+				description: "The name of the source type."
+				required:    true
+				type: string: {
+					examples: ["websocket_server"]
+				}
+			}
+			timestamp: fields._current_timestamp
+		}
+	}
+
+	telemetry: metrics: {
+		open_connections: components.sources.internal_metrics.output.metrics.open_connections
+	}
+
+	how_it_works: {
+		messages: {
+			title: "Messages"
+			body: """
+				Each text or binary message received over a connection is decoded on its own
+				according to the `framing` and `decoding` options, so that a message can carry a
+				single event or, with a framing such as `newline_delimited`, several of them.
+				Ping and close frames are answered by the source.
+				"""
+		}
+		authentication: {
+			title: "Authentication"
+			body: """
+				When the `auth` option is set, the WebSocket handshake request must carry the
+				matching HTTP Basic `Authorization` header, otherwise the handshake is refused with
+				a `401 Unauthorized` response.
+				"""
+		}
+		acknowledgement_replies: {
+			title: "Acknowledgement replies"
+			body: """
+				When acknowledgements are enabled, each message received on a connection is answered,
+				in order, with a text message such as `{"seq":1,"status":"delivered"}` once its events
+				have been processed by the sinks. `seq` counts the messages received on the connection,
+				starting at 1, and `status` is either `delivered`, `errored`, or `rejected`. Messages
+				that can't be decoded are `rejected`. Messages keep being read while their
+				acknowledgements are pending, up to 1024 messages per connection, past which no more
+				messages are read from the connection until some of them are acknowledged.
+				"""
+		}
+	}
+}
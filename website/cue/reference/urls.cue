@@ -321,6 +321,7 @@ urls: {
 	log_namespacing_blog:                       "/blog/log-namespacing/"
 	loki:                                       "https://grafana.com/oss/loki/"
 	loki_multi_tenancy:                         "\(github)/grafana/loki/blob/master/docs/operations/multi-tenancy.md"
+	loki_push_api:                              "https://grafana.com/docs/loki/latest/reference/loki-http-api/#ingest-logs"
 	log_event_source:                           "\(vector_repo)/blob/master/src/event/"
 	logplex:                                    "https://devcenter.heroku.com/articles/logplex"
 	logplex_protocol:                           "\(github)/heroku/logplex/blob/master/doc/README.http_drains.md"